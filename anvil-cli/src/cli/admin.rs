@@ -10,10 +10,14 @@ mod audit;
 mod bucket;
 #[path = "admin/cell.rs"]
 mod cell;
+#[path = "admin/cluster.rs"]
+mod cluster;
 #[path = "admin/common.rs"]
 mod common;
 #[path = "admin/diagnostics.rs"]
 mod diagnostics;
+#[path = "admin/gc.rs"]
+mod gc;
 #[path = "admin/host_alias.rs"]
 mod host_alias;
 #[path = "admin/mesh.rs"]
@@ -34,6 +38,8 @@ mod routing;
 mod secret_encryption_key;
 #[path = "admin/storage_class.rs"]
 mod storage_class;
+#[path = "admin/task.rs"]
+mod task;
 #[path = "admin/tenant.rs"]
 mod tenant;
 
@@ -41,7 +47,9 @@ pub use self::app::AppCommands;
 pub use self::audit::AuditCommands;
 pub use self::bucket::BucketCommands;
 pub use self::cell::CellCommands;
+pub use self::cluster::ClusterCommands;
 pub use self::diagnostics::DiagnosticsCommands;
+pub use self::gc::GcCommands;
 pub use self::host_alias::HostAliasCommands;
 pub use self::mesh::MeshCommands;
 pub use self::node::NodeCommands;
@@ -52,13 +60,16 @@ pub use self::repair::RepairCommands;
 pub use self::routing::RoutingCommands;
 pub use self::secret_encryption_key::SecretEncryptionKeyCommands;
 pub use self::storage_class::StorageClassCommands;
+pub use self::task::TaskCommands;
 pub use self::tenant::TenantCommands;
 
 use self::app::handle_app_command;
 use self::audit::handle_audit_command;
 use self::bucket::handle_bucket_command;
 use self::cell::handle_cell_command;
+use self::cluster::handle_cluster_command;
 use self::diagnostics::handle_diagnostics_command;
+use self::gc::handle_gc_command;
 use self::host_alias::handle_host_alias_command;
 use self::mesh::handle_mesh_command;
 use self::node::handle_node_command;
@@ -69,6 +80,7 @@ use self::repair::handle_repair_command;
 use self::routing::handle_routing_command;
 use self::secret_encryption_key::handle_secret_encryption_key_command;
 use self::storage_class::handle_storage_class_command;
+use self::task::handle_task_command;
 use self::tenant::handle_tenant_command;
 
 #[derive(Subcommand)]
@@ -118,6 +130,11 @@ pub enum AdminCommands {
         #[clap(subcommand)]
         command: NodeCommands,
     },
+    /// Cordon or uncordon nodes for new shard placement
+    Cluster {
+        #[clap(subcommand)]
+        command: ClusterCommands,
+    },
     /// Manage mesh regions, cells, nodes and bucket placement through MeshControlService
     Mesh {
         #[clap(subcommand)]
@@ -138,6 +155,16 @@ pub enum AdminCommands {
         #[clap(subcommand)]
         command: RepairCommands,
     },
+    /// Collect orphaned shards left behind by deleted objects
+    Gc {
+        #[clap(subcommand)]
+        command: GcCommands,
+    },
+    /// Inspect and requeue dead-lettered background tasks
+    Task {
+        #[clap(subcommand)]
+        command: TaskCommands,
+    },
     /// List administrative diagnostics
     Diagnostics {
         #[clap(subcommand)]
@@ -185,6 +212,9 @@ pub async fn handle_admin_command(command: &AdminCommands, ctx: &Context) -> any
         AdminCommands::Node { command } => {
             handle_node_command(command, &mut client, &token).await?
         }
+        AdminCommands::Cluster { command } => {
+            handle_cluster_command(command, &mut client, &token).await?
+        }
         AdminCommands::Mesh { command } => handle_mesh_command(command, ctx, &token).await?,
         AdminCommands::HostAlias { command } => {
             handle_host_alias_command(command, &mut client, &token).await?
@@ -195,6 +225,10 @@ pub async fn handle_admin_command(command: &AdminCommands, ctx: &Context) -> any
         AdminCommands::Repair { command } => {
             handle_repair_command(command, &mut client, &token).await?
         }
+        AdminCommands::Gc { command } => handle_gc_command(command, &mut client, &token).await?,
+        AdminCommands::Task { command } => {
+            handle_task_command(command, &mut client, &token).await?
+        }
         AdminCommands::Diagnostics { command } => {
             handle_diagnostics_command(command, &mut client, &token).await?
         }
@@ -1,4 +1,6 @@
-use super::common::{AdminClient, MutationOptions, print_rpc_response, with_auth};
+use super::common::{
+    AdminClient, MutationOptions, parse_duration_secs, print_rpc_response, with_auth,
+};
 use anvil::anvil_api as api;
 use clap::Subcommand;
 
@@ -21,6 +23,9 @@ pub enum AppCommands {
         tenant_id: String,
         #[clap(long)]
         app_name: String,
+        /// Keep the old secret valid for this long (e.g. 90s, 30m, 24h, 7d).
+        #[clap(long, value_parser = parse_duration_secs)]
+        grace: Option<u64>,
     },
 }
 
@@ -55,6 +60,7 @@ pub(super) async fn handle_app_command(
             context,
             tenant_id,
             app_name,
+            grace,
         } => {
             let admin_context = context.to_update_context()?;
             print_rpc_response(
@@ -66,6 +72,7 @@ pub(super) async fn handle_app_command(
                         context: Some(admin_context.clone()),
                         tenant_id: tenant_id.clone(),
                         app_name: app_name.clone(),
+                        grace_secs: grace.unwrap_or_default(),
                     },
                     token,
                 )?),
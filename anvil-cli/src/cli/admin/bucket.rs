@@ -20,6 +20,61 @@ pub enum BucketCommands {
         #[clap(subcommand)]
         command: BucketPublicAccessCommands,
     },
+    /// Cap a bucket's stored bytes and object count; omitted limits are unlimited
+    SetQuota {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        tenant_id: String,
+        #[clap(long)]
+        bucket_name: String,
+        #[clap(long)]
+        max_bytes: Option<u64>,
+        #[clap(long)]
+        max_objects: Option<u64>,
+    },
+    /// Replicate new objects to other regions; pass no --region to stop replicating
+    SetReplication {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        tenant_id: String,
+        #[clap(long)]
+        bucket_name: String,
+        #[clap(long = "region")]
+        regions: Vec<String>,
+    },
+    /// Expire objects by age; pass no --rule to remove the lifecycle rules
+    SetLifecycle {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        tenant_id: String,
+        #[clap(long)]
+        bucket_name: String,
+        /// PREFIX=DAYS, e.g. `tmp/=7`; `=30` covers the whole bucket
+        #[clap(long = "rule", value_parser = parse_lifecycle_rule)]
+        rules: Vec<api::BucketLifecycleRule>,
+    },
+    /// Copy an object between tenants for a migration
+    CopyObject {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        source_tenant_id: String,
+        #[clap(long)]
+        source_bucket_name: String,
+        #[clap(long)]
+        source_object_key: String,
+        #[clap(long)]
+        source_version_id: Option<String>,
+        #[clap(long)]
+        destination_tenant_id: String,
+        #[clap(long)]
+        destination_bucket_name: String,
+        #[clap(long)]
+        destination_object_key: String,
+    },
 }
 #[derive(Subcommand)]
 pub enum BucketPublicAccessCommands {
@@ -91,6 +146,121 @@ pub(super) async fn handle_bucket_command(
             )
             .await?;
         }
+        BucketCommands::SetQuota {
+            context,
+            tenant_id,
+            bucket_name,
+            max_bytes,
+            max_objects,
+        } => {
+            let admin_context = context.to_update_context()?;
+            print_rpc_response(
+                "bucket_quota",
+                Some(&admin_context),
+                None,
+                client.set_bucket_quota_admin(with_auth(
+                    api::SetBucketQuotaAdminRequest {
+                        context: Some(admin_context.clone()),
+                        tenant_id: tenant_id.clone(),
+                        bucket_name: bucket_name.clone(),
+                        max_bytes: *max_bytes,
+                        max_objects: *max_objects,
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
+        BucketCommands::SetReplication {
+            context,
+            tenant_id,
+            bucket_name,
+            regions,
+        } => {
+            let admin_context = context.to_update_context()?;
+            print_rpc_response(
+                "bucket_replication",
+                Some(&admin_context),
+                None,
+                client.set_bucket_replication_admin(with_auth(
+                    api::SetBucketReplicationAdminRequest {
+                        context: Some(admin_context.clone()),
+                        tenant_id: tenant_id.clone(),
+                        bucket_name: bucket_name.clone(),
+                        replicate_to: regions.clone(),
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
+        BucketCommands::SetLifecycle {
+            context,
+            tenant_id,
+            bucket_name,
+            rules,
+        } => {
+            let admin_context = context.to_update_context()?;
+            print_rpc_response(
+                "bucket_lifecycle",
+                Some(&admin_context),
+                None,
+                client.set_bucket_lifecycle_admin(with_auth(
+                    api::SetBucketLifecycleAdminRequest {
+                        context: Some(admin_context.clone()),
+                        tenant_id: tenant_id.clone(),
+                        bucket_name: bucket_name.clone(),
+                        rules: rules.clone(),
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
+        BucketCommands::CopyObject {
+            context,
+            source_tenant_id,
+            source_bucket_name,
+            source_object_key,
+            source_version_id,
+            destination_tenant_id,
+            destination_bucket_name,
+            destination_object_key,
+        } => {
+            let admin_context = context.to_action_context();
+            print_rpc_response(
+                "object_copy",
+                Some(&admin_context),
+                None,
+                client.admin_copy_object(with_auth(
+                    api::AdminCopyObjectRequest {
+                        context: Some(admin_context.clone()),
+                        source_tenant_id: source_tenant_id.clone(),
+                        source_bucket_name: source_bucket_name.clone(),
+                        source_object_key: source_object_key.clone(),
+                        source_version_id: source_version_id.clone(),
+                        destination_tenant_id: destination_tenant_id.clone(),
+                        destination_bucket_name: destination_bucket_name.clone(),
+                        destination_object_key: destination_object_key.clone(),
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
     }
     Ok(())
 }
+
+fn parse_lifecycle_rule(value: &str) -> Result<api::BucketLifecycleRule, String> {
+    let (prefix, days) = value
+        .rsplit_once('=')
+        .ok_or_else(|| format!("invalid lifecycle rule {value:?}; expected PREFIX=DAYS"))?;
+    let expiration_days = days
+        .parse::<u32>()
+        .map_err(|_| format!("invalid expiration days {days:?} in lifecycle rule"))?;
+    Ok(api::BucketLifecycleRule {
+        prefix: prefix.to_string(),
+        expiration_days,
+    })
+}
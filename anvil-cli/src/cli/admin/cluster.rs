@@ -0,0 +1,34 @@
+use super::common::{AdminClient, MutationOptions};
+use super::node::set_node_placement_eligible;
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum ClusterCommands {
+    /// Stop placing new shards on a node while it keeps serving reads
+    Cordon {
+        #[clap(flatten)]
+        context: MutationOptions,
+        node_id: String,
+    },
+    /// Make a cordoned node eligible for new shard placement again
+    Uncordon {
+        #[clap(flatten)]
+        context: MutationOptions,
+        node_id: String,
+    },
+}
+
+pub(super) async fn handle_cluster_command(
+    command: &ClusterCommands,
+    client: &mut AdminClient,
+    token: &str,
+) -> anyhow::Result<()> {
+    match command {
+        ClusterCommands::Cordon { context, node_id } => {
+            set_node_placement_eligible(client, token, context, node_id, false).await
+        }
+        ClusterCommands::Uncordon { context, node_id } => {
+            set_node_placement_eligible(client, token, context, node_id, true).await
+        }
+    }
+}
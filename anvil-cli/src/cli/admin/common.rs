@@ -63,6 +63,23 @@ impl MutationOptions {
         }
     }
 }
+/// Parses a duration such as `90s`, `30m`, `24h`, or `7d` into seconds. A bare
+/// number is taken as seconds.
+pub(super) fn parse_duration_secs(value: &str) -> Result<u64, String> {
+    let (digits, unit_secs) = match value.char_indices().last() {
+        Some((index, 's')) => (&value[..index], 1),
+        Some((index, 'm')) => (&value[..index], 60),
+        Some((index, 'h')) => (&value[..index], 60 * 60),
+        Some((index, 'd')) => (&value[..index], 24 * 60 * 60),
+        _ => (value, 1),
+    };
+    digits
+        .parse::<u64>()
+        .ok()
+        .and_then(|count| count.checked_mul(unit_secs))
+        .ok_or_else(|| format!("invalid duration {value:?}; expected e.g. 90s, 30m, 24h or 7d"))
+}
+
 #[derive(Args, Debug, Clone, Default)]
 pub struct PageOptions {
     #[clap(long)]
@@ -0,0 +1,50 @@
+use super::common::{AdminClient, MutationOptions, print_rpc_response, with_auth};
+use anvil::anvil_api as api;
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum GcCommands {
+    /// Delete orphaned shards on the connected node, or report them with --dry-run
+    Run {
+        #[clap(flatten)]
+        context: MutationOptions,
+        /// Report reclaimable shards and bytes without deleting anything
+        #[clap(long, action = clap::ArgAction::SetTrue)]
+        dry_run: bool,
+        /// Override the node's configured grace period in seconds
+        #[clap(long)]
+        grace_period_secs: Option<u64>,
+    },
+}
+
+pub(super) async fn handle_gc_command(
+    command: &GcCommands,
+    client: &mut AdminClient,
+    token: &str,
+) -> anyhow::Result<()> {
+    match command {
+        GcCommands::Run {
+            context,
+            dry_run,
+            grace_period_secs,
+        } => {
+            let admin_context = context.to_action_context();
+            print_rpc_response(
+                "garbage_collection",
+                Some(&admin_context),
+                None,
+                client.run_garbage_collection(with_auth(
+                    api::RunGarbageCollectionRequest {
+                        context: Some(admin_context.clone()),
+                        dry_run: *dry_run,
+                        grace_period_secs: grace_period_secs.unwrap_or_default(),
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -48,6 +48,11 @@ pub enum NodeCommands {
         #[clap(long, action = clap::ArgAction::SetTrue)]
         force_after_timeout: bool,
     },
+    /// Show how many of a draining node's shard migrations have run
+    DrainStatus {
+        #[clap(long)]
+        node_id: String,
+    },
     /// Force an active or draining node offline
     ForceOffline {
         #[clap(flatten)]
@@ -55,6 +60,20 @@ pub enum NodeCommands {
         #[clap(long)]
         node_id: String,
     },
+    /// Stop placing new shards on a node while it keeps serving reads
+    Cordon {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        node_id: String,
+    },
+    /// Make a cordoned node eligible for new shard placement again
+    Uncordon {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        node_id: String,
+    },
     /// Remove a drained node
     Remove {
         #[clap(flatten)]
@@ -71,6 +90,8 @@ pub enum NodeCommands {
         #[clap(flatten)]
         page: PageOptions,
     },
+    /// List peers in this server's gossip membership, with capacity and liveness
+    Ls,
 }
 #[derive(Copy, Clone, Debug, ValueEnum)]
 pub enum NodeCapabilityArg {
@@ -95,6 +116,31 @@ impl NodeCapabilityArg {
     }
 }
 
+/// Shared by `node cordon/uncordon` and `cluster cordon/uncordon`.
+pub(super) async fn set_node_placement_eligible(
+    client: &mut AdminClient,
+    token: &str,
+    context: &MutationOptions,
+    node_id: &str,
+    placement_eligible: bool,
+) -> anyhow::Result<()> {
+    let admin_context = context.to_update_context()?;
+    print_rpc_response(
+        "node",
+        Some(&admin_context),
+        None,
+        client.set_node_placement_eligible(with_auth(
+            api::SetNodePlacementEligibleRequest {
+                context: Some(admin_context.clone()),
+                node_id: node_id.to_string(),
+                placement_eligible,
+            },
+            token,
+        )?),
+    )
+    .await
+}
+
 pub(super) async fn handle_node_command(
     command: &NodeCommands,
     client: &mut AdminClient,
@@ -196,6 +242,20 @@ pub(super) async fn handle_node_command(
             )
             .await?;
         }
+        NodeCommands::DrainStatus { node_id } => {
+            print_rpc_response(
+                "node_drain_progress",
+                None,
+                None,
+                client.get_node_drain_progress(with_auth(
+                    api::GetNodeDrainProgressRequest {
+                        node_id: node_id.clone(),
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
         NodeCommands::ForceOffline { context, node_id } => {
             let admin_context = context.to_update_context()?;
             print_rpc_response(
@@ -212,6 +272,12 @@ pub(super) async fn handle_node_command(
             )
             .await?;
         }
+        NodeCommands::Cordon { context, node_id } => {
+            set_node_placement_eligible(client, token, context, node_id, false).await?;
+        }
+        NodeCommands::Uncordon { context, node_id } => {
+            set_node_placement_eligible(client, token, context, node_id, true).await?;
+        }
         NodeCommands::Remove { context, node_id } => {
             let admin_context = context.to_update_context()?;
             print_rpc_response(
@@ -248,6 +314,15 @@ pub(super) async fn handle_node_command(
             )
             .await?;
         }
+        NodeCommands::Ls => {
+            print_rpc_response(
+                "peers",
+                None,
+                None,
+                client.get_cluster_state(with_auth(api::GetClusterStateRequest {}, token)?),
+            )
+            .await?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,64 @@
+use super::common::{AdminClient, MutationOptions, PageOptions, print_rpc_response, with_auth};
+use anvil::anvil_api as api;
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum TaskCommands {
+    /// List background tasks, optionally filtered by status
+    Ls {
+        /// pending, running, completed, failed or dead
+        #[clap(long)]
+        status: Option<String>,
+        #[clap(flatten)]
+        page: PageOptions,
+    },
+    /// Return a failed or dead-lettered task to the queue immediately
+    Retry {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        task_id: i64,
+    },
+}
+
+pub(super) async fn handle_task_command(
+    command: &TaskCommands,
+    client: &mut AdminClient,
+    token: &str,
+) -> anyhow::Result<()> {
+    match command {
+        TaskCommands::Ls { status, page } => {
+            print_rpc_response(
+                "tasks",
+                None,
+                None,
+                client.list_tasks(with_auth(
+                    api::ListTasksRequest {
+                        status: status.clone().unwrap_or_default(),
+                        page: page.to_page_request(),
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
+        TaskCommands::Retry { context, task_id } => {
+            let admin_context = context.to_action_context();
+            print_rpc_response(
+                "task",
+                Some(&admin_context),
+                None,
+                client.retry_task(with_auth(
+                    api::RetryTaskRequest {
+                        context: Some(admin_context.clone()),
+                        task_id: *task_id,
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
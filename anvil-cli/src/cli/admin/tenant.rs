@@ -1,4 +1,4 @@
-use super::common::{AdminClient, MutationOptions, print_rpc_response, with_auth};
+use super::common::{AdminClient, MutationOptions, PageOptions, print_rpc_response, with_auth};
 use anvil::anvil_api as api;
 use clap::Subcommand;
 
@@ -13,6 +13,43 @@ pub enum TenantCommands {
         #[clap(long, default_value = "")]
         home_region: String,
     },
+    /// List tenants with their limits
+    List {
+        #[clap(flatten)]
+        page: PageOptions,
+    },
+    /// Delete a tenant that no longer owns buckets or apps
+    Delete {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        tenant_id: String,
+    },
+    /// Override how many buckets a tenant may own
+    SetBucketLimit {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        tenant_id: String,
+        /// Maximum bucket count; zero removes the limit. Omit to restore the
+        /// server default.
+        #[clap(long)]
+        max_buckets: Option<u64>,
+    },
+    /// Throttle a tenant's requests on the gRPC and S3 surfaces
+    SetRateLimit {
+        #[clap(flatten)]
+        context: MutationOptions,
+        #[clap(long)]
+        tenant_id: String,
+        /// Sustained requests per second. Omit to remove the limit.
+        #[clap(long)]
+        requests_per_second: Option<u32>,
+        /// Requests that may be made at once after a quiet period. Defaults to
+        /// the per-second rate.
+        #[clap(long, requires = "requests_per_second")]
+        burst: Option<u32>,
+    },
 }
 
 pub(super) async fn handle_tenant_command(
@@ -42,6 +79,83 @@ pub(super) async fn handle_tenant_command(
             )
             .await?;
         }
+        TenantCommands::List { page } => {
+            print_rpc_response(
+                "tenants",
+                None,
+                None,
+                client.list_tenants(with_auth(
+                    api::ListTenantsRequest {
+                        page: page.to_page_request(),
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
+        TenantCommands::Delete { context, tenant_id } => {
+            let admin_context = context.to_action_context();
+            print_rpc_response(
+                "tenant",
+                Some(&admin_context),
+                None,
+                client.delete_tenant(with_auth(
+                    api::DeleteTenantRequest {
+                        context: Some(admin_context.clone()),
+                        tenant_id: tenant_id.clone(),
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
+        TenantCommands::SetBucketLimit {
+            context,
+            tenant_id,
+            max_buckets,
+        } => {
+            let admin_context = context.to_action_context();
+            print_rpc_response(
+                "tenant",
+                Some(&admin_context),
+                None,
+                client.set_tenant_bucket_limit(with_auth(
+                    api::SetTenantBucketLimitRequest {
+                        context: Some(admin_context.clone()),
+                        tenant_id: tenant_id.clone(),
+                        max_buckets: *max_buckets,
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
+        TenantCommands::SetRateLimit {
+            context,
+            tenant_id,
+            requests_per_second,
+            burst,
+        } => {
+            let admin_context = context.to_action_context();
+            let rate_limit = requests_per_second.map(|requests_per_second| api::TenantRateLimit {
+                requests_per_second,
+                burst: burst.unwrap_or(requests_per_second),
+            });
+            print_rpc_response(
+                "tenant",
+                Some(&admin_context),
+                None,
+                client.set_tenant_rate_limit(with_auth(
+                    api::SetTenantRateLimitRequest {
+                        context: Some(admin_context.clone()),
+                        tenant_id: tenant_id.clone(),
+                        rate_limit,
+                    },
+                    token,
+                )?),
+            )
+            .await?;
+        }
     }
     Ok(())
 }
@@ -592,6 +592,92 @@ fn missing_lifecycle_commands_parse_with_mutation_context() {
     assert_eq!(node_id, "node-a");
 }
 
+#[test]
+fn cluster_cordon_takes_the_node_id_as_an_argument() {
+    let cli = TestAdminCli::try_parse_from([
+        "admin",
+        "cluster",
+        "cordon",
+        "node-a",
+        "--audit-reason",
+        "disk replacement",
+        "--expected-generation",
+        "7",
+    ])
+    .unwrap();
+    let AdminCommands::Cluster {
+        command: ClusterCommands::Cordon { context, node_id },
+    } = cli.command
+    else {
+        panic!("expected cluster cordon command");
+    };
+    assert_eq!(node_id, "node-a");
+    assert_eq!(context.audit_reason, "disk replacement");
+    assert_eq!(context.expected_generation, Some(7));
+
+    assert!(matches!(
+        TestAdminCli::try_parse_from([
+            "admin",
+            "cluster",
+            "uncordon",
+            "node-a",
+            "--audit-reason",
+            "done"
+        ])
+        .unwrap()
+        .command,
+        AdminCommands::Cluster {
+            command: ClusterCommands::Uncordon { .. }
+        }
+    ));
+}
+
+#[test]
+fn node_ls_parses_without_arguments() {
+    let cli = TestAdminCli::try_parse_from(["admin", "node", "ls"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        AdminCommands::Node {
+            command: NodeCommands::Ls
+        }
+    ));
+}
+
+#[test]
+fn task_commands_parse_status_filter_and_task_id() {
+    let ls_cli = TestAdminCli::try_parse_from([
+        "admin", "task", "ls", "--status", "failed", "--limit", "10",
+    ])
+    .unwrap();
+    let AdminCommands::Task {
+        command: TaskCommands::Ls { status, page },
+    } = ls_cli.command
+    else {
+        panic!("expected task ls command");
+    };
+    assert_eq!(status.as_deref(), Some("failed"));
+    assert_eq!(page.limit, Some(10));
+
+    let retry_cli = TestAdminCli::try_parse_from([
+        "admin",
+        "task",
+        "retry",
+        "--audit-reason",
+        "upstream fixed",
+        "--task-id",
+        "42",
+    ])
+    .unwrap();
+    let AdminCommands::Task {
+        command: TaskCommands::Retry { context, task_id },
+    } = retry_cli.command
+    else {
+        panic!("expected task retry command");
+    };
+    assert_eq!(context.audit_reason, "upstream fixed");
+    assert_eq!(task_id, 42);
+}
+
 #[test]
 fn routing_commands_parse_family_and_mutation_context() {
     let list_cli = TestAdminCli::try_parse_from([
@@ -788,6 +874,27 @@ fn tenant_app_and_bucket_admin_commands_parse() {
     assert_eq!(name, "acme");
     assert_eq!(home_region, "eu-west-1");
 
+    let delete_cli = TestAdminCli::try_parse_from([
+        "admin",
+        "tenant",
+        "delete",
+        "--audit-reason",
+        "offboard tenant",
+        "--expected-generation",
+        "0",
+        "--tenant-id",
+        "acme",
+    ])
+    .unwrap();
+    let AdminCommands::Tenant {
+        command: TenantCommands::Delete { context, tenant_id },
+    } = delete_cli.command
+    else {
+        panic!("expected tenant delete command");
+    };
+    assert_eq!(context.audit_reason, "offboard tenant");
+    assert_eq!(tenant_id, "acme");
+
     let app_cli = TestAdminCli::try_parse_from([
         "admin",
         "app",
@@ -800,6 +907,8 @@ fn tenant_app_and_bucket_admin_commands_parse() {
         "acme",
         "--app-name",
         "publisher",
+        "--grace",
+        "24h",
     ])
     .unwrap();
     let AdminCommands::App {
@@ -808,6 +917,7 @@ fn tenant_app_and_bucket_admin_commands_parse() {
                 context,
                 tenant_id,
                 app_name,
+                grace,
             },
     } = app_cli.command
     else {
@@ -816,6 +926,25 @@ fn tenant_app_and_bucket_admin_commands_parse() {
     assert_eq!(context.expected_generation, Some(1));
     assert_eq!(tenant_id, "acme");
     assert_eq!(app_name, "publisher");
+    assert_eq!(grace, Some(24 * 60 * 60));
+    assert!(
+        TestAdminCli::try_parse_from([
+            "admin",
+            "app",
+            "rotate-secret",
+            "--audit-reason",
+            "rotate app",
+            "--expected-generation",
+            "1",
+            "--tenant-id",
+            "acme",
+            "--app-name",
+            "publisher",
+            "--grace",
+            "1w",
+        ])
+        .is_err()
+    );
 
     let bucket_cli = TestAdminCli::try_parse_from([
         "admin",
@@ -853,6 +982,114 @@ fn tenant_app_and_bucket_admin_commands_parse() {
     assert_eq!(tenant_id, "acme");
     assert_eq!(bucket_name, "releases");
     assert!(allow);
+
+    let quota_cli = TestAdminCli::try_parse_from([
+        "admin",
+        "bucket",
+        "set-quota",
+        "--audit-reason",
+        "cap releases",
+        "--expected-generation",
+        "2",
+        "--tenant-id",
+        "acme",
+        "--bucket-name",
+        "releases",
+        "--max-bytes",
+        "1048576",
+    ])
+    .unwrap();
+    let AdminCommands::Bucket {
+        command:
+            BucketCommands::SetQuota {
+                max_bytes,
+                max_objects,
+                ..
+            },
+    } = quota_cli.command
+    else {
+        panic!("expected bucket set-quota command");
+    };
+    assert_eq!(max_bytes, Some(1_048_576));
+    assert_eq!(max_objects, None);
+
+    let replication_cli = TestAdminCli::try_parse_from([
+        "admin",
+        "bucket",
+        "set-replication",
+        "--audit-reason",
+        "geo copy",
+        "--expected-generation",
+        "3",
+        "--tenant-id",
+        "acme",
+        "--bucket-name",
+        "releases",
+        "--region",
+        "eu-west-1",
+        "--region",
+        "ap-south-1",
+    ])
+    .unwrap();
+    let AdminCommands::Bucket {
+        command: BucketCommands::SetReplication { regions, .. },
+    } = replication_cli.command
+    else {
+        panic!("expected bucket set-replication command");
+    };
+    assert_eq!(regions, ["eu-west-1", "ap-south-1"]);
+}
+
+#[test]
+fn bucket_set_lifecycle_parses_prefix_rules() {
+    let cli = TestAdminCli::try_parse_from([
+        "admin",
+        "bucket",
+        "set-lifecycle",
+        "--audit-reason",
+        "expire scratch data",
+        "--expected-generation",
+        "4",
+        "--tenant-id",
+        "acme",
+        "--bucket-name",
+        "scratch",
+        "--rule",
+        "tmp/=7",
+        "--rule",
+        "=365",
+    ])
+    .unwrap();
+    let AdminCommands::Bucket {
+        command: BucketCommands::SetLifecycle { rules, .. },
+    } = cli.command
+    else {
+        panic!("expected bucket set-lifecycle command");
+    };
+    assert_eq!(
+        rules
+            .iter()
+            .map(|rule| (rule.prefix.as_str(), rule.expiration_days))
+            .collect::<Vec<_>>(),
+        [("tmp/", 7), ("", 365)]
+    );
+
+    assert!(
+        TestAdminCli::try_parse_from([
+            "admin",
+            "bucket",
+            "set-lifecycle",
+            "--audit-reason",
+            "expire scratch data",
+            "--tenant-id",
+            "acme",
+            "--bucket-name",
+            "scratch",
+            "--rule",
+            "tmp/",
+        ])
+        .is_err()
+    );
 }
 
 #[tokio::test]
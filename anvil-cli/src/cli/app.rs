@@ -31,10 +31,13 @@ pub async fn handle_app_command(command: &AppCommands, ctx: &Context) -> anyhow:
                 .create_application_credential(request)
                 .await?
                 .into_inner();
-            println!(
-                "app_id={}\napp_name={}\nclient_id={}\nclient_secret={}",
-                response.app_id, response.app_name, response.client_id, response.client_secret
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "app_id={}\napp_name={}\nclient_id={}\nclient_secret={}",
+                    response.app_id, response.app_name, response.client_id, response.client_secret
+                ),
+            )?;
         }
         AppCommands::RotateSecret { app_name } => {
             let mut request = tonic::Request::new(api::RotateApplicationCredentialSecretRequest {
@@ -47,10 +50,13 @@ pub async fn handle_app_command(command: &AppCommands, ctx: &Context) -> anyhow:
                 .rotate_application_credential_secret(request)
                 .await?
                 .into_inner();
-            println!(
-                "app_id={}\napp_name={}\nclient_id={}\nclient_secret={}",
-                response.app_id, response.app_name, response.client_id, response.client_secret
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "app_id={}\napp_name={}\nclient_id={}\nclient_secret={}",
+                    response.app_id, response.app_name, response.client_id, response.client_secret
+                ),
+            )?;
         }
         AppCommands::Delete { app_name } => {
             let mut request = tonic::Request::new(api::DeleteApplicationCredentialRequest {
@@ -63,15 +69,18 @@ pub async fn handle_app_command(command: &AppCommands, ctx: &Context) -> anyhow:
                 .delete_application_credential(request)
                 .await?
                 .into_inner();
-            println!("deleted app_id={}", response.app_id);
+            ctx.print(&response, format!("deleted app_id={}", response.app_id))?;
         }
         AppCommands::List => {
             let mut request = tonic::Request::new(api::ListApplicationsRequest {});
             add_auth(&mut request, &token);
             let response = client.list_applications(request).await?.into_inner();
-            for app in response.applications {
-                println!("{}\t{}\t{}", app.app_id, app.app_name, app.client_id);
-            }
+            let lines = response
+                .applications
+                .iter()
+                .map(|app| format!("{}\t{}\t{}", app.app_id, app.app_name, app.client_id))
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
     }
 
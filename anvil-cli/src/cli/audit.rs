@@ -44,19 +44,28 @@ pub async fn handle_audit_command(command: &AuditCommands, ctx: &Context) -> any
                 .metadata_mut()
                 .insert("authorization", format!("Bearer {token}").parse().unwrap());
             let response = client.list_tenant_audit_events(request).await?.into_inner();
-            for event in response.events {
-                println!(
-                    "{}\t{}\t{}\t{}\t{}",
-                    event.created_at,
-                    event.principal_id,
-                    event.action,
-                    event.resource_id,
-                    event.audit_event_id
-                );
-            }
-            if let Some(page) = response.page.filter(|page| !page.next_cursor.is_empty()) {
-                println!("next_cursor={}", page.next_cursor);
+            let mut lines = response
+                .events
+                .iter()
+                .map(|event| {
+                    format!(
+                        "{}\t{}\t{}\t{}\t{}",
+                        event.created_at,
+                        event.principal_id,
+                        event.action,
+                        event.resource_id,
+                        event.audit_event_id
+                    )
+                })
+                .collect::<Vec<_>>();
+            if let Some(page) = response
+                .page
+                .as_ref()
+                .filter(|page| !page.next_cursor.is_empty())
+            {
+                lines.push(format!("next_cursor={}", page.next_cursor));
             }
+            ctx.print(&response, lines.join("\n"))?;
         }
     }
     Ok(())
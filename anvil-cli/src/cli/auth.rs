@@ -13,6 +13,9 @@ pub enum AuthCommands {
         client_id: Option<String>,
         #[clap(long)]
         client_secret: Option<String>,
+        /// Request a token that expires sooner than the server's configured lifetime
+        #[clap(long)]
+        ttl_secs: Option<u64>,
     },
     /// Grant a permission to another app
     Grant {
@@ -41,6 +44,7 @@ pub async fn handle_auth_command(command: &AuthCommands, ctx: &Context) -> anyho
         AuthCommands::GetToken {
             client_id,
             client_secret,
+            ttl_secs,
         } => {
             let (id, secret) = match (client_id.as_ref(), client_secret.as_ref()) {
                 (Some(id), Some(secret)) => (id.clone(), secret.clone()),
@@ -61,14 +65,15 @@ pub async fn handle_auth_command(command: &AuthCommands, ctx: &Context) -> anyho
                 c.get_access_token(api::GetAccessTokenRequest {
                     client_id: id,
                     client_secret: secret,
+                    requested_ttl_secs: ttl_secs.unwrap_or_default(),
                 }),
             )
             .await
             .map_err(|_| anyhow::anyhow!("get-token request timed out"))??;
-            let token = resp.into_inner().access_token;
+            let response = resp.into_inner();
             // Explicitly drop client before printing/exiting to tear down h2 cleanly
             drop(c);
-            println!("{}", token);
+            ctx.print(&response, &response.access_token)?;
         }
         AuthCommands::Grant {
             app,
@@ -86,7 +91,15 @@ pub async fn handle_auth_command(command: &AuthCommands, ctx: &Context) -> anyho
                 format!("Bearer {}", token).parse().unwrap(),
             );
             client.grant_access(request).await?;
-            println!("Permission granted.");
+            ctx.print(
+                &serde_json::json!({
+                    "granted": true,
+                    "app": app,
+                    "action": action,
+                    "resource": resource,
+                }),
+                "Permission granted.",
+            )?;
         }
         AuthCommands::Revoke {
             app,
@@ -104,7 +117,15 @@ pub async fn handle_auth_command(command: &AuthCommands, ctx: &Context) -> anyho
                 format!("Bearer {}", token).parse().unwrap(),
             );
             client.revoke_access(request).await?;
-            println!("Permission revoked.");
+            ctx.print(
+                &serde_json::json!({
+                    "revoked": true,
+                    "app": app,
+                    "action": action,
+                    "resource": resource,
+                }),
+                "Permission revoked.",
+            )?;
         }
         AuthCommands::ListGrants { app } => {
             let token = ctx.get_bearer_token().await?;
@@ -115,9 +136,12 @@ pub async fn handle_auth_command(command: &AuthCommands, ctx: &Context) -> anyho
                 format!("Bearer {}", token).parse().unwrap(),
             );
             let response = client.list_access_grants(request).await?.into_inner();
-            for grant in response.grants {
-                println!("{}\t{}\t{}", grant.app_name, grant.action, grant.resource);
-            }
+            let lines = response
+                .grants
+                .iter()
+                .map(|grant| format!("{}\t{}\t{}", grant.app_name, grant.action, grant.resource))
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
     }
 
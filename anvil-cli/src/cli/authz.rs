@@ -140,11 +140,14 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.put_authz_schema(request).await?.into_inner();
-            let schema_ref = response.schema_ref.unwrap_or_default();
-            println!(
-                "{}\t{}\t{}",
-                schema_ref.schema_id, schema_ref.schema_revision, schema_ref.schema_digest
-            );
+            let schema_ref = response.schema_ref.clone().unwrap_or_default();
+            ctx.print(
+                &response,
+                format!(
+                    "{}\t{}\t{}",
+                    schema_ref.schema_id, schema_ref.schema_revision, schema_ref.schema_digest
+                ),
+            )?;
         }
         AuthzCommands::Schema {
             command:
@@ -169,10 +172,13 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.bind_authz_schema(request).await?.into_inner();
-            println!(
-                "binding_generation={} zookie={}",
-                response.binding_generation, response.zookie
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "binding_generation={} zookie={}",
+                    response.binding_generation, response.zookie
+                ),
+            )?;
         }
         AuthzCommands::Schema {
             command:
@@ -194,11 +200,14 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.get_authz_schema(request).await?.into_inner();
-            println!(
-                "namespaces={} version={}",
-                response.namespaces.len(),
-                response.schema_version
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "namespaces={} version={}",
+                    response.namespaces.len(),
+                    response.schema_version
+                ),
+            )?;
         }
         AuthzCommands::Schema {
             command: SchemaCommands::Binding { realm_id },
@@ -208,11 +217,14 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.get_authz_schema_binding(request).await?.into_inner();
-            let schema_ref = response.schema_ref.unwrap_or_default();
-            println!(
-                "{}\t{}\t{}",
-                schema_ref.schema_id, schema_ref.schema_revision, response.binding_generation
-            );
+            let schema_ref = response.schema_ref.clone().unwrap_or_default();
+            ctx.print(
+                &response,
+                format!(
+                    "{}\t{}\t{}",
+                    schema_ref.schema_id, schema_ref.schema_revision, response.binding_generation
+                ),
+            )?;
         }
         AuthzCommands::Tuple {
             command:
@@ -240,7 +252,10 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.write_authz_tuple(request).await?.into_inner();
-            println!("revision={} zookie={}", response.revision, response.zookie);
+            ctx.print(
+                &response,
+                format!("revision={} zookie={}", response.revision, response.zookie),
+            )?;
         }
         AuthzCommands::Tuple {
             command:
@@ -269,19 +284,24 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.read_authz_tuples(request).await?.into_inner();
-            for tuple in response.tuples {
-                println!(
-                    "{}:{}#{} <- {}:{}",
-                    tuple.namespace,
-                    tuple.object_id,
-                    tuple.relation,
-                    tuple.subject_kind,
-                    tuple.subject_id
-                );
-            }
+            let mut lines = response
+                .tuples
+                .iter()
+                .map(|tuple| {
+                    format!(
+                        "{}:{}#{} <- {}:{}",
+                        tuple.namespace,
+                        tuple.object_id,
+                        tuple.relation,
+                        tuple.subject_kind,
+                        tuple.subject_id
+                    )
+                })
+                .collect::<Vec<_>>();
             if !response.next_page_token.is_empty() {
-                println!("next_page_token={}", response.next_page_token);
+                lines.push(format!("next_page_token={}", response.next_page_token));
             }
+            ctx.print(&response, lines.join("\n"))?;
         }
         AuthzCommands::Check {
             namespace,
@@ -303,10 +323,13 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.check_permission(request).await?.into_inner();
-            println!(
-                "allowed={} revision={} zookie={}",
-                response.allowed, response.revision, response.zookie
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "allowed={} revision={} zookie={}",
+                    response.allowed, response.revision, response.zookie
+                ),
+            )?;
         }
         AuthzCommands::ListObjects {
             namespace,
@@ -330,9 +353,7 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.list_authz_objects(request).await?.into_inner();
-            for object_id in response.object_ids {
-                println!("{object_id}");
-            }
+            ctx.print(&response, response.object_ids.join("\n"))?;
         }
         AuthzCommands::ListSubjects {
             namespace,
@@ -355,9 +376,12 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.list_authz_subjects(request).await?.into_inner();
-            for subject in response.subjects {
-                println!("{}:{}", subject.subject_kind, subject.subject_id);
-            }
+            let lines = response
+                .subjects
+                .iter()
+                .map(|subject| format!("{}:{}", subject.subject_kind, subject.subject_id))
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
         AuthzCommands::Watch {
             namespace,
@@ -372,10 +396,17 @@ pub async fn handle_authz_command(command: &AuthzCommands, ctx: &Context) -> any
             let mut stream = client.watch_authz_tuple_log(request).await?.into_inner();
             while let Some(item) = stream.next().await {
                 let item = item?;
-                println!(
-                    "{}\t{}\t{}:{}#{}",
-                    item.revision, item.operation, item.namespace, item.object_id, item.relation
-                );
+                ctx.print(
+                    &item,
+                    format!(
+                        "{}\t{}\t{}:{}#{}",
+                        item.revision,
+                        item.operation,
+                        item.namespace,
+                        item.object_id,
+                        item.relation
+                    ),
+                )?;
             }
         }
     }
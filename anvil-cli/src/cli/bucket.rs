@@ -49,8 +49,8 @@ pub async fn handle_bucket_command(command: &BucketCommands, ctx: &Context) -> a
                 "authorization",
                 format!("Bearer {}", token).parse().unwrap(),
             );
-            client.create_bucket(request).await?;
-            println!("Bucket {} created", name);
+            let response = client.create_bucket(request).await?.into_inner();
+            ctx.print(&response, format!("Bucket {} created", name))?;
         }
         BucketCommands::Rm {
             name,
@@ -64,8 +64,8 @@ pub async fn handle_bucket_command(command: &BucketCommands, ctx: &Context) -> a
                 "authorization",
                 format!("Bearer {}", token).parse().unwrap(),
             );
-            client.delete_bucket(request).await?;
-            println!("Bucket {} deleted", name);
+            let response = client.delete_bucket(request).await?.into_inner();
+            ctx.print(&response, format!("Bucket {} deleted", name))?;
         }
         BucketCommands::Ls => {
             let mut request = tonic::Request::new(api::ListBucketsRequest {});
@@ -73,10 +73,13 @@ pub async fn handle_bucket_command(command: &BucketCommands, ctx: &Context) -> a
                 "authorization",
                 format!("Bearer {}", token).parse().unwrap(),
             );
-            let resp = client.list_buckets(request).await?;
-            for bucket in resp.into_inner().buckets {
-                println!("{}\t{}", bucket.name, bucket.creation_date);
-            }
+            let resp = client.list_buckets(request).await?.into_inner();
+            let lines = resp
+                .buckets
+                .iter()
+                .map(|bucket| format!("{}\t{}", bucket.name, bucket.creation_date))
+                .collect::<Vec<_>>();
+            ctx.print(&resp, lines.join("\n"))?;
         }
         BucketCommands::SetPublic {
             name,
@@ -92,8 +95,11 @@ pub async fn handle_bucket_command(command: &BucketCommands, ctx: &Context) -> a
                 "authorization",
                 format!("Bearer {}", token).parse().unwrap(),
             );
-            client.put_bucket_policy(request).await?;
-            println!("Public access for bucket {} set to {}", name, allow);
+            let response = client.put_bucket_policy(request).await?.into_inner();
+            ctx.print(
+                &response,
+                format!("Public access for bucket {} set to {}", name, allow),
+            )?;
         }
     }
     Ok(())
@@ -41,17 +41,18 @@ pub async fn handle_diagnostics_command(
             request
                 .metadata_mut()
                 .insert("authorization", format!("Bearer {token}").parse().unwrap());
-            for diagnostic in client
-                .list_index_diagnostics(request)
-                .await?
-                .into_inner()
+            let response = client.list_index_diagnostics(request).await?.into_inner();
+            let lines = response
                 .diagnostics
-            {
-                println!(
-                    "{}\t{}\t{}\t{}",
-                    diagnostic.cursor, diagnostic.severity, diagnostic.code, diagnostic.message
-                );
-            }
+                .iter()
+                .map(|diagnostic| {
+                    format!(
+                        "{}\t{}\t{}\t{}",
+                        diagnostic.cursor, diagnostic.severity, diagnostic.code, diagnostic.message
+                    )
+                })
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
     }
     Ok(())
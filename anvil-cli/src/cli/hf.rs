@@ -49,6 +49,9 @@ pub enum HfIngestCommands {
         repo: String,
         #[clap(long)]
         revision: Option<String>,
+        /// Repository type on the Hub
+        #[clap(long, default_value = "model", value_parser = ["model", "dataset", "space"])]
+        repo_type: String,
         #[clap(long)]
         bucket: String,
         #[clap(long)]
@@ -70,6 +73,14 @@ pub enum HfIngestCommands {
         #[clap(long)]
         id: String,
     },
+    /// List files of an ingestion with their state and error
+    Items {
+        #[clap(long)]
+        id: String,
+        /// Only list items in this state
+        #[clap(long, value_parser = ["queued", "downloading", "stored", "failed", "skipped"])]
+        state: Option<String>,
+    },
 }
 
 pub async fn handle_hf_command(command: &HfCommands, ctx: &Context) -> anyhow::Result<()> {
@@ -92,8 +103,8 @@ pub async fn handle_hf_command(command: &HfCommands, ctx: &Context) -> anyhow::R
                             .parse()
                             .unwrap(),
                     );
-                    let resp = client.create_key(request).await?;
-                    println!("created key: {}", resp.into_inner().name);
+                    let resp = client.create_key(request).await?.into_inner();
+                    ctx.print(&resp, format!("created key: {}", resp.name))?;
                 }
                 HfKeyCommands::Ls => {
                     let mut request = tonic::Request::new(api::ListHfKeysRequest {});
@@ -101,10 +112,13 @@ pub async fn handle_hf_command(command: &HfCommands, ctx: &Context) -> anyhow::R
                         "authorization",
                         format!("Bearer {}", token).parse().unwrap(),
                     );
-                    let resp = client.list_keys(request).await?;
-                    for k in resp.into_inner().keys {
-                        println!("{}\t{}", k.name, k.updated_at);
-                    }
+                    let resp = client.list_keys(request).await?.into_inner();
+                    let lines = resp
+                        .keys
+                        .iter()
+                        .map(|k| format!("{}\t{}", k.name, k.updated_at))
+                        .collect::<Vec<_>>();
+                    ctx.print(&resp, lines.join("\n"))?;
                 }
                 HfKeyCommands::Rm { name } => {
                     let mut request =
@@ -114,7 +128,10 @@ pub async fn handle_hf_command(command: &HfCommands, ctx: &Context) -> anyhow::R
                         format!("Bearer {}", token).parse().unwrap(),
                     );
                     client.delete_key(request).await?;
-                    println!("deleted key: {}", name);
+                    ctx.print(
+                        &serde_json::json!({ "deleted": true, "name": name }),
+                        format!("deleted key: {}", name),
+                    )?;
                 }
             }
         }
@@ -126,6 +143,7 @@ pub async fn handle_hf_command(command: &HfCommands, ctx: &Context) -> anyhow::R
                     key,
                     repo,
                     revision,
+                    repo_type,
                     bucket,
                     target_region,
                     prefix,
@@ -141,13 +159,14 @@ pub async fn handle_hf_command(command: &HfCommands, ctx: &Context) -> anyhow::R
                         include_globs: include.clone(),
                         exclude_globs: exclude.clone(),
                         target_region: target_region.clone(),
+                        repo_type: repo_type.clone(),
                     });
                     request.metadata_mut().insert(
                         "authorization",
                         format!("Bearer {}", token).parse().unwrap(),
                     );
-                    let resp = client.start_ingestion(request).await?;
-                    println!("ingestion id: {}", resp.into_inner().ingestion_id);
+                    let resp = client.start_ingestion(request).await?.into_inner();
+                    ctx.print(&resp, format!("ingestion id: {}", resp.ingestion_id))?;
                 }
                 HfIngestCommands::Status { id } => {
                     let mut request = tonic::Request::new(api::GetHfIngestionStatusRequest {
@@ -159,10 +178,26 @@ pub async fn handle_hf_command(command: &HfCommands, ctx: &Context) -> anyhow::R
                     );
                     let resp = client.get_ingestion_status(request).await?;
                     let s = resp.into_inner();
-                    println!(
-                        "state={} queued={} downloading={} stored={} failed={} error={}",
-                        s.state, s.queued, s.downloading, s.stored, s.failed, s.error
-                    );
+                    let percent = if s.bytes_total > 0 {
+                        s.bytes_done as f64 * 100.0 / s.bytes_total as f64
+                    } else {
+                        0.0
+                    };
+                    ctx.print(
+                        &s,
+                        format!(
+                            "state={} queued={} downloading={} stored={} failed={} bytes={}/{} ({:.1}%) error={}",
+                            s.state,
+                            s.queued,
+                            s.downloading,
+                            s.stored,
+                            s.failed,
+                            s.bytes_done,
+                            s.bytes_total,
+                            percent,
+                            s.error
+                        ),
+                    )?;
                 }
                 HfIngestCommands::Cancel { id } => {
                     let mut request = tonic::Request::new(api::CancelHfIngestionRequest {
@@ -173,7 +208,30 @@ pub async fn handle_hf_command(command: &HfCommands, ctx: &Context) -> anyhow::R
                         format!("Bearer {}", token).parse().unwrap(),
                     );
                     client.cancel_ingestion(request).await?;
-                    println!("canceled: {}", id);
+                    ctx.print(
+                        &serde_json::json!({ "canceled": true, "ingestion_id": id }),
+                        format!("canceled: {}", id),
+                    )?;
+                }
+                HfIngestCommands::Items { id, state } => {
+                    let mut request = tonic::Request::new(api::GetHfIngestionItemsRequest {
+                        ingestion_id: id.clone(),
+                        state: state.clone().unwrap_or_default(),
+                    });
+                    request.metadata_mut().insert(
+                        "authorization",
+                        format!("Bearer {}", token).parse().unwrap(),
+                    );
+                    let resp = client.get_ingestion_items(request).await?.into_inner();
+                    let lines = resp
+                        .items
+                        .iter()
+                        .map(|item| {
+                            let size = item.size.map(|size| size.to_string()).unwrap_or_default();
+                            format!("{}\t{}\t{}\t{}", item.path, item.state, size, item.error)
+                        })
+                        .collect::<Vec<_>>();
+                    ctx.print(&resp, lines.join("\n"))?;
                 }
             }
         }
@@ -73,12 +73,13 @@ pub async fn handle_host_alias_command(
             });
             attach_auth(&mut request, &token)?;
             print_host_alias(
+                ctx,
                 client
                     .create_host_alias(request)
                     .await?
                     .into_inner()
                     .host_alias,
-            );
+            )?;
         }
         HostAliasCommands::Read { hostname } => {
             let mut request = tonic::Request::new(api::ReadHostAliasRequest {
@@ -87,12 +88,13 @@ pub async fn handle_host_alias_command(
             });
             attach_auth(&mut request, &token)?;
             print_host_alias(
+                ctx,
                 client
                     .read_host_alias(request)
                     .await?
                     .into_inner()
                     .host_alias,
-            );
+            )?;
         }
         HostAliasCommands::Verify {
             hostname,
@@ -111,12 +113,13 @@ pub async fn handle_host_alias_command(
             });
             attach_auth(&mut request, &token)?;
             print_host_alias(
+                ctx,
                 client
                     .verify_host_alias(request)
                     .await?
                     .into_inner()
                     .host_alias,
-            );
+            )?;
         }
         HostAliasCommands::List { region, limit } => {
             let mut request = tonic::Request::new(api::ListHostAliasesRequest {
@@ -128,16 +131,21 @@ pub async fn handle_host_alias_command(
             });
             attach_auth(&mut request, &token)?;
             let response = client.list_host_aliases(request).await?.into_inner();
-            for alias in response.host_aliases {
-                println!(
-                    "{}\t{}\t{}\t{}\t{}",
-                    alias.hostname,
-                    alias.bucket_name,
-                    alias.region,
-                    host_alias_state_name(alias.state),
-                    alias.generation
-                );
-            }
+            let lines = response
+                .host_aliases
+                .iter()
+                .map(|alias| {
+                    format!(
+                        "{}\t{}\t{}\t{}\t{}",
+                        alias.hostname,
+                        alias.bucket_name,
+                        alias.region,
+                        host_alias_state_name(alias.state),
+                        alias.generation
+                    )
+                })
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
         HostAliasCommands::Delete {
             hostname,
@@ -154,10 +162,13 @@ pub async fn handle_host_alias_command(
             });
             attach_auth(&mut request, &token)?;
             let response = client.delete_host_alias(request).await?.into_inner();
-            println!(
-                "Deleted {} at generation {}",
-                response.resource_id, response.generation
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "Deleted {} at generation {}",
+                    response.resource_id, response.generation
+                ),
+            )?;
         }
     }
 
@@ -186,9 +197,10 @@ fn attach_auth<T>(request: &mut tonic::Request<T>, token: &str) -> anyhow::Resul
     Ok(())
 }
 
-fn print_host_alias(alias: Option<api::HostAliasDescriptor>) {
-    if let Some(alias) = alias {
-        println!(
+fn print_host_alias(ctx: &Context, alias: Option<api::HostAliasDescriptor>) -> anyhow::Result<()> {
+    let mut text = String::new();
+    if let Some(alias) = &alias {
+        text = format!(
             "{} -> {}/{} ({}, generation {})",
             alias.hostname,
             alias.bucket_name,
@@ -197,9 +209,13 @@ fn print_host_alias(alias: Option<api::HostAliasDescriptor>) {
             alias.generation
         );
         if !alias.verification_challenge.is_empty() {
-            println!("verification_challenge={}", alias.verification_challenge);
+            text.push_str(&format!(
+                "\nverification_challenge={}",
+                alias.verification_challenge
+            ));
         }
     }
+    ctx.print(&alias, text)
 }
 
 fn host_alias_state_name(value: i32) -> &'static str {
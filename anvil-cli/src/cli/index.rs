@@ -118,7 +118,8 @@ pub async fn handle_index_command(command: &IndexCommands, ctx: &Context) -> any
                 options: write_options(transaction_id),
             });
             add_auth(&mut request, &token);
-            print_index(client.create_index(request).await?.into_inner().index);
+            let response = client.create_index(request).await?.into_inner();
+            ctx.print(&response, index_text(response.index.as_ref()))?;
         }
         IndexCommands::Update {
             bucket,
@@ -140,7 +141,8 @@ pub async fn handle_index_command(command: &IndexCommands, ctx: &Context) -> any
                 options: write_options(transaction_id),
             });
             add_auth(&mut request, &token);
-            print_index(client.update_index(request).await?.into_inner().index);
+            let response = client.update_index(request).await?.into_inner();
+            ctx.print(&response, index_text(response.index.as_ref()))?;
         }
         IndexCommands::Disable {
             bucket,
@@ -154,7 +156,8 @@ pub async fn handle_index_command(command: &IndexCommands, ctx: &Context) -> any
                 options: write_options(transaction_id),
             });
             add_auth(&mut request, &token);
-            print_index(client.disable_index(request).await?.into_inner().index);
+            let response = client.disable_index(request).await?.into_inner();
+            ctx.print(&response, index_text(response.index.as_ref()))?;
         }
         IndexCommands::Drop {
             bucket,
@@ -168,8 +171,8 @@ pub async fn handle_index_command(command: &IndexCommands, ctx: &Context) -> any
                 options: write_options(transaction_id),
             });
             add_auth(&mut request, &token);
-            client.drop_index(request).await?;
-            println!("dropped {bucket}/{name}");
+            let response = client.drop_index(request).await?.into_inner();
+            ctx.print(&response, format!("dropped {bucket}/{name}"))?;
         }
         IndexCommands::List {
             bucket,
@@ -180,9 +183,13 @@ pub async fn handle_index_command(command: &IndexCommands, ctx: &Context) -> any
                 include_disabled: *include_disabled,
             });
             add_auth(&mut request, &token);
-            for index in client.list_indexes(request).await?.into_inner().indexes {
-                print_index(Some(index));
-            }
+            let response = client.list_indexes(request).await?.into_inner();
+            let lines = response
+                .indexes
+                .iter()
+                .map(|index| index_text(Some(index)))
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
         IndexCommands::Query {
             bucket,
@@ -218,12 +225,15 @@ pub async fn handle_index_command(command: &IndexCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.query_index(request).await?.into_inner();
-            for hit in response.hits {
-                println!("{}\t{}\t{}", hit.score, hit.object_key, hit.metadata_json);
-            }
+            let mut lines = response
+                .hits
+                .iter()
+                .map(|hit| format!("{}\t{}\t{}", hit.score, hit.object_key, hit.metadata_json))
+                .collect::<Vec<_>>();
             if !response.next_page_token.is_empty() {
-                println!("next_page_token={}", response.next_page_token);
+                lines.push(format!("next_page_token={}", response.next_page_token));
             }
+            ctx.print(&response, lines.join("\n"))?;
         }
         IndexCommands::Diagnostics {
             bucket,
@@ -240,17 +250,18 @@ pub async fn handle_index_command(command: &IndexCommands, ctx: &Context) -> any
                 severity: severity.clone(),
             });
             add_auth(&mut request, &token);
-            for diagnostic in client
-                .list_index_diagnostics(request)
-                .await?
-                .into_inner()
+            let response = client.list_index_diagnostics(request).await?.into_inner();
+            let lines = response
                 .diagnostics
-            {
-                println!(
-                    "{}\t{}\t{}\t{}",
-                    diagnostic.cursor, diagnostic.severity, diagnostic.code, diagnostic.message
-                );
-            }
+                .iter()
+                .map(|diagnostic| {
+                    format!(
+                        "{}\t{}\t{}\t{}",
+                        diagnostic.cursor, diagnostic.severity, diagnostic.code, diagnostic.message
+                    )
+                })
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
     }
     Ok(())
@@ -273,17 +284,19 @@ fn parse_index_kind(value: &str) -> anyhow::Result<i32> {
     Ok(kind as i32)
 }
 
-fn print_index(index: Option<api::IndexDefinitionRecord>) {
-    if let Some(index) = index {
-        println!(
-            "{}\t{}\t{:?}\t{}\tv{}",
-            index.bucket_name,
-            index.name,
-            api::IndexKind::try_from(index.kind).unwrap_or(api::IndexKind::Unspecified),
-            index.enabled,
-            index.version
-        );
-    }
+fn index_text(index: Option<&api::IndexDefinitionRecord>) -> String {
+    index
+        .map(|index| {
+            format!(
+                "{}\t{}\t{:?}\t{}\tv{}",
+                index.bucket_name,
+                index.name,
+                api::IndexKind::try_from(index.kind).unwrap_or(api::IndexKind::Unspecified),
+                index.enabled,
+                index.version
+            )
+        })
+        .unwrap_or_default()
 }
 
 fn write_options(transaction_id: &Option<String>) -> Option<api::WriteOptions> {
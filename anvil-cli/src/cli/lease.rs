@@ -64,7 +64,8 @@ pub async fn handle_lease_command(command: &LeaseCommands, ctx: &Context) -> any
                 requested_ttl_nanos: *ttl_nanos,
             });
             add_auth(&mut request, &token);
-            print_lease(client.acquire_task_lease(request).await?.into_inner().lease);
+            let response = client.acquire_task_lease(request).await?.into_inner();
+            ctx.print(&response, lease_text(response.lease.as_ref()))?;
         }
         LeaseCommands::Checkpoint {
             task_id,
@@ -79,13 +80,8 @@ pub async fn handle_lease_command(command: &LeaseCommands, ctx: &Context) -> any
                 checkpoint_cursor_high: *checkpoint_cursor_high,
             });
             add_auth(&mut request, &token);
-            print_lease(
-                client
-                    .checkpoint_task_lease(request)
-                    .await?
-                    .into_inner()
-                    .lease,
-            );
+            let response = client.checkpoint_task_lease(request).await?.into_inner();
+            ctx.print(&response, lease_text(response.lease.as_ref()))?;
         }
         LeaseCommands::Commit {
             task_id,
@@ -101,8 +97,12 @@ pub async fn handle_lease_command(command: &LeaseCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.commit_task_lease(request).await?.into_inner();
-            println!("committed={}", response.committed);
-            print_lease(response.previous_lease);
+            let text = format!(
+                "committed={}\n{}",
+                response.committed,
+                lease_text(response.previous_lease.as_ref())
+            );
+            ctx.print(&response, text.trim_end())?;
         }
         LeaseCommands::Read { task_id } => {
             let mut request = tonic::Request::new(api::ReadTaskLeaseRequest {
@@ -110,8 +110,12 @@ pub async fn handle_lease_command(command: &LeaseCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.read_task_lease(request).await?.into_inner();
-            println!("found={}", response.found);
-            print_lease(response.lease);
+            let text = format!(
+                "found={}\n{}",
+                response.found,
+                lease_text(response.lease.as_ref())
+            );
+            ctx.print(&response, text.trim_end())?;
         }
         LeaseCommands::ForceRelease { task_id } => {
             let mut request = tonic::Request::new(api::ForceReleaseTaskLeaseRequest {
@@ -119,24 +123,30 @@ pub async fn handle_lease_command(command: &LeaseCommands, ctx: &Context) -> any
             });
             add_auth(&mut request, &token);
             let response = client.force_release_task_lease(request).await?.into_inner();
-            println!("released={}", response.released);
-            print_lease(response.previous_lease);
+            let text = format!(
+                "released={}\n{}",
+                response.released,
+                lease_text(response.previous_lease.as_ref())
+            );
+            ctx.print(&response, text.trim_end())?;
         }
     }
     Ok(())
 }
 
-fn print_lease(lease: Option<api::TaskLease>) {
-    if let Some(lease) = lease {
-        println!(
-            "{}\tfence={}\towner={}\tcheckpoint={}:{}",
-            lease.task_id,
-            lease.fence_token,
-            lease.owner_principal_id,
-            lease.checkpoint_cursor_low,
-            lease.checkpoint_cursor_high
-        );
-    }
+fn lease_text(lease: Option<&api::TaskLease>) -> String {
+    lease
+        .map(|lease| {
+            format!(
+                "{}\tfence={}\towner={}\tcheckpoint={}:{}",
+                lease.task_id,
+                lease.fence_token,
+                lease.owner_principal_id,
+                lease.checkpoint_cursor_low,
+                lease.checkpoint_cursor_high
+            )
+        })
+        .unwrap_or_default()
 }
 
 fn add_auth<T>(request: &mut tonic::Request<T>, token: &str) {
@@ -2,12 +2,22 @@ use crate::context::Context;
 use anvil::anvil_api as api;
 use anvil::anvil_api::object_service_client::ObjectServiceClient;
 use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
-use clap::Subcommand;
-use serde::Deserialize;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tokio::sync::mpsc;
+use clap::{Subcommand, ValueEnum};
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::sync::{Semaphore, mpsc};
+use tokio::task::JoinSet;
 use tokio_stream::wrappers::ReceiverStream;
 
+const MIB: u64 = 1024 * 1024;
+const PART_UPLOAD_ATTEMPTS: u32 = 3;
+
 #[derive(Subcommand)]
 pub enum ObjectCommands {
     /// Upload a file to an object
@@ -22,6 +32,15 @@ pub enum ObjectCommands {
         transaction_id: Option<String>,
         #[clap(long)]
         storage_class: Option<String>,
+        /// Files at least this large are uploaded as concurrent multipart parts.
+        #[clap(long, default_value_t = 128)]
+        multipart_threshold_mib: u64,
+        /// Size of each multipart part.
+        #[clap(long, default_value_t = 64, value_parser = clap::value_parser!(u64).range(5..))]
+        part_size_mib: u64,
+        /// Maximum number of parts uploaded at once.
+        #[clap(long, default_value_t = 4)]
+        concurrency: usize,
     },
     /// Download an object to a file or stdout
     Get { src: String, dest: Option<String> },
@@ -31,10 +50,45 @@ pub enum ObjectCommands {
         #[clap(long)]
         transaction_id: Option<String>,
     },
+    /// Copy objects server-side without downloading them
+    Cp {
+        src: String,
+        dest: String,
+        /// Copy every object under the source prefix to the destination prefix.
+        #[clap(long)]
+        recursive: bool,
+        #[clap(long)]
+        transaction_id: Option<String>,
+    },
+    /// Upload a local directory tree, skipping files that are unchanged remotely
+    Sync {
+        src: String,
+        dest: String,
+        /// Remove remote objects under the prefix that have no local file.
+        #[clap(long)]
+        delete: bool,
+        /// Maximum number of files uploaded at once.
+        #[clap(long, default_value_t = 4)]
+        concurrency: usize,
+        #[clap(long)]
+        storage_class: Option<String>,
+    },
     /// List objects in a bucket
     Ls { path: String },
     /// Show object metadata
     Head { path: String },
+    /// Print a presigned S3 URL that grants temporary access to one object
+    Presign {
+        path: String,
+        /// How long the URL stays valid, such as `900s`, `30m`, `1h` or `7d`.
+        #[clap(long, default_value = "1h", value_parser = parse_presign_expiry)]
+        expires: Duration,
+        #[clap(long, value_enum, default_value_t = PresignMethod::Get)]
+        method: PresignMethod,
+        /// Region named in the signature's credential scope.
+        #[clap(long, default_value = "us-east-1")]
+        region: String,
+    },
     /// Manage bucket boundary schemas used by CoreStore placement and query planning.
     Boundary {
         #[clap(subcommand)]
@@ -47,6 +101,21 @@ pub enum ObjectCommands {
     },
 }
 
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum PresignMethod {
+    Get,
+    Put,
+}
+
+impl PresignMethod {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Put => "PUT",
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub enum ObjectBoundaryCommands {
     /// Create or update a bucket boundary schema from a JSON file.
@@ -177,6 +246,104 @@ fn parse_s3_path(path: &str) -> anyhow::Result<(String, String)> {
     Ok((parts[0].to_string(), parts[1].to_string()))
 }
 
+/// Parses a path such as `s3://bucket` or `s3://bucket/prefix` into a bucket
+/// and a key prefix that is empty or ends with `/`.
+fn parse_s3_prefix(path: &str) -> anyhow::Result<(String, String)> {
+    let path = path.strip_prefix("s3://").unwrap_or(path);
+    let (bucket, prefix) = path.split_once('/').unwrap_or((path, ""));
+    if bucket.is_empty() {
+        return Err(anyhow::anyhow!(
+            "expected a path such as s3://bucket-name/prefix/"
+        ));
+    }
+    let mut prefix = prefix.trim_start_matches('/').to_string();
+    if !prefix.is_empty() && !prefix.ends_with('/') {
+        prefix.push('/');
+    }
+    Ok((bucket.to_string(), prefix))
+}
+
+/// Parses a presign lifetime: whole seconds, optionally suffixed with `s`,
+/// `m`, `h` or `d`.
+fn parse_presign_expiry(value: &str) -> Result<Duration, String> {
+    let (amount, unit_secs) = match value.char_indices().last() {
+        Some((index, 's')) => (&value[..index], 1),
+        Some((index, 'm')) => (&value[..index], 60),
+        Some((index, 'h')) => (&value[..index], 60 * 60),
+        Some((index, 'd')) => (&value[..index], 24 * 60 * 60),
+        _ => (value, 1),
+    };
+    amount
+        .parse::<u64>()
+        .ok()
+        .and_then(|amount| amount.checked_mul(unit_secs))
+        .map(Duration::from_secs)
+        .ok_or_else(|| {
+            format!("invalid duration {value:?}; expected a value such as 900s, 30m, 1h or 7d")
+        })
+}
+
+/// Percent-encodes an object key for a URL path, keeping `/` separators.
+fn encode_object_key_path(key: &str) -> String {
+    key.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+#[derive(Debug, Serialize)]
+struct PresignedObjectUrl {
+    url: String,
+    method: &'static str,
+    expires_in_secs: u64,
+}
+
+/// Signs a path-style S3 URL for the object locally with the profile's
+/// client credentials; nothing is sent to the server.
+fn presign_object_url(
+    ctx: &Context,
+    path: &str,
+    expires: Duration,
+    method: PresignMethod,
+    region: &str,
+) -> anyhow::Result<()> {
+    let (bucket, key) = parse_s3_path(path)?;
+    if ctx.profile.client_id.is_empty() || ctx.profile.client_secret.is_empty() {
+        return Err(anyhow::anyhow!(
+            "profile '{}' has no client credentials to sign with",
+            ctx.profile.name
+        ));
+    }
+    let url = format!(
+        "{}/{bucket}/{}",
+        ctx.profile.host.trim_end_matches('/'),
+        encode_object_key_path(&key)
+    );
+    let url = anvil::s3_auth::presign_url(
+        &ctx.profile.client_id,
+        &ctx.profile.client_secret,
+        region,
+        method.as_str(),
+        &url,
+        expires,
+        SystemTime::now(),
+    )
+    .map_err(anyhow::Error::msg)?;
+    let text = url.clone();
+    ctx.print(
+        &PresignedObjectUrl {
+            url,
+            method: method.as_str(),
+            expires_in_secs: expires.as_secs(),
+        },
+        text,
+    )
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct NativeTokenClaims {
     pub(crate) sub: String,
@@ -218,10 +385,17 @@ pub(crate) async fn native_mutation_context(
     })
 }
 
-pub async fn handle_object_command(command: &ObjectCommands, ctx: &Context) -> anyhow::Result<()> {
-    let mut client = ObjectServiceClient::connect(ctx.profile.host.clone()).await?;
+/// Connects to the object service and fetches the bearer token to call it
+/// with.
+async fn connect_object_service(
+    ctx: &Context,
+) -> anyhow::Result<(ObjectServiceClient<tonic::transport::Channel>, String)> {
+    let client = ObjectServiceClient::connect(ctx.profile.host.clone()).await?;
     let token = ctx.get_bearer_token().await?;
+    Ok((client, token))
+}
 
+pub async fn handle_object_command(command: &ObjectCommands, ctx: &Context) -> anyhow::Result<()> {
     match command {
         ObjectCommands::Put {
             src,
@@ -230,62 +404,68 @@ pub async fn handle_object_command(command: &ObjectCommands, ctx: &Context) -> a
             user_metadata_json,
             transaction_id,
             storage_class,
+            multipart_threshold_mib,
+            part_size_mib,
+            concurrency,
         } => {
+            let (mut client, token) = connect_object_service(ctx).await?;
             let (bucket, key) = parse_s3_path(dest)?;
             serde_json::from_str::<serde_json::Value>(user_metadata_json)
                 .map_err(|error| anyhow::anyhow!("invalid --user-metadata-json: {error}"))?;
-            let mutation_context =
-                native_mutation_context(ctx, &token, &bucket, "put", transaction_id.clone())
+            let size = tokio::fs::metadata(src).await?.len();
+            // Multipart uploads cannot carry object metadata yet, so files
+            // that set any of it always go through a single streaming put.
+            let has_object_metadata = content_type.is_some()
+                || storage_class.is_some()
+                || user_metadata_json.trim() != "{}";
+            let (etag, version_id) =
+                if size >= multipart_threshold_mib.saturating_mul(MIB) && !has_object_metadata {
+                    let response = upload_file_multipart(
+                        &client,
+                        ctx,
+                        &token,
+                        Path::new(src),
+                        size,
+                        MultipartTarget {
+                            bucket,
+                            key,
+                            transaction_id: transaction_id.clone(),
+                            part_size: part_size_mib.saturating_mul(MIB),
+                            concurrency: *concurrency,
+                        },
+                    )
                     .await?;
-            let metadata = api::ObjectMetadata {
-                bucket_name: bucket,
-                object_key: key,
-                mutation_context: Some(mutation_context),
-                content_type: content_type.clone(),
-                user_metadata_json: user_metadata_json.clone(),
-                storage_class: storage_class.clone(),
-            };
-            let mut file = tokio::fs::File::open(src).await?;
-            let (tx, rx) = mpsc::channel(4);
-            let metadata_tx = tx.clone();
-            metadata_tx
-                .send(api::PutObjectRequest {
-                    data: Some(api::put_object_request::Data::Metadata(metadata)),
-                })
-                .await?;
-            drop(metadata_tx);
-            let upload_task = tokio::spawn(async move {
-                let mut buffer = vec![0_u8; 256 * 1024];
-                loop {
-                    let read = match file.read(&mut buffer).await {
-                        Ok(0) => break,
-                        Ok(read) => read,
-                        Err(error) => return Err(error),
-                    };
-                    if tx
-                        .send(api::PutObjectRequest {
-                            data: Some(api::put_object_request::Data::Chunk(
-                                buffer[..read].to_vec(),
-                            )),
-                        })
-                        .await
-                        .is_err()
-                    {
-                        break;
-                    }
-                }
-                Ok::<(), std::io::Error>(())
-            });
-            let mut request = tonic::Request::new(ReceiverStream::new(rx));
-            request.metadata_mut().insert(
-                "authorization",
-                format!("Bearer {}", token).parse().unwrap(),
-            );
-            client.put_object(request).await?;
-            upload_task.await??;
-            println!("Uploaded {} to {}", src, dest);
+                    (response.etag, response.version_id)
+                } else {
+                    let response = upload_file(
+                        &mut client,
+                        ctx,
+                        &token,
+                        Path::new(src),
+                        UploadTarget {
+                            bucket,
+                            key,
+                            content_type: content_type.clone(),
+                            user_metadata_json: user_metadata_json.clone(),
+                            transaction_id: transaction_id.clone(),
+                            storage_class: storage_class.clone(),
+                        },
+                    )
+                    .await?;
+                    (response.etag, response.version_id)
+                };
+            ctx.print(
+                &serde_json::json!({
+                    "source": src,
+                    "destination": dest,
+                    "etag": etag,
+                    "version_id": version_id,
+                }),
+                format!("Uploaded {} to {}", src, dest),
+            )?;
         }
         ObjectCommands::Get { src, dest } => {
+            let (mut client, token) = connect_object_service(ctx).await?;
             let (bucket, key) = parse_s3_path(src)?;
             let mut request = tonic::Request::new(api::GetObjectRequest {
                 bucket_name: bucket,
@@ -324,7 +504,14 @@ pub async fn handle_object_command(command: &ObjectCommands, ctx: &Context) -> a
                         "downloaded {bytes_written} bytes from {src}, expected {expected_len}"
                     );
                 }
-                println!("Downloaded {} to {}", src, dest_path);
+                ctx.print(
+                    &serde_json::json!({
+                        "source": src,
+                        "destination": dest_path,
+                        "bytes": bytes_written,
+                    }),
+                    format!("Downloaded {} to {}", src, dest_path),
+                )?;
             } else {
                 let mut expected_len = None;
                 let mut bytes_written = 0_u64;
@@ -352,6 +539,7 @@ pub async fn handle_object_command(command: &ObjectCommands, ctx: &Context) -> a
             path,
             transaction_id,
         } => {
+            let (mut client, token) = connect_object_service(ctx).await?;
             let (bucket, key) = parse_s3_path(path)?;
             let mutation_context =
                 native_mutation_context(ctx, &token, &bucket, "rm", transaction_id.clone()).await?;
@@ -366,9 +554,99 @@ pub async fn handle_object_command(command: &ObjectCommands, ctx: &Context) -> a
                 format!("Bearer {}", token).parse().unwrap(),
             );
             client.delete_object(request).await?;
-            println!("Removed {}", path);
+            ctx.print(
+                &serde_json::json!({ "removed": path }),
+                format!("Removed {}", path),
+            )?;
+        }
+        ObjectCommands::Cp {
+            src,
+            dest,
+            recursive,
+            transaction_id,
+        } => {
+            let (mut client, token) = connect_object_service(ctx).await?;
+            let copies = if *recursive {
+                let (src_bucket, src_prefix) = parse_s3_prefix(src)?;
+                let (dest_bucket, dest_prefix) = parse_s3_prefix(dest)?;
+                list_remote_sync_objects(&mut client, &token, &src_bucket, &src_prefix)
+                    .await?
+                    .into_keys()
+                    .map(|key| {
+                        let relative = key.strip_prefix(&src_prefix).unwrap_or(&key);
+                        let dest_key = format!("{dest_prefix}{relative}");
+                        ((src_bucket.clone(), key), (dest_bucket.clone(), dest_key))
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                let (src_bucket, src_key) = parse_s3_path(src)?;
+                let (dest_bucket, mut dest_key) = parse_s3_path(dest)?;
+                if dest_key.is_empty() || dest_key.ends_with('/') {
+                    dest_key.push_str(src_key.rsplit('/').next().unwrap_or(&src_key));
+                }
+                vec![((src_bucket, src_key), (dest_bucket, dest_key))]
+            };
+            for ((src_bucket, src_key), (dest_bucket, dest_key)) in copies {
+                let mutation_context = native_mutation_context(
+                    ctx,
+                    &token,
+                    &dest_bucket,
+                    "cp",
+                    transaction_id.clone(),
+                )
+                .await?;
+                let mut request = tonic::Request::new(api::CopyObjectRequest {
+                    source_bucket_name: src_bucket.clone(),
+                    source_object_key: src_key.clone(),
+                    destination_bucket_name: dest_bucket.clone(),
+                    destination_object_key: dest_key.clone(),
+                    mutation_context: Some(mutation_context),
+                    ..Default::default()
+                });
+                request.metadata_mut().insert(
+                    "authorization",
+                    format!("Bearer {}", token).parse().unwrap(),
+                );
+                let response = client.copy_object(request).await?.into_inner();
+                ctx.print(
+                    &response,
+                    format!("Copied s3://{src_bucket}/{src_key} to s3://{dest_bucket}/{dest_key}"),
+                )?;
+            }
+        }
+        ObjectCommands::Sync {
+            src,
+            dest,
+            delete,
+            concurrency,
+            storage_class,
+        } => {
+            let (client, token) = connect_object_service(ctx).await?;
+            let (bucket, prefix) = parse_s3_prefix(dest)?;
+            let report = sync_directory(
+                &client,
+                ctx,
+                &token,
+                Path::new(src),
+                &bucket,
+                &prefix,
+                SyncOptions {
+                    delete: *delete,
+                    concurrency: *concurrency,
+                    storage_class: storage_class.clone(),
+                },
+            )
+            .await?;
+            ctx.print(
+                &report,
+                format!(
+                    "Synced {} to {}: {} uploaded, {} unchanged, {} deleted",
+                    src, dest, report.uploaded, report.unchanged, report.deleted
+                ),
+            )?;
         }
         ObjectCommands::Ls { path } => {
+            let (mut client, token) = connect_object_service(ctx).await?;
             let (bucket, prefix) = parse_s3_path(path)?;
             let mut request = tonic::Request::new(api::ListObjectsRequest {
                 bucket_name: bucket,
@@ -379,12 +657,16 @@ pub async fn handle_object_command(command: &ObjectCommands, ctx: &Context) -> a
                 "authorization",
                 format!("Bearer {}", token).parse().unwrap(),
             );
-            let resp = client.list_objects(request).await?;
-            for obj in resp.into_inner().objects {
-                println!("{}\t{}\t{}", obj.last_modified, obj.size, obj.key);
-            }
+            let resp = client.list_objects(request).await?.into_inner();
+            let lines = resp
+                .objects
+                .iter()
+                .map(|obj| format!("{}\t{}\t{}", obj.last_modified, obj.size, obj.key))
+                .collect::<Vec<_>>();
+            ctx.print(&resp, lines.join("\n"))?;
         }
         ObjectCommands::Head { path } => {
+            let (mut client, token) = connect_object_service(ctx).await?;
             let (bucket, key) = parse_s3_path(path)?;
             let mut request = tonic::Request::new(api::HeadObjectRequest {
                 bucket_name: bucket,
@@ -399,15 +681,28 @@ pub async fn handle_object_command(command: &ObjectCommands, ctx: &Context) -> a
             );
             let resp = client.head_object(request).await?;
             let obj = resp.into_inner();
-            println!(
-                "ETag: {}\nSize: {}\nLast Modified: {}",
-                obj.etag, obj.size, obj.last_modified
-            );
+            ctx.print(
+                &obj,
+                format!(
+                    "ETag: {}\nSize: {}\nLast Modified: {}",
+                    obj.etag, obj.size, obj.last_modified
+                ),
+            )?;
+        }
+        ObjectCommands::Presign {
+            path,
+            expires,
+            method,
+            region,
+        } => {
+            presign_object_url(ctx, path, *expires, *method, region)?;
         }
         ObjectCommands::Boundary { command } => {
-            handle_object_boundary_command(command, &mut client, &token).await?;
+            let (mut client, token) = connect_object_service(ctx).await?;
+            handle_object_boundary_command(command, ctx, &mut client, &token).await?;
         }
         ObjectCommands::Link { command } => {
+            let (mut client, token) = connect_object_service(ctx).await?;
             handle_object_link_command(command, ctx, &mut client, &token).await?;
         }
     }
@@ -415,8 +710,545 @@ pub async fn handle_object_command(command: &ObjectCommands, ctx: &Context) -> a
     Ok(())
 }
 
+struct UploadTarget {
+    bucket: String,
+    key: String,
+    content_type: Option<String>,
+    user_metadata_json: String,
+    transaction_id: Option<String>,
+    storage_class: Option<String>,
+}
+
+async fn upload_file(
+    client: &mut ObjectServiceClient<tonic::transport::Channel>,
+    ctx: &Context,
+    token: &str,
+    src: &Path,
+    target: UploadTarget,
+) -> anyhow::Result<api::PutObjectResponse> {
+    let mutation_context =
+        native_mutation_context(ctx, token, &target.bucket, "put", target.transaction_id).await?;
+    let metadata = api::ObjectMetadata {
+        bucket_name: target.bucket,
+        object_key: target.key,
+        mutation_context: Some(mutation_context),
+        content_type: target.content_type,
+        user_metadata_json: target.user_metadata_json,
+        storage_class: target.storage_class,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
+    };
+    let mut file = tokio::fs::File::open(src).await?;
+    let (tx, rx) = mpsc::channel(4);
+    let metadata_tx = tx.clone();
+    metadata_tx
+        .send(api::PutObjectRequest {
+            data: Some(api::put_object_request::Data::Metadata(metadata)),
+        })
+        .await?;
+    drop(metadata_tx);
+    let upload_task = tokio::spawn(async move {
+        let mut buffer = vec![0_u8; 256 * 1024];
+        loop {
+            let read = match file.read(&mut buffer).await {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(error) => return Err(error),
+            };
+            if tx
+                .send(api::PutObjectRequest {
+                    data: Some(api::put_object_request::Data::Chunk(
+                        buffer[..read].to_vec(),
+                    )),
+                })
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+        Ok::<(), std::io::Error>(())
+    });
+    let mut request = tonic::Request::new(ReceiverStream::new(rx));
+    request.metadata_mut().insert(
+        "authorization",
+        format!("Bearer {}", token).parse().unwrap(),
+    );
+    let response = client.put_object(request).await?.into_inner();
+    upload_task.await??;
+    Ok(response)
+}
+
+struct MultipartTarget {
+    bucket: String,
+    key: String,
+    transaction_id: Option<String>,
+    part_size: u64,
+    concurrency: usize,
+}
+
+/// Uploads `src` as a multipart upload whose parts are sent concurrently and
+/// retried individually, so a dropped connection only resends one part. The
+/// upload is aborted if any part exhausts its retries.
+async fn upload_file_multipart(
+    client: &ObjectServiceClient<tonic::transport::Channel>,
+    ctx: &Context,
+    token: &str,
+    src: &Path,
+    size: u64,
+    target: MultipartTarget,
+) -> anyhow::Result<api::CompleteMultipartResponse> {
+    let mut control = client.clone();
+    let mutation_context = native_mutation_context(
+        ctx,
+        token,
+        &target.bucket,
+        "multipart-initiate",
+        target.transaction_id.clone(),
+    )
+    .await?;
+    let mut request = tonic::Request::new(api::InitiateMultipartRequest {
+        bucket_name: target.bucket.clone(),
+        object_key: target.key.clone(),
+        mutation_context: Some(mutation_context),
+    });
+    request.metadata_mut().insert(
+        "authorization",
+        format!("Bearer {}", token).parse().unwrap(),
+    );
+    let upload_id = control
+        .initiate_multipart_upload(request)
+        .await?
+        .into_inner()
+        .upload_id;
+
+    let progress = ProgressBar::new(size);
+    progress.set_style(ProgressStyle::with_template(
+        "{bar:40} {bytes}/{total_bytes} {binary_bytes_per_sec} eta {eta}",
+    )?);
+    let parts = match upload_parts(
+        client, ctx, token, src, size, &upload_id, &target, &progress,
+    )
+    .await
+    {
+        Ok(parts) => parts,
+        Err(error) => {
+            progress.abandon();
+            let mutation_context = native_mutation_context(
+                ctx,
+                token,
+                &target.bucket,
+                "multipart-abort",
+                target.transaction_id.clone(),
+            )
+            .await?;
+            let mut request = tonic::Request::new(api::AbortMultipartRequest {
+                bucket_name: target.bucket.clone(),
+                object_key: target.key.clone(),
+                upload_id,
+                mutation_context: Some(mutation_context),
+            });
+            request.metadata_mut().insert(
+                "authorization",
+                format!("Bearer {}", token).parse().unwrap(),
+            );
+            if let Err(status) = control.abort_multipart_upload(request).await {
+                eprintln!("Failed to abort multipart upload: {}", status.message());
+            }
+            return Err(error);
+        }
+    };
+    progress.finish();
+
+    let mutation_context = native_mutation_context(
+        ctx,
+        token,
+        &target.bucket,
+        "multipart-complete",
+        target.transaction_id.clone(),
+    )
+    .await?;
+    let mut request = tonic::Request::new(api::CompleteMultipartRequest {
+        bucket_name: target.bucket,
+        object_key: target.key,
+        upload_id,
+        parts,
+        mutation_context: Some(mutation_context),
+    });
+    request.metadata_mut().insert(
+        "authorization",
+        format!("Bearer {}", token).parse().unwrap(),
+    );
+    Ok(control
+        .complete_multipart_upload(request)
+        .await?
+        .into_inner())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn upload_parts(
+    client: &ObjectServiceClient<tonic::transport::Channel>,
+    ctx: &Context,
+    token: &str,
+    src: &Path,
+    size: u64,
+    upload_id: &str,
+    target: &MultipartTarget,
+    progress: &ProgressBar,
+) -> anyhow::Result<Vec<api::CompleteMultipartPart>> {
+    let part_count = size.div_ceil(target.part_size).max(1);
+    let semaphore = Arc::new(Semaphore::new(target.concurrency.max(1)));
+    let mut uploads = JoinSet::new();
+    for index in 0..part_count {
+        let permit = semaphore.clone().acquire_owned().await?;
+        let offset = index * target.part_size;
+        let part = PartUpload {
+            client: client.clone(),
+            ctx: ctx.clone(),
+            token: token.to_string(),
+            path: src.to_path_buf(),
+            bucket: target.bucket.clone(),
+            key: target.key.clone(),
+            upload_id: upload_id.to_string(),
+            part_number: i32::try_from(index + 1)?,
+            offset,
+            len: target.part_size.min(size - offset),
+            transaction_id: target.transaction_id.clone(),
+            progress: progress.clone(),
+        };
+        uploads.spawn(async move {
+            let _permit = permit;
+            let part_number = part.part_number;
+            let etag = part.upload_with_retries().await?;
+            Ok::<_, anyhow::Error>(api::CompleteMultipartPart { part_number, etag })
+        });
+    }
+    let mut parts = Vec::new();
+    while let Some(result) = uploads.join_next().await {
+        parts.push(result??);
+    }
+    parts.sort_by_key(|part| part.part_number);
+    Ok(parts)
+}
+
+struct PartUpload {
+    client: ObjectServiceClient<tonic::transport::Channel>,
+    ctx: Context,
+    token: String,
+    path: PathBuf,
+    bucket: String,
+    key: String,
+    upload_id: String,
+    part_number: i32,
+    offset: u64,
+    len: u64,
+    transaction_id: Option<String>,
+    progress: ProgressBar,
+}
+
+impl PartUpload {
+    async fn upload_with_retries(mut self) -> anyhow::Result<String> {
+        let mut attempt = 1;
+        loop {
+            match self.upload_once().await {
+                Ok(etag) => return Ok(etag),
+                Err(error) if attempt < PART_UPLOAD_ATTEMPTS => {
+                    self.progress.println(format!(
+                        "Retrying part {} after error: {error}",
+                        self.part_number
+                    ));
+                    tokio::time::sleep(Duration::from_millis(500 * u64::from(attempt))).await;
+                    attempt += 1;
+                }
+                Err(error) => {
+                    return Err(error.context(format!("upload part {}", self.part_number)));
+                }
+            }
+        }
+    }
+
+    async fn upload_once(&mut self) -> anyhow::Result<String> {
+        // Each attempt gets a fresh idempotency key: a part that reached the
+        // server truncated must be rewritten, not replayed.
+        let mutation_context = native_mutation_context(
+            &self.ctx,
+            &self.token,
+            &self.bucket,
+            "upload-part",
+            self.transaction_id.clone(),
+        )
+        .await?;
+        let mut file = tokio::fs::File::open(&self.path).await?;
+        file.seek(std::io::SeekFrom::Start(self.offset)).await?;
+        let mut reader = file.take(self.len);
+        let (tx, rx) = mpsc::channel(4);
+        tx.send(api::UploadPartRequest {
+            data: Some(api::upload_part_request::Data::Metadata(
+                api::UploadPartMetadata {
+                    bucket_name: self.bucket.clone(),
+                    object_key: self.key.clone(),
+                    upload_id: self.upload_id.clone(),
+                    part_number: self.part_number,
+                    mutation_context: Some(mutation_context),
+                },
+            )),
+        })
+        .await?;
+        let progress = self.progress.clone();
+        let upload_task = tokio::spawn(async move {
+            let mut buffer = vec![0_u8; 256 * 1024];
+            let mut sent = 0_u64;
+            loop {
+                let read = match reader.read(&mut buffer).await {
+                    Ok(0) => break,
+                    Ok(read) => read,
+                    Err(error) => return (sent, Err(error)),
+                };
+                if tx
+                    .send(api::UploadPartRequest {
+                        data: Some(api::upload_part_request::Data::Chunk(
+                            buffer[..read].to_vec(),
+                        )),
+                    })
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+                sent += read as u64;
+                progress.inc(read as u64);
+            }
+            (sent, Ok(()))
+        });
+        let mut request = tonic::Request::new(ReceiverStream::new(rx));
+        request.metadata_mut().insert(
+            "authorization",
+            format!("Bearer {}", self.token).parse().unwrap(),
+        );
+        let response = self.client.upload_part(request).await;
+        let (sent, read_result) = upload_task.await?;
+        let result = match (response, read_result) {
+            (Ok(response), Ok(())) => Ok(response.into_inner().etag),
+            (Err(status), _) => Err(status.into()),
+            (_, Err(error)) => Err(error.into()),
+        };
+        if result.is_err() {
+            self.progress
+                .set_position(self.progress.position().saturating_sub(sent));
+        }
+        result
+    }
+}
+
+struct SyncOptions {
+    delete: bool,
+    concurrency: usize,
+    storage_class: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct SyncReport {
+    uploaded: usize,
+    unchanged: usize,
+    deleted: usize,
+}
+
+struct LocalSyncFile {
+    path: PathBuf,
+    size: u64,
+}
+
+struct RemoteSyncObject {
+    size: i64,
+    etag: String,
+}
+
+async fn sync_directory(
+    client: &ObjectServiceClient<tonic::transport::Channel>,
+    ctx: &Context,
+    token: &str,
+    src: &Path,
+    bucket: &str,
+    prefix: &str,
+    options: SyncOptions,
+) -> anyhow::Result<SyncReport> {
+    anyhow::ensure!(
+        tokio::fs::metadata(src).await?.is_dir(),
+        "{} is not a directory",
+        src.display()
+    );
+    let local = list_local_sync_files(src, prefix).await?;
+    let remote = list_remote_sync_objects(&mut client.clone(), token, bucket, prefix).await?;
+
+    let mut report = SyncReport::default();
+    let mut pending = Vec::new();
+    for (key, file) in &local {
+        match remote.get(key) {
+            Some(object) if local_file_matches(file, object).await? => report.unchanged += 1,
+            _ => pending.push((key.clone(), file.path.clone())),
+        }
+    }
+
+    let semaphore = Arc::new(Semaphore::new(options.concurrency.max(1)));
+    let mut uploads = JoinSet::new();
+    for (key, path) in pending {
+        let permit = semaphore.clone().acquire_owned().await?;
+        let mut client = client.clone();
+        let ctx = ctx.clone();
+        let token = token.to_string();
+        let target = UploadTarget {
+            bucket: bucket.to_string(),
+            key,
+            content_type: None,
+            user_metadata_json: "{}".to_string(),
+            transaction_id: None,
+            storage_class: options.storage_class.clone(),
+        };
+        uploads.spawn(async move {
+            let _permit = permit;
+            let destination = format!("s3://{}/{}", target.bucket, target.key);
+            upload_file(&mut client, &ctx, &token, &path, target)
+                .await
+                .map_err(|error| anyhow::anyhow!("upload {}: {error}", path.display()))?;
+            ctx.print(
+                &serde_json::json!({ "uploaded": destination }),
+                format!("Uploaded {} to {}", path.display(), destination),
+            )?;
+            Ok::<(), anyhow::Error>(())
+        });
+    }
+    while let Some(result) = uploads.join_next().await {
+        result??;
+        report.uploaded += 1;
+    }
+
+    if options.delete {
+        let mut client = client.clone();
+        for key in remote.keys().filter(|key| !local.contains_key(*key)) {
+            let mutation_context = native_mutation_context(ctx, token, bucket, "rm", None).await?;
+            let mut request = tonic::Request::new(api::DeleteObjectRequest {
+                bucket_name: bucket.to_string(),
+                object_key: key.clone(),
+                version_id: None,
+                mutation_context: Some(mutation_context),
+            });
+            request.metadata_mut().insert(
+                "authorization",
+                format!("Bearer {}", token).parse().unwrap(),
+            );
+            client.delete_object(request).await?;
+            let removed = format!("s3://{}/{}", bucket, key);
+            ctx.print(
+                &serde_json::json!({ "removed": removed }),
+                format!("Removed {}", removed),
+            )?;
+            report.deleted += 1;
+        }
+    }
+    Ok(report)
+}
+
+/// Walks `root` and returns its regular files keyed by their object key under
+/// `prefix`. Symlinks and other special files are skipped.
+async fn list_local_sync_files(
+    root: &Path,
+    prefix: &str,
+) -> anyhow::Result<BTreeMap<String, LocalSyncFile>> {
+    let mut files = BTreeMap::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            let path = entry.path();
+            if file_type.is_dir() {
+                pending.push(path);
+                continue;
+            }
+            if !file_type.is_file() {
+                continue;
+            }
+            let relative = path
+                .strip_prefix(root)?
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let size = entry.metadata().await?.len();
+            files.insert(format!("{prefix}{relative}"), LocalSyncFile { path, size });
+        }
+    }
+    Ok(files)
+}
+
+async fn list_remote_sync_objects(
+    client: &mut ObjectServiceClient<tonic::transport::Channel>,
+    token: &str,
+    bucket: &str,
+    prefix: &str,
+) -> anyhow::Result<BTreeMap<String, RemoteSyncObject>> {
+    let mut objects = BTreeMap::new();
+    let mut page_token = String::new();
+    loop {
+        let mut request = tonic::Request::new(api::ListObjectsRequest {
+            bucket_name: bucket.to_string(),
+            prefix: prefix.to_string(),
+            page_token: page_token.clone(),
+            ..Default::default()
+        });
+        request.metadata_mut().insert(
+            "authorization",
+            format!("Bearer {}", token).parse().unwrap(),
+        );
+        let response = client.list_objects(request).await?.into_inner();
+        for object in response.objects {
+            objects.insert(
+                object.key,
+                RemoteSyncObject {
+                    size: object.size,
+                    etag: object.etag,
+                },
+            );
+        }
+        if response.next_page_token.is_empty() {
+            break;
+        }
+        page_token = response.next_page_token;
+    }
+    Ok(objects)
+}
+
+/// Native single-part uploads record the payload's `sha256:` content hash as
+/// the ETag, so a file is unchanged when both its size and hash agree. Objects
+/// written through S3 or assembled from multipart uploads carry MD5-based
+/// ETags and are re-uploaded once.
+async fn local_file_matches(
+    file: &LocalSyncFile,
+    remote: &RemoteSyncObject,
+) -> anyhow::Result<bool> {
+    if u64::try_from(remote.size).ok() != Some(file.size) {
+        return Ok(false);
+    }
+    if !remote.etag.starts_with("sha256:") {
+        return Ok(false);
+    }
+    let mut hasher = Sha256::new();
+    let mut reader = tokio::fs::File::open(&file.path).await?;
+    let mut buffer = vec![0_u8; 256 * 1024];
+    loop {
+        let read = reader.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(remote.etag == format!("sha256:{:x}", hasher.finalize()))
+}
+
 async fn handle_object_boundary_command(
     command: &ObjectBoundaryCommands,
+    ctx: &Context,
     client: &mut ObjectServiceClient<tonic::transport::Channel>,
     token: &str,
 ) -> anyhow::Result<()> {
@@ -446,7 +1278,7 @@ async fn handle_object_boundary_command(
                 format!("Bearer {}", token).parse().unwrap(),
             );
             let response = client.put_boundary_schema(request).await?.into_inner();
-            print_boundary_schema(response.schema)?;
+            print_boundary_schema(ctx, response.schema)?;
         }
         ObjectBoundaryCommands::Get { bucket } => {
             let bucket_name = parse_bucket_path(bucket)?;
@@ -459,7 +1291,7 @@ async fn handle_object_boundary_command(
                 format!("Bearer {}", token).parse().unwrap(),
             );
             let response = client.get_boundary_schema(request).await?.into_inner();
-            print_boundary_schema(response.schema)?;
+            print_boundary_schema(ctx, response.schema)?;
         }
         ObjectBoundaryCommands::StartMigration {
             bucket,
@@ -493,7 +1325,7 @@ async fn handle_object_boundary_command(
                 format!("Bearer {}", token).parse().unwrap(),
             );
             let response = client.start_boundary_migration(request).await?.into_inner();
-            println!("{}", serde_json::to_string_pretty(&response)?);
+            ctx.print(&response, serde_json::to_string_pretty(&response)?)?;
         }
         ObjectBoundaryCommands::GetMigration {
             bucket,
@@ -509,7 +1341,7 @@ async fn handle_object_boundary_command(
                 format!("Bearer {}", token).parse().unwrap(),
             );
             let response = client.get_boundary_migration(request).await?.into_inner();
-            println!("{}", serde_json::to_string_pretty(&response)?);
+            ctx.print(&response, serde_json::to_string_pretty(&response)?)?;
         }
     }
     Ok(())
@@ -545,14 +1377,19 @@ fn boundary_dimension_input_to_proto(value: BoundaryDimensionInput) -> api::Boun
     }
 }
 
-fn print_boundary_schema(schema: Option<api::BoundarySchemaRecord>) -> anyhow::Result<()> {
+fn print_boundary_schema(
+    ctx: &Context,
+    schema: Option<api::BoundarySchemaRecord>,
+) -> anyhow::Result<()> {
     let schema = schema.ok_or_else(|| anyhow::anyhow!("server returned no boundary schema"))?;
-    println!("Bucket: {}", schema.bucket_name);
-    println!("Generation: {}", schema.generation);
-    println!("Hash: {}", schema.schema_hash);
-    for dimension in schema.dimensions {
-        let source = dimension.source.unwrap_or_default();
-        println!(
+    let mut lines = vec![
+        format!("Bucket: {}", schema.bucket_name),
+        format!("Generation: {}", schema.generation),
+        format!("Hash: {}", schema.schema_hash),
+    ];
+    for dimension in &schema.dimensions {
+        let source = dimension.source.clone().unwrap_or_default();
+        lines.push(format!(
             "- {} {} required={} source={}({}) categories={}",
             dimension.name,
             dimension.value_type,
@@ -560,9 +1397,9 @@ fn print_boundary_schema(schema: Option<api::BoundarySchemaRecord>) -> anyhow::R
             source.kind,
             source.value,
             dimension.categories.join(",")
-        );
+        ));
     }
-    Ok(())
+    ctx.print(&schema, lines.join("\n"))
 }
 
 async fn handle_object_link_command(
@@ -603,7 +1440,8 @@ async fn handle_object_link_command(
                 "authorization",
                 format!("Bearer {}", token).parse().unwrap(),
             );
-            print_link(client.create_object_link(request).await?.into_inner().link);
+            let response = client.create_object_link(request).await?.into_inner();
+            ctx.print(&response, link_text(response.link.as_ref()))?;
         }
         ObjectLinkCommands::Update {
             link,
@@ -637,7 +1475,8 @@ async fn handle_object_link_command(
                 "authorization",
                 format!("Bearer {}", token).parse().unwrap(),
             );
-            print_link(client.update_object_link(request).await?.into_inner().link);
+            let response = client.update_object_link(request).await?.into_inner();
+            ctx.print(&response, link_text(response.link.as_ref()))?;
         }
         ObjectLinkCommands::Delete {
             link,
@@ -660,10 +1499,13 @@ async fn handle_object_link_command(
                 format!("Bearer {}", token).parse().unwrap(),
             );
             let response = client.delete_object_link(request).await?.into_inner();
-            println!(
-                "Deleted {} at generation {}",
-                response.resource_id, response.generation
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "Deleted {} at generation {}",
+                    response.resource_id, response.generation
+                ),
+            )?;
         }
         ObjectLinkCommands::Read { link } => {
             let (bucket, link_key) = parse_s3_path(link)?;
@@ -679,7 +1521,8 @@ async fn handle_object_link_command(
                 "authorization",
                 format!("Bearer {}", token).parse().unwrap(),
             );
-            print_link(client.read_object_link(request).await?.into_inner().link);
+            let response = client.read_object_link(request).await?.into_inner();
+            ctx.print(&response, link_text(response.link.as_ref()))?;
         }
         ObjectLinkCommands::List { path, limit } => {
             let (bucket, prefix) = parse_s3_path(path)?;
@@ -699,16 +1542,20 @@ async fn handle_object_link_command(
                 format!("Bearer {}", token).parse().unwrap(),
             );
             let response = client.list_object_links(request).await?.into_inner();
-            for link in response.links {
-                println!(
-                    "{} -> {} (generation {})",
-                    link.link_key, link.target_key, link.generation
-                );
-            }
+            let lines = response
+                .links
+                .iter()
+                .map(|link| {
+                    format!(
+                        "{} -> {} (generation {})",
+                        link.link_key, link.target_key, link.generation
+                    )
+                })
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
     }
 
-    let _ = ctx;
     Ok(())
 }
 
@@ -737,9 +1584,9 @@ fn parse_link_resolution(value: &str) -> anyhow::Result<i32> {
     }
 }
 
-fn print_link(link: Option<api::ObjectLinkDescriptor>) {
-    if let Some(link) = link {
-        println!(
+fn link_text(link: Option<&api::ObjectLinkDescriptor>) -> String {
+    link.map(|link| {
+        format!(
             "{} -> {}{} (generation {})",
             link.link_key,
             link.target_key,
@@ -749,6 +1596,7 @@ fn print_link(link: Option<api::ObjectLinkDescriptor>) {
                 format!("@{}", link.target_version)
             },
             link.generation
-        );
-    }
+        )
+    })
+    .unwrap_or_default()
 }
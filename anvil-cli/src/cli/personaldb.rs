@@ -114,7 +114,8 @@ pub async fn handle_personaldb_command(
                 schema_sql: schema_sql.clone(),
             });
             add_auth(&mut request, &token);
-            print_group(client.create_personal_db_group(request).await?.into_inner());
+            let response = client.create_personal_db_group(request).await?.into_inner();
+            ctx.print(&response, group_text(&response))?;
         }
         PersonalDbCommands::Group {
             command: GroupCommands::Read { database_id },
@@ -124,7 +125,8 @@ pub async fn handle_personaldb_command(
                 database_id: database_id.clone(),
             });
             add_auth(&mut request, &token);
-            print_group(client.get_personal_db_group(request).await?.into_inner());
+            let response = client.get_personal_db_group(request).await?.into_inner();
+            ctx.print(&response, group_text(&response))?;
         }
         PersonalDbCommands::Projection {
             command:
@@ -139,14 +141,11 @@ pub async fn handle_personaldb_command(
                 projection_definition_json: projection_definition_json.clone(),
             });
             add_auth(&mut request, &token);
-            println!(
-                "{}",
-                client
-                    .create_personal_db_projection(request)
-                    .await?
-                    .into_inner()
-                    .projection_definition_json
-            );
+            let response = client
+                .create_personal_db_projection(request)
+                .await?
+                .into_inner();
+            ctx.print(&response, &response.projection_definition_json)?;
         }
         PersonalDbCommands::Projection {
             command:
@@ -161,14 +160,11 @@ pub async fn handle_personaldb_command(
                 projection_id: projection_id.clone(),
             });
             add_auth(&mut request, &token);
-            println!(
-                "{}",
-                client
-                    .get_personal_db_projection(request)
-                    .await?
-                    .into_inner()
-                    .projection_definition_json
-            );
+            let response = client
+                .get_personal_db_projection(request)
+                .await?
+                .into_inner();
+            ctx.print(&response, &response.projection_definition_json)?;
         }
         PersonalDbCommands::Changeset {
             command: ChangesetCommands::Submit(args),
@@ -197,10 +193,13 @@ pub async fn handle_personaldb_command(
                 .submit_personal_db_changeset(request)
                 .await?
                 .into_inner();
-            println!(
-                "log_index={} log_hash={}",
-                response.log_index, response.log_hash
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "log_index={} log_hash={}",
+                    response.log_index, response.log_hash
+                ),
+            )?;
         }
         PersonalDbCommands::CatchUp(args) => {
             let mut request = tonic::Request::new(api::PersonalDbCatchUpRequest {
@@ -214,12 +213,15 @@ pub async fn handle_personaldb_command(
             });
             add_auth(&mut request, &token);
             let response = client.catch_up_personal_db(request).await?.into_inner();
-            println!(
-                "entries={} has_more={} snapshot_required={}",
-                response.entries.len(),
-                response.has_more,
-                response.snapshot_required
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "entries={} has_more={} snapshot_required={}",
+                    response.entries.len(),
+                    response.has_more,
+                    response.snapshot_required
+                ),
+            )?;
         }
         PersonalDbCommands::Watch {
             database_id,
@@ -236,23 +238,30 @@ pub async fn handle_personaldb_command(
             let mut stream = client.watch_personal_db_group(request).await?.into_inner();
             while let Some(item) = stream.next().await {
                 let item = item?;
-                println!(
-                    "{}:{}\t{}",
-                    item.cursor_low, item.cursor_high, item.event_type
-                );
+                ctx.print(
+                    &item,
+                    format!(
+                        "{}:{}\t{}",
+                        item.cursor_low, item.cursor_high, item.event_type
+                    ),
+                )?;
             }
         }
     }
     Ok(())
 }
 
-fn print_group(response: api::PersonalDbGroupResponse) {
-    if let Some(manifest) = response.manifest {
-        println!(
-            "{}\t{}\t{}",
-            manifest.tenant_id, manifest.database_id, manifest.schema_hash
-        );
-    }
+fn group_text(response: &api::PersonalDbGroupResponse) -> String {
+    response
+        .manifest
+        .as_ref()
+        .map(|manifest| {
+            format!(
+                "{}\t{}\t{}",
+                manifest.tenant_id, manifest.database_id, manifest.schema_hash
+            )
+        })
+        .unwrap_or_default()
 }
 
 fn add_auth<T>(request: &mut tonic::Request<T>, token: &str) {
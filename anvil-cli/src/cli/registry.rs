@@ -83,7 +83,7 @@ pub async fn handle_registry_command(command: &RegistryCommands, ctx: &Context)
             });
             attach_auth(&mut request, &token)?;
             let response = client.put_package_blob(request).await?.into_inner();
-            println!("{}", serde_json::to_string_pretty(&response)?);
+            ctx.print(&response, serde_json::to_string_pretty(&response)?)?;
         }
         RegistryCommands::PutVersion {
             registry_kind,
@@ -107,7 +107,7 @@ pub async fn handle_registry_command(command: &RegistryCommands, ctx: &Context)
             });
             attach_auth(&mut request, &token)?;
             let response = client.put_package_version(request).await?.into_inner();
-            println!("{}", serde_json::to_string_pretty(&response)?);
+            ctx.print(&response, serde_json::to_string_pretty(&response)?)?;
         }
         RegistryCommands::PutRef {
             registry_kind,
@@ -128,7 +128,7 @@ pub async fn handle_registry_command(command: &RegistryCommands, ctx: &Context)
             });
             attach_auth(&mut request, &token)?;
             let response = client.put_registry_ref(request).await?.into_inner();
-            println!("{}", serde_json::to_string_pretty(&response)?);
+            ctx.print(&response, serde_json::to_string_pretty(&response)?)?;
         }
         RegistryCommands::GetVersion {
             registry_kind,
@@ -148,7 +148,7 @@ pub async fn handle_registry_command(command: &RegistryCommands, ctx: &Context)
             });
             attach_auth(&mut request, &token)?;
             let response = client.get_package_version(request).await?.into_inner();
-            println!("{}", serde_json::to_string_pretty(&response)?);
+            ctx.print(&response, serde_json::to_string_pretty(&response)?)?;
         }
         RegistryCommands::ListVersions {
             registry_kind,
@@ -170,7 +170,7 @@ pub async fn handle_registry_command(command: &RegistryCommands, ctx: &Context)
             });
             attach_auth(&mut request, &token)?;
             let response = client.list_package_versions(request).await?.into_inner();
-            println!("{}", serde_json::to_string_pretty(&response)?);
+            ctx.print(&response, serde_json::to_string_pretty(&response)?)?;
         }
     }
     Ok(())
@@ -57,10 +57,13 @@ pub async fn handle_repair_command(command: &RepairCommands, ctx: &Context) -> a
                 });
                 add_auth(&mut request, &token);
                 let response = client.repair_index(request).await?.into_inner();
-                println!(
-                    "{}\t{}\t{}",
-                    response.status, response.bucket_name, response.index_name
-                );
+                ctx.print(
+                    &response,
+                    format!(
+                        "{}\t{}\t{}",
+                        response.status, response.bucket_name, response.index_name
+                    ),
+                )?;
             }
             RepairTarget::Directory { bucket, rebuild } => {
                 let mut request = tonic::Request::new(api::RepairDirectoryIndexRequest {
@@ -69,10 +72,13 @@ pub async fn handle_repair_command(command: &RepairCommands, ctx: &Context) -> a
                 });
                 add_auth(&mut request, &token);
                 let response = client.repair_directory_index(request).await?.into_inner();
-                println!(
-                    "{}\t{}\t{}",
-                    response.status, response.bucket_name, response.reason
-                );
+                ctx.print(
+                    &response,
+                    format!(
+                        "{}\t{}\t{}",
+                        response.status, response.bucket_name, response.reason
+                    ),
+                )?;
             }
             RepairTarget::AuthzDerived {
                 derived_index_id,
@@ -87,7 +93,10 @@ pub async fn handle_repair_command(command: &RepairCommands, ctx: &Context) -> a
                     .repair_authz_derived_index(request)
                     .await?
                     .into_inner();
-                println!("{}\t{}", response.status, response.derived_index_id);
+                ctx.print(
+                    &response,
+                    format!("{}\t{}", response.status, response.derived_index_id),
+                )?;
             }
             RepairTarget::PersonalDb { database_id } => {
                 let mut request = tonic::Request::new(api::RepairPersonalDbLogChainRequest {
@@ -98,10 +107,13 @@ pub async fn handle_repair_command(command: &RepairCommands, ctx: &Context) -> a
                     .repair_personal_db_log_chain(request)
                     .await?
                     .into_inner();
-                println!(
-                    "{}\t{}\t{}",
-                    response.status, response.tenant_id, response.database_id
-                );
+                ctx.print(
+                    &response,
+                    format!(
+                        "{}\t{}\t{}",
+                        response.status, response.tenant_id, response.database_id
+                    ),
+                )?;
             }
         },
         RepairCommands::Findings {
@@ -115,17 +127,18 @@ pub async fn handle_repair_command(command: &RepairCommands, ctx: &Context) -> a
                 limit: *limit,
             });
             add_auth(&mut request, &token);
-            for finding in client
-                .list_repair_findings(request)
-                .await?
-                .into_inner()
+            let response = client.list_repair_findings(request).await?.into_inner();
+            let lines = response
                 .findings
-            {
-                println!(
-                    "{}\t{}\t{}\t{}",
-                    finding.finding_id, finding.severity, finding.status, finding.message
-                );
-            }
+                .iter()
+                .map(|finding| {
+                    format!(
+                        "{}\t{}\t{}\t{}",
+                        finding.finding_id, finding.severity, finding.status, finding.message
+                    )
+                })
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
     }
     Ok(())
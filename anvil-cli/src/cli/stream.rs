@@ -80,10 +80,13 @@ pub async fn handle_stream_command(command: &StreamCommands, ctx: &Context) -> a
             });
             add_auth(&mut request, &token);
             let response = client.create_append_stream(request).await?.into_inner();
-            println!(
-                "stream_id={} version_id={}",
-                response.stream_id, response.version_id
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "stream_id={} version_id={}",
+                    response.stream_id, response.version_id
+                ),
+            )?;
         }
         StreamCommands::Append {
             bucket,
@@ -114,10 +117,13 @@ pub async fn handle_stream_command(command: &StreamCommands, ctx: &Context) -> a
             });
             add_auth(&mut request, &token);
             let response = client.append_stream_record(request).await?.into_inner();
-            println!(
-                "sequence={} hash={}",
-                response.record_sequence, response.payload_hash
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "sequence={} hash={}",
+                    response.record_sequence, response.payload_hash
+                ),
+            )?;
         }
         StreamCommands::Read {
             bucket,
@@ -139,21 +145,26 @@ pub async fn handle_stream_command(command: &StreamCommands, ctx: &Context) -> a
             });
             add_auth(&mut request, &token);
             let response = client.read_append_stream(request).await?.into_inner();
-            for record in response.records {
-                if *include_payload {
-                    println!(
-                        "{}\t{}\t{}",
-                        record.record_sequence,
-                        record.content_type,
-                        String::from_utf8_lossy(&record.payload)
-                    );
-                } else {
-                    println!(
-                        "{}\t{}\t{}",
-                        record.record_sequence, record.payload_size, record.payload_hash
-                    );
-                }
-            }
+            let lines = response
+                .records
+                .iter()
+                .map(|record| {
+                    if *include_payload {
+                        format!(
+                            "{}\t{}\t{}",
+                            record.record_sequence,
+                            record.content_type,
+                            String::from_utf8_lossy(&record.payload)
+                        )
+                    } else {
+                        format!(
+                            "{}\t{}\t{}",
+                            record.record_sequence, record.payload_size, record.payload_hash
+                        )
+                    }
+                })
+                .collect::<Vec<_>>();
+            ctx.print(&response, lines.join("\n"))?;
         }
         StreamCommands::Tail {
             bucket,
@@ -175,8 +186,11 @@ pub async fn handle_stream_command(command: &StreamCommands, ctx: &Context) -> a
             let mut stream = client.tail_append_stream(request).await?.into_inner();
             while let Some(item) = stream.next().await {
                 let item = item?;
-                if let Some(record) = item.record {
-                    println!("{}\t{}", record.record_sequence, record.payload_hash);
+                if let Some(record) = &item.record {
+                    ctx.print(
+                        &item,
+                        format!("{}\t{}", record.record_sequence, record.payload_hash),
+                    )?;
                 }
             }
         }
@@ -201,10 +215,13 @@ pub async fn handle_stream_command(command: &StreamCommands, ctx: &Context) -> a
                 .seal_append_stream_segment(request)
                 .await?
                 .into_inner();
-            println!(
-                "records={} segment_hash={}",
-                response.record_count, response.segment_hash
-            );
+            ctx.print(
+                &response,
+                format!(
+                    "records={} segment_hash={}",
+                    response.record_count, response.segment_hash
+                ),
+            )?;
         }
     }
     Ok(())
@@ -63,9 +63,13 @@ pub async fn handle_transaction_command(
             });
             attach_bearer(&mut request, &token);
             let response = client.begin_transaction(request).await?.into_inner();
-            println!("transaction_id={}", response.transaction_id);
-            println!("state={}", response.state);
-            println!("expires_at_unix_nanos={}", response.expires_at_unix_nanos);
+            ctx.print(
+                &response,
+                format!(
+                    "transaction_id={}\nstate={}\nexpires_at_unix_nanos={}",
+                    response.transaction_id, response.state, response.expires_at_unix_nanos
+                ),
+            )?;
         }
         TransactionCommands::Commit {
             transaction_id,
@@ -83,11 +87,15 @@ pub async fn handle_transaction_command(
             });
             attach_bearer(&mut request, &token);
             let response = client.commit_transaction(request).await?.into_inner();
-            println!("mutation_id={}", response.mutation_id);
-            println!("state={:?}", api::WriteState::try_from(response.state));
+            let mut text = format!(
+                "mutation_id={}\nstate={:?}",
+                response.mutation_id,
+                api::WriteState::try_from(response.state)
+            );
             if let Some(root_generation) = response.root_generation {
-                println!("root_generation={root_generation}");
+                text.push_str(&format!("\nroot_generation={root_generation}"));
             }
+            ctx.print(&response, text)?;
         }
         TransactionCommands::Rollback {
             transaction_id,
@@ -99,8 +107,13 @@ pub async fn handle_transaction_command(
             });
             attach_bearer(&mut request, &token);
             let response = client.rollback_transaction(request).await?.into_inner();
-            println!("transaction_id={}", response.transaction_id);
-            println!("state={}", response.state);
+            ctx.print(
+                &response,
+                format!(
+                    "transaction_id={}\nstate={}",
+                    response.transaction_id, response.state
+                ),
+            )?;
         }
         TransactionCommands::Get { transaction_id } => {
             let mut request = tonic::Request::new(api::GetTransactionRequest {
@@ -108,15 +121,17 @@ pub async fn handle_transaction_command(
             });
             attach_bearer(&mut request, &token);
             let response = client.get_transaction(request).await?.into_inner();
-            println!("transaction_id={}", response.transaction_id);
-            println!("state={}", response.state);
-            println!("root_key_hash={}", response.root_key_hash);
+            let mut text = format!(
+                "transaction_id={}\nstate={}\nroot_key_hash={}",
+                response.transaction_id, response.state, response.root_key_hash
+            );
             if let Some(root_generation) = response.committed_root_generation {
-                println!("committed_root_generation={root_generation}");
+                text.push_str(&format!("\ncommitted_root_generation={root_generation}"));
             }
-            if let Some(error) = response.error {
-                println!("error={} {}", error.code, error.message);
+            if let Some(error) = &response.error {
+                text.push_str(&format!("\nerror={} {}", error.code, error.message));
             }
+            ctx.print(&response, text)?;
         }
     }
     Ok(())
@@ -62,7 +62,10 @@ pub async fn handle_watch_command(command: &WatchCommands, ctx: &Context) -> any
             let mut stream = client.watch_prefix(request).await?.into_inner();
             while let Some(item) = stream.next().await {
                 let item = item?;
-                println!("{}\t{}\t{}", item.cursor, item.event_type, item.object_key);
+                ctx.print(
+                    &item,
+                    format!("{}\t{}\t{}", item.cursor, item.event_type, item.object_key),
+                )?;
             }
         }
         WatchCommands::IndexDefinition {
@@ -78,7 +81,7 @@ pub async fn handle_watch_command(command: &WatchCommands, ctx: &Context) -> any
             let mut stream = client.watch_index_definition(request).await?.into_inner();
             while let Some(item) = stream.next().await {
                 let item = item?;
-                println!("{}\t{}", item.cursor, item.event_type);
+                ctx.print(&item, format!("{}\t{}", item.cursor, item.event_type))?;
             }
         }
         WatchCommands::IndexPartition {
@@ -100,10 +103,13 @@ pub async fn handle_watch_command(command: &WatchCommands, ctx: &Context) -> any
             let mut stream = client.watch_index_partition(request).await?.into_inner();
             while let Some(item) = stream.next().await {
                 let item = item?;
-                println!(
-                    "{}:{}\t{}",
-                    item.cursor_low, item.cursor_high, item.event_type
-                );
+                ctx.print(
+                    &item,
+                    format!(
+                        "{}:{}\t{}",
+                        item.cursor_low, item.cursor_high, item.event_type
+                    ),
+                )?;
             }
         }
         WatchCommands::Authz {
@@ -120,10 +126,17 @@ pub async fn handle_watch_command(command: &WatchCommands, ctx: &Context) -> any
             let mut stream = client.watch_authz_tuple_log(request).await?.into_inner();
             while let Some(item) = stream.next().await {
                 let item = item?;
-                println!(
-                    "{}\t{}\t{}:{}#{}",
-                    item.revision, item.operation, item.namespace, item.object_id, item.relation
-                );
+                ctx.print(
+                    &item,
+                    format!(
+                        "{}\t{}\t{}:{}#{}",
+                        item.revision,
+                        item.operation,
+                        item.namespace,
+                        item.object_id,
+                        item.relation
+                    ),
+                )?;
             }
         }
         WatchCommands::PersonalDb {
@@ -143,10 +156,13 @@ pub async fn handle_watch_command(command: &WatchCommands, ctx: &Context) -> any
             let mut stream = client.watch_personal_db_group(request).await?.into_inner();
             while let Some(item) = stream.next().await {
                 let item = item?;
-                println!(
-                    "{}:{}\t{}",
-                    item.cursor_low, item.cursor_high, item.event_type
-                );
+                ctx.print(
+                    &item,
+                    format!(
+                        "{}:{}\t{}",
+                        item.cursor_low, item.cursor_high, item.event_type
+                    ),
+                )?;
             }
         }
     }
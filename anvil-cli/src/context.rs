@@ -2,10 +2,22 @@ use crate::config::{Config, Profile};
 use anvil::anvil_api as api;
 use anvil::anvil_api::auth_service_client::AuthServiceClient;
 use anyhow::{Result, anyhow};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+/// How commands render their results, selected with the global `--output` flag.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// One JSON document per result, for scripts and CI.
+    Json,
+}
+
+#[derive(Clone)]
 pub struct Context {
     pub profile: Profile,
+    pub output: OutputFormat,
 }
 
 impl Context {
@@ -35,7 +47,10 @@ impl Context {
             profile.host = format!("http://{}", profile.host);
         }
 
-        Ok(Self { profile })
+        Ok(Self {
+            profile,
+            output: OutputFormat::default(),
+        })
     }
 
     #[allow(dead_code)]
@@ -51,7 +66,23 @@ impl Context {
                 client_id: String::new(),
                 client_secret: String::new(),
             },
+            output: OutputFormat::default(),
+        }
+    }
+
+    /// Prints one command result: `text` in text mode, skipped when empty, or
+    /// `value` serialized as a single JSON line in JSON mode.
+    pub fn print<T: Serialize>(&self, value: &T, text: impl std::fmt::Display) -> Result<()> {
+        match self.output {
+            OutputFormat::Text => {
+                let text = text.to_string();
+                if !text.is_empty() {
+                    println!("{text}");
+                }
+            }
+            OutputFormat::Json => println!("{}", serde_json::to_string(value)?),
         }
+        Ok(())
     }
 
     #[allow(dead_code)]
@@ -91,6 +122,7 @@ impl Context {
             .get_access_token(api::GetAccessTokenRequest {
                 client_id,
                 client_secret,
+                requested_ttl_secs: 0,
             })
             .await?
             .into_inner();
@@ -2,7 +2,7 @@ mod cli;
 mod config;
 mod context;
 
-use crate::context::Context;
+use crate::context::{Context, OutputFormat};
 use clap::{Parser, Subcommand};
 
 #[derive(Parser)]
@@ -14,6 +14,9 @@ struct Cli {
     profile: Option<String>,
     #[clap(long, global = true)]
     config: Option<String>,
+    /// Output format for command results.
+    #[clap(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
 }
 
 #[derive(Subcommand)]
@@ -172,7 +175,8 @@ async fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
-    let ctx = Context::new(cli.profile, cli.config)?;
+    let mut ctx = Context::new(cli.profile, cli.config)?;
+    ctx.output = cli.output;
 
     match &cli.command {
         Commands::Configure { .. } => { /* handled above */ }
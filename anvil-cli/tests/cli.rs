@@ -82,6 +82,17 @@ async fn test_cli_configure_and_bucket_ls() {
     assert!(output.status.success());
     let stdout = String::from_utf8(output.stdout).unwrap();
     assert!(stdout.contains(&bucket_name));
+
+    let output = run_cli(&["--output", "json", "bucket", "ls"], config_dir.path()).await;
+    assert!(output.status.success());
+    let listing: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(
+        listing["buckets"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|bucket| bucket["name"] == bucket_name.as_str())
+    );
 }
 
 #[tokio::test]
@@ -222,6 +233,60 @@ async fn test_cli_hf_ingestion() {
     let output = run_cli(&["object", "head", &dest], config_dir.path()).await;
     assert!(output.status.success());
 
+    let output = run_cli(
+        &[
+            "--output",
+            "json",
+            "hf",
+            "ingest",
+            "status",
+            "--id",
+            ingestion_id,
+        ],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+    let status: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(status["state"], "completed");
+
+    let output = run_cli(
+        &[
+            "hf",
+            "ingest",
+            "items",
+            "--id",
+            ingestion_id,
+            "--state",
+            "stored",
+        ],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout
+            .lines()
+            .any(|line| line.starts_with(&format!("{file}\tstored\t"))),
+        "stored items should list {file}: {stdout}"
+    );
+    let output = run_cli(
+        &[
+            "hf",
+            "ingest",
+            "items",
+            "--id",
+            ingestion_id,
+            "--state",
+            "failed",
+        ],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout).unwrap().trim().is_empty());
+
     // Verify anvil-index.json
     let index_key = format!("{}/anvil-index.json", repo);
     let index_dest = format!("s3://{}/{}", bucket_name, index_key);
@@ -316,6 +316,187 @@ async fn test_cli_object_ls() {
     assert!(stdout.contains(object_key));
 }
 
+#[tokio::test]
+async fn test_cli_object_put_multipart_round_trips() {
+    let cluster = shared_docker_test_cluster().await;
+    let config_dir = tempdir().unwrap();
+    let _ = setup_test_profile(&cluster, config_dir.path()).await;
+
+    let bucket_name = format!("my-object-multipart-bucket-{}", uuid::Uuid::new_v4());
+    let output = run_cli(
+        &["bucket", "create", &bucket_name, &cluster.region],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+
+    let temp_dir = tempdir().unwrap();
+    let file_path = temp_dir.path().join("large.bin");
+    let content = (0..11 * 1024 * 1024)
+        .map(|index| (index % 251) as u8)
+        .collect::<Vec<_>>();
+    std::fs::write(&file_path, &content).unwrap();
+
+    let dest = format!("s3://{}/large.bin", bucket_name);
+    let output = run_cli(
+        &[
+            "--output",
+            "json",
+            "object",
+            "put",
+            file_path.to_str().unwrap(),
+            &dest,
+            "--multipart-threshold-mib",
+            "1",
+            "--part-size-mib",
+            "5",
+            "--concurrency",
+            "2",
+        ],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+    // A multipart upload reports the S3 `<md5>-<part count>` ETag: 11 MiB in
+    // 5 MiB parts is three parts.
+    let uploaded: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(
+        uploaded["etag"].as_str().unwrap().ends_with("-3"),
+        "expected a three-part multipart ETag, got {uploaded}"
+    );
+
+    let download_path = temp_dir.path().join("downloaded.bin");
+    let output = run_cli(
+        &["object", "get", &dest, download_path.to_str().unwrap()],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+    assert_eq!(std::fs::read(&download_path).unwrap(), content);
+}
+
+#[tokio::test]
+async fn test_cli_object_sync_uploads_only_changes() {
+    let cluster = shared_docker_test_cluster().await;
+    let config_dir = tempdir().unwrap();
+    let _ = setup_test_profile(&cluster, config_dir.path()).await;
+
+    let bucket_name = format!("my-object-sync-bucket-{}", uuid::Uuid::new_v4());
+    let output = run_cli(
+        &["bucket", "create", &bucket_name, &cluster.region],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+
+    let local_dir = tempdir().unwrap();
+    std::fs::create_dir_all(local_dir.path().join("weights")).unwrap();
+    std::fs::write(local_dir.path().join("config.json"), "{}").unwrap();
+    std::fs::write(local_dir.path().join("weights/part-0.bin"), "part zero").unwrap();
+    let src = local_dir.path().to_str().unwrap();
+    let dest = format!("s3://{}/model/", bucket_name);
+
+    let output = run_cli(&["object", "sync", src, &dest], config_dir.path()).await;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("2 uploaded, 0 unchanged, 0 deleted"));
+
+    let output = run_cli(&["object", "sync", src, &dest], config_dir.path()).await;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("0 uploaded, 2 unchanged, 0 deleted"));
+
+    std::fs::write(local_dir.path().join("config.json"), "{\"v\":2}").unwrap();
+    std::fs::remove_file(local_dir.path().join("weights/part-0.bin")).unwrap();
+    let output = run_cli(
+        &["object", "sync", src, &dest, "--delete"],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("1 uploaded, 0 unchanged, 1 deleted"));
+
+    let output = run_cli(
+        &["object", "ls", &format!("s3://{}/model/", bucket_name)],
+        config_dir.path(),
+    )
+    .await;
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("model/config.json"));
+    assert!(!stdout.contains("model/weights/part-0.bin"));
+}
+
+#[tokio::test]
+async fn test_cli_object_cp_copies_between_buckets() {
+    let cluster = shared_docker_test_cluster().await;
+    let config_dir = tempdir().unwrap();
+    let _ = setup_test_profile(&cluster, config_dir.path()).await;
+
+    let src_bucket = format!("my-object-cp-src-{}", uuid::Uuid::new_v4());
+    let dest_bucket = format!("my-object-cp-dest-{}", uuid::Uuid::new_v4());
+    for bucket in [&src_bucket, &dest_bucket] {
+        let output = run_cli(
+            &["bucket", "create", bucket, &cluster.region],
+            config_dir.path(),
+        )
+        .await;
+        assert!(output.status.success());
+    }
+
+    let temp_dir = tempdir().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "hello from object cp test").unwrap();
+    for key in ["model/a.txt", "model/nested/b.txt"] {
+        let output = run_cli(
+            &[
+                "object",
+                "put",
+                file_path.to_str().unwrap(),
+                &format!("s3://{}/{}", src_bucket, key),
+            ],
+            config_dir.path(),
+        )
+        .await;
+        assert!(output.status.success());
+    }
+
+    let output = run_cli(
+        &[
+            "object",
+            "cp",
+            &format!("s3://{}/model/a.txt", src_bucket),
+            &format!("s3://{}/single/", dest_bucket),
+        ],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+
+    let output = run_cli(
+        &[
+            "object",
+            "cp",
+            "--recursive",
+            &format!("s3://{}/model", src_bucket),
+            &format!("s3://{}/copy", dest_bucket),
+        ],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+
+    let output = run_cli(
+        &["object", "ls", &format!("s3://{}/", dest_bucket)],
+        config_dir.path(),
+    )
+    .await;
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("single/a.txt"));
+    assert!(stdout.contains("copy/a.txt"));
+    assert!(stdout.contains("copy/nested/b.txt"));
+}
+
 #[tokio::test]
 async fn test_cli_object_get_to_file() {
     let cluster = shared_docker_test_cluster().await;
@@ -356,6 +537,77 @@ async fn test_cli_object_get_to_file() {
     assert_eq!(content, downloaded_content);
 }
 
+#[tokio::test]
+async fn test_cli_object_presign_get_and_put() {
+    let cluster = shared_docker_test_cluster().await;
+    let config_dir = tempdir().unwrap();
+    let _ = setup_test_profile(&cluster, config_dir.path()).await;
+
+    let bucket_name = format!("my-object-presign-bucket-{}", uuid::Uuid::new_v4());
+    let output = run_cli(
+        &["bucket", "create", &bucket_name, &cluster.region],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+    let temp_dir = tempdir().unwrap();
+    let file_path = temp_dir.path().join("shared.txt");
+    std::fs::write(&file_path, "shared without credentials").unwrap();
+    let dest_s3 = format!("s3://{bucket_name}/shared/report 1.txt");
+    let output = run_cli(
+        &["object", "put", file_path.to_str().unwrap(), &dest_s3],
+        config_dir.path(),
+    )
+    .await;
+    assert!(output.status.success());
+
+    let presign = |method: &'static str| {
+        let dest_s3 = dest_s3.clone();
+        let config_dir = config_dir.path().to_path_buf();
+        async move {
+            let output = run_cli(
+                &[
+                    "object",
+                    "presign",
+                    &dest_s3,
+                    "--expires",
+                    "15m",
+                    "--method",
+                    method,
+                ],
+                &config_dir,
+            )
+            .await;
+            assert!(output.status.success());
+            String::from_utf8(output.stdout).unwrap().trim().to_string()
+        }
+    };
+    let http = reqwest::Client::new();
+
+    let get_url = presign("get").await;
+    assert!(get_url.contains("X-Amz-Expires=900"), "{get_url}");
+    let response = http.get(&get_url).send().await.unwrap();
+    assert!(response.status().is_success(), "{response:?}");
+    assert_eq!(response.text().await.unwrap(), "shared without credentials");
+    let tampered = get_url.replace("report%201.txt", "report%202.txt");
+    let response = http.get(&tampered).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::FORBIDDEN);
+
+    let put_url = presign("put").await;
+    let response = http
+        .put(&put_url)
+        .body("replaced through a presigned PUT")
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success(), "{response:?}");
+    let response = http.get(&get_url).send().await.unwrap();
+    assert_eq!(
+        response.text().await.unwrap(),
+        "replaced through a presigned PUT"
+    );
+}
+
 #[tokio::test]
 async fn test_cli_hf_key_ls() {
     let cluster = shared_docker_test_cluster().await;
@@ -445,6 +697,8 @@ async fn test_cli_hf_ingest_cancel() {
 
     let output = run_cli(
         &[
+            "--output",
+            "json",
             "hf",
             "ingest",
             "start",
@@ -461,8 +715,8 @@ async fn test_cli_hf_ingest_cancel() {
     )
     .await;
     assert!(output.status.success());
-    let stdout = String::from_utf8(output.stdout).unwrap();
-    let ingestion_id = stdout.split_whitespace().last().unwrap();
+    let started: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let ingestion_id = started["ingestion_id"].as_str().unwrap();
 
     let output = run_cli(
         &["hf", "ingest", "cancel", "--id", ingestion_id],
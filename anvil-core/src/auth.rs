@@ -15,6 +15,24 @@ pub struct Claims {
     pub jti: Option<String>,
 }
 
+/// Access token lifetime used when the configuration leaves it unset.
+pub const DEFAULT_ACCESS_TOKEN_TTL_SECS: u64 = 3600;
+
+/// Lifetime to mint a token with: the configured lifetime (or the default when
+/// it is zero), shortened to `requested_secs` when the caller asks for less.
+pub fn access_token_ttl_secs(configured_secs: u64, requested_secs: u64) -> u64 {
+    let max_secs = if configured_secs == 0 {
+        DEFAULT_ACCESS_TOKEN_TTL_SECS
+    } else {
+        configured_secs
+    };
+    if requested_secs == 0 {
+        max_secs
+    } else {
+        requested_secs.min(max_secs)
+    }
+}
+
 #[derive(Debug)]
 pub struct JwtManager {
     secret: String,
@@ -26,9 +44,20 @@ impl JwtManager {
     }
 
     pub fn mint_token(&self, app_id: String, tenant_id: i64) -> Result<String> {
-        let expiration = chrono::Utc::now()
-            .checked_add_signed(chrono::Duration::hours(1))
-            .expect("valid timestamp")
+        self.mint_token_with_ttl(app_id, tenant_id, DEFAULT_ACCESS_TOKEN_TTL_SECS)
+    }
+
+    pub fn mint_token_with_ttl(
+        &self,
+        app_id: String,
+        tenant_id: i64,
+        ttl_secs: u64,
+    ) -> Result<String> {
+        let expiration = i64::try_from(ttl_secs)
+            .ok()
+            .and_then(chrono::Duration::try_seconds)
+            .and_then(|ttl| chrono::Utc::now().checked_add_signed(ttl))
+            .ok_or_else(|| anyhow::anyhow!("access token ttl {ttl_secs}s is out of range"))?
             .timestamp();
 
         let claims = Claims {
@@ -84,6 +113,22 @@ mod tests {
         assert_eq!(claims.tenant_id, 123);
     }
 
+    #[test]
+    fn access_token_ttl_is_capped_by_configuration() {
+        assert_eq!(access_token_ttl_secs(0, 0), DEFAULT_ACCESS_TOKEN_TTL_SECS);
+        assert_eq!(access_token_ttl_secs(900, 0), 900);
+        assert_eq!(access_token_ttl_secs(900, 60), 60);
+        assert_eq!(access_token_ttl_secs(900, 7200), 900);
+
+        let jwt_manager = JwtManager::new("test_secret".to_string());
+        let token = jwt_manager
+            .mint_token_with_ttl("test_app".to_string(), 123, 60)
+            .unwrap();
+        let claims = jwt_manager.verify_token(&token).unwrap();
+        let remaining = claims.exp as i64 - chrono::Utc::now().timestamp();
+        assert!((55..=60).contains(&remaining), "remaining={remaining}");
+    }
+
     #[test]
     fn test_verify_token_invalid_secret() {
         let jwt_manager = JwtManager::new("test_secret".to_string());
@@ -44,6 +44,12 @@ struct BucketJournalBody {
     bucket_name: String,
     region: String,
     is_public_read: bool,
+    policy_json: Option<String>,
+    max_bytes: Option<u64>,
+    max_objects: Option<u64>,
+    replicate_to: Vec<String>,
+    lifecycle_rules: Option<String>,
+    default_retention_days: Option<u32>,
     mutation_id: String,
     fence_token: u64,
     created_at: String,
@@ -74,6 +80,18 @@ struct BucketJournalBodyProto {
     emitted_at: Option<String>,
     #[prost(uint64, tag = "11")]
     fence_token: u64,
+    #[prost(string, optional, tag = "12")]
+    policy_json: Option<String>,
+    #[prost(uint64, optional, tag = "13")]
+    max_bytes: Option<u64>,
+    #[prost(uint64, optional, tag = "14")]
+    max_objects: Option<u64>,
+    #[prost(string, repeated, tag = "15")]
+    replicate_to: Vec<String>,
+    #[prost(string, optional, tag = "16")]
+    lifecycle_rules: Option<String>,
+    #[prost(uint32, optional, tag = "17")]
+    default_retention_days: Option<u32>,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -96,6 +114,18 @@ struct BucketCurrentRowProto {
     created_at: String,
     #[prost(bool, tag = "9")]
     is_public_read: bool,
+    #[prost(string, optional, tag = "10")]
+    policy_json: Option<String>,
+    #[prost(uint64, optional, tag = "11")]
+    max_bytes: Option<u64>,
+    #[prost(uint64, optional, tag = "12")]
+    max_objects: Option<u64>,
+    #[prost(string, repeated, tag = "13")]
+    replicate_to: Vec<String>,
+    #[prost(string, optional, tag = "14")]
+    lifecycle_rules: Option<String>,
+    #[prost(uint32, optional, tag = "15")]
+    default_retention_days: Option<u32>,
 }
 
 #[cfg(test)]
@@ -234,6 +264,12 @@ pub(crate) async fn stage_bucket_mutation_in_transaction(
             bucket_name: bucket.name.clone(),
             region: bucket.region.clone(),
             is_public_read: bucket.is_public_read,
+            policy_json: bucket.policy_json.clone(),
+            max_bytes: bucket.max_bytes,
+            max_objects: bucket.max_objects,
+            replicate_to: bucket.replicate_to.clone(),
+            lifecycle_rules: bucket.lifecycle_rules.clone(),
+            default_retention_days: bucket.default_retention_days,
             mutation_id: mutation_id.clone(),
             fence_token: 0,
             created_at: bucket.created_at.to_rfc3339(),
@@ -328,6 +364,12 @@ async fn append_bucket_mutation_to_stream(
         bucket_name: bucket.name.clone(),
         region: bucket.region.clone(),
         is_public_read: bucket.is_public_read,
+        policy_json: bucket.policy_json.clone(),
+        max_bytes: bucket.max_bytes,
+        max_objects: bucket.max_objects,
+        replicate_to: bucket.replicate_to.clone(),
+        lifecycle_rules: bucket.lifecycle_rules.clone(),
+        default_retention_days: bucket.default_retention_days,
         mutation_id: mutation_id.to_string(),
         fence_token,
         created_at: bucket.created_at.to_rfc3339(),
@@ -709,6 +751,12 @@ fn encode_bucket_current_row_with_root(
         region: bucket.region.clone(),
         created_at: bucket.created_at.to_rfc3339(),
         is_public_read: bucket.is_public_read,
+        policy_json: bucket.policy_json.clone(),
+        max_bytes: bucket.max_bytes,
+        max_objects: bucket.max_objects,
+        replicate_to: bucket.replicate_to.clone(),
+        lifecycle_rules: bucket.lifecycle_rules.clone(),
+        default_retention_days: bucket.default_retention_days,
     };
     encode_deterministic_proto(&row)
 }
@@ -734,6 +782,12 @@ fn decode_bucket_current_row(bytes: &[u8]) -> Result<BucketCurrentRow> {
         created_at: chrono::DateTime::parse_from_rfc3339(&row.created_at)?
             .with_timezone(&chrono::Utc),
         is_public_read: row.is_public_read,
+        policy_json: row.policy_json,
+        max_bytes: row.max_bytes,
+        max_objects: row.max_objects,
+        replicate_to: row.replicate_to,
+        lifecycle_rules: row.lifecycle_rules,
+        default_retention_days: row.default_retention_days,
     };
     Ok(BucketCurrentRow {
         deleted: row.deleted,
@@ -1002,6 +1056,12 @@ fn encode_bucket_journal_body(body: &BucketJournalBody) -> Result<Vec<u8>> {
         bucket_name: body.bucket_name.clone(),
         region: body.region.clone(),
         is_public_read: body.is_public_read,
+        policy_json: body.policy_json.clone(),
+        max_bytes: body.max_bytes,
+        max_objects: body.max_objects,
+        replicate_to: body.replicate_to.clone(),
+        lifecycle_rules: body.lifecycle_rules.clone(),
+        default_retention_days: body.default_retention_days,
         mutation_id: body.mutation_id.clone(),
         fence_token: body.fence_token,
         created_at: body.created_at.clone(),
@@ -1025,6 +1085,12 @@ fn decode_bucket_journal_body(bytes: &[u8]) -> Result<BucketJournalBody> {
         bucket_name: proto.bucket_name,
         region: proto.region,
         is_public_read: proto.is_public_read,
+        policy_json: proto.policy_json,
+        max_bytes: proto.max_bytes,
+        max_objects: proto.max_objects,
+        replicate_to: proto.replicate_to,
+        lifecycle_rules: proto.lifecycle_rules,
+        default_retention_days: proto.default_retention_days,
         mutation_id: proto.mutation_id,
         fence_token: proto.fence_token,
         created_at: proto.created_at,
@@ -1061,6 +1127,12 @@ mod tests {
             region: "test-region".to_string(),
             created_at: Utc::now(),
             is_public_read,
+            policy_json: None,
+            max_bytes: None,
+            max_objects: None,
+            replicate_to: Vec::new(),
+            lifecycle_rules: None,
+            default_retention_days: None,
         }
     }
 
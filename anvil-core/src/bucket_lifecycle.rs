@@ -0,0 +1,109 @@
+use crate::persistence::Bucket;
+use anyhow::{Result, anyhow, bail};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+pub const MAX_LIFECYCLE_RULES: usize = 100;
+/// Longest expiration accepted, roughly a century.
+pub const MAX_EXPIRATION_DAYS: u32 = 36_500;
+
+/// Expires current objects under `prefix` once they are `expiration_days`
+/// old, like an S3 lifecycle `Expiration` action. An empty prefix covers the
+/// whole bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifecycleRule {
+    #[serde(default)]
+    pub prefix: String,
+    pub expiration_days: u32,
+}
+
+impl LifecycleRule {
+    /// Whether an object written at `created_at` has outlived this rule.
+    pub fn is_expired(&self, created_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        created_at + Duration::days(i64::from(self.expiration_days)) <= now
+    }
+}
+
+/// Checks `rules` and renders them in the form stored on the bucket. An
+/// empty rule list clears the configuration.
+pub fn encode_lifecycle_rules(rules: &[LifecycleRule]) -> Result<Option<String>> {
+    if rules.is_empty() {
+        return Ok(None);
+    }
+    if rules.len() > MAX_LIFECYCLE_RULES {
+        bail!("at most {MAX_LIFECYCLE_RULES} lifecycle rules are allowed");
+    }
+    for (index, rule) in rules.iter().enumerate() {
+        if !(1..=MAX_EXPIRATION_DAYS).contains(&rule.expiration_days) {
+            bail!("rule {index}: expiration_days must be between 1 and {MAX_EXPIRATION_DAYS}");
+        }
+        if !rule.prefix.is_empty() && !crate::validation::is_valid_object_key(&rule.prefix) {
+            bail!("rule {index}: invalid prefix {:?}", rule.prefix);
+        }
+        if rules[..index]
+            .iter()
+            .any(|earlier| earlier.prefix == rule.prefix)
+        {
+            bail!("rule {index}: prefix {:?} is listed twice", rule.prefix);
+        }
+    }
+    Ok(Some(serde_json::to_string(rules)?))
+}
+
+/// Lifecycle rules stored on `bucket`, empty when none are configured.
+pub fn bucket_lifecycle_rules(bucket: &Bucket) -> Result<Vec<LifecycleRule>> {
+    let Some(rules) = bucket.lifecycle_rules.as_deref() else {
+        return Ok(Vec::new());
+    };
+    serde_json::from_str(rules).map_err(|err| {
+        anyhow!(
+            "stored lifecycle rules for bucket {} are corrupt: {err}",
+            bucket.name
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(prefix: &str, expiration_days: u32) -> LifecycleRule {
+        LifecycleRule {
+            prefix: prefix.to_string(),
+            expiration_days,
+        }
+    }
+
+    #[test]
+    fn rules_expire_objects_once_they_reach_the_configured_age() {
+        let now = Utc::now();
+        let rule = rule("tmp/", 7);
+
+        assert!(rule.is_expired(now - Duration::days(7), now));
+        assert!(rule.is_expired(now - Duration::days(30), now));
+        assert!(!rule.is_expired(now - Duration::days(6), now));
+    }
+
+    #[test]
+    fn encoding_validates_and_clears_rules() {
+        assert_eq!(encode_lifecycle_rules(&[]).unwrap(), None);
+        let encoded = encode_lifecycle_rules(&[rule("tmp/", 7), rule("", 365)])
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<LifecycleRule>>(&encoded).unwrap(),
+            [rule("tmp/", 7), rule("", 365)]
+        );
+
+        for invalid in [
+            vec![rule("tmp/", 0)],
+            vec![rule("tmp/", MAX_EXPIRATION_DAYS + 1)],
+            vec![rule("tmp/", 7), rule("tmp/", 30)],
+        ] {
+            assert!(
+                encode_lifecycle_rules(&invalid).is_err(),
+                "rules should be rejected: {invalid:?}"
+            );
+        }
+    }
+}
@@ -12,6 +12,7 @@ use tonic::Status;
 pub struct BucketManager {
     persistence: Persistence,
     storage: Storage,
+    max_buckets_per_tenant: u64,
 }
 
 impl BucketManager {
@@ -19,19 +20,32 @@ impl BucketManager {
         Self {
             persistence,
             storage,
+            max_buckets_per_tenant: 0,
         }
     }
 
+    /// Caps how many buckets a tenant may own unless the tenant carries its
+    /// own override. Zero disables the limit.
+    pub fn with_max_buckets_per_tenant(mut self, max_buckets: u64) -> Self {
+        self.max_buckets_per_tenant = max_buckets;
+        self
+    }
+
+    /// Checks the tenant's bucket limit for a create staged in a transaction,
+    /// which is only counted once it commits. Direct creates are checked by
+    /// [`Self::create_bucket`] together with the write.
+    pub async fn ensure_bucket_capacity(&self, tenant_id: i64) -> Result<(), Status> {
+        self.persistence
+            .ensure_tenant_bucket_capacity(tenant_id, self.max_buckets_per_tenant)
+            .await
+    }
+
     pub async fn create_bucket(
         &self,
         claims: &auth::Claims,
         bucket_name: &str,
         region: &str,
     ) -> Result<Bucket, Status> {
-        tracing::debug!(
-            "[manager] ENTERING create_bucket for bucket: {}",
-            bucket_name
-        );
         if !validation::is_valid_bucket_name(bucket_name) {
             return Err(Status::invalid_argument("Invalid bucket name"));
         }
@@ -44,12 +58,16 @@ impl BucketManager {
         )
         .await?;
 
-        tracing::debug!("[manager] Creating bucket metadata: {}", bucket_name);
+        tracing::trace!(bucket = bucket_name, region, "creating bucket metadata");
         let bucket = self
             .persistence
-            .create_bucket(claims.tenant_id, bucket_name, region)
-            .await
-            .map_err(|e| Status::internal(e.to_string()))?;
+            .create_bucket_within_limit(
+                claims.tenant_id,
+                bucket_name,
+                region,
+                self.max_buckets_per_tenant,
+            )
+            .await?;
         access_control::grant_bucket_defaults(
             &self.persistence,
             &bucket,
@@ -60,10 +78,6 @@ impl BucketManager {
         .await
         .map_err(|e| Status::internal(e.to_string()))?;
 
-        tracing::debug!(
-            "[manager] EXITING create_bucket for bucket: {}",
-            bucket_name
-        );
         Ok(bucket)
     }
 
@@ -114,7 +128,6 @@ impl BucketManager {
 
     pub async fn list_buckets(&self, claims: &auth::Claims) -> Result<Vec<Bucket>, Status> {
         let tenant_id = claims.tenant_id;
-        tracing::debug!("[manager] ENTERING list_buckets for tenant: {}", tenant_id);
         access_control::require_action(
             &self.storage,
             &self.persistence,
@@ -124,18 +137,11 @@ impl BucketManager {
         )
         .await?;
 
-        tracing::debug!(
-            "[manager] Reading bucket metadata journal for tenant: {}",
-            tenant_id
-        );
+        tracing::trace!(tenant_id, "reading bucket metadata journal");
         let buckets = bucket_journal::read_current_buckets(&self.storage, tenant_id)
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
 
-        tracing::debug!(
-            "[manager] EXITING list_buckets, found {} buckets",
-            buckets.len()
-        );
         Ok(buckets)
     }
 
@@ -159,9 +165,58 @@ impl BucketManager {
                 .map_err(|e| Status::internal(e.to_string()))?
                 .ok_or_else(|| Status::not_found("Bucket not found"))?;
 
-        Ok(serde_json::json!({
-            "is_public_read": bucket.is_public_read,
-        }))
+        // Public-read buckets without a stored policy document report the
+        // projection that legacy clients write.
+        match bucket.policy_json.as_deref() {
+            Some(policy_json) => serde_json::from_str(policy_json)
+                .map_err(|e| Status::internal(format!("Stored bucket policy is corrupt: {e}"))),
+            None if bucket.is_public_read => Ok(serde_json::json!({
+                "is_public_read": true,
+            })),
+            None => Err(Status::not_found(format!(
+                "NoSuchBucketPolicy: bucket {bucket_name} has no policy"
+            ))),
+        }
+    }
+
+    /// Removes the bucket's policy document and its public-read flag, leaving
+    /// reads to authz tuples alone.
+    pub async fn delete_bucket_policy(
+        &self,
+        claims: &auth::Claims,
+        bucket_name: &str,
+    ) -> Result<Bucket, Status> {
+        let bucket = self
+            .set_bucket_public_access(claims, bucket_name, false)
+            .await?;
+        if bucket.policy_json.is_none() {
+            return Ok(bucket);
+        }
+        self.persistence
+            .set_bucket_policy(claims.tenant_id, bucket_name, None)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))
+    }
+
+    pub async fn set_bucket_policy_document(
+        &self,
+        claims: &auth::Claims,
+        bucket_name: &str,
+        policy_json: String,
+    ) -> Result<Bucket, Status> {
+        access_control::require_action(
+            &self.storage,
+            &self.persistence,
+            claims,
+            AnvilAction::BucketWrite,
+            bucket_name,
+        )
+        .await?;
+
+        self.persistence
+            .set_bucket_policy(claims.tenant_id, bucket_name, Some(policy_json))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))
     }
 
     pub async fn set_bucket_public_access(
@@ -196,4 +251,32 @@ impl BucketManager {
 
         Ok(bucket)
     }
+
+    /// Sets the retention given to new objects that do not set their own;
+    /// `None` stops protecting new objects by default.
+    pub async fn set_bucket_default_retention(
+        &self,
+        claims: &auth::Claims,
+        bucket_name: &str,
+        default_retention_days: Option<u32>,
+    ) -> Result<Bucket, Status> {
+        if default_retention_days == Some(0) {
+            return Err(Status::invalid_argument(
+                "InvalidArgument: default retention must be at least one day",
+            ));
+        }
+        access_control::require_action(
+            &self.storage,
+            &self.persistence,
+            claims,
+            AnvilAction::BucketWrite,
+            bucket_name,
+        )
+        .await?;
+
+        self.persistence
+            .set_bucket_default_retention(claims.tenant_id, bucket_name, default_retention_days)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))
+    }
 }
@@ -0,0 +1,319 @@
+use crate::persistence::Bucket;
+use anyhow::{Result, anyhow, bail};
+use serde_json::Value as JsonValue;
+
+const RESOURCE_ARN_PREFIX: &str = "arn:aws:s3:::";
+const GET_OBJECT_ACTION: &str = "s3:getobject";
+const SUPPORTED_POLICY_VERSIONS: &[&str] = &["2012-10-17", "2008-10-17"];
+const UNSUPPORTED_STATEMENT_KEYS: &[&str] =
+    &["Condition", "NotAction", "NotPrincipal", "NotResource"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyEffect {
+    Allow,
+    Deny,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PolicyStatement {
+    effect: PolicyEffect,
+    actions: Vec<String>,
+    resources: Vec<String>,
+}
+
+/// Parsed S3-style bucket policy. Only `"Principal": "*"` statements are
+/// supported: an Allow grants anonymous reads, while a Deny applies to every
+/// caller, including ones whose authz tuples allow the read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BucketPolicy {
+    statements: Vec<PolicyStatement>,
+}
+
+impl BucketPolicy {
+    pub fn parse(bucket_name: &str, document: &JsonValue) -> Result<Self> {
+        let document = document
+            .as_object()
+            .ok_or_else(|| anyhow!("policy document must be a JSON object"))?;
+        if let Some(version) = document.get("Version") {
+            let version = version
+                .as_str()
+                .ok_or_else(|| anyhow!("Version must be a string"))?;
+            if !SUPPORTED_POLICY_VERSIONS.contains(&version) {
+                bail!("unsupported policy Version {version}");
+            }
+        }
+        let statements = match document.get("Statement") {
+            Some(JsonValue::Array(statements)) => statements.iter().collect::<Vec<_>>(),
+            Some(statement @ JsonValue::Object(_)) => vec![statement],
+            Some(_) => bail!("Statement must be an object or an array of objects"),
+            None => bail!("policy document is missing Statement"),
+        };
+        if statements.is_empty() {
+            bail!("Statement must not be empty");
+        }
+        let statements = statements
+            .into_iter()
+            .enumerate()
+            .map(|(index, statement)| {
+                parse_statement(bucket_name, statement)
+                    .map_err(|err| anyhow!("Statement[{index}]: {err}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { statements })
+    }
+
+    pub fn from_json(bucket_name: &str, policy_json: &str) -> Result<Self> {
+        let document: JsonValue = serde_json::from_str(policy_json)?;
+        Self::parse(bucket_name, &document)
+    }
+
+    /// Effect of this policy on an `s3:GetObject` of `object_key`, or `None`
+    /// when no statement applies. An explicit deny always wins.
+    pub fn object_read_effect(&self, bucket_name: &str, object_key: &str) -> Option<PolicyEffect> {
+        let resource = format!("{RESOURCE_ARN_PREFIX}{bucket_name}/{object_key}");
+        let mut effect = None;
+        for statement in &self.statements {
+            let action_matches = statement
+                .actions
+                .iter()
+                .any(|action| wildcard_matches(action, GET_OBJECT_ACTION));
+            let resource_matches = statement
+                .resources
+                .iter()
+                .any(|pattern| wildcard_matches(pattern, &resource));
+            if !action_matches || !resource_matches {
+                continue;
+            }
+            match statement.effect {
+                PolicyEffect::Deny => return Some(PolicyEffect::Deny),
+                PolicyEffect::Allow => effect = Some(PolicyEffect::Allow),
+            }
+        }
+        effect
+    }
+}
+
+/// What a `PutBucketPolicy` body changes on the bucket. Documents carrying a
+/// `Statement` are stored as policies; the legacy `{"is_public_read": bool}`
+/// shape keeps toggling the bucket-wide public-read flag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BucketPolicyUpdate {
+    PublicRead(bool),
+    Document(String),
+}
+
+impl BucketPolicyUpdate {
+    pub fn parse(bucket_name: &str, policy_json: &str) -> Result<Self> {
+        let document: JsonValue = serde_json::from_str(policy_json)
+            .map_err(|err| anyhow!("Invalid policy JSON: {err}"))?;
+        if document.get("Statement").is_none() {
+            return Ok(Self::PublicRead(
+                document["is_public_read"].as_bool().unwrap_or(false),
+            ));
+        }
+        BucketPolicy::parse(bucket_name, &document)
+            .map_err(|err| anyhow!("Invalid bucket policy: {err}"))?;
+        Ok(Self::Document(document.to_string()))
+    }
+
+    pub fn apply(&self, bucket: &mut Bucket) {
+        match self {
+            Self::PublicRead(is_public_read) => bucket.is_public_read = *is_public_read,
+            Self::Document(policy_json) => bucket.policy_json = Some(policy_json.clone()),
+        }
+    }
+}
+
+fn parse_statement(bucket_name: &str, statement: &JsonValue) -> Result<PolicyStatement> {
+    let statement = statement
+        .as_object()
+        .ok_or_else(|| anyhow!("statement must be a JSON object"))?;
+    if let Some(key) = UNSUPPORTED_STATEMENT_KEYS
+        .iter()
+        .find(|key| statement.contains_key(**key))
+    {
+        bail!("{key} is not supported");
+    }
+    let effect = match statement.get("Effect").and_then(JsonValue::as_str) {
+        Some("Allow") => PolicyEffect::Allow,
+        Some("Deny") => PolicyEffect::Deny,
+        Some(other) => bail!("Effect must be Allow or Deny, got {other}"),
+        None => bail!("Effect is required"),
+    };
+    let principal = statement
+        .get("Principal")
+        .ok_or_else(|| anyhow!("Principal is required"))?;
+    if !is_anonymous_principal(principal) {
+        bail!("only the \"*\" Principal is supported");
+    }
+    let actions = string_or_list(statement.get("Action"), "Action")?
+        .into_iter()
+        .map(|action| {
+            if action != "*" && !action.starts_with("s3:") {
+                bail!("Action {action} is not an s3 action");
+            }
+            Ok(action.to_ascii_lowercase())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let resources = string_or_list(statement.get("Resource"), "Resource")?;
+    for resource in &resources {
+        let target = resource
+            .strip_prefix(RESOURCE_ARN_PREFIX)
+            .ok_or_else(|| anyhow!("Resource {resource} must start with {RESOURCE_ARN_PREFIX}"))?;
+        let target_bucket = target.split('/').next().unwrap_or_default();
+        if target_bucket != bucket_name {
+            bail!("Resource {resource} does not belong to bucket {bucket_name}");
+        }
+    }
+    Ok(PolicyStatement {
+        effect,
+        actions,
+        resources,
+    })
+}
+
+fn is_anonymous_principal(principal: &JsonValue) -> bool {
+    match principal {
+        JsonValue::String(value) => value == "*",
+        JsonValue::Object(map) if map.len() == 1 => match map.get("AWS") {
+            Some(JsonValue::String(value)) => value == "*",
+            Some(JsonValue::Array(values)) => {
+                !values.is_empty() && values.iter().all(|value| value.as_str() == Some("*"))
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn string_or_list(value: Option<&JsonValue>, field: &str) -> Result<Vec<String>> {
+    let values = match value {
+        Some(JsonValue::String(value)) => vec![value.clone()],
+        Some(JsonValue::Array(values)) => values
+            .iter()
+            .map(|value| {
+                value
+                    .as_str()
+                    .map(ToString::to_string)
+                    .ok_or_else(|| anyhow!("{field} entries must be strings"))
+            })
+            .collect::<Result<Vec<_>>>()?,
+        Some(_) => bail!("{field} must be a string or an array of strings"),
+        None => bail!("{field} is required"),
+    };
+    if values.is_empty() {
+        bail!("{field} must not be empty");
+    }
+    Ok(values)
+}
+
+/// Matches IAM-style patterns where `*` spans any run of characters and `?`
+/// matches exactly one.
+fn wildcard_matches(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let value = value.as_bytes();
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack = None;
+    while v < value.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == value[v]) {
+            p += 1;
+            v += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, v));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            v = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|byte| *byte == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn public_models_policy() -> JsonValue {
+        json!({
+            "Version": "2012-10-17",
+            "Statement": [
+                {
+                    "Sid": "PublicModels",
+                    "Effect": "Allow",
+                    "Principal": "*",
+                    "Action": "s3:GetObject",
+                    "Resource": "arn:aws:s3:::models/public/*"
+                },
+                {
+                    "Effect": "Deny",
+                    "Principal": {"AWS": "*"},
+                    "Action": ["s3:Get*"],
+                    "Resource": ["arn:aws:s3:::models/public/embargoed/*"]
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn object_reads_follow_prefix_grants_and_explicit_denies() {
+        let policy = BucketPolicy::parse("models", &public_models_policy()).unwrap();
+
+        assert_eq!(
+            policy.object_read_effect("models", "public/llama/config.json"),
+            Some(PolicyEffect::Allow)
+        );
+        assert_eq!(
+            policy.object_read_effect("models", "public/embargoed/weights.bin"),
+            Some(PolicyEffect::Deny)
+        );
+        assert_eq!(
+            policy.object_read_effect("models", "private/weights.bin"),
+            None
+        );
+    }
+
+    #[test]
+    fn policy_validation_rejects_malformed_or_foreign_statements() {
+        let cases = [
+            json!({"Version": "2012-10-17"}),
+            json!({"Statement": []}),
+            json!({"Statement": {"Effect": "Maybe", "Principal": "*", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::models/*"}}),
+            json!({"Statement": {"Effect": "Allow", "Principal": {"AWS": "arn:aws:iam::1:root"}, "Action": "s3:GetObject", "Resource": "arn:aws:s3:::models/*"}}),
+            json!({"Statement": {"Effect": "Allow", "Principal": "*", "Action": "ec2:RunInstances", "Resource": "arn:aws:s3:::models/*"}}),
+            json!({"Statement": {"Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::other/*"}}),
+            json!({"Statement": {"Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::models/*", "Condition": {}}}),
+        ];
+        for case in cases {
+            assert!(
+                BucketPolicy::parse("models", &case).is_err(),
+                "policy should be rejected: {case}"
+            );
+        }
+    }
+
+    #[test]
+    fn policy_update_keeps_the_legacy_public_read_shape() {
+        assert_eq!(
+            BucketPolicyUpdate::parse("models", r#"{"is_public_read": true}"#).unwrap(),
+            BucketPolicyUpdate::PublicRead(true)
+        );
+        assert!(matches!(
+            BucketPolicyUpdate::parse("models", &public_models_policy().to_string()).unwrap(),
+            BucketPolicyUpdate::Document(_)
+        ));
+    }
+
+    #[test]
+    fn wildcards_match_iam_patterns() {
+        assert!(wildcard_matches("a*c", "abbbc"));
+        assert!(wildcard_matches("a?c", "abc"));
+        assert!(wildcard_matches("*", ""));
+        assert!(wildcard_matches("a*b*c", "axxbyyc"));
+        assert!(!wildcard_matches("a*c", "abcd"));
+        assert!(!wildcard_matches("a?c", "ac"));
+    }
+}
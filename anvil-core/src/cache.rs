@@ -67,6 +67,12 @@ mod tests {
             region: "test-region".to_string(),
             created_at: Utc::now(),
             is_public_read: false,
+            policy_json: None,
+            max_bytes: None,
+            max_objects: None,
+            replicate_to: Vec::new(),
+            lifecycle_rules: None,
+            default_retention_days: None,
         }
     }
 
@@ -1,5 +1,5 @@
 use anyhow::{Result, anyhow};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use futures_util::StreamExt;
 use hmac::{Hmac, Mac};
 use libp2p::{
@@ -25,6 +25,19 @@ use crate::core_store::{decode_deterministic_proto, encode_deterministic_proto};
 pub struct PeerInfo {
     pub p2p_addrs: Vec<String>,
     pub grpc_addr: String,
+    /// Failure-domain label the peer advertised, if any.
+    #[serde(default)]
+    pub zone: Option<String>,
+    /// Free bytes on the peer's storage volume at its last broadcast.
+    #[serde(default)]
+    pub free_bytes: Option<u64>,
+    /// When this node last heard from the peer over gossip.
+    #[serde(default = "Utc::now")]
+    pub last_seen: DateTime<Utc>,
+    /// Set by the reaper once the peer has been silent for half the peer
+    /// timeout; placement skips stale peers until they gossip again.
+    #[serde(default)]
+    pub stale: bool,
 }
 
 // The shared state of the cluster membership.
@@ -37,17 +50,39 @@ pub struct ClusterMessage {
     pub peer_id: PeerId,
     pub p2p_addrs: Vec<String>,
     pub grpc_addr: String,
+    #[serde(default)]
+    pub zone: Option<String>,
+    #[serde(default)]
+    pub free_bytes: Option<u64>,
     pub timestamp: i64,
     #[serde(with = "serde_bytes")]
     pub signature: Vec<u8>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum MetadataEvent {
     BucketUpdated { tenant_id: i64, name: String },
     TenantUpdated { api_key: String },
 }
 
+// Events are logged as they are published and received, so keep tenant API
+// keys out of the Debug output.
+impl std::fmt::Debug for MetadataEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BucketUpdated { tenant_id, name } => f
+                .debug_struct("BucketUpdated")
+                .field("tenant_id", tenant_id)
+                .field("name", name)
+                .finish(),
+            Self::TenantUpdated { .. } => f
+                .debug_struct("TenantUpdated")
+                .field("api_key", &"<redacted>")
+                .finish(),
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Message)]
 struct ClusterMessageProto {
     #[prost(string, tag = "1")]
@@ -60,6 +95,10 @@ struct ClusterMessageProto {
     timestamp: i64,
     #[prost(bytes = "vec", tag = "5")]
     signature: Vec<u8>,
+    #[prost(string, optional, tag = "6")]
+    zone: Option<String>,
+    #[prost(uint64, optional, tag = "7")]
+    free_bytes: Option<u64>,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -101,6 +140,8 @@ fn encode_cluster_message(message: &ClusterMessage) -> Vec<u8> {
         grpc_addr: message.grpc_addr.clone(),
         timestamp: message.timestamp,
         signature: message.signature.clone(),
+        zone: message.zone.clone(),
+        free_bytes: message.free_bytes,
     })
 }
 
@@ -113,6 +154,8 @@ fn decode_cluster_message(bytes: &[u8]) -> Result<ClusterMessage> {
             .map_err(|err| anyhow!("cluster gossip peer_id is invalid: {err}"))?,
         p2p_addrs: proto.p2p_addrs,
         grpc_addr: proto.grpc_addr,
+        zone: proto.zone,
+        free_bytes: proto.free_bytes,
         timestamp: proto.timestamp,
         signature: proto.signature,
     })
@@ -156,6 +199,14 @@ impl ClusterMessage {
         mac.update(&self.peer_id.to_bytes());
         mac.update(self.p2p_addrs.join(",").as_bytes());
         mac.update(self.grpc_addr.as_bytes());
+        // Messages without the optional fields keep the original MAC input
+        // so mixed-version clusters still verify each other.
+        if let Some(zone) = &self.zone {
+            mac.update(zone.as_bytes());
+        }
+        if let Some(free_bytes) = self.free_bytes {
+            mac.update(&free_bytes.to_le_bytes());
+        }
         mac.update(&self.timestamp.to_le_bytes());
         self.signature = mac.finalize().into_bytes().to_vec();
         Ok(())
@@ -167,12 +218,91 @@ impl ClusterMessage {
         mac.update(&self.peer_id.to_bytes());
         mac.update(self.p2p_addrs.join(",").as_bytes());
         mac.update(self.grpc_addr.as_bytes());
+        if let Some(zone) = &self.zone {
+            mac.update(zone.as_bytes());
+        }
+        if let Some(free_bytes) = self.free_bytes {
+            mac.update(&free_bytes.to_le_bytes());
+        }
         mac.update(&self.timestamp.to_le_bytes());
         mac.verify_slice(&self.signature)?;
         Ok(())
     }
 }
 
+/// Free bytes available to unprivileged writers on the volume holding
+/// `storage_path`, or `None` when the platform or path cannot report it.
+#[cfg(unix)]
+pub fn storage_free_bytes(storage_path: &str) -> Option<u64> {
+    let stats = rustix::fs::statvfs(storage_path).ok()?;
+    Some(stats.f_bavail.saturating_mul(stats.f_frsize))
+}
+
+#[cfg(not(unix))]
+pub fn storage_free_bytes(_storage_path: &str) -> Option<u64> {
+    None
+}
+
+/// Whether the local peer row should pick up a new free-space reading.
+/// Sub-GiB churn is ignored; placement ranks capacity in far coarser steps.
+fn free_bytes_moved(recorded: Option<u64>, current: Option<u64>) -> bool {
+    const FREE_BYTES_REFRESH_STEP: u64 = 1 << 30;
+    match (recorded, current) {
+        (Some(recorded), Some(current)) => recorded.abs_diff(current) >= FREE_BYTES_REFRESH_STEP,
+        (recorded, current) => recorded != current,
+    }
+}
+
+/// How often a node broadcasts its membership message. Peers must be heard
+/// from several times per `peer_timeout`, so short timeouts shorten it.
+fn heartbeat_interval(peer_timeout: Duration) -> Duration {
+    const DEFAULT_HEARTBEAT: Duration = Duration::from_secs(5);
+    if peer_timeout.is_zero() {
+        return DEFAULT_HEARTBEAT;
+    }
+    (peer_timeout / 3).clamp(Duration::from_millis(100), DEFAULT_HEARTBEAT)
+}
+
+/// Flags peers silent for half of `peer_timeout` as stale and removes those
+/// silent for all of it, returning the evicted peers. The local peer is
+/// never reaped; a zero timeout disables reaping.
+pub async fn reap_stale_peers(
+    cluster_state: &ClusterState,
+    local_peer_id: PeerId,
+    peer_timeout: Duration,
+    now: DateTime<Utc>,
+) -> Vec<PeerId> {
+    if peer_timeout.is_zero() {
+        return Vec::new();
+    }
+    let silence_of = |info: &PeerInfo| (now - info.last_seen).to_std().unwrap_or_default();
+    // Most heartbeats change nothing; placement reads the table on every
+    // write, so check under the read lock first.
+    let unchanged = cluster_state.read().await.iter().all(|(peer_id, info)| {
+        *peer_id == local_peer_id
+            || (silence_of(info) < peer_timeout
+                && info.stale == (silence_of(info) >= peer_timeout / 2))
+    });
+    if unchanged {
+        return Vec::new();
+    }
+    let mut state = cluster_state.write().await;
+    let mut evicted = Vec::new();
+    state.retain(|peer_id, info| {
+        if *peer_id == local_peer_id {
+            return true;
+        }
+        let silence = silence_of(info);
+        if silence >= peer_timeout {
+            evicted.push(*peer_id);
+            return false;
+        }
+        info.stale = silence >= peer_timeout / 2;
+        true
+    });
+    evicted
+}
+
 // A module for custom PeerId serialization
 mod serde_peer_id {
     use libp2p::PeerId;
@@ -286,8 +416,11 @@ pub async fn run_gossip(
     mut swarm: Swarm<ClusterBehaviour>,
     cluster_state: ClusterState,
     grpc_addr: String,
+    zone: Option<String>,
+    storage_path: String,
     cluster_secret: Option<String>,
     metadata_cache: MetadataCache,
+    peer_timeout: Duration,
     mut outbound_events: tokio::sync::mpsc::Receiver<MetadataEvent>,
 ) -> Result<()> {
     let cluster_topic = Topic::new("anvil-cluster");
@@ -303,14 +436,38 @@ pub async fn run_gossip(
         state.entry(local_peer_id).or_insert_with(|| PeerInfo {
             p2p_addrs: Vec::new(),
             grpc_addr: grpc_addr.clone(),
+            zone: zone.clone(),
+            free_bytes: storage_free_bytes(&storage_path),
+            last_seen: Utc::now(),
+            stale: false,
         });
     }
 
-    let mut broadcast_interval = tokio::time::interval(Duration::from_secs(5));
+    let mut broadcast_interval = tokio::time::interval(heartbeat_interval(peer_timeout));
 
     loop {
         tokio::select! {
             _ = broadcast_interval.tick() => {
+                let free_bytes = storage_free_bytes(&storage_path);
+                // Placement reads the peer table on every write, so only take
+                // the write lock when the local row's capacity really moved.
+                let stale_row = cluster_state
+                    .read()
+                    .await
+                    .get(&local_peer_id)
+                    .is_some_and(|info| free_bytes_moved(info.free_bytes, free_bytes));
+                if stale_row {
+                    if let Some(info) = cluster_state.write().await.get_mut(&local_peer_id) {
+                        info.free_bytes = free_bytes;
+                        info.last_seen = Utc::now();
+                    }
+                }
+
+                for peer_id in reap_stale_peers(&cluster_state, local_peer_id, peer_timeout, Utc::now()).await {
+                    info!("[GOSSIP] Evicted unresponsive peer: {peer_id}");
+                    swarm.behaviour_mut().gossipsub.remove_explicit_peer(&peer_id);
+                }
+
                 let p2p_addrs = swarm.listeners().map(|addr| addr.to_string()).collect::<Vec<_>>();
                 if p2p_addrs.is_empty() {
                     continue;
@@ -320,6 +477,8 @@ pub async fn run_gossip(
                     peer_id: local_peer_id,
                     p2p_addrs: p2p_addrs.clone(),
                     grpc_addr: grpc_addr.clone(),
+                    zone: zone.clone(),
+                    free_bytes,
                     timestamp: Utc::now().timestamp(),
                     signature: Vec::new(),
                 };
@@ -347,7 +506,7 @@ pub async fn run_gossip(
             }
 
             event = swarm.select_next_some() => {
-                handle_swarm_event(event, &mut swarm, &cluster_state, &grpc_addr, &cluster_secret, &metadata_cache).await;
+                handle_swarm_event(event, &mut swarm, &cluster_state, &grpc_addr, zone.as_deref(), &cluster_secret, &metadata_cache).await;
             }
         }
     }
@@ -358,6 +517,7 @@ pub async fn handle_swarm_event(
     swarm: &mut Swarm<ClusterBehaviour>,
     cluster_state: &ClusterState,
     grpc_addr: &str,
+    zone: Option<&str>,
     cluster_secret: &Option<String>,
     metadata_cache: &MetadataCache,
 ) {
@@ -372,6 +532,10 @@ pub async fn handle_swarm_event(
             let info = state.entry(local_peer_id).or_insert_with(|| PeerInfo {
                 p2p_addrs: Vec::new(),
                 grpc_addr: grpc_addr.to_string(),
+                zone: zone.map(str::to_string),
+                free_bytes: None,
+                last_seen: Utc::now(),
+                stale: false,
             });
             let addr_string = address.to_string();
             if !info.p2p_addrs.contains(&addr_string) {
@@ -432,7 +596,15 @@ pub async fn handle_swarm_event(
                         .or_insert_with(|| PeerInfo {
                             p2p_addrs: Vec::new(),
                             grpc_addr: cluster_message.grpc_addr,
+                            zone: None,
+                            free_bytes: None,
+                            last_seen: Utc::now(),
+                            stale: false,
                         });
+                    info.zone = cluster_message.zone;
+                    info.free_bytes = cluster_message.free_bytes;
+                    info.last_seen = Utc::now();
+                    info.stale = false;
                     for addr in cluster_message.p2p_addrs {
                         if !info.p2p_addrs.contains(&addr) {
                             info.p2p_addrs.push(addr);
@@ -11,6 +11,11 @@ pub struct Config {
     #[arg(long, env)]
     pub jwt_secret: String,
 
+    /// Lifetime of access tokens minted by GetAccessToken, and the upper bound
+    /// for any shorter lifetime a client requests.
+    #[arg(long, env, default_value_t = crate::auth::DEFAULT_ACCESS_TOKEN_TTL_SECS)]
+    pub access_token_ttl_secs: u64,
+
     /// Active hex-encoded 32-byte key used for server-side secret encryption.
     #[arg(long, env)]
     pub anvil_secret_encryption_key: String,
@@ -45,6 +50,12 @@ pub struct Config {
     #[arg(long, env, default_value = "0.0.0.0:50051")]
     pub api_listen_addr: String,
 
+    /// Serve gRPC server reflection on the main listener so tools such as
+    /// `grpcurl` can list and describe methods without the .proto files. The
+    /// descriptors name every anvil_api service, internal ones included.
+    #[arg(long, env, default_value_t = false)]
+    pub grpc_reflection: bool,
+
     /// The address to bind the administrative gRPC service to.
     #[arg(long, env, default_value = "127.0.0.1:50052")]
     pub admin_listen_addr: String,
@@ -86,6 +97,11 @@ pub struct Config {
     #[arg(long, env, default_value = "default")]
     pub cell_id: String,
 
+    /// Failure-domain label (rack or availability zone) advertised to peers.
+    /// Placement spreads an object's shards across distinct zones when it can.
+    #[arg(long, env)]
+    pub zone: Option<String>,
+
     /// Region host suffix advertised for virtual-host routing.
     #[arg(long, env, default_value = "")]
     pub public_region_base_domain: String,
@@ -94,6 +110,11 @@ pub struct Config {
     #[arg(long, env, use_value_delimiter = true, value_delimiter = ',')]
     pub trusted_proxy_source_ranges: Vec<String>,
 
+    /// Serve whole objects for S3 `Range` requests and stop advertising
+    /// `Accept-Ranges: bytes` on GET and HEAD responses.
+    #[arg(long, env, default_value_t = false)]
+    pub s3_disable_range_requests: bool,
+
     /// Policy for requests whose bucket locator is owned by another region.
     #[arg(long, env, default_value_t = CrossRegionRoutingPolicy::RedirectPreferred)]
     pub cross_region_routing_policy: CrossRegionRoutingPolicy,
@@ -111,6 +132,17 @@ pub struct Config {
     #[arg(long, env, default_value_t = false)]
     pub init_cluster: bool,
 
+    /// Peers (including this node) that must be known before `/_anvil/readyz`
+    /// reports the node ready.
+    #[arg(long, env, default_value_t = 1)]
+    pub readiness_min_peers: usize,
+
+    /// Seconds without gossip from a peer before it is evicted from cluster
+    /// state. Placement skips a peer once it has been silent for half this
+    /// interval. Zero disables eviction.
+    #[arg(long, env, default_value_t = 30)]
+    pub cluster_peer_timeout_secs: u64,
+
     /// Enable mDNS for local peer discovery.
     #[arg(long, env, default_value_t = true)]
     pub enable_mdns: bool,
@@ -167,6 +199,99 @@ pub struct Config {
     /// Seconds that an in-process background task lease remains valid without renewal.
     #[arg(long, env, default_value_t = 300)]
     pub task_lease_ttl_secs: u64,
+
+    /// Failed attempts after which a background task is dead-lettered and no
+    /// longer retried. Zero uses the default of 10.
+    #[arg(long, env, default_value_t = 10)]
+    pub task_max_attempts: u32,
+
+    /// Minimum size in bytes of every multipart part except the last. Zero
+    /// disables the check.
+    #[arg(long, env, default_value_t = crate::object_manager::S3_MULTIPART_MIN_PART_SIZE)]
+    pub multipart_min_part_size_bytes: u64,
+
+    /// Largest object, or multipart part, accepted in bytes. Uploads that
+    /// declare or stream more are rejected with `EntityTooLarge`. Zero
+    /// disables the cap.
+    #[arg(long, env, default_value_t = crate::object_manager::S3_MAX_OBJECT_SIZE)]
+    pub max_object_size_bytes: u64,
+
+    /// Record and log a per-phase latency breakdown for every put_object. Intended
+    /// for profiling slow uploads; leave disabled in production.
+    #[arg(long, env, default_value_t = false)]
+    pub put_object_timing_breakdown: bool,
+
+    /// How long identical latest-consistency object listings are served from
+    /// a per-node cache. Writes to a bucket drop its cached listings. Zero
+    /// disables the cache.
+    #[arg(long, env, default_value_t = 0)]
+    pub object_list_cache_ttl_ms: u64,
+
+    /// Maximum number of listings held by the object listing cache.
+    #[arg(long, env, default_value_t = 1024)]
+    pub object_list_cache_max_entries: usize,
+
+    /// Default maximum number of buckets each tenant may own. Admins can
+    /// override it per tenant. Zero means unlimited.
+    #[arg(long, env, default_value_t = 0)]
+    pub max_buckets_per_tenant: u64,
+
+    /// Seconds between reloads of per-tenant request rate limits, so limits
+    /// set through another node take effect here. Zero disables the reload.
+    #[arg(long, env, default_value_t = 30)]
+    pub tenant_rate_limit_refresh_secs: u64,
+
+    /// Data shards per stripe for new objects. Together with `parity_shards`
+    /// this selects the default storage class; `0 + 0` keeps the release
+    /// default. Existing objects are read with the layout they were written with.
+    #[arg(long, env, default_value_t = 0)]
+    pub data_shards: usize,
+
+    /// Parity shards per stripe for new objects. See `data_shards`.
+    #[arg(long, env, default_value_t = 0)]
+    pub parity_shards: usize,
+
+    /// Shard reads kept in flight while reading one erasure-coded block. Zero
+    /// uses the CoreStore default.
+    #[arg(long, env, default_value_t = 0)]
+    pub shard_read_concurrency: usize,
+
+    /// Deadline in milliseconds for a single remote shard read before the
+    /// next placement is tried. Zero uses the CoreStore default.
+    #[arg(long, env, default_value_t = 0)]
+    pub shard_read_timeout_ms: u64,
+
+    /// Seconds between scheduled orphaned-shard garbage collection passes.
+    /// Zero disables the schedule; `admin gc run` still works.
+    #[arg(long, env, default_value_t = 3600)]
+    pub shard_gc_interval_secs: u64,
+
+    /// Seconds an erasure block must stay unreferenced after its last object
+    /// version is deleted before garbage collection removes its shards.
+    #[arg(long, env, default_value_t = 86400)]
+    pub shard_gc_grace_period_secs: u64,
+
+    /// Seconds between integrity scrubs of this node's local shard files.
+    /// Corrupt shards are quarantined so reads rebuild them from parity.
+    /// Zero disables the scrub.
+    #[arg(long, env, default_value_t = 86400)]
+    pub shard_scrub_interval_secs: u64,
+
+    /// Seconds between passes that expire objects matching bucket lifecycle
+    /// rules. Zero disables expiration.
+    #[arg(long, env, default_value_t = 3600)]
+    pub lifecycle_sweep_interval_secs: u64,
+
+    /// Buckets that receive their own label on per-bucket request metrics,
+    /// written `<tenant id>/<bucket name>`. When empty, the first
+    /// `metrics_bucket_label_limit` buckets observed are labelled.
+    #[arg(long, env, use_value_delimiter = true, value_delimiter = ',')]
+    pub metrics_bucket_label_allowlist: Vec<String>,
+
+    /// Maximum distinct bucket labels per node before further buckets are
+    /// reported as `__other__`. Ignored when an allowlist is configured.
+    #[arg(long, env, default_value_t = crate::observability::DEFAULT_BUCKET_LABEL_LIMIT)]
+    pub metrics_bucket_label_limit: usize,
 }
 
 fn parse_positive_usize(value: &str) -> std::result::Result<usize, String> {
@@ -196,6 +321,19 @@ impl Config {
         )
     }
 
+    pub fn bucket_label_policy(&self) -> crate::observability::BucketLabelPolicy {
+        crate::observability::BucketLabelPolicy {
+            allowlist: self
+                .metrics_bucket_label_allowlist
+                .iter()
+                .map(|bucket| bucket.trim())
+                .filter(|bucket| !bucket.is_empty())
+                .map(str::to_string)
+                .collect(),
+            max_buckets: self.metrics_bucket_label_limit,
+        }
+    }
+
     pub fn core_pipeline_keyring(&self) -> Result<crate::core_store::CorePipelineKeyring> {
         let active_key_id = self.active_encryption_key_id();
         crate::core_store::CorePipelineKeyring::from_hex_config(
@@ -205,6 +343,21 @@ impl Config {
         )
     }
 
+    pub fn core_store_options(&self) -> Result<crate::core_store::CoreStoreOptions> {
+        let mut options = crate::core_store::CoreStoreOptions::default();
+        options.storage_classes = options
+            .storage_classes
+            .with_default_byte_layout(self.data_shards, self.parity_shards)?;
+        if self.shard_read_concurrency != 0 {
+            options.shard_read_concurrency = self.shard_read_concurrency;
+        }
+        if self.shard_read_timeout_ms != 0 {
+            options.shard_read_timeout =
+                std::time::Duration::from_millis(self.shard_read_timeout_ms);
+        }
+        Ok(options)
+    }
+
     fn active_encryption_key_id(&self) -> &str {
         if self.anvil_secret_encryption_key_id.trim().is_empty() {
             "primary"
@@ -294,6 +447,44 @@ mod tests {
         assert!(Config::try_parse_from(invalid_args).is_err());
     }
 
+    #[test]
+    fn core_store_options_keep_defaults_unless_shard_settings_are_configured() {
+        let defaults = crate::core_store::CoreStoreOptions::default();
+        let unchanged = Config::default().core_store_options().unwrap();
+        assert_eq!(
+            unchanged.shard_read_concurrency,
+            defaults.shard_read_concurrency
+        );
+        assert_eq!(unchanged.shard_read_timeout, defaults.shard_read_timeout);
+        assert_eq!(unchanged.storage_classes, defaults.storage_classes);
+
+        let configured = Config {
+            data_shards: 1,
+            parity_shards: 2,
+            shard_read_concurrency: 2,
+            shard_read_timeout_ms: 250,
+            ..Config::default()
+        }
+        .core_store_options()
+        .unwrap();
+        assert_eq!(configured.shard_read_concurrency, 2);
+        assert_eq!(
+            configured.shard_read_timeout,
+            std::time::Duration::from_millis(250)
+        );
+        assert_eq!(
+            configured.storage_classes.default_class_id,
+            "low-latency-replicated"
+        );
+
+        let unsupported = Config {
+            data_shards: 5,
+            parity_shards: 1,
+            ..Config::default()
+        };
+        assert!(unsupported.core_store_options().is_err());
+    }
+
     #[test]
     fn production_config_has_no_personaldb_signer_process_or_private_key_input() {
         let command = Config::command();
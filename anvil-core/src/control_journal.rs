@@ -5,7 +5,8 @@ use crate::core_store::{
 };
 use crate::formats::{Hash32, hash32};
 use crate::partition_fence::{PartitionWritePermit, partition_write_precondition};
-use crate::persistence::{App, AppDetails, Tenant};
+use crate::persistence::{App, AppDetails, PreviousAppSecret, Tenant};
+use crate::rate_limit::TenantRateLimit;
 use crate::storage::Storage;
 use anyhow::{Result, anyhow, bail};
 use prost::{Message, Oneof};
@@ -25,6 +26,17 @@ enum ControlEventBody {
         id: i64,
         name: String,
     },
+    TenantBucketLimitUpdate {
+        tenant_id: i64,
+        max_buckets: Option<u64>,
+    },
+    TenantRateLimitUpdate {
+        tenant_id: i64,
+        rate_limit: Option<TenantRateLimit>,
+    },
+    TenantDelete {
+        tenant_id: i64,
+    },
     AppCreate {
         id: i64,
         tenant_id: i64,
@@ -35,6 +47,8 @@ enum ControlEventBody {
     AppSecretUpdate {
         app_id: i64,
         client_secret_encrypted: Vec<u8>,
+        previous_client_secret_encrypted: Vec<u8>,
+        previous_secret_expires_at: i64,
     },
     AppDelete {
         app_id: i64,
@@ -54,6 +68,8 @@ enum ControlCurrentRecord {
         id: i64,
         name: String,
         active: bool,
+        max_buckets: Option<u64>,
+        rate_limit: Option<TenantRateLimit>,
     },
     App {
         id: i64,
@@ -61,6 +77,8 @@ enum ControlCurrentRecord {
         name: String,
         client_id: String,
         client_secret_encrypted: Vec<u8>,
+        previous_client_secret_encrypted: Vec<u8>,
+        previous_secret_expires_at: i64,
         active: bool,
     },
 }
@@ -70,6 +88,9 @@ pub struct ControlState {
     next_id: i64,
     regions: BTreeSet<String>,
     tenants: BTreeMap<i64, Tenant>,
+    deleted_tenants: BTreeSet<i64>,
+    tenant_bucket_limits: BTreeMap<i64, u64>,
+    tenant_rate_limits: BTreeMap<i64, TenantRateLimit>,
     apps: BTreeMap<i64, StoredControlApp>,
 }
 
@@ -80,6 +101,10 @@ struct StoredControlApp {
     name: String,
     client_id: String,
     client_secret_encrypted: Vec<u8>,
+    /// Secret replaced by a graced rotation; empty when there is none.
+    previous_client_secret_encrypted: Vec<u8>,
+    /// Unix seconds after which the previous secret stops authenticating.
+    previous_secret_expires_at: i64,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -92,7 +117,10 @@ struct ControlEventProto {
     fence_token: u64,
     #[prost(string, tag = "4")]
     mutation_id: String,
-    #[prost(oneof = "control_event_proto::Event", tags = "10, 11, 12, 13, 14")]
+    #[prost(
+        oneof = "control_event_proto::Event",
+        tags = "10, 11, 12, 13, 14, 15, 16, 17"
+    )]
     event: Option<control_event_proto::Event>,
 }
 
@@ -111,6 +139,12 @@ mod control_event_proto {
         AppSecretUpdate(super::AppSecretUpdateProto),
         #[prost(message, tag = "14")]
         AppDelete(super::AppDeleteProto),
+        #[prost(message, tag = "15")]
+        TenantBucketLimitUpdate(super::TenantBucketLimitUpdateProto),
+        #[prost(message, tag = "16")]
+        TenantRateLimitUpdate(super::TenantRateLimitUpdateProto),
+        #[prost(message, tag = "17")]
+        TenantDelete(super::TenantDeleteProto),
     }
 }
 
@@ -154,6 +188,36 @@ struct TenantUpsertProto {
     name: String,
 }
 
+#[derive(Clone, PartialEq, Message)]
+struct TenantBucketLimitUpdateProto {
+    #[prost(int64, tag = "1")]
+    tenant_id: i64,
+    #[prost(uint64, optional, tag = "2")]
+    max_buckets: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct TenantRateLimitProto {
+    #[prost(uint32, tag = "1")]
+    requests_per_second: u32,
+    #[prost(uint32, tag = "2")]
+    burst: u32,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct TenantRateLimitUpdateProto {
+    #[prost(int64, tag = "1")]
+    tenant_id: i64,
+    #[prost(message, optional, tag = "2")]
+    rate_limit: Option<TenantRateLimitProto>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct TenantDeleteProto {
+    #[prost(int64, tag = "1")]
+    tenant_id: i64,
+}
+
 #[derive(Clone, PartialEq, Message)]
 struct AppCreateProto {
     #[prost(int64, tag = "1")]
@@ -174,6 +238,10 @@ struct AppSecretUpdateProto {
     app_id: i64,
     #[prost(bytes, tag = "2")]
     client_secret_encrypted: Vec<u8>,
+    #[prost(bytes, tag = "3")]
+    previous_client_secret_encrypted: Vec<u8>,
+    #[prost(int64, tag = "4")]
+    previous_secret_expires_at: i64,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -204,6 +272,10 @@ struct TenantCurrentProto {
     name: String,
     #[prost(bool, tag = "3")]
     active: bool,
+    #[prost(uint64, optional, tag = "4")]
+    max_buckets: Option<u64>,
+    #[prost(message, optional, tag = "5")]
+    rate_limit: Option<TenantRateLimitProto>,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -220,6 +292,10 @@ struct AppCurrentProto {
     client_secret_encrypted: Vec<u8>,
     #[prost(bool, tag = "6")]
     active: bool,
+    #[prost(bytes, tag = "7")]
+    previous_client_secret_encrypted: Vec<u8>,
+    #[prost(int64, tag = "8")]
+    previous_secret_expires_at: i64,
 }
 
 impl ControlState {
@@ -235,6 +311,14 @@ impl ControlState {
         self.tenants.values().cloned().collect()
     }
 
+    pub fn is_tenant_deleted(&self, tenant_id: i64) -> bool {
+        self.deleted_tenants.contains(&tenant_id)
+    }
+
+    pub fn tenant_by_id(&self, tenant_id: i64) -> Option<Tenant> {
+        self.tenants.get(&tenant_id).cloned()
+    }
+
     pub fn tenant_by_name(&self, name: &str) -> Option<Tenant> {
         self.tenants
             .values()
@@ -242,6 +326,24 @@ impl ControlState {
             .cloned()
     }
 
+    /// Per-tenant override of the configured bucket-count limit, if one is set.
+    pub fn tenant_max_buckets(&self, tenant_id: i64) -> Option<u64> {
+        self.tenant_bucket_limits.get(&tenant_id).copied()
+    }
+
+    /// Request rate limit set for `tenant_id`; tenants without one are unlimited.
+    pub fn tenant_rate_limit(&self, tenant_id: i64) -> Option<TenantRateLimit> {
+        self.tenant_rate_limits.get(&tenant_id).copied()
+    }
+
+    pub fn tenant_rate_limits(&self) -> BTreeMap<i64, TenantRateLimit> {
+        self.tenant_rate_limits.clone()
+    }
+
+    pub fn tenant_bucket_limits(&self) -> BTreeMap<i64, u64> {
+        self.tenant_bucket_limits.clone()
+    }
+
     pub fn app_by_name(&self, name: &str) -> Option<App> {
         self.apps
             .values()
@@ -265,11 +367,11 @@ impl ControlState {
         self.apps
             .values()
             .find(|app| app.client_id == client_id)
-            .map(|app| AppDetails {
-                id: app.id,
-                tenant_id: app.tenant_id,
-                client_secret_encrypted: app.client_secret_encrypted.clone(),
-            })
+            .map(app_details)
+    }
+
+    pub fn app_details_by_id(&self, id: i64) -> Option<AppDetails> {
+        self.apps.get(&id).map(app_details)
     }
 }
 
@@ -315,10 +417,24 @@ fn read_control_state_from_coremeta_rows(core_store: &CoreStore) -> Result<Contr
         &tenant_tuple_prefix()?,
     )? {
         match decode_control_current_row(&row.payload)? {
-            ControlCurrentRecord::Tenant { id, name, active } => {
+            ControlCurrentRecord::Tenant {
+                id,
+                name,
+                active,
+                max_buckets,
+                rate_limit,
+            } => {
                 state.next_id = state.next_id.max(id);
                 if active {
                     state.tenants.insert(id, Tenant { id, name });
+                    if let Some(max_buckets) = max_buckets {
+                        state.tenant_bucket_limits.insert(id, max_buckets);
+                    }
+                    if let Some(rate_limit) = rate_limit {
+                        state.tenant_rate_limits.insert(id, rate_limit);
+                    }
+                } else {
+                    state.deleted_tenants.insert(id);
                 }
             }
             _ => bail!("control tenant row contains a different record type"),
@@ -335,6 +451,8 @@ fn read_control_state_from_coremeta_rows(core_store: &CoreStore) -> Result<Contr
                 name,
                 client_id,
                 client_secret_encrypted,
+                previous_client_secret_encrypted,
+                previous_secret_expires_at,
                 active,
             } => {
                 state.next_id = state.next_id.max(id);
@@ -347,6 +465,8 @@ fn read_control_state_from_coremeta_rows(core_store: &CoreStore) -> Result<Contr
                             name,
                             client_id,
                             client_secret_encrypted,
+                            previous_client_secret_encrypted,
+                            previous_secret_expires_at,
                         },
                     );
                 }
@@ -458,6 +578,8 @@ async fn create_tenant_inner(
                 id: tenant.id,
                 name: tenant.name.clone(),
                 active: true,
+                max_buckets: None,
+                rate_limit: None,
             },
         ],
         fence_token,
@@ -553,6 +675,8 @@ async fn create_app_inner(
                 name: app.name.clone(),
                 client_id: app.client_id.clone(),
                 client_secret_encrypted: encrypted_secret.to_vec(),
+                previous_client_secret_encrypted: Vec::new(),
+                previous_secret_expires_at: 0,
                 active: true,
             },
         ],
@@ -563,15 +687,172 @@ async fn create_app_inner(
     Ok(app)
 }
 
+pub(crate) async fn set_tenant_max_buckets_with_permit(
+    storage: &Storage,
+    tenant_id: i64,
+    max_buckets: Option<u64>,
+    permit: &PartitionWritePermit,
+    partition_owner_signing_key: &[u8],
+) -> Result<()> {
+    let partition_precondition =
+        control_write_precondition(storage, permit, partition_owner_signing_key).await?;
+    set_tenant_max_buckets_inner(
+        storage,
+        tenant_id,
+        max_buckets,
+        permit.fence_token,
+        Some(partition_precondition),
+    )
+    .await
+}
+
+async fn set_tenant_max_buckets_inner(
+    storage: &Storage,
+    tenant_id: i64,
+    max_buckets: Option<u64>,
+    fence_token: u64,
+    partition_precondition: Option<CoreMutationPrecondition>,
+) -> Result<()> {
+    let state = read_control_state(storage).await?;
+    let tenant = state
+        .tenants
+        .get(&tenant_id)
+        .ok_or_else(|| anyhow!("tenant not found"))?;
+    append_control_event(
+        storage,
+        ControlEventBody::TenantBucketLimitUpdate {
+            tenant_id,
+            max_buckets,
+        },
+        vec![ControlCurrentRecord::Tenant {
+            id: tenant.id,
+            name: tenant.name.clone(),
+            active: true,
+            max_buckets,
+            rate_limit: state.tenant_rate_limit(tenant_id),
+        }],
+        fence_token,
+        partition_precondition,
+    )
+    .await
+}
+
+pub(crate) async fn set_tenant_rate_limit_with_permit(
+    storage: &Storage,
+    tenant_id: i64,
+    rate_limit: Option<TenantRateLimit>,
+    permit: &PartitionWritePermit,
+    partition_owner_signing_key: &[u8],
+) -> Result<()> {
+    let partition_precondition =
+        control_write_precondition(storage, permit, partition_owner_signing_key).await?;
+    set_tenant_rate_limit_inner(
+        storage,
+        tenant_id,
+        rate_limit,
+        permit.fence_token,
+        Some(partition_precondition),
+    )
+    .await
+}
+
+async fn set_tenant_rate_limit_inner(
+    storage: &Storage,
+    tenant_id: i64,
+    rate_limit: Option<TenantRateLimit>,
+    fence_token: u64,
+    partition_precondition: Option<CoreMutationPrecondition>,
+) -> Result<()> {
+    let state = read_control_state(storage).await?;
+    let tenant = state
+        .tenants
+        .get(&tenant_id)
+        .ok_or_else(|| anyhow!("tenant not found"))?;
+    append_control_event(
+        storage,
+        ControlEventBody::TenantRateLimitUpdate {
+            tenant_id,
+            rate_limit,
+        },
+        vec![ControlCurrentRecord::Tenant {
+            id: tenant.id,
+            name: tenant.name.clone(),
+            active: true,
+            max_buckets: state.tenant_max_buckets(tenant_id),
+            rate_limit,
+        }],
+        fence_token,
+        partition_precondition,
+    )
+    .await
+}
+
+#[cfg(test)]
+async fn delete_tenant(storage: &Storage, tenant_id: i64) -> Result<()> {
+    delete_tenant_inner(storage, tenant_id, 0, None).await
+}
+
+pub(crate) async fn delete_tenant_with_permit(
+    storage: &Storage,
+    tenant_id: i64,
+    permit: &PartitionWritePermit,
+    partition_owner_signing_key: &[u8],
+) -> Result<()> {
+    let partition_precondition =
+        control_write_precondition(storage, permit, partition_owner_signing_key).await?;
+    delete_tenant_inner(
+        storage,
+        tenant_id,
+        permit.fence_token,
+        Some(partition_precondition),
+    )
+    .await
+}
+
+async fn delete_tenant_inner(
+    storage: &Storage,
+    tenant_id: i64,
+    fence_token: u64,
+    partition_precondition: Option<CoreMutationPrecondition>,
+) -> Result<()> {
+    let state = read_control_state(storage).await?;
+    if !state.tenants.contains_key(&tenant_id) {
+        return Err(anyhow!("tenant not found"));
+    }
+    if state.apps.values().any(|app| app.tenant_id == tenant_id) {
+        bail!("tenant still owns apps");
+    }
+    append_control_event(
+        storage,
+        ControlEventBody::TenantDelete { tenant_id },
+        vec![ControlCurrentRecord::Tenant {
+            id: tenant_id,
+            name: String::new(),
+            active: false,
+            max_buckets: None,
+            rate_limit: None,
+        }],
+        fence_token,
+        partition_precondition,
+    )
+    .await
+}
+
 #[cfg(test)]
-async fn update_app_secret(storage: &Storage, app_id: i64, encrypted_secret: &[u8]) -> Result<()> {
-    update_app_secret_inner(storage, app_id, encrypted_secret, 0, None).await
+async fn update_app_secret(
+    storage: &Storage,
+    app_id: i64,
+    encrypted_secret: &[u8],
+    previous: Option<&PreviousAppSecret>,
+) -> Result<()> {
+    update_app_secret_inner(storage, app_id, encrypted_secret, previous, 0, None).await
 }
 
 pub(crate) async fn update_app_secret_with_permit(
     storage: &Storage,
     app_id: i64,
     encrypted_secret: &[u8],
+    previous: Option<&PreviousAppSecret>,
     permit: &PartitionWritePermit,
     partition_owner_signing_key: &[u8],
 ) -> Result<()> {
@@ -581,6 +862,7 @@ pub(crate) async fn update_app_secret_with_permit(
         storage,
         app_id,
         encrypted_secret,
+        previous,
         permit.fence_token,
         Some(partition_precondition),
     )
@@ -591,6 +873,7 @@ async fn update_app_secret_inner(
     storage: &Storage,
     app_id: i64,
     encrypted_secret: &[u8],
+    previous: Option<&PreviousAppSecret>,
     fence_token: u64,
     partition_precondition: Option<CoreMutationPrecondition>,
 ) -> Result<()> {
@@ -599,11 +882,21 @@ async fn update_app_secret_inner(
         .apps
         .get(&app_id)
         .ok_or_else(|| anyhow!("app not found"))?;
+    let (previous_client_secret_encrypted, previous_secret_expires_at) = previous
+        .map(|previous| {
+            (
+                previous.client_secret_encrypted.clone(),
+                previous.expires_at.timestamp(),
+            )
+        })
+        .unwrap_or_default();
     append_control_event(
         storage,
         ControlEventBody::AppSecretUpdate {
             app_id,
             client_secret_encrypted: encrypted_secret.to_vec(),
+            previous_client_secret_encrypted: previous_client_secret_encrypted.clone(),
+            previous_secret_expires_at,
         },
         vec![ControlCurrentRecord::App {
             id: existing.id,
@@ -611,6 +904,8 @@ async fn update_app_secret_inner(
             name: existing.name.clone(),
             client_id: existing.client_id.clone(),
             client_secret_encrypted: encrypted_secret.to_vec(),
+            previous_client_secret_encrypted,
+            previous_secret_expires_at,
             active: true,
         }],
         fence_token,
@@ -655,6 +950,8 @@ async fn delete_app_inner(
             name: String::new(),
             client_id: String::new(),
             client_secret_encrypted: Vec::new(),
+            previous_client_secret_encrypted: Vec::new(),
+            previous_secret_expires_at: 0,
             active: false,
         }],
         fence_token,
@@ -734,6 +1031,22 @@ fn app_record(app: &StoredControlApp) -> App {
     }
 }
 
+fn app_details(app: &StoredControlApp) -> AppDetails {
+    let previous_client_secret = (!app.previous_client_secret_encrypted.is_empty())
+        .then(|| chrono::DateTime::from_timestamp(app.previous_secret_expires_at, 0))
+        .flatten()
+        .map(|expires_at| PreviousAppSecret {
+            client_secret_encrypted: app.previous_client_secret_encrypted.clone(),
+            expires_at,
+        });
+    AppDetails {
+        id: app.id,
+        tenant_id: app.tenant_id,
+        client_secret_encrypted: app.client_secret_encrypted.clone(),
+        previous_client_secret,
+    }
+}
+
 fn control_current_update(
     record: ControlCurrentRecord,
     mutation_id: &str,
@@ -777,6 +1090,27 @@ fn encode_control_event_body(
                     name: name.clone(),
                 })
             }
+            ControlEventBody::TenantBucketLimitUpdate {
+                tenant_id,
+                max_buckets,
+            } => {
+                control_event_proto::Event::TenantBucketLimitUpdate(TenantBucketLimitUpdateProto {
+                    tenant_id: *tenant_id,
+                    max_buckets: *max_buckets,
+                })
+            }
+            ControlEventBody::TenantRateLimitUpdate {
+                tenant_id,
+                rate_limit,
+            } => control_event_proto::Event::TenantRateLimitUpdate(TenantRateLimitUpdateProto {
+                tenant_id: *tenant_id,
+                rate_limit: rate_limit.map(rate_limit_to_proto),
+            }),
+            ControlEventBody::TenantDelete { tenant_id } => {
+                control_event_proto::Event::TenantDelete(TenantDeleteProto {
+                    tenant_id: *tenant_id,
+                })
+            }
             ControlEventBody::AppCreate {
                 id,
                 tenant_id,
@@ -793,9 +1127,13 @@ fn encode_control_event_body(
             ControlEventBody::AppSecretUpdate {
                 app_id,
                 client_secret_encrypted,
+                previous_client_secret_encrypted,
+                previous_secret_expires_at,
             } => control_event_proto::Event::AppSecretUpdate(AppSecretUpdateProto {
                 app_id: *app_id,
                 client_secret_encrypted: client_secret_encrypted.clone(),
+                previous_client_secret_encrypted: previous_client_secret_encrypted.clone(),
+                previous_secret_expires_at: *previous_secret_expires_at,
             }),
             ControlEventBody::AppDelete { app_id } => {
                 control_event_proto::Event::AppDelete(AppDeleteProto { app_id: *app_id })
@@ -835,6 +1173,21 @@ fn decode_control_event_body(bytes: &[u8]) -> Result<ControlEventBody> {
             id: value.id,
             name: value.name,
         }),
+        control_event_proto::Event::TenantBucketLimitUpdate(value) => {
+            Ok(ControlEventBody::TenantBucketLimitUpdate {
+                tenant_id: value.tenant_id,
+                max_buckets: value.max_buckets,
+            })
+        }
+        control_event_proto::Event::TenantRateLimitUpdate(value) => {
+            Ok(ControlEventBody::TenantRateLimitUpdate {
+                tenant_id: value.tenant_id,
+                rate_limit: value.rate_limit.map(rate_limit_from_proto),
+            })
+        }
+        control_event_proto::Event::TenantDelete(value) => Ok(ControlEventBody::TenantDelete {
+            tenant_id: value.tenant_id,
+        }),
         control_event_proto::Event::AppCreate(value) => Ok(ControlEventBody::AppCreate {
             id: value.id,
             tenant_id: value.tenant_id,
@@ -846,6 +1199,8 @@ fn decode_control_event_body(bytes: &[u8]) -> Result<ControlEventBody> {
             Ok(ControlEventBody::AppSecretUpdate {
                 app_id: value.app_id,
                 client_secret_encrypted: value.client_secret_encrypted,
+                previous_client_secret_encrypted: value.previous_client_secret_encrypted,
+                previous_secret_expires_at: value.previous_secret_expires_at,
             })
         }
         control_event_proto::Event::AppDelete(value) => Ok(ControlEventBody::AppDelete {
@@ -880,6 +1235,20 @@ fn ensure_deterministic_control_proto(
     Ok(())
 }
 
+fn rate_limit_to_proto(rate_limit: TenantRateLimit) -> TenantRateLimitProto {
+    TenantRateLimitProto {
+        requests_per_second: rate_limit.requests_per_second,
+        burst: rate_limit.burst,
+    }
+}
+
+fn rate_limit_from_proto(proto: TenantRateLimitProto) -> TenantRateLimit {
+    TenantRateLimit {
+        requests_per_second: proto.requests_per_second,
+        burst: proto.burst,
+    }
+}
+
 fn encode_control_current_row(
     record: &ControlCurrentRecord,
     mutation_id: &str,
@@ -906,19 +1275,27 @@ fn encode_control_current_row(
                     active: *active,
                 })
             }
-            ControlCurrentRecord::Tenant { id, name, active } => {
-                control_current_proto::Record::Tenant(TenantCurrentProto {
-                    id: *id,
-                    name: name.clone(),
-                    active: *active,
-                })
-            }
+            ControlCurrentRecord::Tenant {
+                id,
+                name,
+                active,
+                max_buckets,
+                rate_limit,
+            } => control_current_proto::Record::Tenant(TenantCurrentProto {
+                id: *id,
+                name: name.clone(),
+                active: *active,
+                max_buckets: *max_buckets,
+                rate_limit: rate_limit.map(rate_limit_to_proto),
+            }),
             ControlCurrentRecord::App {
                 id,
                 tenant_id,
                 name,
                 client_id,
                 client_secret_encrypted,
+                previous_client_secret_encrypted,
+                previous_secret_expires_at,
                 active,
             } => control_current_proto::Record::App(AppCurrentProto {
                 id: *id,
@@ -927,6 +1304,8 @@ fn encode_control_current_row(
                 client_id: client_id.clone(),
                 client_secret_encrypted: client_secret_encrypted.clone(),
                 active: *active,
+                previous_client_secret_encrypted: previous_client_secret_encrypted.clone(),
+                previous_secret_expires_at: *previous_secret_expires_at,
             }),
         }),
     };
@@ -980,6 +1359,8 @@ fn decode_control_current_row(bytes: &[u8]) -> Result<ControlCurrentRecord> {
             id: value.id,
             name: value.name,
             active: value.active,
+            max_buckets: value.max_buckets,
+            rate_limit: value.rate_limit.map(rate_limit_from_proto),
         }),
         control_current_proto::Record::App(value) => Ok(ControlCurrentRecord::App {
             id: value.id,
@@ -987,6 +1368,8 @@ fn decode_control_current_row(bytes: &[u8]) -> Result<ControlCurrentRecord> {
             name: value.name,
             client_id: value.client_id,
             client_secret_encrypted: value.client_secret_encrypted,
+            previous_client_secret_encrypted: value.previous_client_secret_encrypted,
+            previous_secret_expires_at: value.previous_secret_expires_at,
             active: value.active,
         }),
     }
@@ -1155,7 +1538,7 @@ mod tests {
         let app = create_app(&storage, tenant.id, "demo", "client-a", b"secret-a")
             .await
             .unwrap();
-        update_app_secret(&storage, app.id, b"secret-b")
+        update_app_secret(&storage, app.id, b"secret-b", None)
             .await
             .unwrap();
 
@@ -1179,6 +1562,57 @@ mod tests {
         assert!(matches!(bodies[0], ControlEventBody::RegionUpsert { .. }));
     }
 
+    #[tokio::test]
+    async fn control_state_keeps_previous_app_secret_until_cleared() {
+        let temp = tempdir().unwrap();
+        let storage = Storage::new_at(temp.path()).await.unwrap();
+        let tenant = create_tenant(&storage, "default").await.unwrap();
+        let app = create_app(&storage, tenant.id, "demo", "client-a", b"secret-a")
+            .await
+            .unwrap();
+        let expires_at = chrono::DateTime::from_timestamp(4_000_000_000, 0).unwrap();
+        update_app_secret(
+            &storage,
+            app.id,
+            b"secret-b",
+            Some(&PreviousAppSecret {
+                client_secret_encrypted: b"secret-a".to_vec(),
+                expires_at,
+            }),
+        )
+        .await
+        .unwrap();
+
+        let details = read_control_state(&storage)
+            .await
+            .unwrap()
+            .app_details_by_id(app.id)
+            .unwrap();
+        assert_eq!(details.client_secret_encrypted, b"secret-b".to_vec());
+        let previous = details.previous_client_secret.unwrap();
+        assert_eq!(previous.client_secret_encrypted, b"secret-a".to_vec());
+        assert_eq!(previous.expires_at, expires_at);
+        let bodies = read_control_journal_bodies(&storage).await.unwrap();
+        assert!(matches!(
+            bodies.last(),
+            Some(ControlEventBody::AppSecretUpdate {
+                previous_secret_expires_at: 4_000_000_000,
+                ..
+            })
+        ));
+
+        update_app_secret(&storage, app.id, b"secret-c", None)
+            .await
+            .unwrap();
+        let details = read_control_state(&storage)
+            .await
+            .unwrap()
+            .app_details_by_id(app.id)
+            .unwrap();
+        assert_eq!(details.client_secret_encrypted, b"secret-c".to_vec());
+        assert!(details.previous_client_secret.is_none());
+    }
+
     #[tokio::test]
     async fn control_current_state_does_not_replay_control_history_stream() {
         let temp = tempdir().unwrap();
@@ -1231,6 +1665,8 @@ mod tests {
             name: "demo".to_string(),
             client_id: "client-a".to_string(),
             client_secret_encrypted: b"secret-a".to_vec(),
+            previous_client_secret_encrypted: Vec::new(),
+            previous_secret_expires_at: 0,
         };
         core_store
             .commit_mutation_batch(CoreMutationBatch {
@@ -1250,6 +1686,8 @@ mod tests {
                         id: tenant.id,
                         name: tenant.name.clone(),
                         active: true,
+                        max_buckets: None,
+                        rate_limit: None,
                     },
                     ControlCurrentRecord::App {
                         id: app.id,
@@ -1257,6 +1695,8 @@ mod tests {
                         name: app.name.clone(),
                         client_id: app.client_id.clone(),
                         client_secret_encrypted: app.client_secret_encrypted.clone(),
+                        previous_client_secret_encrypted: Vec::new(),
+                        previous_secret_expires_at: 0,
                         active: true,
                     },
                 ]
@@ -1300,6 +1740,33 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn deleting_a_tenant_requires_its_apps_to_be_gone_first() {
+        let temp = tempdir().unwrap();
+        let storage = Storage::new_at(temp.path()).await.unwrap();
+        let tenant = create_tenant(&storage, "default").await.unwrap();
+        let app = create_app(&storage, tenant.id, "demo", "client-a", b"secret-a")
+            .await
+            .unwrap();
+
+        assert!(delete_tenant(&storage, tenant.id).await.is_err());
+        delete_app_inner(&storage, app.id, 0, None).await.unwrap();
+        delete_tenant(&storage, tenant.id).await.unwrap();
+
+        let state = read_control_state(&storage).await.unwrap();
+        assert!(state.tenants().is_empty());
+        assert!(state.tenant_by_name("default").is_none());
+        assert_eq!(
+            read_control_journal_bodies(&storage).await.unwrap().last(),
+            Some(&ControlEventBody::TenantDelete {
+                tenant_id: tenant.id
+            })
+        );
+        assert!(delete_tenant(&storage, tenant.id).await.is_err());
+        let recreated = create_tenant(&storage, "default").await.unwrap();
+        assert!(recreated.id > app.id, "tenant ids are not reused");
+    }
+
     #[tokio::test]
     pub(crate) async fn control_journal_with_permit_writes_fenced_payloads_and_current_rows() {
         let temp = tempdir().unwrap();
@@ -1326,7 +1793,7 @@ mod tests {
         )
         .await
         .unwrap();
-        update_app_secret_with_permit(&storage, app.id, b"secret-b", &permit, KEY)
+        update_app_secret_with_permit(&storage, app.id, b"secret-b", None, &permit, KEY)
             .await
             .unwrap();
 
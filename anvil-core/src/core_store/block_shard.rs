@@ -4,6 +4,7 @@ use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use prost::Message;
 use sha2::{Digest, Sha256};
 use std::path::PathBuf;
+use tokio::io::{AsyncReadExt, BufReader};
 
 use crate::formats::writer::WriterFamily;
 
@@ -233,6 +234,155 @@ pub(super) async fn read_block_shard_file(
     Ok(payload)
 }
 
+/// Verifies a shard file against its own container checksums and the payload
+/// hash recorded in its header, without needing the manifest that placed it.
+pub(super) async fn verify_block_shard_file(path: &PathBuf) -> Result<u64> {
+    let bytes = tokio::fs::read(path)
+        .await
+        .with_context(|| format!("scrub: read CoreStore block shard {}", path.display()))?;
+    let (header, payload) = decode_block_shard_file(&bytes)?;
+    let actual_hash = format!("sha256:{}", sha256_hex(&payload));
+    if !header.payload_stored_hash.is_empty() && actual_hash != header.payload_stored_hash {
+        bail!(
+            "CoreStore block shard payload hash mismatch: expected {}, got {}",
+            header.payload_stored_hash,
+            actual_hash
+        );
+    }
+    Ok(bytes.len() as u64)
+}
+
+/// Reads a block shard file front to back without holding the payload in
+/// memory. The header is validated when the file is opened, but the payload
+/// hash and container checksums cover the whole file, so bytes handed out by
+/// [`BlockShardReader::read_chunk`] are only trustworthy once
+/// [`BlockShardReader::finish`] has succeeded.
+pub(super) struct BlockShardReader {
+    file: BufReader<tokio::fs::File>,
+    payload_len: u64,
+    remaining: u64,
+    expected_payload_hash: String,
+    payload_hash: Sha256,
+    file_hash: Sha256,
+    crc: u32,
+}
+
+impl BlockShardReader {
+    pub(super) async fn open(
+        path: &PathBuf,
+        expectation: BlockShardExpectation<'_>,
+        operation: &'static str,
+    ) -> Result<Self> {
+        let file = tokio::fs::File::open(path).await.with_context(|| {
+            format!("{operation}: open CoreStore block shard {}", path.display())
+        })?;
+        let file_len = file.metadata().await?.len();
+        let mut file = BufReader::new(file);
+        let mut file_hash = Sha256::new();
+        let mut prefix = [0u8; CORE_BLOCK_SHARD_MAGIC.len() + 2 + 4];
+        read_frame(&mut file, &mut prefix).await?;
+        file_hash.update(prefix);
+        let mut offset = 0usize;
+        let magic = read_exact(&prefix, &mut offset, CORE_BLOCK_SHARD_MAGIC.len())?;
+        if magic != CORE_BLOCK_SHARD_MAGIC {
+            bail!("CoreStore block shard has invalid magic");
+        }
+        let version = read_u16_le(&prefix, &mut offset)?;
+        if version != CORE_BLOCK_SHARD_VERSION {
+            bail!("CoreStore block shard has unsupported version {version}");
+        }
+        let header_len = u64::from(read_u32_le(&prefix, &mut offset)?);
+        if header_len > file_len {
+            bail!("CoreStore frame ended unexpectedly");
+        }
+        let mut header_proto = vec![0u8; header_len as usize];
+        read_frame(&mut file, &mut header_proto).await?;
+        file_hash.update(&header_proto);
+        let header = decode_block_shard_header_proto(&header_proto)?;
+        validate_block_shard_header(&header, expectation)?;
+        let mut payload_len = [0u8; 8];
+        read_frame(&mut file, &mut payload_len).await?;
+        file_hash.update(payload_len);
+        let payload_len = u64::from_le_bytes(payload_len);
+        if expectation.payload_len > 0 && payload_len != expectation.payload_len {
+            bail!("CoreStore block shard payload length mismatch");
+        }
+        Ok(Self {
+            file,
+            payload_len,
+            remaining: payload_len,
+            expected_payload_hash: expectation.payload_hash.to_string(),
+            payload_hash: Sha256::new(),
+            file_hash,
+            crc: crc32c_update(!0u32, &header_proto),
+        })
+    }
+
+    pub(super) fn payload_len(&self) -> u64 {
+        self.payload_len
+    }
+
+    /// Reads up to `max_len` further payload bytes. Returns an empty chunk
+    /// once the payload is exhausted.
+    pub(super) async fn read_chunk(&mut self, max_len: usize) -> Result<Vec<u8>> {
+        let len = usize::try_from(self.remaining)
+            .unwrap_or(usize::MAX)
+            .min(max_len);
+        let mut chunk = vec![0u8; len];
+        read_frame(&mut self.file, &mut chunk).await?;
+        self.remaining -= len as u64;
+        self.payload_hash.update(&chunk);
+        self.file_hash.update(&chunk);
+        self.crc = crc32c_update(self.crc, &chunk);
+        Ok(chunk)
+    }
+
+    /// Reads whatever payload is left and checks the checksum trailer, the
+    /// file hash and the payload hash the caller expected.
+    pub(super) async fn finish(mut self) -> Result<()> {
+        while self.remaining > 0 {
+            self.read_chunk(BLOCK_SHARD_READ_CHUNK_BYTES).await?;
+        }
+        let mut expected_crc = [0u8; 4];
+        read_frame(&mut self.file, &mut expected_crc).await?;
+        if u32::from_le_bytes(expected_crc) != !self.crc {
+            bail!("CoreStore block shard checksum mismatch");
+        }
+        self.file_hash.update(expected_crc);
+        let mut expected_file_hash = [0u8; 32];
+        read_frame(&mut self.file, &mut expected_file_hash).await?;
+        if self.file.read(&mut [0u8; 1]).await? != 0 {
+            bail!("CoreStore block shard has trailing bytes");
+        }
+        let actual_file_hash = self.file_hash.finalize();
+        let actual_file_hash: &[u8] = actual_file_hash.as_ref();
+        if expected_file_hash != actual_file_hash {
+            bail!("CoreStore block shard file hash mismatch");
+        }
+        let actual_hash = format!("sha256:{}", hex::encode(self.payload_hash.finalize()));
+        if actual_hash != self.expected_payload_hash {
+            bail!(
+                "CoreStore block shard payload hash mismatch: expected {}, got {}",
+                self.expected_payload_hash,
+                actual_hash
+            );
+        }
+        Ok(())
+    }
+}
+
+const BLOCK_SHARD_READ_CHUNK_BYTES: usize = 256 * 1024;
+
+async fn read_frame(file: &mut BufReader<tokio::fs::File>, buf: &mut [u8]) -> Result<()> {
+    match file.read_exact(buf).await {
+        Ok(_) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => {
+            bail!("CoreStore frame ended unexpectedly")
+        }
+        Err(err) => Err(err.into()),
+    }
+}
+
 fn decode_block_shard_file(bytes: &[u8]) -> Result<(BlockShardHeaderProto, Vec<u8>)> {
     let mut offset = 0usize;
     let magic = read_exact(bytes, &mut offset, CORE_BLOCK_SHARD_MAGIC.len())?;
@@ -519,7 +669,10 @@ fn read_u64_le(bytes: &[u8], offset: &mut usize) -> Result<u64> {
 }
 
 fn crc32c(bytes: &[u8]) -> u32 {
-    let mut crc = !0u32;
+    !crc32c_update(!0u32, bytes)
+}
+
+fn crc32c_update(mut crc: u32, bytes: &[u8]) -> u32 {
     for byte in bytes {
         crc ^= u32::from(*byte);
         for _ in 0..8 {
@@ -530,5 +683,5 @@ fn crc32c(bytes: &[u8]) -> u32 {
             }
         }
     }
-    !crc
+    crc
 }
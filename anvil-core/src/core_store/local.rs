@@ -1,7 +1,8 @@
 use super::block_shard::{
-    BlockShardExpectation, BlockShardHeaderInput, ShardReceiptPayloadInput, boundary_summary_hash,
-    encode_block_shard_file, encode_boundary_values_b64, read_block_shard_file,
-    shard_receipt_payload_hash, validate_boundary_summary_fields,
+    BlockShardExpectation, BlockShardHeaderInput, BlockShardReader, ShardReceiptPayloadInput,
+    boundary_summary_hash, encode_block_shard_file, encode_boundary_values_b64,
+    read_block_shard_file, shard_receipt_payload_hash, validate_boundary_summary_fields,
+    verify_block_shard_file,
 };
 #[cfg(test)]
 use super::block_shard::{BlockShardHeaderProto, CORE_BLOCK_SHARD_MAGIC};
@@ -88,6 +89,12 @@ const CORE_CONTROL_READ_RETRY_ATTEMPTS: usize = 400;
 const CORE_INTERNAL_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
 const CORE_INTERNAL_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 const CORE_INTERNAL_REQUEST_ATTEMPTS: usize = 4;
+const CORE_SHARD_READ_CONCURRENCY: usize = 8;
+const CORE_SHARD_READ_TIMEOUT: Duration = Duration::from_secs(10);
+/// Blocks read with missing shards that are kept for the repair scheduler.
+/// Further blocks are dropped until it drains the queue; reading them again
+/// reports them again.
+const CORE_DEGRADED_BLOCK_QUEUE_LIMIT: usize = 1024;
 const LOCAL_ERASURE_PROFILE_ID: &str = "ec-4-2";
 const LOCAL_PLACEMENT_EPOCH: u64 = 1;
 const LOCAL_SHARD_FSYNC_SEQUENCE: u64 = 1;
@@ -171,6 +178,11 @@ struct LocalShardPlacement {
     region_id: String,
     cell_id: String,
     failure_domain: String,
+    /// Rack or availability zone the node advertised over gossip.
+    zone: Option<String>,
+    /// Power-of-two bucket of the node's gossiped free space; zero when any
+    /// candidate has not reported it.
+    capacity_class: u32,
     region_weight: u32,
     cell_weight: u32,
     public_api_addr: String,
@@ -270,8 +282,12 @@ pub struct CoreStore {
     coremeta_streams: Arc<Mutex<BTreeMap<String, local_coremeta_stream::CoreMetaPeerStream>>>,
     pipeline_keyring: Option<Arc<CorePipelineKeyring>>,
     storage_classes: CoreStorageClassCatalog,
+    shard_read_concurrency: usize,
+    shard_read_timeout: Duration,
     node_signing_keypair: Arc<identity::Keypair>,
     node_identity: CoreStoreNodeIdentity,
+    gossip_peers: Option<crate::cluster::ClusterState>,
+    degraded_blocks: Arc<StdMutex<BTreeMap<String, CoreObjectRef>>>,
 }
 
 impl CoreStore {
@@ -416,6 +432,31 @@ impl Default for CoreStoreNodeIdentity {
     }
 }
 
+/// Node-local tuning for a CoreStore instance.
+#[derive(Debug, Clone)]
+pub struct CoreStoreOptions {
+    pub storage_classes: CoreStorageClassCatalog,
+    /// Shard reads kept in flight while reading one erasure-coded block.
+    pub shard_read_concurrency: usize,
+    /// Deadline for a single shard read before it counts as unavailable and
+    /// the next placement is tried.
+    pub shard_read_timeout: Duration,
+    /// Gossip membership view. When set, shard placement spreads across the
+    /// zones nodes advertise and prefers nodes with more free space.
+    pub gossip_peers: Option<crate::cluster::ClusterState>,
+}
+
+impl Default for CoreStoreOptions {
+    fn default() -> Self {
+        Self {
+            storage_classes: CoreStorageClassCatalog::release_defaults(),
+            shard_read_concurrency: CORE_SHARD_READ_CONCURRENCY,
+            shard_read_timeout: CORE_SHARD_READ_TIMEOUT,
+            gossip_peers: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CorePipelineKeyring {
     active_key_id: String,
@@ -831,6 +872,12 @@ pub(crate) use self::local_roots::decode_root_anchor_record;
 mod local_roots;
 #[path = "local_roots_layout.rs"]
 mod local_roots_layout;
+#[path = "local_shard_gc.rs"]
+mod local_shard_gc;
+#[path = "local_shard_rebalance.rs"]
+mod local_shard_rebalance;
+#[path = "local_shard_scrub.rs"]
+mod local_shard_scrub;
 #[path = "local_stream_control.rs"]
 mod local_stream_control;
 #[path = "local_stream_records.rs"]
@@ -83,6 +83,7 @@ impl CoreStore {
         let block_id = manifest.encoding.block_id.as_str();
         let boundary_summary_hash = manifest_boundary_summary_hash.as_str();
         let boundary_values_b64 = manifest_boundary_values_b64.as_str();
+        let boundary_values = manifest.boundary_values.as_slice();
         for placement in &manifest.placements {
             self.verify_object_placement_receipt(
                 &manifest.encoding.block_id,
@@ -98,23 +99,30 @@ impl CoreStore {
                     total_shards
                 );
             }
-            pending_reads.push(async move {
-                let block_read_started_at = Instant::now();
-                let result = self
-                    .read_shard_from_placement(ReadShardFromPlacement {
-                        block_id,
-                        profile,
-                        placement,
-                        boundary_summary_hash,
-                        boundary_values_b64,
-                        range: None,
-                        operation: "read_blob_shard",
-                    })
-                    .await;
-                (placement, result, block_read_started_at.elapsed())
-            });
         }
+        // Keep at most shard_read_concurrency reads in flight, starting the
+        // next placement as each one finishes until enough shards are present.
+        let mut shard_reads = manifest.placements.iter().map(|placement| async move {
+            let block_read_started_at = Instant::now();
+            let result = self
+                .read_shard_from_placement(ReadShardFromPlacement {
+                    block_id,
+                    profile,
+                    placement,
+                    boundary_values,
+                    boundary_summary_hash,
+                    boundary_values_b64,
+                    range: None,
+                    operation: "read_blob_shard",
+                })
+                .await;
+            (placement, result, block_read_started_at.elapsed())
+        });
+        pending_reads.extend(shard_reads.by_ref().take(self.shard_read_concurrency));
         while let Some((placement, result, elapsed)) = pending_reads.next().await {
+            if let Some(read) = shard_reads.next() {
+                pending_reads.push(read);
+            }
             let index = usize::from(placement.shard_index);
             match result {
                 Ok(shard_bytes) => {
@@ -149,6 +157,9 @@ impl CoreStore {
                 }
             }
         }
+        if !shard_failures.is_empty() || manifest.placements.len() < total_shards {
+            self.note_degraded_block(&input.object_ref);
+        }
         let present = shards.iter().filter(|shard| shard.is_some()).count();
         if present < data_shards {
             bail!(
@@ -300,6 +311,7 @@ impl CoreStore {
                     block_id: &manifest.encoding.block_id,
                     profile,
                     placement,
+                    boundary_values: &manifest.boundary_values,
                     boundary_summary_hash: &manifest_boundary_summary_hash,
                     boundary_values_b64: &manifest_boundary_values_b64,
                     range: None,
@@ -3,11 +3,42 @@ use crate::anvil_api::{
     GetShardRequest, InternalRequestHeader, PutShardRequest, ShardReceipt,
     block_store_internal_client::BlockStoreInternalClient,
 };
+use crate::cluster::PeerInfo;
 use crate::mesh_lifecycle::{self, LifecycleState, NodeCapability};
 use futures_util::StreamExt;
 use tonic::metadata::MetadataValue;
 
 impl CoreStore {
+    /// Resolves the storage class for a new write. A request naming a tier
+    /// whose class needs more nodes or cells than the cluster has active
+    /// falls back to the default class; explicit class ids are never swapped.
+    pub async fn resolve_placeable_storage_class_id(
+        &self,
+        requested: Option<&str>,
+    ) -> Result<String> {
+        let class = self.select_storage_class(requested)?;
+        let default_class = self.default_storage_class()?;
+        if class.class_id == default_class.class_id
+            || !requested.is_some_and(|value| StorageClass::from_request(value).is_some())
+        {
+            return Ok(class.class_id.clone());
+        }
+        let profile = local_erasure_profile_from_byte_profile(&class.byte_profile)?;
+        if self
+            .plan_publish_shard_placements(profile, &[])
+            .await
+            .is_ok()
+        {
+            return Ok(class.class_id.clone());
+        }
+        tracing::debug!(
+            requested_class_id = %class.class_id,
+            fallback_class_id = %default_class.class_id,
+            "storage tier cannot be placed on the active nodes; using the default class"
+        );
+        Ok(default_class.class_id.clone())
+    }
+
     pub(super) async fn plan_publish_shard_placements(
         &self,
         profile: LocalErasureProfile,
@@ -30,9 +61,65 @@ impl CoreStore {
         }
     }
 
+    /// Reads a shard from the node the manifest recorded for it. When that
+    /// fails, the node current placement picks for the shard index is tried,
+    /// since a rebalance moves a shard there once its recorded node leaves.
     pub(super) async fn read_shard_from_placement(
         &self,
         input: ReadShardFromPlacement<'_>,
+    ) -> Result<Vec<u8>> {
+        let recorded_error = match self.read_shard_from_node(input).await {
+            Ok(bytes) => return Ok(bytes),
+            Err(error) => error,
+        };
+        let Some(current) = self
+            .current_shard_placement(input.profile, input.placement, input.boundary_values)
+            .await
+        else {
+            return Err(recorded_error);
+        };
+        self.read_shard_from_node(ReadShardFromPlacement {
+            placement: &current,
+            ..input
+        })
+        .await
+        .map_err(|error| {
+            recorded_error.context(format!(
+                "shard is not at its current placement on {} either: {error:#}",
+                current.node_id
+            ))
+        })
+    }
+
+    /// The recorded placement moved to the node current placement assigns
+    /// the same shard index, or `None` when that is the recorded node or no
+    /// placement can be planned.
+    pub(super) async fn current_shard_placement(
+        &self,
+        profile: LocalErasureProfile,
+        recorded: &CoreObjectPlacement,
+        boundary_values: &[CoreBoundaryValue],
+    ) -> Option<CoreObjectPlacement> {
+        let current = self
+            .plan_publish_shard_placements(profile, boundary_values)
+            .await
+            .ok()?
+            .into_iter()
+            .nth(usize::from(recorded.shard_index))?;
+        if current.node_id == recorded.node_id {
+            return None;
+        }
+        Some(CoreObjectPlacement {
+            node_id: current.node_id,
+            region_id: current.region_id,
+            cell_id: current.cell_id,
+            ..recorded.clone()
+        })
+    }
+
+    pub(super) async fn read_shard_from_node(
+        &self,
+        input: ReadShardFromPlacement<'_>,
     ) -> Result<Vec<u8>> {
         if self
             .shard_path(
@@ -57,46 +144,62 @@ impl CoreStore {
         let Some(endpoint) = endpoint else {
             return self.read_local_block_shard(input).await;
         };
-        self.read_remote_block_shard(input, &endpoint).await
+        let placement = input.placement;
+        tokio::time::timeout(
+            self.shard_read_timeout,
+            self.read_remote_block_shard(input, &endpoint),
+        )
+        .await
+        .map_err(|_| {
+            anyhow!(
+                "CoreStore shard read from {} timed out after {:?}",
+                placement.node_id,
+                self.shard_read_timeout
+            )
+        })?
     }
 
-    async fn active_shard_candidates(
+    pub(super) async fn active_shard_candidates(
         &self,
         profile: LocalErasureProfile,
     ) -> Result<Vec<LocalShardPlacement>> {
+        let peers = self.gossip_peers_by_api_addr().await;
         let mut active = Vec::new();
+        let mut free_bytes = Vec::new();
         for node in mesh_lifecycle::list_node_projections_with_core_store(self, None, None)? {
-            if node.mesh_id != self.node_identity.mesh_id {
-                continue;
-            }
-            if node.region != self.node_identity.region_id {
-                continue;
-            }
-            if node.state != LifecycleState::Active {
-                continue;
-            }
-            if !node.capabilities.contains(&NodeCapability::Object) {
+            if !self.is_shard_candidate(&node) {
                 continue;
             }
-            if node.public_api_addr.trim().is_empty() {
+            let peer = peers.get(&node.public_api_addr);
+            if peer.is_some_and(|peer| peer.stale) {
                 continue;
             }
             self.register_node_receipt_signing_public_key(
                 &node.node_id,
                 &node.receipt_signing_public_key_proto,
             )?;
+            free_bytes.push(peer.and_then(|peer| peer.free_bytes));
             let placement = LocalShardPlacement {
                 is_local: node.node_id == self.node_identity.node_id,
                 node_id: node.node_id,
                 region_id: node.region,
                 cell_id: node.cell_id.clone(),
                 failure_domain: node.cell_id,
+                zone: peer.and_then(advertised_zone),
+                capacity_class: 0,
                 region_weight: 100,
                 cell_weight: 100,
                 public_api_addr: node.public_api_addr,
             };
             active.push(placement);
         }
+        // Capacity only ranks candidates once every one of them reports it,
+        // so a node that has not gossiped yet is not pushed aside.
+        if free_bytes.iter().all(Option::is_some) {
+            for (placement, free_bytes) in active.iter_mut().zip(free_bytes) {
+                placement.capacity_class = free_bytes.map_or(0, capacity_class);
+            }
+        }
 
         let mut out = if active.len() >= profile.total_shards() {
             active
@@ -114,6 +217,7 @@ impl CoreStore {
             b.region_weight
                 .cmp(&a.region_weight)
                 .then_with(|| b.cell_weight.cmp(&a.cell_weight))
+                .then_with(|| b.capacity_class.cmp(&a.capacity_class))
                 .then_with(|| a.region_id.cmp(&b.region_id))
                 .then_with(|| a.failure_domain.cmp(&b.failure_domain))
                 .then_with(|| a.cell_id.cmp(&b.cell_id))
@@ -122,15 +226,65 @@ impl CoreStore {
         Ok(out)
     }
 
+    /// Fails when the default storage class needs more shard holders than
+    /// this region currently offers. A single node keeps the local fallback.
+    pub fn ensure_default_storage_class_fits_mesh(&self) -> Result<()> {
+        let class = self.storage_class_catalog().select(None)?;
+        let profile = local_erasure_profile_from_byte_profile(&class.byte_profile)?;
+        let eligible = mesh_lifecycle::list_node_projections_with_core_store(self, None, None)?
+            .iter()
+            .filter(|node| self.is_shard_candidate(node))
+            .count();
+        if eligible > 1 && eligible < profile.total_shards() {
+            bail!(
+                "storage class {} needs {} object nodes for its {}+{} shard layout, but region {} has {}",
+                class.class_id,
+                profile.total_shards(),
+                profile.data_shards,
+                profile.parity_shards,
+                self.node_identity.region_id,
+                eligible
+            );
+        }
+        Ok(())
+    }
+
+    /// Latest gossip from each peer, keyed by the public API address it
+    /// advertises; node descriptors carry the same address. Nodes whose peer
+    /// has missed heartbeats are flagged stale and kept out of placement.
+    async fn gossip_peers_by_api_addr(&self) -> BTreeMap<String, PeerInfo> {
+        let Some(gossip_peers) = &self.gossip_peers else {
+            return BTreeMap::new();
+        };
+        gossip_peers
+            .read()
+            .await
+            .values()
+            .map(|info| (info.grpc_addr.clone(), info.clone()))
+            .collect()
+    }
+
+    fn is_shard_candidate(&self, node: &mesh_lifecycle::NodeDescriptor) -> bool {
+        node.mesh_id == self.node_identity.mesh_id
+            && node.region == self.node_identity.region_id
+            && node.accepts_new_shards()
+            && node.capabilities.contains(&NodeCapability::Object)
+            && !node.public_api_addr.trim().is_empty()
+    }
+
     pub(super) async fn active_placement_cells(
         &self,
     ) -> Result<BTreeMap<(String, String), LocalPlacementCellInfo>> {
+        let peers = self.gossip_peers_by_api_addr().await;
         let mut out = BTreeMap::new();
         for node in mesh_lifecycle::list_node_projections_with_core_store(self, None, None)? {
             if node.mesh_id != self.node_identity.mesh_id
                 || node.region != self.node_identity.region_id
                 || !matches!(node.state, LifecycleState::Active | LifecycleState::Joining)
                 || !node.capabilities.contains(&NodeCapability::Object)
+                || peers
+                    .get(&node.public_api_addr)
+                    .is_some_and(|peer| peer.stale)
             {
                 continue;
             }
@@ -310,6 +464,7 @@ impl CoreStore {
                     request
                         .metadata_mut()
                         .insert("authorization", authorization.clone());
+                    crate::middleware::propagate_request_id(&mut request);
                     async move {
                         client
                             .put_shard(request)
@@ -399,6 +554,7 @@ impl CoreStore {
                     request
                         .metadata_mut()
                         .insert("authorization", authorization.clone());
+                    crate::middleware::propagate_request_id(&mut request);
                     let mut stream = client.get_shard(request).await?.into_inner();
                     let mut bytes = Vec::new();
                     while let Some(chunk) = stream.next().await {
@@ -561,12 +717,28 @@ pub(super) struct ReadShardFromPlacement<'a> {
     pub block_id: &'a str,
     pub profile: LocalErasureProfile,
     pub placement: &'a CoreObjectPlacement,
+    pub boundary_values: &'a [CoreBoundaryValue],
     pub boundary_summary_hash: &'a str,
     pub boundary_values_b64: &'a str,
     pub range: Option<CoreByteRange>,
     pub operation: &'static str,
 }
 
+fn advertised_zone(peer: &PeerInfo) -> Option<String> {
+    peer.zone
+        .as_deref()
+        .map(str::trim)
+        .filter(|zone| !zone.is_empty())
+        .map(str::to_string)
+}
+
+/// Free space rounded down to a power of two GiB, so ordinary disk churn
+/// does not reorder candidates; a node only moves when its free space halves
+/// or doubles.
+fn capacity_class(free_bytes: u64) -> u32 {
+    (free_bytes >> 30).max(1).ilog2() + 1
+}
+
 pub(super) fn choose_spread_placements(
     profile: LocalErasureProfile,
     candidates: Vec<LocalShardPlacement>,
@@ -594,29 +766,53 @@ pub(super) fn choose_spread_placements(
             b.region_weight
                 .cmp(&a.region_weight)
                 .then_with(|| b.cell_weight.cmp(&a.cell_weight))
+                .then_with(|| b.capacity_class.cmp(&a.capacity_class))
                 .then_with(|| a.region_id.cmp(&b.region_id))
                 .then_with(|| a.cell_id.cmp(&b.cell_id))
                 .then_with(|| compare_node_ids(&a.node_id, &b.node_id))
         });
     }
+    // Take one node per failure domain in turn. Among domains holding equally
+    // many shards, prefer a node from the zone holding the fewest, so nodes
+    // that advertise zones also spread across racks within each round.
+    let mut domain_picks = BTreeMap::<String, usize>::new();
+    let mut zone_picks = BTreeMap::<String, usize>::new();
     let mut placements = Vec::with_capacity(total);
     while placements.len() < total {
-        let mut made_progress = false;
-        let failure_domains = by_failure_domain.keys().cloned().collect::<Vec<_>>();
-        for failure_domain in failure_domains {
-            if placements.len() == total {
-                break;
-            }
-            if let Some(nodes) = by_failure_domain.get_mut(&failure_domain) {
-                if !nodes.is_empty() {
-                    placements.push(nodes.remove(0));
-                    made_progress = true;
-                }
-            }
-        }
-        if !made_progress {
+        let next = by_failure_domain
+            .iter()
+            .enumerate()
+            .flat_map(|(domain_index, (failure_domain, nodes))| {
+                nodes
+                    .iter()
+                    .enumerate()
+                    .map(move |(node_index, node)| (domain_index, failure_domain, node_index, node))
+            })
+            .min_by_key(|(domain_index, failure_domain, node_index, node)| {
+                (
+                    domain_picks.get(*failure_domain).copied().unwrap_or(0),
+                    node.zone
+                        .as_ref()
+                        .and_then(|zone| zone_picks.get(zone))
+                        .copied()
+                        .unwrap_or(0),
+                    *domain_index,
+                    *node_index,
+                )
+            })
+            .map(|(_, failure_domain, node_index, _)| (failure_domain.clone(), node_index));
+        let Some((failure_domain, node_index)) = next else {
+            break;
+        };
+        let Some(nodes) = by_failure_domain.get_mut(&failure_domain) else {
             break;
+        };
+        let node = nodes.remove(node_index);
+        *domain_picks.entry(failure_domain).or_default() += 1;
+        if let Some(zone) = &node.zone {
+            *zone_picks.entry(zone.clone()).or_default() += 1;
         }
+        placements.push(node);
     }
     if placements.len() != total {
         bail!("CoreStore placement planner exhausted candidates");
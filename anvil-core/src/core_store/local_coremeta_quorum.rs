@@ -513,6 +513,8 @@ impl CoreStore {
                         region_id: self.node_identity.region_id.clone(),
                         cell_id: cell_id.clone(),
                         failure_domain: cell_id,
+                        zone: None,
+                        capacity_class: 0,
                         region_weight: 100,
                         cell_weight: 100,
                         public_api_addr: String::new(),
@@ -579,6 +581,8 @@ impl CoreStore {
                 region_id: node.region,
                 cell_id: node.cell_id.clone(),
                 failure_domain: node.cell_id,
+                zone: None,
+                capacity_class: 0,
                 region_weight: 100,
                 cell_weight: 100,
                 public_api_addr: node.public_api_addr,
@@ -29,6 +29,7 @@ impl CoreStore {
             storage,
             Some(Arc::new(pipeline_keyring)),
             CoreStoreNodeIdentity::default(),
+            CoreStoreOptions::default(),
         )
         .await
     }
@@ -37,11 +38,13 @@ impl CoreStore {
         storage: Storage,
         pipeline_keyring: CorePipelineKeyring,
         node_identity: CoreStoreNodeIdentity,
+        options: CoreStoreOptions,
     ) -> Result<Self> {
         Self::new_with_optional_pipeline_keyring_and_identity(
             storage,
             Some(Arc::new(pipeline_keyring)),
             node_identity,
+            options,
         )
         .await
     }
@@ -54,6 +57,7 @@ impl CoreStore {
             storage,
             pipeline_keyring,
             CoreStoreNodeIdentity::default(),
+            CoreStoreOptions::default(),
         )
         .await
     }
@@ -62,7 +66,18 @@ impl CoreStore {
         storage: Storage,
         pipeline_keyring: Option<Arc<CorePipelineKeyring>>,
         node_identity: CoreStoreNodeIdentity,
+        options: CoreStoreOptions,
     ) -> Result<Self> {
+        let CoreStoreOptions {
+            storage_classes,
+            shard_read_concurrency,
+            shard_read_timeout,
+            gossip_peers,
+        } = options;
+        storage_classes.select(None)?;
+        if shard_read_concurrency == 0 {
+            bail!("CoreStore shard read concurrency must be greater than zero");
+        }
         clear_stale_process_locks_once(&storage)?;
         let meta = CoreMetaStore::open(storage.core_store_meta_path())?;
         let node_signing_keypair = Arc::new(load_or_create_node_signing_keypair(&meta)?);
@@ -72,7 +87,6 @@ impl CoreStore {
             &node_signing_keypair.public().encode_protobuf(),
         )?;
         let write_lock = process_write_lock(storage.core_store_root_path());
-        let storage_classes = CoreStorageClassCatalog::release_defaults();
         let store = Self {
             storage,
             meta,
@@ -81,8 +95,12 @@ impl CoreStore {
             coremeta_streams: Arc::new(Mutex::new(BTreeMap::new())),
             pipeline_keyring,
             storage_classes,
+            shard_read_concurrency,
+            shard_read_timeout,
             node_signing_keypair,
             node_identity,
+            gossip_peers,
+            degraded_blocks: Arc::new(StdMutex::new(BTreeMap::new())),
         };
         store.ensure_layout().await?;
         store.bootstrap_system_root_anchor().await?;
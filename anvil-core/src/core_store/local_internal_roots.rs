@@ -309,6 +309,7 @@ impl CoreStore {
                         ))
                     })?,
                 );
+                crate::middleware::propagate_request_id(&mut request);
                 client
                     .prepare_root(request)
                     .await
@@ -395,6 +396,7 @@ impl CoreStore {
                         ))
                     })?,
                 );
+                crate::middleware::propagate_request_id(&mut request);
                 client
                     .compare_and_swap_root(request)
                     .await
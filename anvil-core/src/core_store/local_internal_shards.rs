@@ -1,5 +1,44 @@
 use super::*;
-use anyhow::Context;
+
+const INTERNAL_SHARD_READ_CHUNK_BYTES: usize = 256 * 1024;
+
+/// Range of an internal shard read straight from its file. Chunks from
+/// [`Self::next_chunk`] are unverified until [`Self::finish`] succeeds.
+pub(crate) struct CoreInternalShardReader {
+    reader: BlockShardReader,
+    skip: u64,
+    remaining: u64,
+}
+
+impl CoreInternalShardReader {
+    /// Returns up to `max_len` further bytes of the range, or `None` once the
+    /// range has been read.
+    pub(crate) async fn next_chunk(&mut self, max_len: usize) -> Result<Option<Vec<u8>>> {
+        let max_len = max_len.max(1);
+        while self.skip > 0 {
+            let len = usize::try_from(self.skip)
+                .unwrap_or(usize::MAX)
+                .min(max_len);
+            let skipped = self.reader.read_chunk(len).await?;
+            self.skip -= skipped.len() as u64;
+        }
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let len = usize::try_from(self.remaining)
+            .unwrap_or(usize::MAX)
+            .min(max_len);
+        let chunk = self.reader.read_chunk(len).await?;
+        self.remaining -= chunk.len() as u64;
+        Ok(Some(chunk))
+    }
+
+    /// Reads the rest of the shard file and verifies its checksums and
+    /// payload hash.
+    pub(crate) async fn finish(self) -> Result<()> {
+        self.reader.finish().await
+    }
+}
 
 impl CoreStore {
     pub(crate) async fn put_internal_shard(
@@ -82,6 +121,22 @@ impl CoreStore {
         &self,
         request: CoreInternalGetShard,
     ) -> Result<Vec<u8>> {
+        let mut reader = self.open_internal_shard_reader(request).await?;
+        let mut bytes = Vec::with_capacity(reader.remaining.try_into().unwrap_or(0));
+        while let Some(chunk) = reader.next_chunk(INTERNAL_SHARD_READ_CHUNK_BYTES).await? {
+            bytes.extend_from_slice(&chunk);
+        }
+        reader.finish().await?;
+        Ok(bytes)
+    }
+
+    /// Opens an internal shard for streaming the requested range from disk.
+    /// The header and range are checked up front; the shard checksums are
+    /// checked by [`CoreInternalShardReader::finish`].
+    pub(crate) async fn open_internal_shard_reader(
+        &self,
+        request: CoreInternalGetShard,
+    ) -> Result<CoreInternalShardReader> {
         validate_logical_id(&request.block_id, "internal shard block id")?;
         validate_hash(&request.shard_hash, "internal shard hash")?;
         let profile = local_erasure_profile(&request.erasure_profile_id)?;
@@ -91,7 +146,7 @@ impl CoreStore {
         let placement = self.internal_shard_placement(profile, request.shard_index);
         let shard_path =
             self.shard_path(&placement.node_id, &request.block_id, request.shard_index);
-        let bytes = read_block_shard_file(
+        let reader = BlockShardReader::open(
             &shard_path,
             BlockShardExpectation {
                 block_id: &request.block_id,
@@ -106,18 +161,19 @@ impl CoreStore {
             "internal_get_shard",
         )
         .await?;
-        if let Some(range) = request.range {
-            let start =
-                usize::try_from(range.start).context("internal shard range start exceeds usize")?;
-            let end = usize::try_from(range.end_exclusive)
-                .context("internal shard range end exceeds usize")?;
-            if start > end || end > bytes.len() {
-                bail!("CoreStore internal shard requested range is out of bounds");
-            }
-            Ok(bytes[start..end].to_vec())
-        } else {
-            Ok(bytes)
+        let payload_len = reader.payload_len();
+        let (skip, end) = match request.range {
+            Some(range) => (range.start, range.end_exclusive),
+            None => (0, payload_len),
+        };
+        if skip > end || end > payload_len {
+            bail!("CoreStore internal shard requested range is out of bounds");
         }
+        Ok(CoreInternalShardReader {
+            reader,
+            skip,
+            remaining: end - skip,
+        })
     }
 
     pub(crate) async fn get_internal_shard_receipt(
@@ -169,6 +225,8 @@ impl CoreStore {
                 region_id: "local".to_string(),
                 cell_id: local_cell_id_for_shard(profile, usize::from(shard_index)),
                 failure_domain: local_cell_id_for_shard(profile, usize::from(shard_index)),
+                zone: None,
+                capacity_class: 0,
                 region_weight: 100,
                 cell_weight: 100,
                 public_api_addr: String::new(),
@@ -180,6 +238,8 @@ impl CoreStore {
                 region_id: self.node_identity.region_id.clone(),
                 cell_id: self.node_identity.cell_id.clone(),
                 failure_domain: self.node_identity.cell_id.clone(),
+                zone: None,
+                capacity_class: 0,
                 region_weight: 100,
                 cell_weight: 100,
                 public_api_addr: self.node_identity.public_api_addr.clone(),
@@ -73,6 +73,10 @@ pub(super) fn object_manifest_meta_key(object_ref: &CoreObjectRef) -> Vec<u8> {
     ])
 }
 
+pub(super) fn object_manifest_meta_prefix() -> Vec<u8> {
+    meta_tuple_key(&[b"object-manifest"])
+}
+
 pub(super) fn inline_payload_meta_key(object_ref: &CoreObjectRef) -> Vec<u8> {
     meta_tuple_key(&[
         b"inline-payload",
@@ -83,8 +87,12 @@ pub(super) fn inline_payload_meta_key(object_ref: &CoreObjectRef) -> Vec<u8> {
 }
 
 pub(super) fn is_inline_object_ref(object_ref: &CoreObjectRef) -> bool {
-    object_ref.encoding.profile_id == LOCAL_INLINE_PAYLOAD_PROFILE_ID
-        && object_ref.encoding.placement_scope == "coremeta-inline"
+    is_inline_encoding(&object_ref.encoding)
+}
+
+pub(super) fn is_inline_encoding(encoding: &CoreObjectEncoding) -> bool {
+    encoding.profile_id == LOCAL_INLINE_PAYLOAD_PROFILE_ID
+        && encoding.placement_scope == "coremeta-inline"
 }
 
 pub(super) fn local_inline_payload_block_id(hash_hex: &str) -> String {
@@ -1,6 +1,8 @@
 use super::*;
 use crate::object_links;
-use crate::persistence::{Bucket, Object, ObjectVersion, ObjectVersionsPage};
+use crate::persistence::{
+    Bucket, BucketUsage, Object, ObjectRetention, ObjectVersion, ObjectVersionsPage,
+};
 use base64::Engine;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use chrono::{DateTime, Utc};
@@ -75,6 +77,10 @@ struct ObjectMetadataRowProto {
     shard_map_kind: String,
     #[prost(bool, tag = "32")]
     delete_marker: bool,
+    #[prost(message, optional, tag = "33")]
+    retention: Option<ObjectRetentionProto>,
+    #[prost(string, optional, tag = "34")]
+    requested_storage_class: Option<String>,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -87,6 +93,18 @@ struct ObjectMetadataCounterProto {
     max_id: i64,
 }
 
+#[derive(Clone, PartialEq, Message)]
+struct ObjectMetadataUsageProto {
+    #[prost(message, optional, tag = "1")]
+    common: Option<CoreMetaRowCommonProto>,
+    #[prost(string, tag = "2")]
+    schema: String,
+    #[prost(uint64, tag = "3")]
+    used_bytes: u64,
+    #[prost(uint64, tag = "4")]
+    object_count: u64,
+}
+
 #[derive(Clone, PartialEq, Message)]
 struct ObjectLinkTargetProto {
     #[prost(string, tag = "1")]
@@ -105,6 +123,16 @@ struct ObjectLinkTargetProto {
     created_by: String,
 }
 
+#[derive(Clone, PartialEq, Message)]
+struct ObjectRetentionProto {
+    #[prost(string, tag = "1")]
+    retain_until: String,
+    #[prost(bool, tag = "2")]
+    has_retain_until: bool,
+    #[prost(bool, tag = "3")]
+    legal_hold: bool,
+}
+
 impl CoreStore {
     pub async fn put_object_metadata(&self, bucket: &Bucket, object: &Object) -> Result<()> {
         validate_object_scope(bucket, object)?;
@@ -122,6 +150,15 @@ impl CoreStore {
         let payload = encode_object_metadata_row_at_generation(object, root_generation)?;
         let counter_payload =
             self.object_id_counter_payload_at_generation(bucket, object.id, root_generation)?;
+        let previous = self
+            .meta
+            .get(CF_OBJECT_HEADS, TABLE_OBJECT_HEAD_ROW, &current_key)?
+            .map(|bytes| decode_object_metadata_row(&bytes))
+            .transpose()?;
+        let usage =
+            usage_after_replacing(self.object_usage(bucket)?, previous.as_ref(), Some(object));
+        let usage_payload =
+            encode_object_metadata_usage_at_generation(bucket, usage, root_generation)?;
         let transaction_id = object.mutation_id.to_string();
         let mut owned_ops = vec![
             OwnedCoreMetaBatchOp::Put {
@@ -173,11 +210,22 @@ impl CoreStore {
                 payload: counter_payload,
                 common: None,
             },
+            OwnedCoreMetaBatchOp::Put {
+                cf: CF_OBJECT_VERSIONS,
+                table_id: TABLE_OBJECT_VERSION_META_ROW,
+                tuple_key: object_usage_key(bucket),
+                payload: usage_payload,
+                common: None,
+            },
         ];
         owned_ops.extend(
             self.payload_reference_put_ops_for_object(bucket, object, &transaction_id)
                 .await?,
         );
+        owned_ops.extend(
+            self.payload_content_put_ops_for_object(object, &transaction_id)
+                .await?,
+        );
         let ops = borrow_owned_coremeta_batch_ops(&owned_ops);
         self.commit_coremeta_batch_by_embedded_roots(&transaction_id, &ops)
             .await?;
@@ -322,6 +370,19 @@ impl CoreStore {
         Ok(Some(object))
     }
 
+    /// Returns whether `object_key` was ever written in `bucket` here,
+    /// including keys whose versions have all since been deleted.
+    pub async fn object_key_has_history(&self, bucket: &Bucket, object_key: &str) -> Result<bool> {
+        Ok(!self
+            .meta
+            .scan_prefix(
+                CF_OBJECT_HEADS,
+                TABLE_OBJECT_HEAD_ROW,
+                &object_current_history_key_prefix(bucket, object_key),
+            )?
+            .is_empty())
+    }
+
     pub async fn read_object_version_metadata(
         &self,
         bucket: &Bucket,
@@ -699,6 +760,22 @@ impl CoreStore {
             },
         ];
         if deleted_is_current {
+            let usage = usage_after_replacing(
+                self.object_usage(bucket)?,
+                current.as_ref(),
+                replacement.as_ref(),
+            );
+            owned_ops.push(OwnedCoreMetaBatchOp::Put {
+                cf: CF_OBJECT_VERSIONS,
+                table_id: TABLE_OBJECT_VERSION_META_ROW,
+                tuple_key: object_usage_key(bucket),
+                payload: encode_object_metadata_usage_at_generation(
+                    bucket,
+                    usage,
+                    root_generation,
+                )?,
+                common: None,
+            });
             if let Some(replacement_payload) = replacement_payload.as_ref() {
                 let replacement_version = replacement
                     .as_ref()
@@ -767,6 +844,39 @@ impl CoreStore {
         Ok(())
     }
 
+    /// Returns the bytes and object count held by `bucket`'s current
+    /// objects, as maintained alongside every change to a current row.
+    pub async fn bucket_object_usage(&self, bucket: &Bucket) -> Result<BucketUsage> {
+        self.object_usage(bucket)
+    }
+
+    /// Reads the maintained usage row; buckets written before it existed
+    /// are counted from their current rows.
+    fn object_usage(&self, bucket: &Bucket) -> Result<BucketUsage> {
+        if let Some(bytes) = self.meta.get(
+            CF_OBJECT_VERSIONS,
+            TABLE_OBJECT_VERSION_META_ROW,
+            &object_usage_key(bucket),
+        )? {
+            let proto = decode_object_metadata_usage(&bytes)?;
+            return Ok(BucketUsage {
+                used_bytes: proto.used_bytes,
+                object_count: proto.object_count,
+            });
+        }
+        let mut usage = BucketUsage::default();
+        for row in self.meta.scan_prefix(
+            CF_OBJECT_HEADS,
+            TABLE_OBJECT_HEAD_ROW,
+            &object_current_list_prefix(bucket),
+        )? {
+            let object = decode_object_metadata_row(&row.payload)?;
+            validate_object_scope(bucket, &object)?;
+            usage = usage_after_replacing(usage, None, Some(&object));
+        }
+        Ok(usage)
+    }
+
     fn object_versions_by_key(
         &self,
         bucket: &Bucket,
@@ -934,6 +1044,15 @@ fn object_current_history_prefix(bucket: &Bucket) -> Vec<u8> {
     ])
 }
 
+fn object_current_history_key_prefix(bucket: &Bucket, object_key: &str) -> Vec<u8> {
+    meta_tuple_key(&[
+        b"object-history-current",
+        &bucket.tenant_id.to_be_bytes(),
+        &bucket.id.to_be_bytes(),
+        object_key.as_bytes(),
+    ])
+}
+
 fn object_current_history_key(
     bucket: &Bucket,
     object_key: &str,
@@ -1005,6 +1124,38 @@ fn object_id_counter_key(bucket: &Bucket) -> Vec<u8> {
     ])
 }
 
+fn object_usage_key(bucket: &Bucket) -> Vec<u8> {
+    meta_tuple_key(&[
+        b"object-usage",
+        &bucket.tenant_id.to_be_bytes(),
+        &bucket.id.to_be_bytes(),
+    ])
+}
+
+/// Applies replacing the current row `previous` with `next` to `usage`;
+/// delete markers and tombstones hold no bytes and count as no object.
+fn usage_after_replacing(
+    usage: BucketUsage,
+    previous: Option<&Object>,
+    next: Option<&Object>,
+) -> BucketUsage {
+    let live_size = |object: Option<&Object>| {
+        object
+            .filter(|object| object.deleted_at.is_none())
+            .map(|object| u64::try_from(object.size).unwrap_or_default())
+    };
+    let mut usage = usage;
+    if let Some(size) = live_size(previous) {
+        usage.used_bytes = usage.used_bytes.saturating_sub(size);
+        usage.object_count = usage.object_count.saturating_sub(1);
+    }
+    if let Some(size) = live_size(next) {
+        usage.used_bytes = usage.used_bytes.saturating_add(size);
+        usage.object_count = usage.object_count.saturating_add(1);
+    }
+    usage
+}
+
 fn sort_object_versions_descending(objects: &mut [Object]) {
     objects.sort_by(|left, right| {
         right
@@ -1148,6 +1299,7 @@ fn encode_object_metadata_row_at_generation_with_delete_marker(
         has_deleted_at: object.deleted_at.is_some(),
         storage_class: object.storage_class.clone().unwrap_or_default(),
         has_storage_class: object.storage_class.is_some(),
+        requested_storage_class: object.requested_storage_class.clone(),
         user_meta_json: optional_json_bytes(object.user_meta.as_ref())?.unwrap_or_default(),
         has_user_meta: object.user_meta.is_some(),
         shard_map_target: optional_object_data_target_bytes(object.shard_map.as_ref())?
@@ -1161,6 +1313,7 @@ fn encode_object_metadata_row_at_generation_with_delete_marker(
             .map(|target| target.0)
             .unwrap_or_default(),
         delete_marker,
+        retention: object.retention.as_ref().map(retention_to_proto),
     };
     encode_deterministic(&proto)
 }
@@ -1221,6 +1374,7 @@ fn decode_object_metadata_row_with_common(bytes: &[u8]) -> Result<DecodedObjectM
         } else {
             None
         },
+        requested_storage_class: proto.requested_storage_class,
         user_meta: if proto.has_user_meta {
             Some(decode_canonical_json_bytes(
                 &proto.user_meta_json,
@@ -1239,6 +1393,7 @@ fn decode_object_metadata_row_with_common(bytes: &[u8]) -> Result<DecodedObjectM
         },
         checksum: proto.has_checksum.then_some(proto.checksum),
         link: proto.link.map(link_from_proto).transpose()?,
+        retention: proto.retention.map(retention_from_proto).transpose()?,
     };
     Ok(DecodedObjectMetadataRow {
         object,
@@ -1282,6 +1437,38 @@ fn decode_object_metadata_counter(bytes: &[u8]) -> Result<ObjectMetadataCounterP
     Ok(proto)
 }
 
+fn encode_object_metadata_usage_at_generation(
+    bucket: &Bucket,
+    usage: BucketUsage,
+    root_generation: u64,
+) -> Result<Vec<u8>> {
+    encode_deterministic(&ObjectMetadataUsageProto {
+        common: Some(core_meta_committed_row_common(
+            object_metadata_realm_id(bucket.tenant_id),
+            object_metadata_root_key_hash(bucket.tenant_id, bucket.id),
+            root_generation,
+            String::new(),
+            unix_timestamp_nanos(),
+        )),
+        schema: "anvil.core.object_metadata_usage.v1".to_string(),
+        used_bytes: usage.used_bytes,
+        object_count: usage.object_count,
+    })
+}
+
+fn decode_object_metadata_usage(bytes: &[u8]) -> Result<ObjectMetadataUsageProto> {
+    let proto = ObjectMetadataUsageProto::decode(bytes)?;
+    ensure_round_trips(&proto, bytes, "object metadata usage")?;
+    if proto.schema != "anvil.core.object_metadata_usage.v1" {
+        bail!("CoreStore object metadata usage row has invalid schema");
+    }
+    proto
+        .common
+        .as_ref()
+        .ok_or_else(|| anyhow!("CoreStore object metadata usage row missing CoreMeta common"))?;
+    Ok(proto)
+}
+
 fn link_to_proto(link: &object_links::ObjectLinkTarget) -> ObjectLinkTargetProto {
     ObjectLinkTargetProto {
         target_key: link.target_key.clone(),
@@ -1322,6 +1509,31 @@ fn link_from_proto(proto: ObjectLinkTargetProto) -> Result<object_links::ObjectL
     })
 }
 
+fn retention_to_proto(retention: &ObjectRetention) -> ObjectRetentionProto {
+    ObjectRetentionProto {
+        retain_until: retention
+            .retain_until
+            .map(|until| until.to_rfc3339())
+            .unwrap_or_default(),
+        has_retain_until: retention.retain_until.is_some(),
+        legal_hold: retention.legal_hold,
+    }
+}
+
+fn retention_from_proto(proto: ObjectRetentionProto) -> Result<ObjectRetention> {
+    Ok(ObjectRetention {
+        retain_until: if proto.has_retain_until {
+            Some(parse_datetime(
+                &proto.retain_until,
+                "retention.retain_until",
+            )?)
+        } else {
+            None
+        },
+        legal_hold: proto.legal_hold,
+    })
+}
+
 fn parse_datetime(value: &str, field: &str) -> Result<DateTime<Utc>> {
     Ok(DateTime::parse_from_rfc3339(value)
         .with_context(|| format!("CoreStore object metadata row {field} is invalid"))?
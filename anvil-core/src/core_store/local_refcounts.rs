@@ -9,6 +9,8 @@ use serde_json::Value as JsonValue;
 use std::collections::BTreeMap;
 
 const CORE_PAYLOAD_REFERENCE_SCHEMA: &str = "anvil.core.payload_reference.v1";
+const CORE_PAYLOAD_RELEASE_SCHEMA: &str = "anvil.core.payload_release.v1";
+const CORE_PAYLOAD_CONTENT_SCHEMA: &str = "anvil.core.payload_content.v1";
 
 #[derive(Clone, PartialEq, Message)]
 struct PayloadReferenceRowProto {
@@ -42,10 +44,46 @@ struct PayloadReferenceRowProto {
     created_at: String,
 }
 
+/// Written when an object version drops its reference to an erasure block so
+/// shard garbage collection can find blocks that may have no readers left.
+#[derive(Clone, PartialEq, Message)]
+pub(super) struct PayloadReleaseRowProto {
+    #[prost(message, optional, tag = "1")]
+    common: Option<CoreMetaRowCommonProto>,
+    #[prost(string, tag = "2")]
+    schema: String,
+    #[prost(string, tag = "3")]
+    pub(super) payload_identity: String,
+    #[prost(string, tag = "4")]
+    pub(super) block_id: String,
+    #[prost(uint64, tag = "5")]
+    pub(super) released_at_unix_nanos: u64,
+}
+
+/// Points a tenant's content hash in one storage class at the logical file
+/// that last stored it, so later writes of the same bytes can share its
+/// blocks instead of storing them again.
+#[derive(Clone, PartialEq, Message)]
+struct PayloadContentRowProto {
+    #[prost(message, optional, tag = "1")]
+    common: Option<CoreMetaRowCommonProto>,
+    #[prost(string, tag = "2")]
+    schema: String,
+    #[prost(int64, tag = "3")]
+    tenant_id: i64,
+    #[prost(string, tag = "4")]
+    storage_class_id: String,
+    #[prost(string, tag = "5")]
+    content_hash: String,
+    #[prost(string, tag = "6")]
+    shard_map_json: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct PayloadReferenceDescriptor {
     payload_identity: String,
     storage_kind: String,
+    block_id: String,
     target_ref: String,
     logical_size: u64,
     content_hash: String,
@@ -108,6 +146,107 @@ impl CoreStore {
             .await
     }
 
+    /// Records `object`'s logical file as the stored copy of its content so
+    /// [`CoreStore::deduplicated_payload`] can hand it to later writes.
+    pub(super) async fn payload_content_put_ops_for_object(
+        &self,
+        object: &Object,
+        transaction_id: &str,
+    ) -> Result<Vec<OwnedCoreMetaBatchOp>> {
+        if !object_has_payload_reference_edges(object) {
+            return Ok(Vec::new());
+        }
+        let (Some(shard_map), Some(storage_class_id)) =
+            (object.shard_map.as_ref(), object.storage_class.as_deref())
+        else {
+            return Ok(Vec::new());
+        };
+        if !matches!(
+            object_data_target_from_json(shard_map)?,
+            PayloadDataTarget::LogicalFile { .. }
+        ) {
+            return Ok(Vec::new());
+        }
+        let identity =
+            payload_content_identity(object.tenant_id, storage_class_id, &object.content_hash);
+        let root_generation = self
+            .next_payload_reference_root_generation(&identity)
+            .await?;
+        let created_at_nanos =
+            u64::try_from(Utc::now().timestamp_nanos_opt().unwrap_or_default()).unwrap_or_default();
+        let row = PayloadContentRowProto {
+            common: Some(payload_reference_common(
+                &identity,
+                root_generation,
+                transaction_id,
+                created_at_nanos,
+            )),
+            schema: CORE_PAYLOAD_CONTENT_SCHEMA.to_string(),
+            tenant_id: object.tenant_id,
+            storage_class_id: storage_class_id.to_string(),
+            content_hash: object.content_hash.clone(),
+            shard_map_json: serde_json::to_string(shard_map)?,
+        };
+        let key = payload_content_key(&identity)?;
+        let payload = encode_deterministic_proto(&row);
+        validate_coremeta_operation_payload(CF_REFCOUNTS, TABLE_REFCOUNT_ROW, &key, &payload)?;
+        Ok(vec![OwnedCoreMetaBatchOp::Put {
+            cf: CF_REFCOUNTS,
+            table_id: TABLE_REFCOUNT_ROW,
+            tuple_key: key,
+            payload,
+            common: None,
+        }])
+    }
+
+    /// Shard map of a stored logical file holding `content_hash` for
+    /// `tenant_id` in `storage_class_id`, when every one of its blocks is
+    /// still referenced by an object version. Files carrying boundary values
+    /// are not shared, since those values belong to the writer's object.
+    pub async fn deduplicated_payload(
+        &self,
+        tenant_id: i64,
+        storage_class_id: &str,
+        content_hash: &str,
+    ) -> Result<Option<JsonValue>> {
+        let identity = payload_content_identity(tenant_id, storage_class_id, content_hash);
+        let Some(bytes) = self.meta.get(
+            CF_REFCOUNTS,
+            TABLE_REFCOUNT_ROW,
+            &payload_content_key(&identity)?,
+        )?
+        else {
+            return Ok(None);
+        };
+        let row = decode_deterministic_proto::<PayloadContentRowProto>(
+            &bytes,
+            "CoreStore payload content row",
+        )?;
+        if row.schema != CORE_PAYLOAD_CONTENT_SCHEMA {
+            bail!("CoreStore payload content row has invalid schema");
+        }
+        let shard_map: JsonValue = serde_json::from_str(&row.shard_map_json)
+            .context("CoreStore payload content row shard map is not JSON")?;
+        let PayloadDataTarget::LogicalFile { locator } = object_data_target_from_json(&shard_map)?
+        else {
+            return Ok(None);
+        };
+        // Blocks of a file whose last reader was deleted may already be gone.
+        let Ok(manifest) = self.read_logical_file_manifest(&locator).await else {
+            return Ok(None);
+        };
+        if manifest.content_hash != content_hash || !manifest_boundary_values(&manifest).is_empty()
+        {
+            return Ok(None);
+        }
+        for block in &manifest.blocks {
+            if !self.payload_is_referenced(&logical_block_payload_identity(block, &manifest))? {
+                return Ok(None);
+            }
+        }
+        Ok(Some(shard_map))
+    }
+
     async fn payload_reference_descriptors_for_object(
         &self,
         object: &Object,
@@ -138,6 +277,7 @@ impl CoreStore {
                     PayloadReferenceDescriptor {
                         payload_identity,
                         storage_kind: storage_kind.to_string(),
+                        block_id: object_ref.encoding.block_id.clone(),
                         target_ref: target,
                         logical_size: object_ref.logical_size,
                         content_hash: object_ref.hash,
@@ -153,6 +293,7 @@ impl CoreStore {
                         PayloadReferenceDescriptor {
                             payload_identity,
                             storage_kind: "erasure_block".to_string(),
+                            block_id: block.block_id.clone(),
                             target_ref: format!(
                                 "{}:{}",
                                 manifest.erasure_profile_id, block.block_id
@@ -222,6 +363,13 @@ impl CoreStore {
                     common: None,
                 });
             } else {
+                if descriptor.storage_kind == "erasure_block" {
+                    ops.push(payload_release_op(
+                        &descriptor,
+                        common.clone(),
+                        created_at_nanos,
+                    )?);
+                }
                 ops.push(OwnedCoreMetaBatchOp::Delete {
                     cf: CF_REFCOUNTS,
                     table_id: TABLE_REFCOUNT_ROW,
@@ -233,6 +381,49 @@ impl CoreStore {
         Ok(ops)
     }
 
+    /// Marks the erasure blocks behind `shard_map` as released when no object
+    /// version references them. Used when a payload was stored but its object
+    /// metadata never committed, so shard garbage collection can reclaim the
+    /// blocks after the grace period. Returns the number of blocks marked.
+    pub async fn release_unreferenced_payload(
+        &self,
+        shard_map: &JsonValue,
+        transaction_id: &str,
+    ) -> Result<usize> {
+        let descriptors = self
+            .payload_reference_descriptors_from_shard_map(shard_map)
+            .await?;
+        let _guard = self.write_lock.lock().await;
+        let created_at_nanos =
+            u64::try_from(Utc::now().timestamp_nanos_opt().unwrap_or_default()).unwrap_or_default();
+        let mut ops = Vec::new();
+        for descriptor in descriptors {
+            if descriptor.storage_kind != "erasure_block"
+                || self.payload_is_referenced(&descriptor.payload_identity)?
+            {
+                continue;
+            }
+            let root_generation = self
+                .next_payload_reference_root_generation(&descriptor.payload_identity)
+                .await?;
+            let common = payload_reference_common(
+                &descriptor.payload_identity,
+                root_generation,
+                transaction_id,
+                created_at_nanos,
+            );
+            ops.push(payload_release_op(&descriptor, common, created_at_nanos)?);
+        }
+        if !ops.is_empty() {
+            self.commit_coremeta_batch_by_embedded_roots(
+                transaction_id,
+                &borrow_owned_coremeta_batch_ops(&ops),
+            )
+            .await?;
+        }
+        Ok(ops.len())
+    }
+
     async fn next_payload_reference_root_generation(&self, payload_identity: &str) -> Result<u64> {
         let root_anchor_key = payload_reference_root_anchor_key(payload_identity);
         Ok(self
@@ -242,7 +433,30 @@ impl CoreStore {
             .unwrap_or(1))
     }
 
-    #[cfg(test)]
+    /// Released payloads paired with the key of their release row.
+    pub(super) fn payload_releases(&self) -> Result<Vec<(Vec<u8>, PayloadReleaseRowProto)>> {
+        let prefix = core_meta_tuple_key(&[CoreMetaTuplePart::Utf8("payload-release")])?;
+        let mut releases = Vec::new();
+        for row in self
+            .meta
+            .scan_prefix(CF_REFCOUNTS, TABLE_REFCOUNT_ROW, &prefix)?
+        {
+            let release = decode_deterministic_proto::<PayloadReleaseRowProto>(
+                &row.payload,
+                "CoreStore payload release row",
+            )?;
+            if release.schema != CORE_PAYLOAD_RELEASE_SCHEMA {
+                bail!("CoreStore payload release row has invalid schema");
+            }
+            releases.push((payload_release_key(&release.payload_identity)?, release));
+        }
+        Ok(releases)
+    }
+
+    pub(super) fn payload_is_referenced(&self, payload_identity: &str) -> Result<bool> {
+        Ok(self.payload_reference_count(payload_identity)? > 0)
+    }
+
     fn payload_reference_count(&self, payload_identity: &str) -> Result<usize> {
         let mut count = 0_usize;
         for row in self.meta.scan_prefix(
@@ -259,6 +473,30 @@ impl CoreStore {
     }
 }
 
+fn payload_release_op(
+    descriptor: &PayloadReferenceDescriptor,
+    common: CoreMetaRowCommonProto,
+    released_at_unix_nanos: u64,
+) -> Result<OwnedCoreMetaBatchOp> {
+    let row = PayloadReleaseRowProto {
+        common: Some(common),
+        schema: CORE_PAYLOAD_RELEASE_SCHEMA.to_string(),
+        payload_identity: descriptor.payload_identity.clone(),
+        block_id: descriptor.block_id.clone(),
+        released_at_unix_nanos,
+    };
+    let release_key = payload_release_key(&descriptor.payload_identity)?;
+    let payload = encode_deterministic_proto(&row);
+    validate_coremeta_operation_payload(CF_REFCOUNTS, TABLE_REFCOUNT_ROW, &release_key, &payload)?;
+    Ok(OwnedCoreMetaBatchOp::Put {
+        cf: CF_REFCOUNTS,
+        table_id: TABLE_REFCOUNT_ROW,
+        tuple_key: release_key,
+        payload,
+        common: None,
+    })
+}
+
 fn object_has_payload_reference_edges(object: &Object) -> bool {
     object.kind == object_links::ObjectEntryKind::Blob
         && object.deleted_at.is_none()
@@ -332,7 +570,6 @@ fn logical_block_payload_identity(
     )
 }
 
-#[cfg(test)]
 fn payload_reference_prefix(payload_identity: &str) -> Result<Vec<u8>> {
     let hash = payload_reference_identity_hash(payload_identity);
     core_meta_tuple_key(&[
@@ -354,6 +591,26 @@ fn payload_reference_key(payload_identity: &str, object: &Object) -> Result<Vec<
     ])
 }
 
+fn payload_release_key(payload_identity: &str) -> Result<Vec<u8>> {
+    let hash = payload_reference_identity_hash(payload_identity);
+    core_meta_tuple_key(&[
+        CoreMetaTuplePart::Utf8("payload-release"),
+        CoreMetaTuplePart::Hash(&hash),
+    ])
+}
+
+fn payload_content_identity(tenant_id: i64, storage_class_id: &str, content_hash: &str) -> String {
+    format!("payload-content:{tenant_id}:{storage_class_id}:{content_hash}")
+}
+
+fn payload_content_key(identity: &str) -> Result<Vec<u8>> {
+    let hash = payload_reference_identity_hash(identity);
+    core_meta_tuple_key(&[
+        CoreMetaTuplePart::Utf8("payload-content"),
+        CoreMetaTuplePart::Hash(&hash),
+    ])
+}
+
 fn payload_reference_common(
     payload_identity: &str,
     root_generation: u64,
@@ -384,7 +641,6 @@ fn payload_reference_identity_hash(payload_identity: &str) -> String {
     format!("sha256:{}", sha256_hex(payload_identity.as_bytes()))
 }
 
-#[cfg(test)]
 fn decode_payload_reference_row(bytes: &[u8]) -> Result<PayloadReferenceRowProto> {
     let row = decode_deterministic_proto::<PayloadReferenceRowProto>(
         bytes,
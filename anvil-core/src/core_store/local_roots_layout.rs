@@ -26,6 +26,20 @@ impl CoreStore {
     pub async fn read_object_manifest(
         &self,
         object_ref: &CoreObjectRef,
+    ) -> Result<CoreObjectManifest> {
+        let manifest = self.read_recorded_object_manifest(object_ref).await?;
+        if is_inline_encoding(&manifest.encoding) {
+            return Ok(manifest);
+        }
+        self.manifest_with_present_shard_placements(manifest)
+    }
+
+    /// The manifest as written, keeping placements whose local shard file is
+    /// gone. Reads want [`Self::read_object_manifest`]; repair needs to know
+    /// every placement the block was given.
+    pub(super) async fn read_recorded_object_manifest(
+        &self,
+        object_ref: &CoreObjectRef,
     ) -> Result<CoreObjectManifest> {
         let single_block_ref = if let Some(logical_manifest) = self
             .logical_file_manifest_from_object_ref(object_ref)
@@ -61,10 +75,7 @@ impl CoreStore {
             bail!("CoreStore object manifest metadata row has invalid schema");
         }
         validate_manifest_for_object_ref(&manifest, object_ref, object_hash)?;
-        if is_inline_object_ref(object_ref) {
-            return Ok(manifest);
-        }
-        self.manifest_with_present_shard_placements(manifest)
+        Ok(manifest)
     }
 
     pub(super) fn manifest_with_present_shard_placements(
@@ -0,0 +1,85 @@
+use super::local_refcounts::PayloadReleaseRowProto;
+use super::*;
+use crate::storage::StoredShard;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl CoreStore {
+    /// Deletes shard files in this node's block cache whose erasure block lost
+    /// its last object reference more than `grace_period` ago. Shards modified
+    /// inside the grace period are kept so a concurrent re-upload of the same
+    /// content is never collected. A dry run only reports what would be freed.
+    pub async fn collect_orphaned_shards(
+        &self,
+        grace_period: Duration,
+        dry_run: bool,
+    ) -> Result<CoreShardGcReport> {
+        let cutoff = SystemTime::now()
+            .checked_sub(grace_period)
+            .unwrap_or(UNIX_EPOCH);
+        let cutoff_unix_nanos = u64::try_from(
+            cutoff
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos(),
+        )
+        .unwrap_or(u64::MAX);
+
+        let mut shards_by_hash = BTreeMap::<String, Vec<StoredShard>>::new();
+        let mut report = CoreShardGcReport {
+            dry_run,
+            ..CoreShardGcReport::default()
+        };
+        for shard in self.storage.list_stored_shards().await? {
+            report.scanned_shards = report.scanned_shards.saturating_add(1);
+            shards_by_hash
+                .entry(shard.block_id_hash.clone())
+                .or_default()
+                .push(shard);
+        }
+
+        for (release_key, release) in self.payload_releases()? {
+            if self.payload_is_referenced(&release.payload_identity)? {
+                // The block was written again after the release; the marker is stale.
+                if !dry_run {
+                    self.meta
+                        .delete(CF_REFCOUNTS, TABLE_REFCOUNT_ROW, &release_key)?;
+                }
+                continue;
+            }
+            let shards = shards_by_hash
+                .remove(&sha256_hex(release.block_id.as_bytes()))
+                .unwrap_or_default();
+            if release.released_at_unix_nanos > cutoff_unix_nanos
+                || shards.iter().any(|shard| shard.modified > cutoff)
+            {
+                report.pending_releases = report.pending_releases.saturating_add(1);
+                continue;
+            }
+            report.orphaned_shards = report
+                .orphaned_shards
+                .saturating_add(u64::try_from(shards.len()).unwrap_or(u64::MAX));
+            report.reclaimable_bytes = shards
+                .iter()
+                .fold(report.reclaimable_bytes, |total, shard| {
+                    total.saturating_add(shard.size_bytes)
+                });
+            if dry_run {
+                continue;
+            }
+            for shard in &shards {
+                match fs::remove_file(&shard.path).await {
+                    Ok(()) => report.deleted_shards = report.deleted_shards.saturating_add(1),
+                    Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                    Err(err) => {
+                        return Err(err).with_context(|| {
+                            format!("delete orphaned shard {}", shard.path.display())
+                        });
+                    }
+                }
+            }
+            self.meta
+                .delete(CF_REFCOUNTS, TABLE_REFCOUNT_ROW, &release_key)?;
+        }
+        Ok(report)
+    }
+}
@@ -0,0 +1,232 @@
+use super::local_stream_control::control_record_proto::decode_object_manifest_record;
+use super::*;
+
+impl CoreStore {
+    /// Queues a block that was read with shards missing for the repair
+    /// scheduler. The queue is bounded; a block left out is queued again the
+    /// next time it is read.
+    pub(super) fn note_degraded_block(&self, object_ref: &CoreObjectRef) {
+        let mut blocks = self
+            .degraded_blocks
+            .lock()
+            .expect("CoreStore degraded block queue poisoned");
+        if blocks.len() < CORE_DEGRADED_BLOCK_QUEUE_LIMIT
+            || blocks.contains_key(&object_ref.encoding.block_id)
+        {
+            blocks.insert(object_ref.encoding.block_id.clone(), object_ref.clone());
+        }
+    }
+
+    /// Takes the blocks read with shards missing since the last call, once
+    /// each.
+    pub fn take_degraded_blocks(&self) -> Vec<CoreObjectRef> {
+        let mut blocks = self
+            .degraded_blocks
+            .lock()
+            .expect("CoreStore degraded block queue poisoned");
+        std::mem::take(&mut *blocks).into_values().collect()
+    }
+
+    /// Lists the erasure-coded blocks with a shard recorded on `node_id`, so
+    /// a drain can move them off it.
+    pub fn object_refs_with_shards_on_node(&self, node_id: &str) -> Result<Vec<CoreObjectRef>> {
+        let mut object_refs = Vec::new();
+        for row in self.meta.scan_prefix(
+            CF_OBJECT_VERSIONS,
+            TABLE_OBJECT_VERSION_META_ROW,
+            &object_manifest_meta_prefix(),
+        )? {
+            let manifest = decode_object_manifest_record(&row.payload)?;
+            if !manifest
+                .placements
+                .iter()
+                .any(|placement| placement.node_id == node_id)
+            {
+                continue;
+            }
+            object_refs.push(CoreObjectRef {
+                manifest_ref: encode_manifest_ref_with_profile(
+                    strip_sha256_prefix(&manifest.object_hash)?,
+                    &manifest.encoding.profile_id,
+                ),
+                hash: manifest.object_hash,
+                logical_size: manifest.logical_size,
+                encoding: manifest.encoding,
+                placements: manifest.placements,
+            });
+        }
+        Ok(object_refs)
+    }
+
+    /// Makes every shard of an erasure-coded block readable where reads look
+    /// for it. A shard stays on its recorded node while that node still takes
+    /// shards; otherwise it belongs on the node current placement assigns its
+    /// index, which is where reads fall back to. Shards missing from where
+    /// they belong are rebuilt from the others and written there. The
+    /// manifest is not rewritten, so a shard's recorded hash still verifies
+    /// it wherever it lives.
+    pub async fn rebalance_object_ref_shards(
+        &self,
+        object_ref: &CoreObjectRef,
+    ) -> Result<CoreShardRebalanceReport> {
+        let mut report = CoreShardRebalanceReport::default();
+        if is_inline_object_ref(object_ref) {
+            return Ok(report);
+        }
+        let manifest = self.read_recorded_object_manifest(object_ref).await?;
+        if is_inline_encoding(&manifest.encoding) {
+            return Ok(report);
+        }
+        let profile = local_erasure_profile_for_counts(
+            &manifest.encoding.profile_id,
+            usize::from(manifest.encoding.data_shards),
+            usize::from(manifest.encoding.parity_shards),
+        )?;
+        let block_id = manifest.encoding.block_id.as_str();
+        let boundary_summary_hash = boundary_summary_hash(&manifest.boundary_values)?;
+        let boundary_values_b64 = encode_boundary_values_b64(&manifest.boundary_values)?;
+        let candidates = self
+            .active_shard_candidates(profile)
+            .await
+            .unwrap_or_default();
+
+        let total_shards = profile.total_shards();
+        let mut shards = vec![None; total_shards];
+        let mut misplaced = Vec::new();
+        for placement in &manifest.placements {
+            self.verify_object_placement_receipt(
+                block_id,
+                profile.id,
+                placement,
+                &boundary_summary_hash,
+            )?;
+            let index = usize::from(placement.shard_index);
+            if index >= total_shards {
+                bail!(
+                    "CoreStore manifest placement index {} exceeds total shard count {}",
+                    index,
+                    total_shards
+                );
+            }
+            report.checked_shards = report.checked_shards.saturating_add(1);
+            let keeps_shards = is_local_shard_node_id(&placement.node_id)
+                || candidates
+                    .iter()
+                    .any(|candidate| candidate.node_id == placement.node_id);
+            let home = if keeps_shards {
+                placement.clone()
+            } else {
+                self.current_shard_placement(profile, placement, &manifest.boundary_values)
+                    .await
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "CoreStore has no node to take shard {}:{} from {}",
+                            block_id,
+                            placement.shard_index,
+                            placement.node_id
+                        )
+                    })?
+            };
+            let recorded_read = ReadShardFromPlacement {
+                block_id,
+                profile,
+                placement,
+                boundary_values: &manifest.boundary_values,
+                boundary_summary_hash: &boundary_summary_hash,
+                boundary_values_b64: &boundary_values_b64,
+                range: None,
+                operation: "rebalance_block_shard",
+            };
+            let home_read = ReadShardFromPlacement {
+                placement: &home,
+                ..recorded_read
+            };
+            match self.read_shard_from_node(home_read).await {
+                Ok(bytes) => shards[index] = Some(bytes),
+                Err(_) => {
+                    if home.node_id != placement.node_id {
+                        shards[index] = self.read_shard_from_node(recorded_read).await.ok();
+                    }
+                    misplaced.push((placement, home));
+                }
+            }
+        }
+        if misplaced.is_empty() {
+            return Ok(report);
+        }
+
+        reconstruct_data_shards(&mut shards, profile)
+            .with_context(|| format!("CoreStore cannot rebuild shards of block {block_id}"))?;
+        let mut stored = Vec::new();
+        for shard in shards.iter().take(profile.data_shards) {
+            let Some(shard) = shard else {
+                bail!("CoreStore erasure reconstruction left a missing data shard");
+            };
+            stored.extend_from_slice(shard);
+        }
+        let rebuilt = encode_erasure_shards(&stored, profile)?;
+        // The block's original writer is not recorded in its manifest.
+        let logical_file_id = canonical_logical_file_id(
+            WriterFamily::CoreControl,
+            0,
+            &format!("shard-rebalance/{block_id}"),
+            manifest.encoding.stored_hash.as_bytes(),
+        );
+        for (recorded, home) in misplaced {
+            let shard = &rebuilt[usize::from(recorded.shard_index)];
+            let shard_hash = format!("sha256:{}", sha256_hex(shard));
+            if shard_hash != recorded.shard_hash {
+                bail!(
+                    "CoreStore rebuilt shard {}:{} hash mismatch: expected {}, got {}",
+                    block_id,
+                    recorded.shard_index,
+                    recorded.shard_hash,
+                    shard_hash
+                );
+            }
+            let target = candidates
+                .iter()
+                .find(|candidate| candidate.node_id == home.node_id)
+                .cloned()
+                .unwrap_or_else(|| LocalShardPlacement {
+                    node_id: home.node_id.clone(),
+                    region_id: home.region_id.clone(),
+                    cell_id: home.cell_id.clone(),
+                    failure_domain: home.cell_id.clone(),
+                    zone: None,
+                    capacity_class: 0,
+                    region_weight: 100,
+                    cell_weight: 100,
+                    public_api_addr: String::new(),
+                    is_local: true,
+                });
+            self.write_shard_to_placement(WriteShardToPlacement {
+                logical_file_id: &logical_file_id,
+                block_id,
+                shard_index: recorded.shard_index,
+                shard,
+                shard_hash: &shard_hash,
+                logical_offset: u64::from(recorded.shard_index) * shard.len() as u64,
+                profile,
+                placement: &target,
+                boundary_summary_hash: &boundary_summary_hash,
+                boundary_values_b64: &boundary_values_b64,
+                mutation_id: &manifest.mutation_id,
+                encryption_algorithm: "none",
+                writer_family: WriterFamily::CoreControl.as_str(),
+            })
+            .await
+            .with_context(|| {
+                format!(
+                    "write rebuilt CoreStore shard {}:{} to {}",
+                    block_id, recorded.shard_index, target.node_id
+                )
+            })?;
+            report.rebuilt_shards = report.rebuilt_shards.saturating_add(1);
+            if target.node_id != recorded.node_id {
+                report.relocated_shards = report.relocated_shards.saturating_add(1);
+            }
+        }
+        Ok(report)
+    }
+}
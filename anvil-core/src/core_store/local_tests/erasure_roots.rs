@@ -244,6 +244,97 @@ async fn core_store_put_blob_writes_erasure_shards_and_reconstructs_missing_data
     assert_eq!(reconstructed, payload);
 }
 
+#[tokio::test]
+async fn core_store_internal_shard_reader_streams_ranges_and_verifies_at_the_end() {
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    let store = CoreStore::new(storage).await.unwrap();
+    let payload: Vec<u8> = (0..80 * 1024).map(|i| (i % 251) as u8).collect();
+    let object_ref = store
+        .put_blob(PutBlob {
+            logical_name: "mesh:test/tenant:t/bucket:b/object:streamed".to_string(),
+            bytes: payload,
+            boundary_values: Vec::new(),
+            region_id: "local".to_string(),
+            mutation_id: "mut-streamed-shard".to_string(),
+        })
+        .await
+        .unwrap();
+    let manifest = store.read_object_manifest(&object_ref).await.unwrap();
+    let placement = &manifest.placements[0];
+    let request = |range| CoreInternalGetShard {
+        block_id: object_ref.encoding.block_id.clone(),
+        shard_index: placement.shard_index,
+        erasure_profile_id: LOCAL_ERASURE_PROFILE_ID.to_string(),
+        placement_epoch: placement.placement_epoch,
+        shard_hash: placement.shard_hash.clone(),
+        boundary_summary_hash: None,
+        range,
+    };
+    let shard = store
+        .read_internal_shard_range(request(None))
+        .await
+        .unwrap();
+    assert_eq!(shard.len() as u64, placement.stored_size);
+
+    let mut reader = store
+        .open_internal_shard_reader(request(None))
+        .await
+        .unwrap();
+    let mut streamed = Vec::new();
+    while let Some(chunk) = reader.next_chunk(1000).await.unwrap() {
+        assert!(chunk.len() <= 1000);
+        streamed.extend_from_slice(&chunk);
+    }
+    reader.finish().await.unwrap();
+    assert_eq!(streamed, shard);
+
+    let range = CoreByteRange {
+        start: 1500,
+        end_exclusive: 4100,
+    };
+    let mut reader = store
+        .open_internal_shard_reader(request(Some(range)))
+        .await
+        .unwrap();
+    let mut streamed = Vec::new();
+    while let Some(chunk) = reader.next_chunk(1000).await.unwrap() {
+        streamed.extend_from_slice(&chunk);
+    }
+    reader.finish().await.unwrap();
+    assert_eq!(streamed, shard[1500..4100]);
+
+    let past_end = CoreByteRange {
+        start: 0,
+        end_exclusive: placement.stored_size + 1,
+    };
+    assert!(
+        store
+            .open_internal_shard_reader(request(Some(past_end)))
+            .await
+            .is_err()
+    );
+
+    let path = store.shard_path(
+        &placement.node_id,
+        &object_ref.encoding.block_id,
+        placement.shard_index,
+    );
+    let mut shard_file = tokio::fs::read(&path).await.unwrap();
+    let last_payload_byte = shard_file.len() - 32 - 4 - 1;
+    shard_file[last_payload_byte] ^= 0xff;
+    tokio::fs::write(&path, &shard_file).await.unwrap();
+    let mut reader = store
+        .open_internal_shard_reader(request(Some(range)))
+        .await
+        .unwrap();
+    while reader.next_chunk(1000).await.unwrap().is_some() {}
+    assert!(
+        reader.finish().await.is_err(),
+        "corruption outside the requested range must still fail verification"
+    );
+}
+
 #[tokio::test]
 async fn core_store_object_ref_chunking_reads_each_data_shard_once() {
     let tmp = tempfile::tempdir().unwrap();
@@ -336,6 +427,57 @@ async fn core_store_get_blob_fails_when_too_many_erasure_shards_are_missing() {
     );
 }
 
+#[tokio::test]
+async fn core_store_scrub_quarantines_corrupt_shards_and_reads_still_reconstruct() {
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    let store = CoreStore::new(storage).await.unwrap();
+    let payload = vec![0x43; 80 * 1024];
+    let object_ref = store
+        .put_blob(PutBlob {
+            logical_name: "mesh:test/tenant:t/bucket:b/object:scrub".to_string(),
+            bytes: payload.clone(),
+            boundary_values: Vec::new(),
+            region_id: "local".to_string(),
+            mutation_id: "scrub-mut-1".to_string(),
+        })
+        .await
+        .unwrap();
+    let manifest = store.read_object_manifest(&object_ref).await.unwrap();
+    let placement = &manifest.placements[0];
+    let path = store.shard_path(
+        &placement.node_id,
+        &object_ref.encoding.block_id,
+        placement.shard_index,
+    );
+    let mut shard_file = tokio::fs::read(&path).await.unwrap();
+    let middle = shard_file.len() / 2;
+    shard_file[middle] ^= 0xff;
+    tokio::fs::write(&path, shard_file).await.unwrap();
+
+    let report = store.scrub_local_shards().await.unwrap();
+    assert!(report.scanned_shards >= (LOCAL_DATA_SHARDS + LOCAL_PARITY_SHARDS) as u64);
+    assert_eq!(report.corrupt_shards, 1);
+    assert!(!path.exists(), "corrupt shard must be moved aside");
+    assert!(path.with_extension("anb.corrupt").exists());
+    assert_eq!(report.quarantined.len(), 1);
+    assert_eq!(
+        report.quarantined[0].block_id_hash,
+        sha256_hex(object_ref.encoding.block_id.as_bytes())
+    );
+    assert_eq!(
+        std::path::Path::new(&report.quarantined[0].quarantine_path),
+        path.with_extension("anb.corrupt")
+    );
+
+    let clean = store.scrub_local_shards().await.unwrap();
+    assert_eq!(clean.corrupt_shards, 0);
+    assert_eq!(
+        store.get_blob(GetBlob { object_ref }).await.unwrap(),
+        payload
+    );
+}
+
 #[tokio::test]
 async fn core_store_streams_are_gap_free_hash_chained_and_idempotent() {
     let tmp = tempfile::tempdir().unwrap();
@@ -1119,6 +1119,25 @@ fn core_store_erasure_codec_recovers_every_allowed_missing_shard_set() {
     }
 }
 
+#[test]
+fn configured_shard_layout_selects_the_default_storage_class() {
+    let catalog = CoreStorageClassCatalog::release_defaults();
+    for class in catalog.classes.values() {
+        local_erasure_profile_from_byte_profile(&class.byte_profile).unwrap();
+    }
+
+    let unchanged = catalog.clone().with_default_byte_layout(0, 0).unwrap();
+    assert_eq!(unchanged.default_class_id, "standard-r3-ec4-2");
+    let wide = catalog.clone().with_default_byte_layout(8, 3).unwrap();
+    assert_eq!(wide.select(None).unwrap().byte_profile.profile_id, "ec-8-3");
+    let replicated = catalog.clone().with_default_byte_layout(1, 2).unwrap();
+    assert_eq!(
+        replicated.select(None).unwrap().byte_profile.profile_id,
+        "replicated-3"
+    );
+    assert!(catalog.with_default_byte_layout(5, 1).is_err());
+}
+
 #[test]
 fn core_store_local_placement_satisfies_profile_failure_domains() {
     let ec_4_2 = plan_local_shard_placements(LOCAL_EC_4_2_PROFILE).unwrap();
@@ -1398,3 +1417,39 @@ async fn core_store_boundary_schema_rejects_incompatible_evolution() {
             .contains(AnvilErrorCode::BoundarySchemaIncompatibleChange.as_str())
     );
 }
+
+#[test]
+fn storage_class_tiers_resolve_to_catalog_classes() {
+    let catalog = CoreStorageClassCatalog::release_defaults();
+    assert_eq!(
+        catalog.select(Some("standard")).unwrap().class_id,
+        catalog.default_class_id
+    );
+    let cold = catalog.select(Some("COLD")).unwrap();
+    assert_eq!(cold.class_id, COLD_STORAGE_CLASS_ID);
+    assert!(
+        cold.byte_profile.parity_shards > catalog.select(None).unwrap().byte_profile.parity_shards
+    );
+    assert_eq!(
+        StorageClass::of_class_id(Some(cold.class_id.as_str())),
+        StorageClass::Cold
+    );
+    assert_eq!(
+        StorageClass::of_class_id(Some("standard-r3-ec4-2")),
+        StorageClass::Standard
+    );
+    assert_eq!(StorageClass::of_class_id(None), StorageClass::Standard);
+    assert_eq!(StorageClass::from_s3("GLACIER"), Some(StorageClass::Cold));
+    assert_eq!(
+        StorageClass::from_s3("STANDARD"),
+        Some(StorageClass::Standard)
+    );
+    assert_eq!(StorageClass::from_s3("INTELLIGENT_TIERING"), None);
+    assert_eq!(StorageClass::Cold.s3_name(), "STANDARD_IA");
+    assert_eq!(
+        catalog.select(Some("GLACIER")).unwrap().class_id,
+        COLD_STORAGE_CLASS_ID
+    );
+    assert_eq!(StorageClass::s3_class_name("GLACIER"), Some("GLACIER"));
+    assert_eq!(StorageClass::s3_class_name("glacier"), None);
+}
@@ -438,4 +438,5 @@ mod control_record_encoding;
 mod erasure_roots;
 mod logical;
 mod pending;
+mod placement;
 mod record_formats;
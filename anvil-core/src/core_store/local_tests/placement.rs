@@ -0,0 +1,594 @@
+use super::*;
+use crate::cluster::{ClusterState, PeerInfo};
+use crate::mesh_lifecycle::{
+    CreateRegionDescriptor, LifecycleState, NodeCapability, RegisterCellDescriptor,
+    RegisterNodeDescriptor, create_region, list_node_projections_with_core_store, register_cell,
+    register_node, set_node_cordoned, transition_cell, transition_node, transition_region,
+};
+use std::collections::HashMap;
+
+const TEST_MESH: &str = "mesh-a";
+const TEST_REGION: &str = "eu-west-1";
+
+fn test_api_addr(node_id: &str) -> String {
+    format!("http://{node_id}.anvil-storage.test:50051")
+}
+
+/// Registers an active object node for each `(node_id, cell_id)` pair, with
+/// its cells and region active too.
+async fn register_active_object_nodes(storage: &Storage, nodes: &[(&str, &str)]) {
+    let cells = nodes
+        .iter()
+        .map(|(_, cell_id)| *cell_id)
+        .collect::<BTreeSet<_>>();
+    let region = create_region(
+        storage,
+        CreateRegionDescriptor {
+            mesh_id: TEST_MESH.to_string(),
+            region: TEST_REGION.to_string(),
+            public_base_url: "https://eu-west-1.anvil-storage.test".to_string(),
+            virtual_host_suffix: "eu-west-1.anvil-storage.test".to_string(),
+            placement_weight: 100,
+            default_cell: cells.first().map(|cell_id| cell_id.to_string()),
+        },
+    )
+    .await
+    .unwrap();
+    for cell_id in cells {
+        let cell = register_cell(
+            storage,
+            RegisterCellDescriptor {
+                mesh_id: TEST_MESH.to_string(),
+                region: TEST_REGION.to_string(),
+                cell_id: cell_id.to_string(),
+                placement_weight: 100,
+                failure_domain: cell_id.to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        transition_cell(
+            storage,
+            TEST_REGION,
+            cell_id,
+            cell.generation,
+            LifecycleState::Active,
+        )
+        .await
+        .unwrap();
+    }
+    transition_region(
+        storage,
+        TEST_REGION,
+        region.generation,
+        LifecycleState::Active,
+    )
+    .await
+    .unwrap();
+    for (node_id, cell_id) in nodes {
+        let node = register_node(
+            storage,
+            RegisterNodeDescriptor {
+                mesh_id: TEST_MESH.to_string(),
+                node_id: node_id.to_string(),
+                region: TEST_REGION.to_string(),
+                cell_id: cell_id.to_string(),
+                libp2p_peer_id: format!("peer-{node_id}"),
+                receipt_signing_public_key_proto: identity::Keypair::generate_ed25519()
+                    .public()
+                    .encode_protobuf(),
+                public_api_addr: test_api_addr(node_id),
+                public_cluster_addrs: vec!["/ip4/127.0.0.1/udp/7443/quic-v1".to_string()],
+                capabilities: vec![NodeCapability::Object],
+                capacity_json: "{}".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        transition_node(
+            storage,
+            node_id,
+            node.generation,
+            LifecycleState::Active,
+            None,
+        )
+        .await
+        .unwrap();
+    }
+}
+
+async fn open_gossip_store(
+    storage: Storage,
+    node_id: &str,
+    cell_id: &str,
+    gossip_peers: ClusterState,
+) -> CoreStore {
+    CoreStore::new_with_optional_pipeline_keyring_and_identity(
+        storage,
+        None,
+        CoreStoreNodeIdentity {
+            mesh_id: TEST_MESH.to_string(),
+            node_id: node_id.to_string(),
+            region_id: TEST_REGION.to_string(),
+            cell_id: cell_id.to_string(),
+            public_api_addr: test_api_addr(node_id),
+            internal_bearer_token: None,
+        },
+        CoreStoreOptions {
+            gossip_peers: Some(gossip_peers),
+            ..CoreStoreOptions::default()
+        },
+    )
+    .await
+    .unwrap()
+}
+
+async fn gossip_from(
+    gossip_peers: &ClusterState,
+    node_id: &str,
+    zone: Option<&str>,
+    free_bytes: Option<u64>,
+) {
+    gossip_peers.write().await.insert(
+        libp2p::PeerId::random(),
+        PeerInfo {
+            p2p_addrs: Vec::new(),
+            grpc_addr: test_api_addr(node_id),
+            zone: zone.map(str::to_string),
+            free_bytes,
+            last_seen: chrono::Utc::now(),
+            stale: false,
+        },
+    );
+}
+
+#[tokio::test]
+async fn shard_placement_spreads_across_advertised_zones() {
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    // Every cell holds one node per zone, and the zone-a node sorts first.
+    let nodes = [
+        ("node-a1", "cell-1"),
+        ("node-b1", "cell-1"),
+        ("node-a2", "cell-2"),
+        ("node-b2", "cell-2"),
+        ("node-a3", "cell-3"),
+        ("node-b3", "cell-3"),
+        ("node-a4", "cell-4"),
+        ("node-b4", "cell-4"),
+    ];
+    register_active_object_nodes(&storage, &nodes).await;
+    let gossip_peers: ClusterState = Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+    let store = open_gossip_store(storage, "node-a1", "cell-1", gossip_peers.clone()).await;
+    let zone_a_shards = |placements: &[LocalShardPlacement]| {
+        placements
+            .iter()
+            .filter(|placement| placement.node_id.starts_with("node-a"))
+            .count()
+    };
+
+    let unzoned = store
+        .plan_publish_shard_placements(LOCAL_EC_4_2_PROFILE, &[])
+        .await
+        .unwrap();
+    assert_eq!(unzoned.len(), 6);
+    assert_eq!(
+        zone_a_shards(&unzoned),
+        4,
+        "without zones every cell gives up its first node first"
+    );
+
+    for (node_id, _) in nodes {
+        let zone = if node_id.starts_with("node-a") {
+            "zone-a"
+        } else {
+            "zone-b"
+        };
+        gossip_from(&gossip_peers, node_id, Some(zone), None).await;
+    }
+    let zoned = store
+        .plan_publish_shard_placements(LOCAL_EC_4_2_PROFILE, &[])
+        .await
+        .unwrap();
+    assert_eq!(zoned.len(), 6);
+    assert_eq!(zone_a_shards(&zoned), 3);
+    for placement in &zoned {
+        let expected_zone = if placement.node_id.starts_with("node-a") {
+            "zone-a"
+        } else {
+            "zone-b"
+        };
+        assert_eq!(placement.zone.as_deref(), Some(expected_zone));
+    }
+    let mut shards_per_cell = BTreeMap::<&str, usize>::new();
+    for placement in &zoned {
+        *shards_per_cell
+            .entry(placement.cell_id.as_str())
+            .or_default() += 1;
+    }
+    assert_eq!(shards_per_cell.len(), 4, "zones must not undo cell spread");
+    assert!(shards_per_cell.values().all(|shards| *shards <= 2));
+}
+
+#[tokio::test]
+async fn shard_placement_leaves_out_the_fullest_node_in_a_cell() {
+    const GIB: u64 = 1 << 30;
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    // Seven candidates for six shards: one of cell-3's nodes sits out.
+    let nodes = [
+        ("node-1", "cell-1"),
+        ("node-2", "cell-1"),
+        ("node-3", "cell-2"),
+        ("node-4", "cell-2"),
+        ("node-5", "cell-3"),
+        ("node-6", "cell-3"),
+        ("node-7", "cell-4"),
+    ];
+    register_active_object_nodes(&storage, &nodes).await;
+    let gossip_peers: ClusterState = Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+    let store = open_gossip_store(storage, "node-1", "cell-1", gossip_peers.clone()).await;
+    let placed = |placements: &[LocalShardPlacement], node_id: &str| {
+        placements
+            .iter()
+            .any(|placement| placement.node_id == node_id)
+    };
+
+    let unweighted = store
+        .plan_publish_shard_placements(LOCAL_EC_4_2_PROFILE, &[])
+        .await
+        .unwrap();
+    assert!(placed(&unweighted, "node-5"));
+    assert!(!placed(&unweighted, "node-6"));
+
+    // node-5 is nearly full. Until every node reports capacity it keeps its
+    // slot, so a node that has not gossiped yet is not penalised.
+    gossip_from(&gossip_peers, "node-5", None, Some(GIB)).await;
+    let partial = store
+        .plan_publish_shard_placements(LOCAL_EC_4_2_PROFILE, &[])
+        .await
+        .unwrap();
+    assert!(placed(&partial, "node-5"));
+
+    for (node_id, _) in nodes.iter().filter(|(node_id, _)| *node_id != "node-5") {
+        gossip_from(&gossip_peers, node_id, None, Some(1024 * GIB)).await;
+    }
+    let weighted = store
+        .plan_publish_shard_placements(LOCAL_EC_4_2_PROFILE, &[])
+        .await
+        .unwrap();
+    assert!(
+        !placed(&weighted, "node-5"),
+        "the fullest node kept its shard"
+    );
+    assert!(placed(&weighted, "node-6"));
+}
+
+#[tokio::test]
+async fn shard_placement_skips_nodes_whose_gossip_went_stale() {
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    let nodes = [
+        ("node-1", "cell-1"),
+        ("node-2", "cell-1"),
+        ("node-3", "cell-2"),
+        ("node-4", "cell-2"),
+        ("node-5", "cell-3"),
+        ("node-6", "cell-3"),
+        ("node-7", "cell-4"),
+    ];
+    register_active_object_nodes(&storage, &nodes).await;
+    let gossip_peers: ClusterState = Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+    let store = open_gossip_store(storage, "node-1", "cell-1", gossip_peers.clone()).await;
+    for (node_id, _) in nodes {
+        gossip_from(&gossip_peers, node_id, None, None).await;
+    }
+    let fresh = store
+        .plan_publish_shard_placements(LOCAL_EC_4_2_PROFILE, &[])
+        .await
+        .unwrap();
+    assert!(fresh.iter().any(|placement| placement.node_id == "node-7"));
+    assert!(
+        store
+            .active_placement_cells()
+            .await
+            .unwrap()
+            .contains_key(&(TEST_REGION.to_string(), "cell-4".to_string()))
+    );
+
+    for info in gossip_peers.write().await.values_mut() {
+        if info.grpc_addr == test_api_addr("node-7") {
+            info.stale = true;
+        }
+    }
+    let placements = store
+        .plan_publish_shard_placements(LOCAL_EC_4_2_PROFILE, &[])
+        .await
+        .unwrap();
+    assert_eq!(placements.len(), 6);
+    assert!(
+        placements
+            .iter()
+            .all(|placement| placement.node_id != "node-7"),
+        "a stale node received a shard"
+    );
+    let cells = store.active_placement_cells().await.unwrap();
+    assert!(!cells.contains_key(&(TEST_REGION.to_string(), "cell-4".to_string())));
+    assert_eq!(cells.len(), 3);
+}
+
+#[tokio::test]
+async fn cordoned_nodes_receive_no_new_shards() {
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    let nodes = [
+        ("node-1", "cell-1"),
+        ("node-2", "cell-1"),
+        ("node-3", "cell-2"),
+        ("node-4", "cell-2"),
+        ("node-5", "cell-3"),
+        ("node-6", "cell-3"),
+        ("node-7", "cell-4"),
+    ];
+    register_active_object_nodes(&storage, &nodes).await;
+    let gossip_peers: ClusterState = Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+    let store = open_gossip_store(storage.clone(), "node-1", "cell-1", gossip_peers).await;
+    let before = store
+        .plan_publish_shard_placements(LOCAL_EC_4_2_PROFILE, &[])
+        .await
+        .unwrap();
+    assert!(before.iter().any(|placement| placement.node_id == "node-7"));
+
+    let node = list_node_projections_with_core_store(&store, None, None)
+        .unwrap()
+        .into_iter()
+        .find(|node| node.node_id == "node-7")
+        .unwrap();
+    set_node_cordoned(&storage, "node-7", node.generation, true)
+        .await
+        .unwrap();
+
+    let placements = store
+        .plan_publish_shard_placements(LOCAL_EC_4_2_PROFILE, &[])
+        .await
+        .unwrap();
+    assert_eq!(placements.len(), 6);
+    assert!(
+        placements
+            .iter()
+            .all(|placement| placement.node_id != "node-7"),
+        "a cordoned node received a shard"
+    );
+}
+
+#[tokio::test]
+async fn cold_tier_falls_back_to_the_default_class_on_a_small_cluster() {
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    // Six nodes hold an ec-4-2 stripe but not the eleven shards of ec-8-3.
+    let nodes = [
+        ("node-1", "cell-1"),
+        ("node-2", "cell-1"),
+        ("node-3", "cell-2"),
+        ("node-4", "cell-2"),
+        ("node-5", "cell-3"),
+        ("node-6", "cell-3"),
+    ];
+    register_active_object_nodes(&storage, &nodes).await;
+    let gossip_peers: ClusterState = Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+    let store = open_gossip_store(storage, "node-1", "cell-1", gossip_peers).await;
+    let default_class_id = store.storage_class_catalog().default_class_id.clone();
+
+    for tier in ["cold", "GLACIER"] {
+        assert_eq!(
+            store
+                .resolve_placeable_storage_class_id(Some(tier))
+                .await
+                .unwrap(),
+            default_class_id
+        );
+    }
+    // A concrete class id is honoured as asked, even if writes will fail.
+    assert_eq!(
+        store
+            .resolve_placeable_storage_class_id(Some(COLD_STORAGE_CLASS_ID))
+            .await
+            .unwrap(),
+        COLD_STORAGE_CLASS_ID
+    );
+}
+
+const MEMBERSHIP_TEST_NODES: [(&str, &str); 7] = [
+    ("node-1", "cell-1"),
+    ("node-2", "cell-1"),
+    ("node-3", "cell-2"),
+    ("node-4", "cell-2"),
+    ("node-5", "cell-3"),
+    ("node-6", "cell-3"),
+    ("node-7", "cell-4"),
+];
+
+async fn put_membership_test_blob(store: &CoreStore, payload: &[u8]) -> CoreObjectRef {
+    store
+        .put_blob(PutBlob {
+            logical_name: "mesh:test/tenant:t/bucket:b/object:membership".to_string(),
+            bytes: payload.to_vec(),
+            boundary_values: Vec::new(),
+            region_id: "local".to_string(),
+            mutation_id: "membership-mut-1".to_string(),
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn reads_use_recorded_shards_after_membership_changes_and_missing_ones_are_rebuilt() {
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    let gossip_peers: ClusterState = Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+    let store = open_gossip_store(storage.clone(), "node-1", "cell-1", gossip_peers).await;
+    let payload = vec![0x5a; 80 * 1024];
+    let object_ref = put_membership_test_blob(&store, &payload).await;
+    let recorded = store.read_object_manifest(&object_ref).await.unwrap();
+
+    // Seven nodes join, so current placement no longer matches the shards'
+    // recorded nodes.
+    register_active_object_nodes(&storage, &MEMBERSHIP_TEST_NODES).await;
+    let profile = local_erasure_profile(&recorded.encoding.profile_id).unwrap();
+    let current = store
+        .plan_publish_shard_placements(profile, &recorded.boundary_values)
+        .await
+        .unwrap();
+    assert!(
+        recorded
+            .placements
+            .iter()
+            .all(|placement| current.iter().all(|node| node.node_id != placement.node_id)),
+        "membership change must move current placement off the recorded nodes"
+    );
+    assert_eq!(
+        store
+            .get_blob(GetBlob {
+                object_ref: object_ref.clone()
+            })
+            .await
+            .unwrap(),
+        payload
+    );
+    assert!(store.take_degraded_blocks().is_empty());
+
+    let lost = &recorded.placements[1];
+    let lost_path = store.shard_path(
+        &lost.node_id,
+        &object_ref.encoding.block_id,
+        lost.shard_index,
+    );
+    tokio::fs::remove_file(&lost_path).await.unwrap();
+    assert_eq!(
+        store
+            .get_blob(GetBlob {
+                object_ref: object_ref.clone()
+            })
+            .await
+            .unwrap(),
+        payload
+    );
+    assert_eq!(store.take_degraded_blocks(), vec![object_ref.clone()]);
+
+    let report = store
+        .rebalance_object_ref_shards(&object_ref)
+        .await
+        .unwrap();
+    assert_eq!(report.checked_shards, recorded.placements.len() as u64);
+    assert_eq!(report.rebuilt_shards, 1);
+    assert_eq!(report.relocated_shards, 0);
+    assert!(
+        lost_path.exists(),
+        "the lost shard was not rebuilt in place"
+    );
+    assert_eq!(
+        store.read_object_manifest(&object_ref).await.unwrap(),
+        recorded
+    );
+    assert_eq!(
+        store
+            .rebalance_object_ref_shards(&object_ref)
+            .await
+            .unwrap(),
+        CoreShardRebalanceReport {
+            checked_shards: recorded.placements.len() as u64,
+            ..CoreShardRebalanceReport::default()
+        }
+    );
+}
+
+#[tokio::test]
+async fn shard_reads_fall_back_to_the_current_placement() {
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    let gossip_peers: ClusterState = Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+    let store = open_gossip_store(storage.clone(), "node-1", "cell-1", gossip_peers).await;
+    let object_ref = put_membership_test_blob(&store, &[0x21; 80 * 1024]).await;
+    let manifest = store.read_object_manifest(&object_ref).await.unwrap();
+    register_active_object_nodes(&storage, &MEMBERSHIP_TEST_NODES).await;
+
+    let profile = local_erasure_profile(&manifest.encoding.profile_id).unwrap();
+    let block_id = manifest.encoding.block_id.as_str();
+    let boundary_summary_hash = boundary_summary_hash(&manifest.boundary_values).unwrap();
+    let boundary_values_b64 = encode_boundary_values_b64(&manifest.boundary_values).unwrap();
+    // The shard was recorded on a node that has since left the mesh.
+    let departed = CoreObjectPlacement {
+        node_id: "node-departed".to_string(),
+        ..manifest.placements[0].clone()
+    };
+    let read = ReadShardFromPlacement {
+        block_id,
+        profile,
+        placement: &departed,
+        boundary_values: &manifest.boundary_values,
+        boundary_summary_hash: &boundary_summary_hash,
+        boundary_values_b64: &boundary_values_b64,
+        range: None,
+        operation: "test_read_shard",
+    };
+
+    let current = store
+        .current_shard_placement(profile, &departed, &manifest.boundary_values)
+        .await
+        .expect("current placement for the shard");
+    assert!(
+        MEMBERSHIP_TEST_NODES
+            .iter()
+            .any(|(node_id, _)| *node_id == current.node_id)
+    );
+    let moved_path = store.shard_path(&current.node_id, block_id, departed.shard_index);
+    tokio::fs::create_dir_all(moved_path.parent().unwrap())
+        .await
+        .unwrap();
+    tokio::fs::copy(
+        store.shard_path(
+            &manifest.placements[0].node_id,
+            block_id,
+            departed.shard_index,
+        ),
+        &moved_path,
+    )
+    .await
+    .unwrap();
+
+    let shard = store.read_shard_from_placement(read).await.unwrap();
+    assert_eq!(shard.len() as u64, departed.stored_size);
+    assert_eq!(
+        format!("sha256:{}", sha256_hex(&shard)),
+        departed.shard_hash
+    );
+}
+
+#[tokio::test]
+async fn object_refs_with_shards_on_node_finds_blocks_to_drain() {
+    let tmp = tempfile::tempdir().unwrap();
+    let storage = Storage::new_at(tmp.path()).await.unwrap();
+    let gossip_peers: ClusterState = Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+    let store = open_gossip_store(storage, "node-1", "cell-1", gossip_peers).await;
+    let payload = vec![0x3c; 80 * 1024];
+    let object_ref = put_membership_test_blob(&store, &payload).await;
+    let holder = object_ref.placements[2].node_id.clone();
+
+    let found = store.object_refs_with_shards_on_node(&holder).unwrap();
+    assert_eq!(found, vec![object_ref.clone()]);
+    assert_eq!(
+        store
+            .get_blob(GetBlob {
+                object_ref: found[0].clone()
+            })
+            .await
+            .unwrap(),
+        payload
+    );
+    assert!(
+        store
+            .object_refs_with_shards_on_node("node-without-shards")
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -221,6 +221,8 @@ pub(super) fn plan_local_shard_placements(
             region_id: "local".to_string(),
             cell_id: local_cell_id_for_shard(profile, shard_index),
             failure_domain: local_cell_id_for_shard(profile, shard_index),
+            zone: None,
+            capacity_class: 0,
             region_weight: 100,
             cell_weight: 100,
             public_api_addr: String::new(),
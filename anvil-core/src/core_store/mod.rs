@@ -35,7 +35,7 @@ pub(crate) use local::commit_coremeta_batch_for_storage;
 pub(crate) use local::decode_root_anchor_record;
 pub(crate) use local::record_corestore_trace_event;
 pub use local::{
-    CorePipelineKeyring, CoreStore, CoreStoreCommitError, CoreStoreNodeIdentity,
+    CorePipelineKeyring, CoreStore, CoreStoreCommitError, CoreStoreNodeIdentity, CoreStoreOptions,
     is_stream_head_mismatch,
 };
 pub(crate) use local::{decode_core_object_ref_target, encode_core_object_ref_target};
@@ -4,6 +4,93 @@ use std::collections::BTreeMap;
 
 use super::meta::CORE_META_MAX_INLINE_PAYLOAD_BYTES;
 
+/// Release class that backs the `cold` tier: wider stripes with three parity
+/// shards, so each object survives more shard losses at a lower byte overhead.
+pub const COLD_STORAGE_CLASS_ID: &str = "standard-r3-ec8-3";
+
+/// S3 `x-amz-storage-class` values Anvil accepts, and the tier each lands on.
+/// Infrequent-access and archive classes all land on the cold tier; objects
+/// stay directly readable.
+const S3_STORAGE_CLASSES: &[(&str, StorageClass)] = &[
+    ("STANDARD", StorageClass::Standard),
+    ("REDUCED_REDUNDANCY", StorageClass::Standard),
+    ("STANDARD_IA", StorageClass::Cold),
+    ("ONEZONE_IA", StorageClass::Cold),
+    ("GLACIER_IR", StorageClass::Cold),
+    ("GLACIER", StorageClass::Cold),
+    ("DEEP_ARCHIVE", StorageClass::Cold),
+];
+
+/// Coarse durability/cost tier for an object.
+///
+/// Writes may name a tier (`standard` or `cold`), or an S3 storage class,
+/// instead of a concrete class id; the catalog resolves it and the object
+/// records the concrete class. S3 clients see the tier through
+/// `x-amz-storage-class`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageClass {
+    #[default]
+    Standard,
+    Cold,
+}
+
+impl StorageClass {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "standard" => Some(Self::Standard),
+            "cold" => Some(Self::Cold),
+            _ => None,
+        }
+    }
+
+    /// Maps an S3 `x-amz-storage-class` value.
+    pub fn from_s3(value: &str) -> Option<Self> {
+        S3_STORAGE_CLASSES
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, tier)| *tier)
+    }
+
+    /// Returns `value` if it is an S3 storage class Anvil accepts.
+    pub fn s3_class_name(value: &str) -> Option<&'static str> {
+        S3_STORAGE_CLASSES
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(name, _)| *name)
+    }
+
+    /// Tier named by a write request, either as a tier or an S3 class.
+    pub fn from_request(value: &str) -> Option<Self> {
+        Self::parse(value).or_else(|| Self::from_s3(value))
+    }
+
+    /// Tier of an object from its recorded class id.
+    pub fn of_class_id(class_id: Option<&str>) -> Self {
+        if class_id == Some(COLD_STORAGE_CLASS_ID) {
+            Self::Cold
+        } else {
+            Self::Standard
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Cold => "cold",
+        }
+    }
+
+    /// Name reported to S3 clients for objects written without an S3 class.
+    /// Cold objects never need a restore, so they advertise an
+    /// infrequent-access class rather than an archive one.
+    pub fn s3_name(self) -> &'static str {
+        match self {
+            Self::Standard => "STANDARD",
+            Self::Cold => "STANDARD_IA",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CoreStorageClass {
     pub class_id: String,
@@ -64,6 +151,16 @@ impl CoreStorageClassCatalog {
             tenant_selectable: true,
         };
         classes.insert(standard.class_id.clone(), standard);
+        let wide = CoreStorageClass {
+            class_id: "standard-r3-ec8-3".to_string(),
+            description: "three metadata replicas with wide erasure-coded byte shards".to_string(),
+            metadata_profile: CoreMetadataProfile::metadata_r3_q2(),
+            byte_profile: CoreByteStorageProfile::ec_8_3(),
+            inline_payload_policy: CoreInlinePayloadPolicy::default_tiny_object_fast_path(),
+            min_cell_spread: 4,
+            tenant_selectable: true,
+        };
+        classes.insert(wide.class_id.clone(), wide);
         let replicated = CoreStorageClass {
             class_id: "low-latency-replicated".to_string(),
             description: "replicated byte profile for tiny hot operational data".to_string(),
@@ -81,7 +178,11 @@ impl CoreStorageClassCatalog {
     }
 
     pub fn select(&self, requested: Option<&str>) -> Result<&CoreStorageClass> {
-        let id = requested.unwrap_or(&self.default_class_id);
+        let id = match requested.and_then(StorageClass::from_request) {
+            Some(StorageClass::Standard) => self.default_class_id.as_str(),
+            Some(StorageClass::Cold) => COLD_STORAGE_CLASS_ID,
+            None => requested.unwrap_or(&self.default_class_id),
+        };
         let class = self
             .classes
             .get(id)
@@ -89,6 +190,46 @@ impl CoreStorageClassCatalog {
         class.validate()?;
         Ok(class)
     }
+
+    /// Makes the class whose byte profile has `data_shards + parity_shards`
+    /// the default for new objects. Objects keep the profile recorded in
+    /// their manifest, so changing the default never affects existing reads.
+    /// `0 + 0` keeps the release default.
+    pub fn with_default_byte_layout(
+        mut self,
+        data_shards: usize,
+        parity_shards: usize,
+    ) -> Result<Self> {
+        if data_shards == 0 && parity_shards == 0 {
+            return Ok(self);
+        }
+        let class_id = self
+            .classes
+            .values()
+            .find(|class| {
+                usize::from(class.byte_profile.data_shards) == data_shards
+                    && usize::from(class.byte_profile.parity_shards) == parity_shards
+            })
+            .map(|class| class.class_id.clone());
+        let Some(class_id) = class_id else {
+            let supported = self
+                .classes
+                .values()
+                .map(|class| {
+                    format!(
+                        "{}+{}",
+                        class.byte_profile.data_shards, class.byte_profile.parity_shards
+                    )
+                })
+                .collect::<Vec<_>>();
+            bail!(
+                "no CoreStore storage class uses a {data_shards}+{parity_shards} shard layout; supported layouts: {}",
+                supported.join(", ")
+            );
+        };
+        self.default_class_id = class_id;
+        Ok(self)
+    }
 }
 
 impl CoreMetadataProfile {
@@ -134,6 +275,21 @@ impl CoreByteStorageProfile {
         }
     }
 
+    pub fn ec_8_3() -> Self {
+        Self {
+            profile_id: "ec-8-3".to_string(),
+            codec_id: "rs-gf256-vandermonde-0x11d-v1/ec-8-3".to_string(),
+            data_shards: 8,
+            parity_shards: 3,
+            read_quorum: 8,
+            write_publish_threshold: 11,
+            target_block_bytes: 128 * 1024 * 1024,
+            max_shard_bytes: 16 * 1024 * 1024,
+            compression: "zstd".to_string(),
+            encryption: "none".to_string(),
+        }
+    }
+
     pub fn replicated_3() -> Self {
         Self {
             profile_id: "replicated-3".to_string(),
@@ -36,6 +36,49 @@ pub(crate) struct CorePayloadReferenceSummary {
     pub reference_count: usize,
 }
 
+/// Outcome of one orphaned-shard garbage collection pass on this node.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CoreShardGcReport {
+    pub dry_run: bool,
+    pub scanned_shards: u64,
+    pub orphaned_shards: u64,
+    pub reclaimable_bytes: u64,
+    pub deleted_shards: u64,
+    /// Released blocks still inside the grace period.
+    pub pending_releases: u64,
+}
+
+/// Outcome of one integrity scrub over this node's local shard files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CoreShardScrubReport {
+    pub scanned_shards: u64,
+    pub scanned_bytes: u64,
+    /// Shards that failed verification and were moved aside so reads treat
+    /// them as missing and reconstruct from the remaining shards.
+    pub corrupt_shards: u64,
+    /// One entry per quarantined shard. The block keeps serving reads with
+    /// reduced redundancy until the shard is rebuilt.
+    pub quarantined: Vec<CoreQuarantinedShard>,
+}
+
+/// Outcome of making one block's shards readable where reads look for them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CoreShardRebalanceReport {
+    pub checked_shards: u64,
+    /// Shards rebuilt or copied because they were missing where they belong.
+    pub rebuilt_shards: u64,
+    /// Rebuilt shards written to a node other than the recorded one.
+    pub relocated_shards: u64,
+}
+
+/// A shard file the scrub moved aside.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CoreQuarantinedShard {
+    pub block_id_hash: String,
+    pub quarantine_path: String,
+    pub error: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct GetBlob {
     pub object_ref: CoreObjectRef,
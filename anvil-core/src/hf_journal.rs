@@ -142,6 +142,8 @@ struct HfIngestionProto {
     started_at: Option<String>,
     #[prost(string, optional, tag = "16")]
     finished_at: Option<String>,
+    #[prost(enumeration = "HfRepoTypeProto", tag = "17")]
+    repo_type: i32,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -178,6 +180,16 @@ enum HfIngestionStateProto {
     Canceled = 5,
 }
 
+/// `Unspecified` decodes as a model repository so ingestions recorded before
+/// repo types existed keep their meaning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, prost::Enumeration)]
+enum HfRepoTypeProto {
+    Unspecified = 0,
+    Model = 1,
+    Dataset = 2,
+    Space = 3,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, prost::Enumeration)]
 enum HfIngestionItemStateProto {
     Unspecified = 0,
@@ -400,6 +412,7 @@ async fn create_ingestion(
     requester_app_id: i64,
     repo: &str,
     revision: Option<&str>,
+    repo_type: crate::tasks::HFRepoType,
     target_bucket: &str,
     target_region: &str,
     target_prefix: Option<&str>,
@@ -413,6 +426,7 @@ async fn create_ingestion(
         requester_app_id,
         repo,
         revision,
+        repo_type,
         target_bucket,
         target_region,
         target_prefix,
@@ -431,6 +445,7 @@ pub(crate) async fn create_ingestion_with_permit(
     requester_app_id: i64,
     repo: &str,
     revision: Option<&str>,
+    repo_type: crate::tasks::HFRepoType,
     target_bucket: &str,
     target_region: &str,
     target_prefix: Option<&str>,
@@ -447,6 +462,7 @@ pub(crate) async fn create_ingestion_with_permit(
         requester_app_id,
         repo,
         revision,
+        repo_type,
         target_bucket,
         target_region,
         target_prefix,
@@ -465,6 +481,7 @@ async fn create_ingestion_inner(
     requester_app_id: i64,
     repo: &str,
     revision: Option<&str>,
+    repo_type: crate::tasks::HFRepoType,
     target_bucket: &str,
     target_region: &str,
     target_prefix: Option<&str>,
@@ -486,6 +503,7 @@ async fn create_ingestion_inner(
             requester_app_id,
             repo: repo.to_string(),
             revision: revision.unwrap_or("main").to_string(),
+            repo_type,
             target_bucket: target_bucket.to_string(),
             target_region: target_region.to_string(),
             target_prefix: target_prefix.unwrap_or_default().to_string(),
@@ -515,6 +533,7 @@ pub async fn get_ingestion_job(storage: &Storage, id: i64) -> Result<Option<HfIn
             requester_app_id: job.requester_app_id,
             repo: job.repo,
             revision: job.revision,
+            repo_type: job.repo_type,
             target_bucket: job.target_bucket,
             target_region: job.target_region,
             target_prefix: job.target_prefix,
@@ -739,6 +758,44 @@ async fn update_item_state_inner(
     .await
 }
 
+#[cfg(test)]
+async fn update_item_size(storage: &Storage, id: i64, size: i64) -> Result<()> {
+    update_item_size_inner(storage, id, size, HfWriteGuard::default()).await
+}
+
+pub(crate) async fn update_item_size_with_permit(
+    storage: &Storage,
+    id: i64,
+    size: i64,
+    permit: &PartitionWritePermit,
+    partition_owner_signing_key: &[u8],
+) -> Result<()> {
+    let guard = hf_write_guard(storage, permit, partition_owner_signing_key).await?;
+    update_item_size_inner(storage, id, size, guard).await
+}
+
+async fn update_item_size_inner(
+    storage: &Storage,
+    id: i64,
+    size: i64,
+    guard: HfWriteGuard,
+) -> Result<()> {
+    let Some(mut item) = read_state(storage).await?.items.remove(&id) else {
+        return Ok(());
+    };
+    item.size = Some(size);
+    append_body(
+        storage,
+        HfMutationKind::ItemUpsert,
+        None,
+        None,
+        None,
+        Some(item),
+        guard,
+    )
+    .await
+}
+
 #[cfg(test)]
 async fn update_item_success(storage: &Storage, id: i64, size: i64, etag: &str) -> Result<()> {
     update_item_success_inner(storage, id, size, etag, HfWriteGuard::default()).await
@@ -798,6 +855,25 @@ pub async fn get_ingestion_items(
         .collect())
 }
 
+/// Every item of an ingestion ordered by path, optionally only those in
+/// `state`.
+pub async fn get_ingestion_items_detailed(
+    storage: &Storage,
+    ingestion_id: i64,
+    state: Option<crate::tasks::HFIngestionItemState>,
+) -> Result<Vec<HfIngestionItem>> {
+    let mut items = read_state(storage)
+        .await?
+        .items
+        .into_values()
+        .filter(|item| {
+            item.ingestion_id == ingestion_id && state.is_none_or(|state| item.state == state)
+        })
+        .collect::<Vec<_>>();
+    items.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(items)
+}
+
 pub async fn get_all_items_for_prefix(
     storage: &Storage,
     tenant_id: i64,
@@ -833,6 +909,8 @@ pub async fn status_summary(
     i64,
     i64,
     i64,
+    i64,
+    i64,
     Option<String>,
     Option<DateTime<Utc>>,
     Option<DateTime<Utc>>,
@@ -847,12 +925,15 @@ pub async fn status_summary(
     let downloading = count_items(&state, id, crate::tasks::HFIngestionItemState::Downloading);
     let stored = count_items(&state, id, crate::tasks::HFIngestionItemState::Stored);
     let failed = count_items(&state, id, crate::tasks::HFIngestionItemState::Failed);
+    let (bytes_done, bytes_total) = item_bytes(&state, id);
     Ok((
         job.state.as_str().to_string(),
         queued,
         downloading,
         stored,
         failed,
+        bytes_done,
+        bytes_total,
         job.error.clone(),
         job.started_at,
         job.finished_at,
@@ -868,6 +949,24 @@ fn count_items(state: &HfState, id: i64, item_state: crate::tasks::HFIngestionIt
         .count() as i64
 }
 
+/// Sums item sizes for an ingestion as `(done, total)`. Stored and skipped
+/// items count as done; items whose size is not yet known count toward neither.
+fn item_bytes(state: &HfState, id: i64) -> (i64, i64) {
+    state
+        .items
+        .values()
+        .filter(|item| item.ingestion_id == id)
+        .filter_map(|item| item.size.map(|size| (item.state, size)))
+        .fold((0, 0), |(done, total), (item_state, size)| {
+            let finished = matches!(
+                item_state,
+                crate::tasks::HFIngestionItemState::Stored
+                    | crate::tasks::HFIngestionItemState::Skipped
+            );
+            (done + if finished { size } else { 0 }, total + size)
+        })
+}
+
 async fn read_state(storage: &Storage) -> Result<HfState> {
     let bodies = read_hf_bodies(storage).await?;
     let mut state = HfState::default();
@@ -1129,6 +1228,7 @@ fn hf_ingestion_to_proto(ingestion: &HfIngestion) -> HfIngestionProto {
         requester_app_id: ingestion.requester_app_id,
         repo: ingestion.repo.clone(),
         revision: ingestion.revision.clone(),
+        repo_type: hf_repo_type_to_proto(ingestion.repo_type) as i32,
         target_bucket: ingestion.target_bucket.clone(),
         target_region: ingestion.target_region.clone(),
         target_prefix: ingestion.target_prefix.clone(),
@@ -1150,6 +1250,7 @@ fn hf_ingestion_from_proto(proto: HfIngestionProto) -> Result<HfIngestion> {
         requester_app_id: proto.requester_app_id,
         repo: proto.repo,
         revision: proto.revision,
+        repo_type: hf_repo_type_from_proto(proto.repo_type)?,
         target_bucket: proto.target_bucket,
         target_region: proto.target_region,
         target_prefix: proto.target_prefix,
@@ -1220,6 +1321,28 @@ fn hf_ingestion_state_from_proto(value: i32) -> Result<crate::tasks::HFIngestion
     )
 }
 
+fn hf_repo_type_to_proto(repo_type: crate::tasks::HFRepoType) -> HfRepoTypeProto {
+    match repo_type {
+        crate::tasks::HFRepoType::Model => HfRepoTypeProto::Model,
+        crate::tasks::HFRepoType::Dataset => HfRepoTypeProto::Dataset,
+        crate::tasks::HFRepoType::Space => HfRepoTypeProto::Space,
+    }
+}
+
+fn hf_repo_type_from_proto(value: i32) -> Result<crate::tasks::HFRepoType> {
+    Ok(
+        match HfRepoTypeProto::try_from(value)
+            .map_err(|_| anyhow!("hf ingestion body has invalid repo type"))?
+        {
+            HfRepoTypeProto::Unspecified | HfRepoTypeProto::Model => {
+                crate::tasks::HFRepoType::Model
+            }
+            HfRepoTypeProto::Dataset => crate::tasks::HFRepoType::Dataset,
+            HfRepoTypeProto::Space => crate::tasks::HFRepoType::Space,
+        },
+    )
+}
+
 fn hf_ingestion_item_state_to_proto(
     state: crate::tasks::HFIngestionItemState,
 ) -> HfIngestionItemStateProto {
@@ -1391,6 +1514,7 @@ mod tests {
             2,
             "owner/repo",
             None,
+            crate::tasks::HFRepoType::Dataset,
             "bucket",
             "region",
             Some("prefix"),
@@ -1399,6 +1523,14 @@ mod tests {
         )
         .await
         .unwrap();
+        assert_eq!(
+            get_ingestion_job(&storage, ingestion_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .repo_type,
+            crate::tasks::HFRepoType::Dataset
+        );
         update_ingestion_state(
             &storage,
             ingestion_id,
@@ -1422,6 +1554,7 @@ mod tests {
         );
         let summary = status_summary(&storage, ingestion_id).await.unwrap();
         assert_eq!(summary.3, 1);
+        assert_eq!((summary.5, summary.6), (10, 10));
         assert_eq!(delete_key(&storage, 1, "primary").await.unwrap(), 1);
         assert!(
             get_key_encrypted_by_id(&storage, 1, key_id)
@@ -1431,6 +1564,139 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn hf_status_summary_reports_byte_progress() {
+        let temp = tempdir().unwrap();
+        let storage = Storage::new_at(temp.path()).await.unwrap();
+        create_key(&storage, 1, "primary", b"secret", None)
+            .await
+            .unwrap();
+        let (key_id, _) = get_key_encrypted(&storage, 1, "primary")
+            .await
+            .unwrap()
+            .unwrap();
+        let ingestion_id = create_ingestion(
+            &storage,
+            key_id,
+            1,
+            2,
+            "owner/repo",
+            None,
+            crate::tasks::HFRepoType::Model,
+            "bucket",
+            "region",
+            None,
+            &[],
+            &[],
+        )
+        .await
+        .unwrap();
+
+        let stored = add_item(&storage, ingestion_id, "stored.bin", None, None)
+            .await
+            .unwrap();
+        update_item_success(&storage, stored, 100, "etag")
+            .await
+            .unwrap();
+        let skipped = add_item(&storage, ingestion_id, "skipped.bin", None, None)
+            .await
+            .unwrap();
+        update_item_size(&storage, skipped, 50).await.unwrap();
+        update_item_state_inner(
+            &storage,
+            skipped,
+            crate::tasks::HFIngestionItemState::Skipped,
+            None,
+            HfWriteGuard::default(),
+        )
+        .await
+        .unwrap();
+        let downloaded = add_item(&storage, ingestion_id, "uploading.bin", None, None)
+            .await
+            .unwrap();
+        update_item_size(&storage, downloaded, 250).await.unwrap();
+        add_item(&storage, ingestion_id, "pending.bin", None, None)
+            .await
+            .unwrap();
+
+        let summary = status_summary(&storage, ingestion_id).await.unwrap();
+        assert_eq!((summary.5, summary.6), (150, 400));
+    }
+
+    #[tokio::test]
+    async fn hf_detailed_items_report_errors_and_filter_by_state() {
+        let temp = tempdir().unwrap();
+        let storage = Storage::new_at(temp.path()).await.unwrap();
+        create_key(&storage, 1, "primary", b"secret", None)
+            .await
+            .unwrap();
+        let (key_id, _) = get_key_encrypted(&storage, 1, "primary")
+            .await
+            .unwrap()
+            .unwrap();
+        let ingestion_id = create_ingestion(
+            &storage,
+            key_id,
+            1,
+            2,
+            "owner/repo",
+            None,
+            crate::tasks::HFRepoType::Model,
+            "bucket",
+            "region",
+            None,
+            &[],
+            &[],
+        )
+        .await
+        .unwrap();
+
+        let broken = add_item(&storage, ingestion_id, "z-broken.bin", Some(7), None)
+            .await
+            .unwrap();
+        update_item_state_inner(
+            &storage,
+            broken,
+            crate::tasks::HFIngestionItemState::Failed,
+            Some("download returned 404"),
+            HfWriteGuard::default(),
+        )
+        .await
+        .unwrap();
+        let stored = add_item(&storage, ingestion_id, "a-stored.bin", None, None)
+            .await
+            .unwrap();
+        update_item_success(&storage, stored, 100, "etag")
+            .await
+            .unwrap();
+        add_item(&storage, ingestion_id, "m-queued.bin", None, None)
+            .await
+            .unwrap();
+
+        let all = get_ingestion_items_detailed(&storage, ingestion_id, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            all.iter()
+                .map(|item| item.path.as_str())
+                .collect::<Vec<_>>(),
+            ["a-stored.bin", "m-queued.bin", "z-broken.bin"]
+        );
+
+        let failed = get_ingestion_items_detailed(
+            &storage,
+            ingestion_id,
+            Some(crate::tasks::HFIngestionItemState::Failed),
+        )
+        .await
+        .unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].path, "z-broken.bin");
+        assert_eq!(failed[0].size, Some(7));
+        assert_eq!(failed[0].error.as_deref(), Some("download returned 404"));
+        assert!(failed[0].finished_at.is_some());
+    }
+
     #[tokio::test]
     async fn hf_keys_are_isolated_by_tenant() {
         let temp = tempdir().unwrap();
@@ -1493,6 +1759,7 @@ mod tests {
             2,
             "owner/repo",
             None,
+            crate::tasks::HFRepoType::Model,
             "bucket",
             "region",
             Some("prefix"),
@@ -1627,6 +1894,7 @@ mod tests {
             2,
             "owner/repo",
             None,
+            crate::tasks::HFRepoType::Model,
             "bucket",
             "region",
             Some("prefix"),
@@ -284,6 +284,12 @@ fn typed_json_row_extracts_body_metadata_and_source_id() {
         region: "local".to_string(),
         created_at: Utc::now(),
         is_public_read: false,
+        policy_json: None,
+        max_bytes: None,
+        max_objects: None,
+        replicate_to: Vec::new(),
+        lifecycle_rules: None,
+        default_retention_days: None,
     };
     let index = index_definition(serde_json::json!({
         "source_kind": "object_current",
@@ -322,6 +328,12 @@ fn typed_json_required_field_missing_fails_extraction() {
         region: "local".to_string(),
         created_at: Utc::now(),
         is_public_read: false,
+        policy_json: None,
+        max_bytes: None,
+        max_objects: None,
+        replicate_to: Vec::new(),
+        lifecycle_rules: None,
+        default_retention_days: None,
     };
     let index = index_definition(serde_json::json!({
         "source_kind": "object_current",
@@ -344,6 +356,12 @@ fn typed_json_append_row_extracts_payload_and_metadata() {
         region: "local".to_string(),
         created_at: Utc::now(),
         is_public_read: false,
+        policy_json: None,
+        max_bytes: None,
+        max_objects: None,
+        replicate_to: Vec::new(),
+        lifecycle_rules: None,
+        default_retention_days: None,
     };
     let stream = AppendStream {
         id: 3,
@@ -419,10 +437,12 @@ fn object(key: &str, content_type: Option<&str>) -> Object {
         created_at: Utc::now(),
         deleted_at: None,
         storage_class: None,
+        requested_storage_class: None,
         user_meta: None,
         shard_map: None,
         checksum: None,
         link: None,
+        retention: None,
     }
 }
 
@@ -41,7 +41,9 @@ pub mod authz_scope;
 pub mod authz_segment;
 pub mod authz_userset_index;
 pub mod bucket_journal;
+pub mod bucket_lifecycle;
 pub mod bucket_manager;
+pub mod bucket_policy;
 pub mod cache;
 pub mod cluster;
 pub mod cluster_identity;
@@ -82,10 +84,12 @@ pub mod middleware;
 pub mod model_journal;
 pub mod multipart_journal;
 pub mod native_idempotency;
+pub mod object_checksum;
 pub mod object_links;
 pub mod object_manager;
 pub mod observability;
 pub mod partition_fence;
+pub mod peer_channels;
 pub mod perf;
 pub mod perf_baseline;
 pub mod permissions;
@@ -113,9 +117,11 @@ pub mod personaldb_submit;
 pub mod personaldb_watch;
 pub mod placement;
 pub mod query_planner;
+pub mod rate_limit;
 pub mod registry_segment;
 pub mod repair_finding;
 pub mod routing;
+pub mod safetensors;
 pub mod search_query;
 pub mod services;
 pub mod sharding;
@@ -144,6 +150,9 @@ pub mod admin_audit;
 pub mod append_journal;
 pub mod anvil_api {
     tonic::include_proto!("anvil");
+
+    /// Encoded descriptors for every message and service in `anvil.proto`.
+    pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("anvil_descriptor");
 }
 
 // Our application state, which will hold the persistence layer, storage engine, etc.
@@ -153,6 +162,7 @@ pub struct AppState {
     pub storage: storage::Storage,
     pub core_store: core_store::CoreStore,
     pub cluster: ClusterState,
+    pub peer_channels: peer_channels::PeerChannelPool,
     pub sharder: sharding::ShardManager,
     pub placer: placement::PlacementManager,
     pub jwt_manager: Arc<JwtManager>,
@@ -172,6 +182,7 @@ pub struct AppState {
     pub personaldb_commit_locks: Arc<Mutex<HashMap<String, Arc<Mutex<()>>>>>,
     pub native_mutation_locks: Arc<Mutex<HashMap<String, Arc<Mutex<()>>>>>,
     pub observability: observability::Observability,
+    pub tenant_rate_limiter: Arc<rate_limit::TenantRateLimiter>,
 }
 
 impl AppState {
@@ -188,6 +199,7 @@ impl AppState {
         let arc_config = Arc::new(config);
         let jwt_manager = Arc::new(JwtManager::new(arc_config.jwt_secret.clone()));
         let storage = storage::Storage::new_at(&arc_config.storage_path).await?;
+        storage.self_test().await?;
         let personaldb_signing_key_store =
             Arc::new(personaldb_signing_store::PersonalDbSigningKeyStore::new(
                 storage.clone(),
@@ -208,6 +220,7 @@ impl AppState {
             }
         };
         let personaldb_protocol_keyring = Arc::new(personaldb_protocol_keyring);
+        let cluster_state: ClusterState = Arc::new(RwLock::new(HashMap::new()));
         let core_store = core_store::CoreStore::new_with_pipeline_keyring_and_identity(
             storage.clone(),
             arc_config.core_pipeline_keyring()?,
@@ -220,14 +233,28 @@ impl AppState {
                 internal_bearer_token: (!arc_config.corestore_internal_bearer_token.is_empty())
                     .then(|| arc_config.corestore_internal_bearer_token.clone()),
             },
+            core_store::CoreStoreOptions {
+                gossip_peers: Some(cluster_state.clone()),
+                ..arc_config.core_store_options()?
+            },
         )
         .await?;
-        let cluster_state = Arc::new(RwLock::new(HashMap::new()));
+        let configured_shard_layout = arc_config.data_shards != 0 || arc_config.parity_shards != 0;
+        if configured_shard_layout {
+            core_store.ensure_default_storage_class_fits_mesh()?;
+        }
         let persistence = persistence::Persistence::new(&arc_config, event_publisher)?;
         if !arc_config.region.is_empty() {
             persistence.create_region(&arc_config.region).await?;
         }
-        let sharder = sharding::ShardManager::new();
+        let sharder = if configured_shard_layout {
+            sharding::ShardManager::new_with_config(
+                arc_config.data_shards,
+                arc_config.parity_shards,
+            )?
+        } else {
+            sharding::ShardManager::new()
+        };
         let placer = placement::PlacementManager::default();
         let (object_watch_tx, _object_watch_rx) = tokio::sync::broadcast::channel(1024);
         let (bucket_watch_tx, _bucket_watch_rx) = tokio::sync::broadcast::channel(1024);
@@ -238,10 +265,16 @@ impl AppState {
             tokio::sync::broadcast::channel(1024);
         let personaldb_commit_locks = Arc::new(Mutex::new(HashMap::new()));
         let native_mutation_locks = Arc::new(Mutex::new(HashMap::new()));
-        let observability = observability::Observability::default();
+        let observability = observability::Observability::with_bucket_label_policy(
+            arc_config.bucket_label_policy(),
+        );
+        let tenant_rate_limiter = Arc::new(rate_limit::TenantRateLimiter::default());
+        tenant_rate_limiter.replace_limits(persistence.list_tenant_rate_limits().await?);
 
         let bucket_manager =
-            bucket_manager::BucketManager::new(persistence.clone(), storage.clone());
+            bucket_manager::BucketManager::new(persistence.clone(), storage.clone())
+                .with_max_buckets_per_tenant(arc_config.max_buckets_per_tenant);
+        let peer_channels = peer_channels::PeerChannelPool::default();
         let object_manager = object_manager::ObjectManager::new(
             persistence.clone(),
             storage.clone(),
@@ -251,7 +284,18 @@ impl AppState {
             partition_signing_key,
             object_watch_tx,
             observability.clone(),
-        );
+        )
+        .with_multipart_min_part_size(arc_config.multipart_min_part_size_bytes)
+        .with_max_object_size(arc_config.max_object_size_bytes)
+        .with_put_timing_breakdown(arc_config.put_object_timing_breakdown)
+        .with_listing_cache(
+            Duration::from_millis(arc_config.object_list_cache_ttl_ms),
+            arc_config.object_list_cache_max_entries,
+        )
+        .with_replication(object_manager::ReplicationTransport {
+            peer_channels: peer_channels.clone(),
+            internal_bearer_token: arc_config.corestore_internal_bearer_token.clone(),
+        });
         system_realm::ensure_bootstrapped(
             &arc_config,
             &persistence,
@@ -265,6 +309,7 @@ impl AppState {
             storage,
             core_store,
             cluster: cluster_state,
+            peer_channels,
             sharder,
             placer,
             jwt_manager,
@@ -283,6 +328,7 @@ impl AppState {
             personaldb_commit_locks,
             native_mutation_locks,
             observability,
+            tenant_rate_limiter,
         })
     }
 }
@@ -172,12 +172,22 @@ pub struct NodeDescriptor {
     pub capacity_json_hash: String,
     pub state: LifecycleState,
     pub drain: Option<NodeDrainDescriptor>,
+    /// Cordoned nodes keep serving reads and coremeta quorum but receive no
+    /// new block shards.
+    #[serde(default)]
+    pub cordoned: bool,
     pub last_heartbeat_at: Option<String>,
     pub created_at: String,
     pub updated_at: String,
     pub generation: u64,
 }
 
+impl NodeDescriptor {
+    pub fn accepts_new_shards(&self) -> bool {
+        self.state == LifecycleState::Active && !self.cordoned
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RegionDescriptor {
     pub schema: String,
@@ -1566,6 +1576,7 @@ async fn register_node_inner(
         capacity_json_hash,
         state: LifecycleState::Joining,
         drain: None,
+        cordoned: false,
         last_heartbeat_at: None,
         created_at: now.clone(),
         updated_at: now,
@@ -1672,6 +1683,7 @@ pub async fn put_node_in_transaction(
             capacity_json_hash,
             state: LifecycleState::Joining,
             drain: None,
+            cordoned: false,
             last_heartbeat_at: None,
             created_at: now.clone(),
             updated_at: now,
@@ -1807,6 +1819,78 @@ async fn transition_node_inner(
     Ok(out)
 }
 
+pub async fn set_node_cordoned(
+    storage: &Storage,
+    node_id: &str,
+    expected_generation: u64,
+    cordoned: bool,
+) -> LifecycleResult<NodeDescriptor> {
+    set_node_cordoned_inner(storage, node_id, expected_generation, cordoned, None).await
+}
+
+pub async fn set_node_cordoned_with_control(
+    storage: &Storage,
+    node_id: &str,
+    expected_generation: u64,
+    cordoned: bool,
+    authority: LifecycleControlWriteAuthority<'_>,
+) -> LifecycleResult<NodeDescriptor> {
+    set_node_cordoned_inner(
+        storage,
+        node_id,
+        expected_generation,
+        cordoned,
+        Some(authority),
+    )
+    .await
+}
+
+async fn set_node_cordoned_inner(
+    storage: &Storage,
+    node_id: &str,
+    expected_generation: u64,
+    cordoned: bool,
+    authority: Option<LifecycleControlWriteAuthority<'_>>,
+) -> LifecycleResult<NodeDescriptor> {
+    require_identifier(node_id, "node id")?;
+    let mut state = read_state(storage).await?;
+    let descriptor = state
+        .nodes
+        .get_mut(node_id)
+        .ok_or_else(|| LifecycleError::NotFound {
+            resource_kind: "node",
+            resource_id: node_id.to_string(),
+        })?;
+    ensure_generation("node", node_id, descriptor.generation, expected_generation)?;
+    if descriptor.state == LifecycleState::Removed {
+        return Err(LifecycleError::InvalidArgument(format!(
+            "node {node_id} has been removed"
+        )));
+    }
+    descriptor.cordoned = cordoned;
+    descriptor.updated_at = timestamp_now();
+    descriptor.generation = descriptor.generation.saturating_add(1);
+    let out = descriptor.clone();
+    if let Some(authority) = authority {
+        let record_key = node_record_key(&out.region, &out.cell_id, &out.node_id)?;
+        append_lifecycle_control_mutation(
+            storage,
+            NODE_DESCRIPTOR_STREAM_FAMILY,
+            &lifecycle_control_partition(NODE_DESCRIPTOR_STREAM_FAMILY, &record_key),
+            &record_key,
+            "upsert",
+            Some(expected_generation),
+            out.generation,
+            &out.mesh_id,
+            &out,
+            authority,
+        )
+        .await?;
+    }
+    write_state(storage, &state).await?;
+    Ok(out)
+}
+
 pub async fn list_nodes(
     storage: &Storage,
     region_filter: Option<&str>,
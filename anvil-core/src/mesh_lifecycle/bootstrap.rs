@@ -147,6 +147,7 @@ fn bootstrap_lifecycle_state(
             capacity_json_hash: capacity_json_hash(&input.capacity_json)?,
             state: LifecycleState::Active,
             drain: None,
+            cordoned: false,
             last_heartbeat_at: None,
             created_at: now.clone(),
             updated_at: now.clone(),
@@ -161,6 +161,8 @@ struct NodeDescriptorProto {
     generation: u64,
     #[prost(string, tag = "17")]
     capacity_json_hash: String,
+    #[prost(bool, tag = "18")]
+    cordoned: bool,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -662,6 +664,7 @@ fn node_to_proto(descriptor: &NodeDescriptor) -> NodeDescriptorProto {
             .collect(),
         state: lifecycle_state_to_proto(descriptor.state),
         drain: descriptor.drain.as_ref().map(node_drain_to_proto),
+        cordoned: descriptor.cordoned,
         last_heartbeat_at: descriptor.last_heartbeat_at.clone(),
         created_at: descriptor.created_at.clone(),
         updated_at: descriptor.updated_at.clone(),
@@ -689,6 +692,7 @@ fn node_from_proto(proto: NodeDescriptorProto) -> LifecycleResult<NodeDescriptor
         capacity_json_hash: proto.capacity_json_hash,
         state: lifecycle_state_from_proto(proto.state)?,
         drain: proto.drain.map(node_drain_from_proto).transpose()?,
+        cordoned: proto.cordoned,
         last_heartbeat_at: proto.last_heartbeat_at,
         created_at: proto.created_at,
         updated_at: proto.updated_at,
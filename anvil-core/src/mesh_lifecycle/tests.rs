@@ -508,6 +508,7 @@ async fn lifecycle_read_model_replays_control_streams_as_source_of_truth() {
         capacity_json_hash: blake3::hash(b"{}").to_hex().to_string(),
         state: LifecycleState::Active,
         drain: None,
+        cordoned: false,
         last_heartbeat_at: Some("2026-07-02T00:01:00Z".to_string()),
         created_at: "2026-07-02T00:00:00Z".to_string(),
         updated_at: "2026-07-02T00:01:00Z".to_string(),
@@ -547,6 +548,38 @@ async fn lifecycle_read_model_replays_control_streams_as_source_of_truth() {
     assert_eq!(replayed_without_projection.nodes["node-a"].generation, 2);
 }
 
+#[tokio::test]
+async fn cordoned_nodes_stay_active_but_are_excluded_from_new_shard_placement() {
+    let temp = tempdir().unwrap();
+    let storage = Storage::new_at(temp.path()).await.unwrap();
+    let (_region, _cell, node) = create_active_placement_model(&storage).await;
+    assert!(node.accepts_new_shards());
+
+    assert!(matches!(
+        set_node_cordoned(&storage, "node-a", node.generation + 1, true).await,
+        Err(LifecycleError::GenerationConflict { .. })
+    ));
+    let cordoned = set_node_cordoned(&storage, "node-a", node.generation, true)
+        .await
+        .unwrap();
+    assert!(cordoned.cordoned);
+    assert_eq!(cordoned.state, LifecycleState::Active);
+    assert_eq!(cordoned.generation, node.generation + 1);
+
+    let store = CoreStore::new(storage.clone()).await.unwrap();
+    let projected = list_node_projections_with_core_store(&store, None, None).unwrap();
+    assert_eq!(projected, vec![cordoned.clone()]);
+    assert!(!projected[0].accepts_new_shards());
+
+    let uncordoned = set_node_cordoned(&storage, "node-a", cordoned.generation, false)
+        .await
+        .unwrap();
+    let store = CoreStore::new(storage.clone()).await.unwrap();
+    let projected = list_node_projections_with_core_store(&store, None, None).unwrap();
+    assert_eq!(projected, vec![uncordoned]);
+    assert!(projected[0].accepts_new_shards());
+}
+
 async fn create_test_region(storage: &Storage) -> RegionDescriptor {
     create_region(
         storage,
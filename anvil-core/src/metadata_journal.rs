@@ -19,7 +19,9 @@ use crate::formats::{
 };
 use crate::object_links;
 use crate::partition_fence::{PartitionWritePermit, partition_write_precondition};
-use crate::persistence::{Bucket, Object, ObjectVersion, ObjectVersionsPage};
+use crate::persistence::{
+    Bucket, BucketUsage, Object, ObjectRetention, ObjectVersion, ObjectVersionsPage,
+};
 use crate::storage::Storage;
 use crate::writer_segment_catalog::{
     WriterSegmentCatalogRecord, read_writer_segment_catalog_record,
@@ -51,6 +53,7 @@ pub enum ObjectJournalMutation {
     Put,
     DeleteMarker,
     DeleteVersion,
+    Retention,
 }
 
 impl ObjectJournalMutation {
@@ -59,6 +62,7 @@ impl ObjectJournalMutation {
             "put" => Ok(Self::Put),
             "delete_marker" => Ok(Self::DeleteMarker),
             "delete_version" => Ok(Self::DeleteVersion),
+            "retention" => Ok(Self::Retention),
             other => Err(anyhow!("unknown object metadata mutation event {other}")),
         }
     }
@@ -68,12 +72,13 @@ impl ObjectJournalMutation {
             Self::Put => "put",
             Self::DeleteMarker => "delete_marker",
             Self::DeleteVersion => "delete_version",
+            Self::Retention => "retention",
         }
     }
 
     fn object_record_kind(self) -> &'static str {
         match self {
-            Self::Put | Self::DeleteVersion => OBJECT_VERSION_RECORD_KIND,
+            Self::Put | Self::DeleteVersion | Self::Retention => OBJECT_VERSION_RECORD_KIND,
             Self::DeleteMarker => DELETE_MARKER_RECORD_KIND,
         }
     }
@@ -104,10 +109,12 @@ struct ObjectVersionBody {
     index_policy_snapshot: String,
     record_hash: String,
     storage_class: Option<String>,
+    requested_storage_class: Option<String>,
     user_meta: Option<serde_json::Value>,
     shard_map: Option<serde_json::Value>,
     checksum: Option<Vec<u8>>,
     link: Option<object_links::ObjectLinkTarget>,
+    retention: Option<ObjectRetention>,
     delete_marker: bool,
     created_at: String,
     deleted_at: Option<String>,
@@ -134,10 +141,12 @@ struct DirectoryEntryBody {
     index_policy_snapshot: String,
     record_hash: String,
     storage_class: Option<String>,
+    requested_storage_class: Option<String>,
     user_meta: Option<serde_json::Value>,
     shard_map: Option<serde_json::Value>,
     checksum: Option<Vec<u8>>,
     link: Option<object_links::ObjectLinkTarget>,
+    retention: Option<ObjectRetention>,
     delete_marker: bool,
     created_at: String,
     deleted_at: Option<String>,
@@ -201,6 +210,18 @@ struct ObjectMetadataBodyProto {
     deleted_at: Option<String>,
     #[prost(string, optional, tag = "28")]
     shard_map_kind: Option<String>,
+    #[prost(message, optional, tag = "29")]
+    retention: Option<ObjectRetentionProto>,
+    #[prost(string, optional, tag = "30")]
+    requested_storage_class: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+struct ObjectRetentionProto {
+    #[prost(string, optional, tag = "1")]
+    retain_until: Option<String>,
+    #[prost(bool, tag = "2")]
+    legal_hold: bool,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -306,6 +327,7 @@ fn encode_object_metadata_body_proto(body: &ObjectVersionBody) -> Result<Vec<u8>
         index_policy_snapshot: body.index_policy_snapshot.clone(),
         record_hash: body.record_hash.clone(),
         storage_class: body.storage_class.clone(),
+        requested_storage_class: body.requested_storage_class.clone(),
         user_meta_json: body
             .user_meta
             .as_ref()
@@ -323,6 +345,7 @@ fn encode_object_metadata_body_proto(body: &ObjectVersionBody) -> Result<Vec<u8>
             .transpose()?,
         checksum: body.checksum.clone(),
         link: body.link.as_ref().map(link_target_to_proto),
+        retention: body.retention.as_ref().map(retention_to_proto),
         delete_marker: body.delete_marker,
         created_at: body.created_at.clone(),
         deleted_at: body.deleted_at.clone(),
@@ -356,6 +379,7 @@ fn decode_object_metadata_body_proto(bytes: &[u8]) -> Result<ObjectVersionBody>
         index_policy_snapshot: proto.index_policy_snapshot,
         record_hash: proto.record_hash,
         storage_class: proto.storage_class,
+        requested_storage_class: proto.requested_storage_class,
         user_meta: proto
             .user_meta_json
             .as_deref()
@@ -373,6 +397,7 @@ fn decode_object_metadata_body_proto(bytes: &[u8]) -> Result<ObjectVersionBody>
             .transpose()?,
         checksum: proto.checksum,
         link: proto.link.map(link_target_from_proto).transpose()?,
+        retention: proto.retention.map(retention_from_proto).transpose()?,
         delete_marker: proto.delete_marker,
         created_at: proto.created_at,
         deleted_at: proto.deleted_at,
@@ -443,10 +468,12 @@ fn object_version_body_from_directory_entry(body: &DirectoryEntryBody) -> Object
         index_policy_snapshot: body.index_policy_snapshot.clone(),
         record_hash: body.record_hash.clone(),
         storage_class: body.storage_class.clone(),
+        requested_storage_class: body.requested_storage_class.clone(),
         user_meta: body.user_meta.clone(),
         shard_map: body.shard_map.clone(),
         checksum: body.checksum.clone(),
         link: body.link.clone(),
+        retention: body.retention,
         delete_marker: body.delete_marker,
         created_at: body.created_at.clone(),
         deleted_at: body.deleted_at.clone(),
@@ -512,6 +539,27 @@ fn link_target_from_proto(value: ObjectLinkTargetProto) -> Result<object_links::
     })
 }
 
+fn retention_to_proto(value: &ObjectRetention) -> ObjectRetentionProto {
+    ObjectRetentionProto {
+        retain_until: value
+            .retain_until
+            .map(|until| until.to_rfc3339_opts(chrono::SecondsFormat::Nanos, true)),
+        legal_hold: value.legal_hold,
+    }
+}
+
+fn retention_from_proto(value: ObjectRetentionProto) -> Result<ObjectRetention> {
+    Ok(ObjectRetention {
+        retain_until: value
+            .retain_until
+            .as_deref()
+            .map(chrono::DateTime::parse_from_rfc3339)
+            .transpose()?
+            .map(|until| until.with_timezone(&chrono::Utc)),
+        legal_hold: value.legal_hold,
+    })
+}
+
 #[cfg(test)]
 async fn append_object_mutation(
     storage: &Storage,
@@ -641,10 +689,12 @@ async fn append_object_mutation_inner_once(
         index_policy_snapshot: object.index_policy_snapshot.clone(),
         record_hash: object.record_hash.clone(),
         storage_class: object.storage_class.clone(),
+        requested_storage_class: object.requested_storage_class.clone(),
         user_meta: object.user_meta.clone(),
         shard_map: object.shard_map.clone(),
         checksum: object.checksum.clone(),
         link: object.link.clone(),
+        retention: object.retention,
         delete_marker: mutation.is_delete_marker(),
         created_at: object.created_at.to_rfc3339(),
         deleted_at: object.deleted_at.map(|ts| ts.to_rfc3339()),
@@ -671,10 +721,12 @@ async fn append_object_mutation_inner_once(
         index_policy_snapshot: object.index_policy_snapshot.clone(),
         record_hash: object.record_hash.clone(),
         storage_class: object.storage_class.clone(),
+        requested_storage_class: object.requested_storage_class.clone(),
         user_meta: object.user_meta.clone(),
         shard_map: object.shard_map.clone(),
         checksum: object.checksum.clone(),
         link: object.link.clone(),
+        retention: object.retention,
         delete_marker: mutation.is_delete_marker(),
         created_at: object.created_at.to_rfc3339(),
         deleted_at: object.deleted_at.map(|ts| ts.to_rfc3339()),
@@ -1383,7 +1435,7 @@ pub async fn list_current_objects(
     let mut objects = read_current_directory_objects(storage, bucket, manifest_signing_key).await?;
     objects.retain(|object| {
         object.key.starts_with(prefix)
-            && object.key.as_str() > start_after
+            && key_listed_after(&object.key, prefix, delimiter, start_after)
             && !crate::validation::is_reserved_internal_key(&object.key)
     });
     objects.sort_by(|left, right| left.key.cmp(&right.key));
@@ -1433,6 +1485,27 @@ pub async fn list_current_objects(
     Ok(listing)
 }
 
+/// Whether `key` (which starts with `prefix`) sorts after `start_after` in a
+/// delimiter listing. Keys that roll up into a common prefix at or before
+/// `start_after` were already returned as that prefix.
+pub(crate) fn key_listed_after(
+    key: &str,
+    prefix: &str,
+    delimiter: &str,
+    start_after: &str,
+) -> bool {
+    if key <= start_after {
+        return false;
+    }
+    if delimiter.is_empty() {
+        return true;
+    }
+    match key[prefix.len()..].find(delimiter) {
+        Some(position) => &key[..prefix.len() + position + delimiter.len()] > start_after,
+        None => true,
+    }
+}
+
 pub(crate) async fn read_current_directory_objects(
     storage: &Storage,
     bucket: &Bucket,
@@ -1445,6 +1518,16 @@ pub(crate) async fn read_current_directory_objects(
         .await
 }
 
+pub(crate) async fn read_current_object_usage(
+    storage: &Storage,
+    bucket: &Bucket,
+) -> Result<BucketUsage> {
+    CoreStore::new(storage.clone())
+        .await?
+        .bucket_object_usage(bucket)
+        .await
+}
+
 pub async fn read_current_objects(
     storage: &Storage,
     bucket: &Bucket,
@@ -1929,10 +2012,12 @@ fn directory_entry_from_object_version_body(body: &ObjectVersionBody) -> Directo
         index_policy_snapshot: body.index_policy_snapshot.clone(),
         record_hash: body.record_hash.clone(),
         storage_class: body.storage_class.clone(),
+        requested_storage_class: body.requested_storage_class.clone(),
         user_meta: body.user_meta.clone(),
         shard_map: body.shard_map.clone(),
         checksum: body.checksum.clone(),
         link: body.link.clone(),
+        retention: body.retention,
         delete_marker: body.delete_marker,
         created_at: body.created_at.clone(),
         deleted_at: body.deleted_at.clone(),
@@ -460,10 +460,12 @@ pub(super) fn object_from_body(body: &ObjectVersionBody) -> Result<Object> {
             .map(parse_body_timestamp)
             .transpose()?,
         storage_class: body.storage_class.clone(),
+        requested_storage_class: body.requested_storage_class.clone(),
         user_meta: body.user_meta.clone(),
         shard_map: body.shard_map.clone(),
         checksum: body.checksum.clone(),
         link: body.link.clone(),
+        retention: body.retention,
     })
 }
 
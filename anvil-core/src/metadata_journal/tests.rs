@@ -16,6 +16,12 @@ fn sample_bucket() -> Bucket {
         region: "test-region".to_string(),
         created_at: Utc::now(),
         is_public_read: false,
+        policy_json: None,
+        max_bytes: None,
+        max_objects: None,
+        replicate_to: Vec::new(),
+        lifecycle_rules: None,
+        default_retention_days: None,
     }
 }
 
@@ -39,10 +45,12 @@ fn sample_object(id: i64, key: &str, delete_marker: bool) -> Object {
         created_at: Utc::now(),
         deleted_at: delete_marker.then(Utc::now),
         storage_class: None,
+        requested_storage_class: None,
         user_meta: None,
         shard_map: None,
         checksum: None,
         link: None,
+        retention: None,
     }
 }
 
@@ -812,3 +820,74 @@ async fn object_metadata_stream_rejects_corrupted_appended_frame() {
             .is_err()
     );
 }
+
+#[tokio::test]
+async fn delimiter_list_splits_raw_keys_and_resumes_after_common_prefixes() {
+    let temp = tempdir().unwrap();
+    let storage = Storage::new_at(temp.path()).await.unwrap();
+    let bucket = sample_bucket();
+    let keys = [
+        "docs/-x/y.txt",
+        "docs/.hidden",
+        "docs/a b/c.txt",
+        "docs/a b/d.txt",
+        "docs/my file.txt",
+        "docs/v1.2/readme.md",
+        "docs/🦀/crab.txt",
+    ];
+    for (id, key) in keys.iter().enumerate() {
+        let object = sample_object(id as i64 + 1, key, false);
+        append_object_mutation(&storage, &bucket, &object, ObjectJournalMutation::Put)
+            .await
+            .unwrap();
+    }
+    let signing_key = b"manifest signing key";
+
+    let mut pages = Vec::new();
+    let mut start_after = String::new();
+    loop {
+        let page = list_current_objects(
+            &storage,
+            &bucket,
+            signing_key,
+            "docs/",
+            &start_after,
+            2,
+            "/",
+        )
+        .await
+        .unwrap();
+        let mut entries = page
+            .objects
+            .iter()
+            .map(|object| object.key.clone())
+            .chain(page.common_prefixes.iter().cloned())
+            .collect::<Vec<_>>();
+        entries.sort();
+        let Some(last) = entries.last().cloned() else {
+            break;
+        };
+        pages.push(entries);
+        start_after = last;
+    }
+    assert_eq!(
+        pages,
+        vec![
+            vec!["docs/-x/".to_string(), "docs/.hidden".to_string()],
+            vec!["docs/a b/".to_string(), "docs/my file.txt".to_string()],
+            vec!["docs/v1.2/".to_string(), "docs/🦀/".to_string()],
+        ]
+    );
+
+    let dotted = list_current_objects(&storage, &bucket, signing_key, "docs/v1", "", 10, ".")
+        .await
+        .unwrap();
+    assert!(dotted.objects.is_empty());
+    assert_eq!(dotted.common_prefixes, vec!["docs/v1."]);
+
+    let emoji = list_current_objects(&storage, &bucket, signing_key, "docs/🦀/", "", 10, "/")
+        .await
+        .unwrap();
+    assert_eq!(emoji.objects.len(), 1);
+    assert_eq!(emoji.objects[0].key, "docs/🦀/crab.txt");
+}
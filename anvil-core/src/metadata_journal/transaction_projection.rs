@@ -7,7 +7,9 @@ pub(super) async fn materialize_object_metadata_projection(
     mutation: ObjectJournalMutation,
 ) -> Result<()> {
     match mutation {
-        ObjectJournalMutation::Put | ObjectJournalMutation::DeleteMarker => {
+        ObjectJournalMutation::Put
+        | ObjectJournalMutation::DeleteMarker
+        | ObjectJournalMutation::Retention => {
             core_store.put_object_metadata(bucket, object).await?;
         }
         ObjectJournalMutation::DeleteVersion => {
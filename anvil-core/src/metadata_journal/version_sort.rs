@@ -10,6 +10,12 @@ pub(super) fn object_versions_by_key(
         let versions = versions_by_key.entry(body.object_key.clone()).or_default();
         if body.event == "delete_version" {
             versions.retain(|(_, existing)| existing.version_id != body.version_id);
+        } else if body.event == "retention"
+            && let Some((_, existing)) = versions
+                .iter_mut()
+                .find(|(_, existing)| existing.version_id == body.version_id)
+        {
+            *existing = body;
         } else {
             versions.push((order, body));
         }
@@ -2,13 +2,50 @@ use crate::{AppState, auth::AuthenticatedBearerToken};
 use axum::{http::HeaderMap, http::HeaderValue, response::Response};
 use http::Uri;
 use std::time::Instant;
-use tonic::{Request, Status};
+use tonic::{Request, Status, metadata::MetadataValue};
+use tracing::Instrument;
 
 pub const ANVIL_REQUEST_ID_HEADER: &str = "x-anvil-request-id";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AnvilRequestId(pub String);
 
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: String;
+}
+
+/// The id of the API request the current task is serving, if any.
+pub fn current_request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Tags an outgoing internal RPC with the current request id, so the peer
+/// serves it under the same id and its logs can be correlated with ours.
+pub fn propagate_request_id<T>(request: &mut Request<T>) {
+    if let Some(value) = current_request_id().and_then(|id| MetadataValue::try_from(id).ok()) {
+        request
+            .metadata_mut()
+            .insert(ANVIL_REQUEST_ID_HEADER, value);
+    }
+}
+
+/// Reuses the id a peer forwarded with the request, or mints a new one.
+/// Forwarded ids only end up in logs and headers, but they are still
+/// restricted to short token characters.
+fn inbound_request_id(headers: &HeaderMap) -> String {
+    headers
+        .get(ANVIL_REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| {
+            !id.is_empty()
+                && id.len() <= 64
+                && id
+                    .bytes()
+                    .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_')
+        })
+        .map_or_else(|| uuid::Uuid::new_v4().simple().to_string(), str::to_string)
+}
+
 pub fn auth_interceptor<T>(mut req: Request<T>, state: &AppState) -> Result<Request<T>, Status> {
     let has_auth = req.metadata().get("authorization").is_some();
 
@@ -21,7 +58,7 @@ pub fn auth_interceptor<T>(mut req: Request<T>, state: &AppState) -> Result<Requ
             "Invalid gRPC request, extension not found",
         ));
     };
-    tracing::info!("[auth_interceptor] path={} auth_present={}", uri, has_auth);
+    tracing::debug!(path = %uri, auth_present = has_auth, "authenticating gRPC request");
     // A list of public routes that do not require authentication.
     const PUBLIC_ROUTES: &[&str] = &[
         "/anvil.AuthService/GetAccessToken",
@@ -34,10 +71,27 @@ pub fn auth_interceptor<T>(mut req: Request<T>, state: &AppState) -> Result<Requ
         return Ok(req);
     }
 
-    authenticate_bearer(&mut req, state)?;
+    let claims = authenticate_bearer(&mut req, state)?;
+    enforce_tenant_rate_limit(claims.tenant_id, state)?;
     Ok(req)
 }
 
+/// Fails with `ResourceExhausted` and a `retry-after` hint once the tenant
+/// has spent its request rate limit.
+fn enforce_tenant_rate_limit(tenant_id: i64, state: &AppState) -> Result<(), Status> {
+    let Err(retry_after) = state.tenant_rate_limiter.check(tenant_id) else {
+        return Ok(());
+    };
+    let retry_after_secs = crate::rate_limit::retry_after_secs(retry_after);
+    let mut status = Status::resource_exhausted(format!(
+        "SlowDown: tenant request rate limit exceeded; retry after {retry_after_secs}s"
+    ));
+    status
+        .metadata_mut()
+        .insert("retry-after", MetadataValue::from(retry_after_secs));
+    Err(status)
+}
+
 /// Admin-plane authentication boundary. This only authenticates and rejects
 /// credentials that are clearly data-plane-only; method code still performs the
 /// Zanzibar system-realm relation check for the specific admin operation.
@@ -126,7 +180,11 @@ pub async fn request_id_mw(
     } else {
         "public-http"
     };
-    let request_id = uuid::Uuid::new_v4().simple().to_string();
+    let request_id = inbound_request_id(req.headers());
+    if let Ok(header_value) = HeaderValue::from_str(&request_id) {
+        req.headers_mut()
+            .insert(ANVIL_REQUEST_ID_HEADER, header_value);
+    }
     req.extensions_mut()
         .insert(AnvilRequestId(request_id.clone()));
 
@@ -136,7 +194,19 @@ pub async fn request_id_mw(
         ("method".to_string(), method.clone()),
         ("path".to_string(), path.clone()),
     ];
-    let mut response = crate::perf::with_context(context, next.run(req)).await;
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        plane,
+        method = %method,
+        path = %path,
+    );
+    let mut response = crate::perf::with_context(
+        context,
+        CURRENT_REQUEST_ID.scope(request_id.clone(), next.run(req)),
+    )
+    .instrument(span)
+    .await;
     let status = response.status().as_u16().to_string();
     crate::perf::record_request_duration(
         plane,
@@ -167,6 +237,13 @@ pub async fn request_id_mw(
         crate::perf::record_protocol_errors_total(path.as_str(), status.as_str());
     }
     if let Ok(header_value) = HeaderValue::from_str(&request_id) {
+        // S3 clients look for their own header name; error documents set it
+        // already, so only fill it in where a handler did not.
+        if plane == "public-http" && !response.headers().contains_key("x-amz-request-id") {
+            response
+                .headers_mut()
+                .insert("x-amz-request-id", header_value.clone());
+        }
         response
             .headers_mut()
             .insert(ANVIL_REQUEST_ID_HEADER, header_value);
@@ -201,4 +278,44 @@ mod tests {
         assert!(!logged.contains("session-secret"));
         assert!(!logged.contains("Bearer"));
     }
+
+    #[test]
+    fn inbound_request_id_reuses_forwarded_ids_and_rejects_unsafe_ones() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            ANVIL_REQUEST_ID_HEADER,
+            HeaderValue::from_static("4f1c2b7e9a6d4e0f8b3a5c7d9e1f2a3b"),
+        );
+        assert_eq!(
+            inbound_request_id(&headers),
+            "4f1c2b7e9a6d4e0f8b3a5c7d9e1f2a3b"
+        );
+
+        headers.insert(
+            ANVIL_REQUEST_ID_HEADER,
+            HeaderValue::from_static("id with spaces"),
+        );
+        let minted = inbound_request_id(&headers);
+        assert_eq!(minted.len(), 32);
+        assert!(minted.bytes().all(|byte| byte.is_ascii_hexdigit()));
+    }
+
+    #[tokio::test]
+    async fn propagate_request_id_copies_the_current_id_onto_internal_requests() {
+        let mut outside = Request::new(());
+        propagate_request_id(&mut outside);
+        assert!(outside.metadata().get(ANVIL_REQUEST_ID_HEADER).is_none());
+
+        let request = CURRENT_REQUEST_ID
+            .scope("req-123".to_string(), async {
+                let mut request = Request::new(());
+                propagate_request_id(&mut request);
+                request
+            })
+            .await;
+        assert_eq!(
+            request.metadata().get(ANVIL_REQUEST_ID_HEADER).unwrap(),
+            "req-123"
+        );
+    }
 }
@@ -69,9 +69,18 @@ struct ModelTensorsReplaceProto {
     tensors: Vec<TensorIndexRow>,
 }
 
+/// Bucket and object key an artifact was registered under. Tensor `file_path`s
+/// are relative to `key`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelArtifactLocation {
+    pub bucket_id: i64,
+    pub key: String,
+}
+
 #[derive(Debug, Clone, Default)]
 struct ModelState {
     artifacts: BTreeMap<String, ModelManifest>,
+    locations: BTreeMap<String, ModelArtifactLocation>,
     tensors: BTreeMap<String, Vec<TensorIndexRow>>,
 }
 
@@ -201,6 +210,43 @@ pub async fn list_tensors(
         .collect())
 }
 
+/// Optional filters applied to an artifact's tensor index by `query_tensors`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TensorQuery {
+    pub prefix: String,
+    pub dtype: Option<i32>,
+    pub min_bytes: u64,
+}
+
+impl TensorQuery {
+    fn matches(&self, row: &TensorIndexRow) -> bool {
+        row.tensor_name.starts_with(&self.prefix)
+            && self.dtype.is_none_or(|dtype| row.dtype == dtype)
+            && row.byte_length >= self.min_bytes
+    }
+}
+
+pub async fn query_tensors(
+    storage: &Storage,
+    artifact_id: &str,
+    query: &TensorQuery,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<TensorIndexRow>> {
+    let mut tensors = read_model_state(storage)
+        .await?
+        .tensors
+        .remove(artifact_id)
+        .unwrap_or_default();
+    tensors.retain(|row| query.matches(row));
+    tensors.sort_by(|a, b| a.tensor_name.cmp(&b.tensor_name));
+    Ok(tensors
+        .into_iter()
+        .skip(offset.max(0) as usize)
+        .take(limit.max(0) as usize)
+        .collect())
+}
+
 pub async fn get_tensor_metadata(
     storage: &Storage,
     artifact_id: &str,
@@ -225,6 +271,16 @@ pub async fn get_model_artifact(
         .cloned())
 }
 
+pub async fn get_model_artifact_location(
+    storage: &Storage,
+    artifact_id: &str,
+) -> Result<Option<ModelArtifactLocation>> {
+    Ok(read_model_state(storage)
+        .await?
+        .locations
+        .remove(artifact_id))
+}
+
 async fn read_model_state(storage: &Storage) -> Result<ModelState> {
     let events = read_model_events(storage).await?;
     let mut state = ModelState::default();
@@ -232,9 +288,14 @@ async fn read_model_state(storage: &Storage) -> Result<ModelState> {
         match event {
             ModelEventBody::ArtifactUpsert {
                 artifact_id,
+                bucket_id,
+                key,
                 manifest,
-                ..
             } => {
+                state.locations.insert(
+                    artifact_id.clone(),
+                    ModelArtifactLocation { bucket_id, key },
+                );
                 state.artifacts.insert(artifact_id, manifest);
             }
             ModelEventBody::TensorsReplace {
@@ -516,6 +577,92 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn query_tensors_filters_by_dtype_and_min_bytes() {
+        let temp = tempdir().unwrap();
+        let storage = Storage::new_at(temp.path()).await.unwrap();
+        let sized = |name: &str, dtype: i32, byte_length: u64| TensorIndexRow {
+            dtype,
+            byte_length,
+            ..tensor(name)
+        };
+        create_model_tensors(
+            &storage,
+            "artifact-a",
+            &[
+                sized("embed", 1, 4096),
+                sized("bias", 3, 16),
+                sized("proj", 3, 8192),
+                sized("norm", 1, 8),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let names = |rows: Vec<TensorIndexRow>| {
+            rows.into_iter()
+                .map(|row| row.tensor_name)
+                .collect::<Vec<_>>()
+        };
+        let f32_only = TensorQuery {
+            dtype: Some(3),
+            ..TensorQuery::default()
+        };
+        assert_eq!(
+            names(
+                query_tensors(&storage, "artifact-a", &f32_only, 10, 0)
+                    .await
+                    .unwrap()
+            ),
+            vec!["bias".to_string(), "proj".to_string()]
+        );
+        let large = TensorQuery {
+            min_bytes: 1024,
+            ..TensorQuery::default()
+        };
+        assert_eq!(
+            names(
+                query_tensors(&storage, "artifact-a", &large, 10, 0)
+                    .await
+                    .unwrap()
+            ),
+            vec!["embed".to_string(), "proj".to_string()]
+        );
+        let large_f16 = TensorQuery {
+            dtype: Some(1),
+            min_bytes: 1024,
+            ..TensorQuery::default()
+        };
+        assert_eq!(
+            names(
+                query_tensors(&storage, "artifact-a", &large_f16, 10, 0)
+                    .await
+                    .unwrap()
+            ),
+            vec!["embed".to_string()]
+        );
+        let prefixed = TensorQuery {
+            prefix: "n".to_string(),
+            ..TensorQuery::default()
+        };
+        assert_eq!(
+            names(
+                query_tensors(&storage, "artifact-a", &prefixed, 10, 0)
+                    .await
+                    .unwrap()
+            ),
+            vec!["norm".to_string()]
+        );
+        assert_eq!(
+            names(
+                query_tensors(&storage, "artifact-a", &TensorQuery::default(), 2, 2)
+                    .await
+                    .unwrap()
+            ),
+            vec!["norm".to_string(), "proj".to_string()]
+        );
+    }
+
     #[tokio::test]
     pub(crate) async fn model_journal_with_permit_writes_fenced_frames_and_header() {
         let temp = tempdir().unwrap();
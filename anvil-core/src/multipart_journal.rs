@@ -109,6 +109,8 @@ struct MultipartPartProto {
     etag: String,
     #[prost(int64, tag = "9")]
     created_at_unix_nanos: i64,
+    #[prost(bytes = "vec", optional, tag = "10")]
+    checksum: Option<Vec<u8>>,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -450,6 +452,7 @@ async fn upsert_multipart_part(
         etag,
         None,
         None,
+        None,
     )
     .await
 }
@@ -461,6 +464,7 @@ pub(crate) async fn upsert_multipart_part_with_permit(
     object_ref: CoreObjectRef,
     size: i64,
     etag: &str,
+    checksum: Option<Vec<u8>>,
     permit: &PartitionWritePermit,
     partition_owner_signing_key: &[u8],
 ) -> Result<MultipartUploadPartMutation> {
@@ -471,6 +475,7 @@ pub(crate) async fn upsert_multipart_part_with_permit(
         object_ref,
         size,
         etag,
+        checksum,
         Some((permit, partition_owner_signing_key)),
         None,
     )
@@ -484,6 +489,7 @@ pub(crate) async fn upsert_multipart_part_with_permit_in_transaction(
     object_ref: CoreObjectRef,
     size: i64,
     etag: &str,
+    checksum: Option<Vec<u8>>,
     permit: &PartitionWritePermit,
     partition_owner_signing_key: &[u8],
     transaction_id: &str,
@@ -496,6 +502,7 @@ pub(crate) async fn upsert_multipart_part_with_permit_in_transaction(
         object_ref,
         size,
         etag,
+        checksum,
         Some((permit, partition_owner_signing_key)),
         Some((transaction_id, transaction_principal)),
     )
@@ -509,6 +516,7 @@ async fn upsert_multipart_part_inner(
     object_ref: CoreObjectRef,
     size: i64,
     etag: &str,
+    checksum: Option<Vec<u8>>,
     permit: Option<(&PartitionWritePermit, &[u8])>,
     transaction: Option<(&str, &str)>,
 ) -> Result<MultipartUploadPartMutation> {
@@ -540,6 +548,7 @@ async fn upsert_multipart_part_inner(
         size,
         etag: etag.to_string(),
         created_at: Utc::now(),
+        checksum,
     };
     let receipt = append_body(
         storage,
@@ -332,6 +332,7 @@ pub(super) fn part_to_proto(part: &MultipartUploadPart) -> Result<MultipartPartP
         size: part.size,
         etag: part.etag.clone(),
         created_at_unix_nanos: datetime_to_unix_nanos(&part.created_at)?,
+        checksum: part.checksum.clone(),
     })
 }
 
@@ -352,6 +353,7 @@ pub(super) fn part_from_proto(proto: MultipartPartProto) -> Result<MultipartUplo
         size: proto.size,
         etag: proto.etag,
         created_at: datetime_from_unix_nanos(proto.created_at_unix_nanos)?,
+        checksum: proto.checksum,
     })
 }
 
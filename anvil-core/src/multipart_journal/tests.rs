@@ -180,6 +180,7 @@ pub(crate) async fn multipart_journal_with_permit_writes_fenced_payloads() {
         payload_ref("hash-a", 10),
         10,
         "etag-a",
+        None,
         &permit,
         KEY,
     )
@@ -215,6 +216,7 @@ pub(crate) async fn multipart_journal_with_permit_rejects_stale_fence() {
         payload_ref("hash-a", 10),
         10,
         "etag-a",
+        None,
         &stale_permit,
         KEY,
     )
@@ -384,6 +386,7 @@ fn test_part(
         size,
         etag: etag.to_string(),
         created_at: Utc::now(),
+        checksum: None,
     }
 }
 
@@ -0,0 +1,276 @@
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+const CRC32_TABLE: [u32; 256] = crc32_table(0xedb8_8320);
+const CRC32C_TABLE: [u32; 256] = crc32_table(0x82f6_3b78);
+const CRC64_NVME_TABLE: [u64; 256] = crc64_table(0x9a6c_9329_ac4b_c9b5);
+
+/// Additional payload checksum an S3 client can ask for with
+/// `x-amz-checksum-algorithm`, on top of the ETag and the content hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    Crc32,
+    Crc32c,
+    Crc64Nvme,
+    Sha1,
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    pub const ALL: [Self; 5] = [
+        Self::Crc32,
+        Self::Crc32c,
+        Self::Crc64Nvme,
+        Self::Sha1,
+        Self::Sha256,
+    ];
+
+    /// Parses an S3 algorithm name such as `CRC32C`, ignoring case.
+    pub fn from_s3(name: &str) -> Option<Self> {
+        let name = name.trim();
+        Self::ALL
+            .into_iter()
+            .find(|algorithm| algorithm.s3_name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn s3_name(self) -> &'static str {
+        match self {
+            Self::Crc32 => "CRC32",
+            Self::Crc32c => "CRC32C",
+            Self::Crc64Nvme => "CRC64NVME",
+            Self::Sha1 => "SHA1",
+            Self::Sha256 => "SHA256",
+        }
+    }
+
+    /// Header (or aws-chunked trailer) carrying a checksum of this algorithm.
+    pub fn header_name(self) -> &'static str {
+        match self {
+            Self::Crc32 => "x-amz-checksum-crc32",
+            Self::Crc32c => "x-amz-checksum-crc32c",
+            Self::Crc64Nvme => "x-amz-checksum-crc64nvme",
+            Self::Sha1 => "x-amz-checksum-sha1",
+            Self::Sha256 => "x-amz-checksum-sha256",
+        }
+    }
+}
+
+/// Additional checksum of an object's payload. CRCs are held big-endian, the
+/// byte order S3 base64-encodes them in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectChecksum {
+    Crc32([u8; 4]),
+    Crc32c([u8; 4]),
+    Crc64Nvme([u8; 8]),
+    Sha1([u8; 20]),
+    Sha256([u8; 32]),
+}
+
+impl ObjectChecksum {
+    pub fn from_digest(algorithm: ChecksumAlgorithm, digest: &[u8]) -> Option<Self> {
+        Some(match algorithm {
+            ChecksumAlgorithm::Crc32 => Self::Crc32(digest.try_into().ok()?),
+            ChecksumAlgorithm::Crc32c => Self::Crc32c(digest.try_into().ok()?),
+            ChecksumAlgorithm::Crc64Nvme => Self::Crc64Nvme(digest.try_into().ok()?),
+            ChecksumAlgorithm::Sha1 => Self::Sha1(digest.try_into().ok()?),
+            ChecksumAlgorithm::Sha256 => Self::Sha256(digest.try_into().ok()?),
+        })
+    }
+
+    /// Decodes a base64 value from an `x-amz-checksum-*` header.
+    pub fn from_base64(algorithm: ChecksumAlgorithm, value: &str) -> Option<Self> {
+        let digest = STANDARD.decode(value.trim()).ok()?;
+        Self::from_digest(algorithm, &digest)
+    }
+
+    pub fn algorithm(&self) -> ChecksumAlgorithm {
+        match self {
+            Self::Crc32(_) => ChecksumAlgorithm::Crc32,
+            Self::Crc32c(_) => ChecksumAlgorithm::Crc32c,
+            Self::Crc64Nvme(_) => ChecksumAlgorithm::Crc64Nvme,
+            Self::Sha1(_) => ChecksumAlgorithm::Sha1,
+            Self::Sha256(_) => ChecksumAlgorithm::Sha256,
+        }
+    }
+
+    pub fn digest(&self) -> &[u8] {
+        match self {
+            Self::Crc32(digest) | Self::Crc32c(digest) => digest.as_slice(),
+            Self::Crc64Nvme(digest) => digest.as_slice(),
+            Self::Sha1(digest) => digest.as_slice(),
+            Self::Sha256(digest) => digest.as_slice(),
+        }
+    }
+
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.digest())
+    }
+
+    /// Form stored in `Object::checksum`: the S3 algorithm name, a colon and
+    /// the raw digest.
+    pub fn encode(&self) -> Vec<u8> {
+        let name = self.algorithm().s3_name();
+        let mut out = Vec::with_capacity(name.len() + 1 + self.digest().len());
+        out.extend_from_slice(name.as_bytes());
+        out.push(b':');
+        out.extend_from_slice(self.digest());
+        out
+    }
+
+    /// Reverses [`ObjectChecksum::encode`]. Returns `None` for values written
+    /// in any other form.
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let separator = bytes.iter().position(|byte| *byte == b':')?;
+        let name = std::str::from_utf8(&bytes[..separator]).ok()?;
+        let algorithm = ChecksumAlgorithm::from_s3(name)?;
+        Self::from_digest(algorithm, &bytes[separator + 1..])
+    }
+}
+
+/// Computes an [`ObjectChecksum`] over a payload fed in chunks.
+pub struct ChecksumHasher(HasherState);
+
+enum HasherState {
+    Crc32(u32),
+    Crc32c(u32),
+    Crc64Nvme(u64),
+    Sha1(Sha1),
+    Sha256(Sha256),
+}
+
+impl ChecksumHasher {
+    pub fn new(algorithm: ChecksumAlgorithm) -> Self {
+        Self(match algorithm {
+            ChecksumAlgorithm::Crc32 => HasherState::Crc32(!0),
+            ChecksumAlgorithm::Crc32c => HasherState::Crc32c(!0),
+            ChecksumAlgorithm::Crc64Nvme => HasherState::Crc64Nvme(!0),
+            ChecksumAlgorithm::Sha1 => HasherState::Sha1(Sha1::new()),
+            ChecksumAlgorithm::Sha256 => HasherState::Sha256(Sha256::new()),
+        })
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        match &mut self.0 {
+            HasherState::Crc32(crc) => *crc = crc32_update(&CRC32_TABLE, *crc, bytes),
+            HasherState::Crc32c(crc) => *crc = crc32_update(&CRC32C_TABLE, *crc, bytes),
+            HasherState::Crc64Nvme(crc) => {
+                for byte in bytes {
+                    *crc =
+                        CRC64_NVME_TABLE[((*crc ^ u64::from(*byte)) & 0xff) as usize] ^ (*crc >> 8);
+                }
+            }
+            HasherState::Sha1(hasher) => hasher.update(bytes),
+            HasherState::Sha256(hasher) => hasher.update(bytes),
+        }
+    }
+
+    pub fn finish(self) -> ObjectChecksum {
+        match self.0 {
+            HasherState::Crc32(crc) => ObjectChecksum::Crc32((!crc).to_be_bytes()),
+            HasherState::Crc32c(crc) => ObjectChecksum::Crc32c((!crc).to_be_bytes()),
+            HasherState::Crc64Nvme(crc) => ObjectChecksum::Crc64Nvme((!crc).to_be_bytes()),
+            HasherState::Sha1(hasher) => ObjectChecksum::Sha1(hasher.finalize().into()),
+            HasherState::Sha256(hasher) => ObjectChecksum::Sha256(hasher.finalize().into()),
+        }
+    }
+}
+
+fn crc32_update(table: &[u32; 256], mut crc: u32, bytes: &[u8]) -> u32 {
+    for byte in bytes {
+        crc = table[((crc ^ u32::from(*byte)) & 0xff) as usize] ^ (crc >> 8);
+    }
+    crc
+}
+
+const fn crc32_table(poly: u32) -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+}
+
+const fn crc64_table(poly: u64) -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u64;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checksum(algorithm: ChecksumAlgorithm, chunks: &[&[u8]]) -> ObjectChecksum {
+        let mut hasher = ChecksumHasher::new(algorithm);
+        for chunk in chunks {
+            hasher.update(chunk);
+        }
+        hasher.finish()
+    }
+
+    #[test]
+    fn checksums_match_the_s3_encodings() {
+        let cases = [
+            (ChecksumAlgorithm::Crc32, "DUoRhQ=="),
+            (ChecksumAlgorithm::Crc32c, "yZRlqg=="),
+            (ChecksumAlgorithm::Crc64Nvme, "jSnVw/bqjr4="),
+            (ChecksumAlgorithm::Sha1, "Kq5sNclPz7QV2+lfQIuc6R7oRu0="),
+            (
+                ChecksumAlgorithm::Sha256,
+                "uU0nuZNNPgilLlLX2n2r+sSE7+N6U4DukIj3rOLvzek=",
+            ),
+        ];
+        for (algorithm, expected) in cases {
+            let split = checksum(algorithm, &[b"hello", b" ", b"world"]);
+            assert_eq!(split.to_base64(), expected, "{algorithm:?}");
+            assert_eq!(split, checksum(algorithm, &[b"hello world"]));
+            assert_eq!(
+                ObjectChecksum::from_base64(algorithm, expected),
+                Some(split)
+            );
+        }
+    }
+
+    #[test]
+    fn stored_checksums_round_trip_with_their_algorithm() {
+        let stored = checksum(ChecksumAlgorithm::Crc32c, &[b"payload"]);
+        let encoded = stored.encode();
+        assert!(encoded.starts_with(b"CRC32C:"));
+        assert_eq!(ObjectChecksum::decode(&encoded), Some(stored));
+        assert_eq!(ObjectChecksum::decode(b"CRC32C:abc"), None);
+        assert_eq!(ObjectChecksum::decode(b"MD5:0123456789abcdef"), None);
+        assert_eq!(
+            ChecksumAlgorithm::from_s3("crc64nvme"),
+            Some(ChecksumAlgorithm::Crc64Nvme)
+        );
+        assert_eq!(ChecksumAlgorithm::from_s3("MD5"), None);
+    }
+}
@@ -3,21 +3,26 @@ use crate::{
     core_store::{
         AppendStreamRecord as CoreAppendStreamRecord, AuthzScopeRef, CoreBoundarySchema,
         CoreBoundarySource, CoreBoundaryValue, CoreByteRange, CoreManifestLocator, CoreObjectRef,
-        CorePrefetchPolicy, CoreStore, GetBlob, PutBlob, SealStreamSegment,
-        WriteLogicalFilePathRequest, WriteLogicalFileRequest,
+        CorePrefetchPolicy, CoreShardRebalanceReport, CoreStore, GetBlob, PutBlob,
+        SealStreamSegment, StorageClass, WriteLogicalFilePathRequest, WriteLogicalFileRequest,
         core_object_ref_from_logical_file_write, decode_core_object_ref_target,
         decode_manifest_locator_proto, encode_core_object_ref_target,
         encode_manifest_locator_proto,
     },
     error_codes::AnvilErrorCode,
     formats::writer::WriterFamily,
+    object_checksum::{ChecksumHasher, ObjectChecksum},
     object_links,
     observability::{
-        OBJECT_READ_LATENCY, OBJECT_WRITE_LATENCY, Observability, PREFIX_LIST_LATENCY,
-        RESERVED_NAMESPACE_REJECTION_COUNT,
+        OBJECT_LIST_CACHE_RESULTS, OBJECT_PUT_PHASE_LATENCY, OBJECT_READ_LATENCY,
+        OBJECT_REQUEST_BYTES, OBJECT_REQUEST_COUNT, OBJECT_WRITE_LATENCY, Observability,
+        PREFIX_LIST_LATENCY, RESERVED_NAMESPACE_REJECTION_COUNT,
     },
     permissions::AnvilAction,
-    persistence::{Bucket, MetadataMutationReceipt, Object, ObjectWatchEvent, Persistence},
+    persistence::{
+        Bucket, ExpectedCurrentVersion, MetadataMutationReceipt, Object, ObjectRetention,
+        ObjectWatchEvent, Persistence,
+    },
     routing::{self, CrossRegionRoutingPolicy},
     storage::Storage,
     validation, watch_log,
@@ -29,7 +34,7 @@ use serde_json::Value as JsonValue;
 use std::collections::{HashMap, HashSet, hash_map::Entry};
 use std::path::Path;
 use std::pin::Pin;
-use std::sync::{Mutex, OnceLock};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
 use std::time::Instant;
 use tokio::sync::{broadcast, mpsc};
@@ -38,11 +43,22 @@ use tonic::Status;
 use tonic::metadata::MetadataValue;
 use tracing::info;
 
+mod lifecycle;
+mod list_cache;
+mod preconditions;
+mod replication;
 mod write_visibility;
+pub use lifecycle::{LifecycleSweepCursor, LifecycleSweepOutcome};
+use list_cache::{ObjectListingCache, ObjectListingCacheKey};
+pub use preconditions::{ConditionalRead, ObjectConditions, etag_condition_matches};
+pub use replication::{
+    REPLICA_BUCKET_HEADER, REPLICA_GET_METHOD, REPLICA_OBJECT_HEADER, REPLICA_PUT_METHOD,
+    REPLICA_RANGE_HEADER, ReplicationTransport, replica_json_value,
+};
 pub use write_visibility::{
     AuthzMaterializationVisibility, AuthzRevisionVisibility, BoundaryExtractionVisibility,
-    IndexMaintenanceVisibility, IndexPolicySnapshotVisibility, ObjectWriteOptions,
-    ObjectWriteVisibility, WatchVisibility,
+    CopyObjectMetadata, IndexMaintenanceVisibility, IndexPolicySnapshotVisibility,
+    ObjectWriteOptions, ObjectWriteVisibility, UploadPartOptions, WatchVisibility,
 };
 
 #[derive(Debug, Clone)]
@@ -55,8 +71,18 @@ pub struct ObjectManager {
     signing_key: Vec<u8>,
     watch_tx: broadcast::Sender<ObjectWatchEvent>,
     observability: Observability,
+    multipart_min_part_size: u64,
+    max_object_size: Option<u64>,
+    put_timing_breakdown: bool,
+    listing_cache: Option<Arc<ObjectListingCache>>,
+    replication: Option<ReplicationTransport>,
 }
 
+/// S3 rejects multipart completions whose non-final parts are smaller than 5 MiB.
+pub const S3_MULTIPART_MIN_PART_SIZE: u64 = 5 * 1024 * 1024;
+/// S3 caps a single object at 5 TiB.
+pub const S3_MAX_OBJECT_SIZE: u64 = 5 * 1024 * 1024 * 1024 * 1024;
+
 #[derive(Debug, Clone)]
 pub struct ComposeSource {
     pub bucket_name: String,
@@ -80,6 +106,8 @@ pub struct InitiateMultipartUploadResult {
 pub struct UploadPartResult {
     pub etag: String,
     pub payload_hash: String,
+    /// Additional checksum recorded with the part, if one was requested.
+    pub checksum: Option<ObjectChecksum>,
     pub receipt: MetadataMutationReceipt,
 }
 
@@ -147,6 +175,61 @@ struct ComposeStreamState {
     current: Option<Pin<Box<dyn Stream<Item = Result<Vec<u8>, Status>> + Send + 'static>>>,
 }
 
+/// S3 caps user-defined metadata at 2 KB, counted over keys and values.
+pub const MAX_USER_METADATA_BYTES: usize = 2 * 1024;
+
+/// Lowercases user metadata keys, matching how S3 reports `x-amz-meta-*`
+/// headers, and rejects maps larger than [`MAX_USER_METADATA_BYTES`].
+fn normalize_user_metadata(user_metadata: Option<JsonValue>) -> Result<Option<JsonValue>, Status> {
+    let Some(JsonValue::Object(values)) = user_metadata else {
+        return Ok(user_metadata);
+    };
+    let mut normalized = serde_json::Map::with_capacity(values.len());
+    let mut total_bytes = 0usize;
+    for (key, value) in values {
+        let key = key.to_ascii_lowercase();
+        total_bytes += key.len()
+            + match &value {
+                JsonValue::String(value) => value.len(),
+                other => other.to_string().len(),
+            };
+        normalized.insert(key, value);
+    }
+    if total_bytes > MAX_USER_METADATA_BYTES {
+        return Err(Status::invalid_argument(format!(
+            "MetadataTooLarge: user metadata is {total_bytes} bytes; the limit is {MAX_USER_METADATA_BYTES}"
+        )));
+    }
+    Ok(Some(JsonValue::Object(normalized)))
+}
+
+/// Content type inferred from the object key's extension when a writer does
+/// not supply one. Unknown extensions stay untyped.
+pub fn guess_content_type(object_key: &str) -> Option<&'static str> {
+    let file_name = object_key.rsplit('/').next().unwrap_or(object_key);
+    let (_, extension) = file_name.rsplit_once('.')?;
+    let content_type = match extension.to_ascii_lowercase().as_str() {
+        "json" => "application/json",
+        "jsonl" | "ndjson" => "application/x-ndjson",
+        "txt" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "yaml" | "yml" => "application/yaml",
+        "xml" => "application/xml",
+        "pdf" => "application/pdf",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "parquet" => "application/vnd.apache.parquet",
+        "safetensors" | "gguf" | "bin" | "pt" | "onnx" => "application/octet-stream",
+        _ => return None,
+    };
+    Some(content_type)
+}
+
 pub fn transaction_principal_from_claims(claims: &auth::Claims) -> String {
     format!("tenant/{}/principal/{}", claims.tenant_id, claims.sub)
 }
@@ -208,9 +291,87 @@ impl ObjectManager {
             signing_key,
             watch_tx,
             observability,
+            multipart_min_part_size: S3_MULTIPART_MIN_PART_SIZE,
+            max_object_size: None,
+            put_timing_breakdown: false,
+            listing_cache: None,
+            replication: None,
+        }
+    }
+
+    /// Overrides the minimum size of every multipart part except the last.
+    /// Zero disables the check.
+    pub fn with_multipart_min_part_size(mut self, bytes: u64) -> Self {
+        self.multipart_min_part_size = bytes;
+        self
+    }
+
+    /// Rejects objects and multipart parts larger than `bytes`. Zero leaves
+    /// sizes unbounded.
+    pub fn with_max_object_size(mut self, bytes: u64) -> Self {
+        self.max_object_size = (bytes > 0).then_some(bytes);
+        self
+    }
+
+    /// Records a per-phase latency breakdown for every put_object.
+    pub fn with_put_timing_breakdown(mut self, enabled: bool) -> Self {
+        self.put_timing_breakdown = enabled;
+        self
+    }
+
+    /// Caches latest-consistency listings for `ttl`, holding at most
+    /// `max_entries` results. A zero TTL or capacity leaves caching disabled.
+    pub fn with_listing_cache(mut self, ttl: Duration, max_entries: usize) -> Self {
+        self.listing_cache = (!ttl.is_zero() && max_entries > 0)
+            .then(|| Arc::new(ObjectListingCache::new(ttl, max_entries)));
+        self
+    }
+
+    fn invalidate_listing_cache(&self, bucket_id: i64) {
+        if let Some(cache) = &self.listing_cache {
+            cache.invalidate_bucket(bucket_id);
         }
     }
 
+    fn record_put_phase(
+        &self,
+        phases: &mut Vec<(&'static str, Duration)>,
+        phase: &'static str,
+        elapsed: Duration,
+    ) {
+        if self.put_timing_breakdown {
+            phases.push((phase, elapsed));
+        }
+    }
+
+    fn record_put_timing_breakdown(
+        &self,
+        bucket_name: &str,
+        object_key: &str,
+        phases: &[(&'static str, Duration)],
+        total: Duration,
+    ) {
+        if !self.put_timing_breakdown {
+            return;
+        }
+        for (phase, elapsed) in phases {
+            self.observability.record_latency(
+                OBJECT_PUT_PHASE_LATENCY,
+                &[("phase", phase)],
+                *elapsed,
+            );
+        }
+        self.observability
+            .record_latency(OBJECT_PUT_PHASE_LATENCY, &[("phase", "total")], total);
+        info!(
+            bucket_name,
+            object_key,
+            phases = ?phases,
+            total = ?total,
+            "put_object timing breakdown"
+        );
+    }
+
     fn record_reserved_namespace_rejection(&self, operation: &'static str) {
         self.observability.increment_counter(
             RESERVED_NAMESPACE_REJECTION_COUNT,
@@ -218,6 +379,19 @@ impl ObjectManager {
         );
     }
 
+    fn record_bucket_request(&self, bucket: &Bucket, operation: &'static str, bytes: Option<u64>) {
+        let bucket_label = self
+            .observability
+            .bucket_label(bucket.tenant_id, &bucket.name);
+        let labels = [("bucket", bucket_label.as_str()), ("operation", operation)];
+        self.observability
+            .increment_counter(OBJECT_REQUEST_COUNT, &labels);
+        if let Some(bytes) = bytes {
+            self.observability
+                .record_bytes(OBJECT_REQUEST_BYTES, &labels, bytes);
+        }
+    }
+
     async fn object_write_boundary_values_from_file(
         &self,
         tenant_id: i64,
@@ -406,13 +580,62 @@ impl ObjectManager {
         });
     }
 
+    fn enforce_max_object_size(&self, bytes: u64) -> Result<(), Status> {
+        match self.max_object_size {
+            Some(max_object_size) if bytes > max_object_size => {
+                Err(Status::invalid_argument(format!(
+                    "EntityTooLarge: {bytes} bytes exceeds the maximum object size of {max_object_size} bytes"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Rejects a write of `incoming_bytes` to `object_key` when it would take
+    /// the bucket past its byte or object quota. Overwrites are charged only
+    /// for the difference against the object they replace.
+    async fn enforce_bucket_quota(
+        &self,
+        bucket: &Bucket,
+        object_key: &str,
+        incoming_bytes: u64,
+    ) -> Result<(), Status> {
+        if bucket.max_bytes.is_none() && bucket.max_objects.is_none() {
+            return Ok(());
+        }
+        let usage = self
+            .persistence
+            .bucket_usage(bucket, Some(object_key))
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let projected_bytes = usage.used_bytes.saturating_add(incoming_bytes);
+        if let Some(max_bytes) = bucket.max_bytes
+            && projected_bytes > max_bytes
+        {
+            return Err(Status::resource_exhausted(format!(
+                "QuotaExceeded: bucket {} would hold {projected_bytes} bytes, quota is {max_bytes}",
+                bucket.name
+            )));
+        }
+        let projected_objects = usage.object_count + 1;
+        if let Some(max_objects) = bucket.max_objects
+            && projected_objects > max_objects
+        {
+            return Err(Status::resource_exhausted(format!(
+                "QuotaExceeded: bucket {} would hold {projected_objects} objects, quota is {max_objects}",
+                bucket.name
+            )));
+        }
+        Ok(())
+    }
+
     pub async fn put_object(
         &self,
         claims: &auth::Claims,
         bucket_name: &str,
         object_key: &str,
         data_stream: impl Stream<Item = Result<Vec<u8>, Status>> + Unpin,
-        options: ObjectWriteOptions,
+        mut options: ObjectWriteOptions,
     ) -> Result<Object, Status> {
         let _latency = self
             .observability
@@ -424,6 +647,10 @@ impl ObjectManager {
             principal = %claims.sub,
             "put_object called"
         );
+        options.user_metadata = normalize_user_metadata(options.user_metadata.take())?;
+        if options.content_type.as_deref().is_none_or(str::is_empty) {
+            options.content_type = guess_content_type(object_key).map(ToString::to_string);
+        }
         let tenant_id = claims.tenant_id;
         let transaction_id = options.transaction_id.clone();
         let total_start = std::time::Instant::now();
@@ -447,6 +674,14 @@ impl ObjectManager {
             return Err(Status::invalid_argument("Invalid object key"));
         }
 
+        if let Some(expected_size) = options.expected_size {
+            self.enforce_max_object_size(expected_size)?;
+        }
+        if let Some(retention) = &options.retention {
+            validate_retention(retention)?;
+        }
+
+        let mut phases = Vec::new();
         let step_start = std::time::Instant::now();
         let bucket = self.get_tenant_bucket(tenant_id, bucket_name).await?;
         access_control::require_object_permission(
@@ -457,22 +692,68 @@ impl ObjectManager {
             "put",
         )
         .await?;
-        crate::emit_test_timing(
-            "object_manager.put_object get_tenant_bucket",
-            step_start.elapsed(),
-        );
+        let retention = options
+            .retention
+            .or_else(|| bucket_default_retention(&bucket, chrono::Utc::now()));
+        let expected_current = self
+            .verify_write_conditions(&bucket, object_key, &options.conditions)
+            .await?;
+        self.ensure_object_not_locked(&bucket, object_key).await?;
+        let elapsed = step_start.elapsed();
+        crate::emit_test_timing("object_manager.put_object get_tenant_bucket", elapsed);
+        self.record_put_phase(&mut phases, "authorize", elapsed);
         let step_start = std::time::Instant::now();
-        let (temp_path, total_bytes, stream_hash) = self
+        let mut checksum_hasher = options
+            .expected_checksum
+            .map(|checksum| checksum.algorithm())
+            .or(options.checksum_algorithm)
+            .map(ChecksumHasher::new);
+        let data_stream = data_stream.inspect(|chunk| {
+            if let (Some(hasher), Ok(chunk)) = (checksum_hasher.as_mut(), chunk) {
+                hasher.update(chunk);
+            }
+        });
+        let (temp_path, total_bytes, stream_hash, stream_md5) = self
             .storage
-            .stream_to_temp_file(data_stream)
+            .stream_to_temp_file(stop_after_size_limit(data_stream, self.max_object_size))
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
-        crate::emit_test_timing(
-            "object_manager.put_object stream_to_temp_file",
-            step_start.elapsed(),
-        );
+        let checksum = checksum_hasher.map(ChecksumHasher::finish);
+        let elapsed = step_start.elapsed();
+        crate::emit_test_timing("object_manager.put_object stream_to_temp_file", elapsed);
+        self.record_put_phase(&mut phases, "receive", elapsed);
         let total_bytes_u64 =
             u64::try_from(total_bytes).map_err(|_| Status::internal("Negative payload size"))?;
+        if let Err(status) = self.enforce_max_object_size(total_bytes_u64) {
+            discard_rejected_payload(&temp_path).await;
+            return Err(status);
+        }
+        if let Some(expected_size) = options.expected_size
+            && expected_size != total_bytes_u64
+        {
+            discard_rejected_payload(&temp_path).await;
+            return Err(Status::invalid_argument(format!(
+                "IncompleteBody: declared {expected_size} bytes but received {total_bytes_u64}"
+            )));
+        }
+        if let Err(status) = verify_payload_digests(
+            options.expected_md5.as_ref(),
+            options.expected_sha256.as_deref(),
+            options.expected_checksum.as_ref(),
+            &stream_hash,
+            &stream_md5,
+            checksum.as_ref(),
+        ) {
+            discard_rejected_payload(&temp_path).await;
+            return Err(status);
+        }
+        if let Err(status) = self
+            .enforce_bucket_quota(&bucket, object_key, total_bytes_u64)
+            .await
+        {
+            discard_rejected_payload(&temp_path).await;
+            return Err(status);
+        }
         let boundary_values = if options.visibility.requires_payload_boundary_extraction() {
             self.object_write_boundary_values_from_file(
                 tenant_id,
@@ -496,9 +777,241 @@ impl ObjectManager {
             .await?
         };
         let step_start = std::time::Instant::now();
+        let (content_hash, shard_map, effective_storage_class_id) = self
+            .store_staged_payload(
+                tenant_id,
+                &bucket,
+                object_key,
+                &temp_path,
+                total_bytes_u64,
+                &stream_hash,
+                boundary_values,
+                options.storage_class_id.as_deref(),
+            )
+            .await?;
+        let elapsed = step_start.elapsed();
+        crate::emit_test_timing(
+            "object_manager.put_object core_store_write_logical_file_path",
+            elapsed,
+        );
+        self.record_put_phase(&mut phases, "payload_write", elapsed);
+
+        let step_start = std::time::Instant::now();
+        let etag = if let Some(etag) = options.etag.clone() {
+            etag
+        } else if options.md5_etag {
+            hex::encode(stream_md5)
+        } else {
+            content_hash.clone()
+        };
+        // Another writer may have landed while the payload was streaming;
+        // persistence re-checks object lock and the version the conditions
+        // were evaluated against under the key write lock.
+        let created = self
+            .persistence
+            .create_object_with_storage_class_with_options(
+                tenant_id,
+                bucket.id,
+                object_key,
+                &content_hash,
+                total_bytes,
+                &etag,
+                options.content_type.as_deref(),
+                options.user_metadata,
+                shard_map.clone(),
+                None,
+                transaction_id.as_deref(),
+                options.transaction_principal.as_deref(),
+                Some(effective_storage_class_id),
+                crate::persistence::ObjectCreateOptions {
+                    retention,
+                    checksum,
+                    requested_storage_class: options
+                        .storage_class_id
+                        .as_deref()
+                        .and_then(StorageClass::s3_class_name),
+                    expected_current,
+                    ..options.visibility.persistence_options()
+                },
+            )
+            .await
+            .map_err(object_mutation_status);
+        let object = match created {
+            Ok(object) => object,
+            Err(status) => {
+                self.release_uncommitted_payload(&bucket, object_key, shard_map.as_ref())
+                    .await;
+                return Err(status);
+            }
+        };
+        let elapsed = step_start.elapsed();
+        crate::emit_test_timing(
+            "object_manager.put_object persistence_create_object",
+            elapsed,
+        );
+        self.record_put_phase(&mut phases, "metadata_commit", elapsed);
+        let follow_up_start = std::time::Instant::now();
+        if transaction_id.is_none() {
+            if options.visibility.defers_write_maintenance() {
+                self.schedule_deferred_object_maintenance(bucket.clone(), object_key);
+            }
+            if options.visibility.requires_authz_materialization() {
+                let step_start = std::time::Instant::now();
+                access_control::grant_object_defaults(
+                    &self.persistence,
+                    &bucket,
+                    object_key,
+                    "grant object parent bucket relation",
+                )
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+                crate::emit_test_timing(
+                    "object_manager.put_object grant_object_defaults",
+                    step_start.elapsed(),
+                );
+            }
+            self.invalidate_listing_cache(bucket.id);
+            self.enqueue_object_replication(&bucket, &object).await;
+            if options.visibility.requires_watch_visible() {
+                let step_start = std::time::Instant::now();
+                self.publish_object_watch_event(tenant_id, &bucket, &object, "put", false)
+                    .await?;
+                crate::emit_test_timing(
+                    "object_manager.put_object publish_object_watch_event",
+                    step_start.elapsed(),
+                );
+            } else {
+                let manager = self.clone();
+                let bucket = bucket.clone();
+                let object = object.clone();
+                tokio::spawn(async move {
+                    if let Err(error) = manager
+                        .publish_object_watch_event(tenant_id, &bucket, &object, "put", false)
+                        .await
+                    {
+                        tracing::warn!(
+                            tenant_id,
+                            bucket_id = bucket.id,
+                            bucket_name = %bucket.name,
+                            object_key = %object.key,
+                            %error,
+                            "deferred object watch publication failed"
+                        );
+                    }
+                });
+            }
+        }
+        self.record_put_phase(&mut phases, "follow_up", follow_up_start.elapsed());
+        crate::emit_test_timing("object_manager.put_object total", total_start.elapsed());
+        self.record_put_timing_breakdown(&bucket.name, object_key, &phases, total_start.elapsed());
+        self.record_bucket_request(&bucket, "put", Some(total_bytes_u64));
+
+        Ok(object)
+    }
+
+    async fn verify_write_conditions(
+        &self,
+        bucket: &Bucket,
+        object_key: &str,
+        conditions: &ObjectConditions,
+    ) -> Result<ExpectedCurrentVersion, Status> {
+        if conditions.is_empty() {
+            return Ok(ExpectedCurrentVersion::Any);
+        }
+        let current = self
+            .core_store
+            .read_current_object_metadata(bucket, object_key)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        conditions.check_write(current.as_ref())?;
+        Ok(ExpectedCurrentVersion::of(current.as_ref()))
+    }
+
+    /// Refuses to replace or delete `object_key` while its current version is
+    /// under retention or legal hold.
+    async fn ensure_object_not_locked(
+        &self,
+        bucket: &Bucket,
+        object_key: &str,
+    ) -> Result<(), Status> {
+        let current = self
+            .core_store
+            .read_current_object_metadata(bucket, object_key)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        if current.is_some_and(|object| object.is_locked(chrono::Utc::now())) {
+            return Err(object_locked_status(object_key));
+        }
+        Ok(())
+    }
+
+    /// Hands the payload of a write whose metadata did not commit to shard
+    /// garbage collection; nothing references the blocks, so without a
+    /// release marker they would stay on disk indefinitely. A metadata error
+    /// can also surface after the version was journaled, so the payload is
+    /// left alone when the current version of the key still points at it.
+    async fn release_uncommitted_payload(
+        &self,
+        bucket: &Bucket,
+        object_key: &str,
+        shard_map: Option<&JsonValue>,
+    ) {
+        let Some(shard_map) = shard_map else {
+            return;
+        };
+        match self.persistence.get_object(bucket.id, object_key).await {
+            Ok(current)
+                if current.is_some_and(|object| object.shard_map.as_ref() == Some(shard_map)) =>
+            {
+                return;
+            }
+            Ok(_) => {}
+            Err(error) => {
+                tracing::warn!(
+                    bucket_id = bucket.id,
+                    object_key,
+                    %error,
+                    "keeping the payload of a failed object write; its commit state is unknown"
+                );
+                return;
+            }
+        }
+        let transaction_id = format!("uncommitted-payload:{}", uuid::Uuid::new_v4());
+        if let Err(error) = self
+            .core_store
+            .release_unreferenced_payload(shard_map, &transaction_id)
+            .await
+        {
+            tracing::warn!(
+                bucket_id = bucket.id,
+                object_key,
+                %error,
+                "failed to release the payload of an uncommitted object write"
+            );
+        }
+    }
+
+    /// Moves a staged payload into CoreStore, inline or as a logical file
+    /// depending on the storage class, and removes the staging file. Content
+    /// the tenant already holds as a logical file in the same class reuses
+    /// the stored blocks instead. Returns the content hash, the shard map for
+    /// the object row, and the resolved storage class id.
+    #[allow(clippy::too_many_arguments)]
+    async fn store_staged_payload(
+        &self,
+        tenant_id: i64,
+        bucket: &Bucket,
+        object_key: &str,
+        temp_path: &Path,
+        total_bytes_u64: u64,
+        stream_hash: &str,
+        boundary_values: Vec<CoreBoundaryValue>,
+        storage_class_id: Option<&str>,
+    ) -> Result<(String, Option<JsonValue>, String), Status> {
         let effective_storage_class_id = self
             .core_store
-            .resolve_storage_class_id(options.storage_class_id.as_deref())
+            .resolve_placeable_storage_class_id(storage_class_id)
+            .await
             .map_err(|e| Status::invalid_argument(e.to_string()))?;
         let storage_class = self
             .core_store
@@ -518,9 +1031,27 @@ impl ObjectManager {
             .effective_raw_payload_cap_bytes();
         let inline_eligible =
             storage_class.inline_payload_policy.enabled && total_bytes_u64 <= inline_cap;
+        let source_hash = format!("sha256:{stream_hash}");
+        let reused_shard_map = if inline_eligible || !boundary_values.is_empty() {
+            None
+        } else {
+            self.core_store
+                .deduplicated_payload(tenant_id, &effective_storage_class_id, &source_hash)
+                .await
+                .unwrap_or_else(|error| {
+                    tracing::warn!(
+                        %error,
+                        content_hash = %source_hash,
+                        "storing payload without deduplication"
+                    );
+                    None
+                })
+        };
 
-        let (content_hash, shard_map) = if inline_eligible {
-            let payload = tokio::fs::read(&temp_path)
+        let (content_hash, shard_map) = if let Some(shard_map) = reused_shard_map {
+            (source_hash, Some(shard_map))
+        } else if inline_eligible {
+            let payload = tokio::fs::read(temp_path)
                 .await
                 .map_err(|error| Status::internal(error.to_string()))?;
             let object_ref = self
@@ -529,7 +1060,7 @@ impl ObjectManager {
                     PutBlob {
                         logical_name: logical_file_id,
                         bytes: payload,
-                        boundary_values: boundary_values.clone(),
+                        boundary_values,
                         region_id: self.region.clone(),
                         mutation_id: core_mutation_id,
                     },
@@ -550,13 +1081,13 @@ impl ObjectManager {
                     writer_family: WriterFamily::ObjectBlob.as_str().to_string(),
                     generation: 0,
                     logical_file_id,
-                    source_path: temp_path.clone(),
+                    source_path: temp_path.to_path_buf(),
                     source_len: total_bytes_u64,
-                    source_hash: format!("sha256:{stream_hash}"),
+                    source_hash,
                     range_hints: Vec::new(),
                     pipeline_policy,
                     trace_context: Default::default(),
-                    boundary_values: boundary_values.clone(),
+                    boundary_values,
                     mutation_id: core_mutation_id,
                     region_id: self.region.clone(),
                 })
@@ -572,11 +1103,11 @@ impl ObjectManager {
             (content_hash, shard_map)
         };
         let io_start = Instant::now();
-        let remove_result = tokio::fs::remove_file(&temp_path).await;
+        let remove_result = tokio::fs::remove_file(temp_path).await;
         crate::perf::record_io_duration(
             "object_manager",
             "remove_temp_payload",
-            &temp_path,
+            temp_path,
             total_bytes_u64,
             io_start.elapsed(),
         );
@@ -587,87 +1118,7 @@ impl ObjectManager {
                 "failed to remove non-authoritative staged object payload"
             );
         }
-        crate::emit_test_timing(
-            "object_manager.put_object core_store_write_logical_file_path",
-            step_start.elapsed(),
-        );
-
-        let step_start = std::time::Instant::now();
-        let object = self
-            .persistence
-            .create_object_with_storage_class_with_options(
-                tenant_id,
-                bucket.id,
-                object_key,
-                &content_hash,
-                total_bytes,
-                &content_hash,
-                options.content_type.as_deref(),
-                options.user_metadata,
-                shard_map,
-                None,
-                transaction_id.as_deref(),
-                options.transaction_principal.as_deref(),
-                Some(effective_storage_class_id),
-                options.visibility.persistence_options(),
-            )
-            .await
-            .map_err(|e| Status::internal(e.to_string()))?;
-        crate::emit_test_timing(
-            "object_manager.put_object persistence_create_object",
-            step_start.elapsed(),
-        );
-        if transaction_id.is_none() {
-            if options.visibility.defers_write_maintenance() {
-                self.schedule_deferred_object_maintenance(bucket.clone(), object_key);
-            }
-            if options.visibility.requires_authz_materialization() {
-                let step_start = std::time::Instant::now();
-                access_control::grant_object_defaults(
-                    &self.persistence,
-                    &bucket,
-                    object_key,
-                    "grant object parent bucket relation",
-                )
-                .await
-                .map_err(|e| Status::internal(e.to_string()))?;
-                crate::emit_test_timing(
-                    "object_manager.put_object grant_object_defaults",
-                    step_start.elapsed(),
-                );
-            }
-            if options.visibility.requires_watch_visible() {
-                let step_start = std::time::Instant::now();
-                self.publish_object_watch_event(tenant_id, &bucket, &object, "put", false)
-                    .await?;
-                crate::emit_test_timing(
-                    "object_manager.put_object publish_object_watch_event",
-                    step_start.elapsed(),
-                );
-            } else {
-                let manager = self.clone();
-                let bucket = bucket.clone();
-                let object = object.clone();
-                tokio::spawn(async move {
-                    if let Err(error) = manager
-                        .publish_object_watch_event(tenant_id, &bucket, &object, "put", false)
-                        .await
-                    {
-                        tracing::warn!(
-                            tenant_id,
-                            bucket_id = bucket.id,
-                            bucket_name = %bucket.name,
-                            object_key = %object.key,
-                            %error,
-                            "deferred object watch publication failed"
-                        );
-                    }
-                });
-            }
-        }
-        crate::emit_test_timing("object_manager.put_object total", total_start.elapsed());
-
-        Ok(object)
+        Ok((content_hash, shard_map, effective_storage_class_id))
     }
 
     pub async fn initiate_multipart_upload(
@@ -715,9 +1166,10 @@ impl ObjectManager {
         upload_id: uuid::Uuid,
         part_number: i32,
         data_stream: impl Stream<Item = Result<Vec<u8>, Status>> + Unpin,
-        transaction_id: Option<&str>,
-        transaction_principal: Option<&str>,
+        options: UploadPartOptions,
     ) -> Result<UploadPartResult, Status> {
+        let transaction_id = options.transaction_id.as_deref();
+        let transaction_principal = options.transaction_principal.as_deref();
         self.validate_write_request(claims, bucket_name, object_key)
             .await?;
         let tenant_id = claims.tenant_id;
@@ -744,14 +1196,40 @@ impl ObjectManager {
         .map_err(|e| Status::internal(e.to_string()))?
         .ok_or_else(|| Status::not_found("Multipart upload not found"))?;
 
-        let (temp_path, bytes, stream_hash) = self
+        let mut checksum_hasher = options
+            .expected_checksum
+            .map(|checksum| checksum.algorithm())
+            .or(options.checksum_algorithm)
+            .map(ChecksumHasher::new);
+        let data_stream = data_stream.inspect(|chunk| {
+            if let (Some(hasher), Ok(chunk)) = (checksum_hasher.as_mut(), chunk) {
+                hasher.update(chunk);
+            }
+        });
+        let (temp_path, bytes, stream_hash, stream_md5) = self
             .storage
-            .stream_to_temp_file(data_stream)
+            .stream_to_temp_file(stop_after_size_limit(data_stream, self.max_object_size))
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
+        let checksum = checksum_hasher.map(ChecksumHasher::finish);
 
         let bytes_u64 =
             u64::try_from(bytes).map_err(|_| Status::internal("Negative multipart part size"))?;
+        if let Err(status) = self.enforce_max_object_size(bytes_u64) {
+            discard_rejected_payload(&temp_path).await;
+            return Err(status);
+        }
+        if let Err(status) = verify_payload_digests(
+            options.expected_md5.as_ref(),
+            options.expected_sha256.as_deref(),
+            options.expected_checksum.as_ref(),
+            &stream_hash,
+            &stream_md5,
+            checksum.as_ref(),
+        ) {
+            discard_rejected_payload(&temp_path).await;
+            return Err(status);
+        }
         let storage_class_id = self
             .core_store
             .resolve_storage_class_id(None)
@@ -799,6 +1277,8 @@ impl ObjectManager {
         }
         let object_ref = core_object_ref_from_logical_file_write(&write);
         let content_hash = object_ref.hash.clone();
+        // Part ETags are the hex MD5 of the part, as S3 clients expect.
+        let part_etag = hex::encode(stream_md5);
 
         let mutation = if let Some(transaction_id) = transaction_id {
             self.persistence
@@ -807,7 +1287,8 @@ impl ObjectManager {
                     part_number,
                     object_ref,
                     bytes as i64,
-                    &content_hash,
+                    &part_etag,
+                    checksum.as_ref().map(ObjectChecksum::encode),
                     transaction_id,
                     transaction_principal.ok_or_else(|| {
                         Status::invalid_argument("transaction principal is required")
@@ -821,7 +1302,8 @@ impl ObjectManager {
                     part_number,
                     object_ref,
                     bytes as i64,
-                    &content_hash,
+                    &part_etag,
+                    checksum.as_ref().map(ObjectChecksum::encode),
                 )
                 .await
         }
@@ -829,6 +1311,7 @@ impl ObjectManager {
         Ok(UploadPartResult {
             etag: mutation.part.etag,
             payload_hash: content_hash,
+            checksum,
             receipt: mutation.receipt,
         })
     }
@@ -856,6 +1339,7 @@ impl ObjectManager {
         }
 
         let bucket = self.get_tenant_bucket(tenant_id, bucket_name).await?;
+        self.ensure_object_not_locked(&bucket, object_key).await?;
         let upload = if let Some(transaction_id) = transaction_id {
             self.persistence
                 .get_active_multipart_upload_in_transaction(
@@ -891,8 +1375,11 @@ impl ObjectManager {
         }
         .map_err(|e| Status::internal(e.to_string()))?;
 
-        let mut ordered_part_refs = Vec::with_capacity(parts.len());
-        for expected in parts {
+        let part_count = parts.len();
+        let mut ordered_part_refs = Vec::with_capacity(part_count);
+        let mut ordered_part_etags = Vec::with_capacity(part_count);
+        let mut total_size = 0_u64;
+        for (index, expected) in parts.into_iter().enumerate() {
             let stored = stored_parts
                 .iter()
                 .find(|part| part.part_number == expected.part_number)
@@ -904,8 +1391,22 @@ impl ObjectManager {
                     "Complete request part ETag mismatch",
                 ));
             }
+            let is_last_part = index + 1 == part_count;
+            if !is_last_part
+                && u64::try_from(stored.size).unwrap_or_default() < self.multipart_min_part_size
+            {
+                return Err(Status::invalid_argument(format!(
+                    "EntityTooSmall: part {} is {} bytes; every part except the last must be at least {} bytes",
+                    stored.part_number, stored.size, self.multipart_min_part_size
+                )));
+            }
             ordered_part_refs.push(stored.object_ref.clone());
+            ordered_part_etags.push(stored.etag.clone());
+            total_size = total_size.saturating_add(u64::try_from(stored.size).unwrap_or_default());
         }
+        self.enforce_max_object_size(total_size)?;
+        self.enforce_bucket_quota(&bucket, object_key, total_size)
+            .await?;
 
         let core_store = self.core_store.clone();
         let (tx, rx) = mpsc::channel(4);
@@ -938,6 +1439,7 @@ impl ObjectManager {
                     transaction_id: transaction_id.map(ToOwned::to_owned),
                     transaction_principal: transaction_principal.map(ToOwned::to_owned),
                     visibility: ObjectWriteVisibility::strict(),
+                    etag: multipart_etag(&ordered_part_etags),
                     ..Default::default()
                 },
             )
@@ -1480,6 +1982,17 @@ impl ObjectManager {
         Ok(out)
     }
 
+    /// Puts back any shards of a block that are missing where reads look for
+    /// them. Run by `RebalanceShard` tasks.
+    pub async fn rebalance_object_ref_shards(
+        &self,
+        object_ref: &CoreObjectRef,
+    ) -> AnyhowResult<CoreShardRebalanceReport> {
+        self.core_store
+            .rebalance_object_ref_shards(object_ref)
+            .await
+    }
+
     pub async fn compare_and_swap_manifest(
         &self,
         claims: &auth::Claims,
@@ -1935,6 +2448,135 @@ fn trim_s3_etag(value: &str) -> &str {
     value.trim().trim_matches('"')
 }
 
+/// Builds the S3 multipart ETag: the MD5 of the concatenated binary part
+/// MD5s, suffixed with `-<part count>`. Returns `None` when a part predates
+/// MD5 part ETags, in which case the object falls back to its content hash.
+fn multipart_etag(part_etags: &[String]) -> Option<String> {
+    use md5::Digest;
+
+    let mut hasher = md5::Md5::new();
+    for etag in part_etags {
+        let digest = hex::decode(trim_s3_etag(etag)).ok()?;
+        if digest.len() != 16 {
+            return None;
+        }
+        hasher.update(&digest);
+    }
+    Some(format!(
+        "{}-{}",
+        hex::encode(hasher.finalize()),
+        part_etags.len()
+    ))
+}
+
+/// Checks retention requested on a write. A retention date must lie in the
+/// future; a lock that is already over would protect nothing.
+fn validate_retention(retention: &ObjectRetention) -> Result<(), Status> {
+    if let Some(retain_until) = retention.retain_until
+        && retain_until <= chrono::Utc::now()
+    {
+        return Err(Status::invalid_argument(
+            "InvalidArgument: retain_until must be in the future",
+        ));
+    }
+    Ok(())
+}
+
+/// Retention a new object in `bucket` gets when its write sets none.
+fn bucket_default_retention(
+    bucket: &Bucket,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<ObjectRetention> {
+    bucket.default_retention_days.map(|days| ObjectRetention {
+        retain_until: Some(now + chrono::Duration::days(i64::from(days))),
+        legal_hold: false,
+    })
+}
+
+fn object_locked_status(object_key: &str) -> Status {
+    Status::permission_denied(format!(
+        "AccessDenied: object {object_key} is protected by object lock"
+    ))
+}
+
+/// Maps a persistence failure to a status, surfacing object-lock refusals as
+/// `AccessDenied` and lost conditional-write races as `PreconditionFailed`
+/// instead of an internal error.
+fn object_mutation_status(error: anyhow::Error) -> Status {
+    if let Some(locked) = crate::persistence::ObjectLockedError::find(&error) {
+        return object_locked_status(&locked.key);
+    }
+    if crate::persistence::ObjectChangedError::find(&error).is_some() {
+        return preconditions::precondition_failed("If-Match/If-None-Match");
+    }
+    Status::internal(error.to_string())
+}
+
+/// Ends `data_stream` after the chunk that takes it past `max_bytes`, so an
+/// oversize upload stops being read as soon as it is known to be too large.
+fn stop_after_size_limit(
+    data_stream: impl Stream<Item = Result<Vec<u8>, Status>> + Unpin,
+    max_bytes: Option<u64>,
+) -> impl Stream<Item = Result<Vec<u8>, Status>> + Unpin {
+    data_stream.scan(0_u64, move |received, chunk| {
+        if max_bytes.is_some_and(|max_bytes| *received > max_bytes) {
+            return futures_util::future::ready(None);
+        }
+        if let Ok(chunk) = &chunk {
+            *received = received.saturating_add(chunk.len() as u64);
+        }
+        futures_util::future::ready(Some(chunk))
+    })
+}
+
+async fn discard_rejected_payload(temp_path: &Path) {
+    if let Err(error) = tokio::fs::remove_file(temp_path).await {
+        tracing::warn!(
+            path = %temp_path.display(),
+            %error,
+            "failed to remove rejected staged object payload"
+        );
+    }
+}
+
+/// Checks the received payload against any digests the client declared.
+fn verify_payload_digests(
+    expected_md5: Option<&[u8; 16]>,
+    expected_sha256: Option<&str>,
+    expected_checksum: Option<&ObjectChecksum>,
+    sha256_hex: &str,
+    md5: &[u8; 16],
+    checksum: Option<&ObjectChecksum>,
+) -> Result<(), Status> {
+    if let Some(expected) = expected_md5
+        && expected != md5
+    {
+        return Err(Status::invalid_argument(format!(
+            "BadDigest: declared MD5 {} does not match received {}",
+            hex::encode(expected),
+            hex::encode(md5)
+        )));
+    }
+    if let Some(expected) = expected_sha256
+        && !expected.eq_ignore_ascii_case(sha256_hex)
+    {
+        return Err(Status::invalid_argument(format!(
+            "BadDigest: declared SHA-256 {expected} does not match received {sha256_hex}"
+        )));
+    }
+    if let Some(expected) = expected_checksum
+        && Some(expected) != checksum
+    {
+        return Err(Status::invalid_argument(format!(
+            "BadDigest: declared {} {} does not match received {}",
+            expected.algorithm().s3_name(),
+            expected.to_base64(),
+            checksum.map(ObjectChecksum::to_base64).unwrap_or_default()
+        )));
+    }
+    Ok(())
+}
+
 fn core_append_stream_id(tenant_id: i64, bucket_id: i64, stream_id: uuid::Uuid) -> String {
     format!("object-append-stream-{tenant_id}-{bucket_id}-{stream_id}")
 }
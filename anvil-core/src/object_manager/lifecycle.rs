@@ -0,0 +1,135 @@
+use super::*;
+use crate::bucket_lifecycle;
+use crate::persistence::ObjectCreateOptions;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Position a lifecycle sweep resumes from: the rule of bucket `bucket_id`
+/// being walked and the last key listed under it. The default cursor starts
+/// a new pass at the lowest bucket id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LifecycleSweepCursor {
+    #[serde(default)]
+    pub bucket_id: i64,
+    #[serde(default)]
+    pub rule: usize,
+    #[serde(default)]
+    pub start_after: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LifecycleSweepOutcome {
+    pub scanned_objects: usize,
+    pub expired_objects: usize,
+    /// Where the next batch should resume, or `None` once the pass has
+    /// covered every bucket.
+    pub next: Option<LifecycleSweepCursor>,
+}
+
+impl ObjectManager {
+    /// Lists at most `batch_size` current objects under the lifecycle rules of
+    /// buckets homed in this region, starting at `cursor`, and writes a delete
+    /// marker for each one older than its rule allows.
+    pub async fn run_lifecycle_sweep(
+        &self,
+        cursor: &LifecycleSweepCursor,
+        batch_size: usize,
+        now: DateTime<Utc>,
+    ) -> Result<LifecycleSweepOutcome, Status> {
+        let buckets = self
+            .persistence
+            .list_lifecycle_buckets(&self.region)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let mut outcome = LifecycleSweepOutcome::default();
+        for bucket in buckets
+            .into_iter()
+            .filter(|bucket| bucket.id >= cursor.bucket_id)
+        {
+            let rules = match bucket_lifecycle::bucket_lifecycle_rules(&bucket) {
+                Ok(rules) => rules,
+                Err(error) => {
+                    tracing::warn!(bucket_id = bucket.id, %error, "skipping lifecycle rules");
+                    continue;
+                }
+            };
+            let resume = bucket.id == cursor.bucket_id;
+            let first_rule = if resume { cursor.rule } else { 0 };
+            for (rule_index, rule) in rules.iter().enumerate().skip(first_rule) {
+                let mut start_after = if resume && rule_index == cursor.rule {
+                    cursor.start_after.clone()
+                } else {
+                    String::new()
+                };
+                loop {
+                    let remaining = batch_size.saturating_sub(outcome.scanned_objects);
+                    if remaining == 0 {
+                        outcome.next = Some(LifecycleSweepCursor {
+                            bucket_id: bucket.id,
+                            rule: rule_index,
+                            start_after,
+                        });
+                        return Ok(outcome);
+                    }
+                    let limit = i32::try_from(remaining).unwrap_or(i32::MAX);
+                    let (objects, _) = self
+                        .persistence
+                        .list_objects(bucket.id, &rule.prefix, &start_after, limit, "")
+                        .await
+                        .map_err(|e| Status::internal(e.to_string()))?;
+                    let listed_all = objects.len() < remaining;
+                    for object in objects {
+                        outcome.scanned_objects += 1;
+                        start_after.clone_from(&object.key);
+                        if rule.is_expired(object.created_at, now)
+                            && self.expire_object(&bucket, &object).await?
+                        {
+                            outcome.expired_objects += 1;
+                        }
+                    }
+                    if listed_all {
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(outcome)
+    }
+
+    /// Writes a delete marker over `expired`, the version the sweep listed.
+    /// A key overwritten since the listing keeps its new version, and objects
+    /// under object lock are left in place until their retention runs out.
+    pub(super) async fn expire_object(
+        &self,
+        bucket: &Bucket,
+        expired: &Object,
+    ) -> Result<bool, Status> {
+        let deleted = self
+            .persistence
+            .soft_delete_object_in_transaction_with_options(
+                bucket.id,
+                &expired.key,
+                None,
+                None,
+                ObjectCreateOptions {
+                    expected_current: ExpectedCurrentVersion::Version(expired.version_id),
+                    ..ObjectCreateOptions::strict()
+                },
+            )
+            .await;
+        let delete_marker = match deleted {
+            Ok(Some(delete_marker)) => delete_marker,
+            Ok(None) => return Ok(false),
+            Err(error)
+                if crate::persistence::ObjectLockedError::find(&error).is_some()
+                    || crate::persistence::ObjectChangedError::find(&error).is_some() =>
+            {
+                return Ok(false);
+            }
+            Err(error) => return Err(Status::internal(error.to_string())),
+        };
+        self.publish_object_watch_event(bucket.tenant_id, bucket, &delete_marker, "delete", true)
+            .await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,71 @@
+use crate::persistence::Object;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub(super) type ObjectListing = (Vec<Object>, Vec<String>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct ObjectListingCacheKey {
+    pub(super) bucket_id: i64,
+    /// Listings are filtered per principal, so the reader is part of the key.
+    pub(super) principal: String,
+    pub(super) prefix: String,
+    pub(super) delimiter: String,
+    pub(super) start_after: String,
+    pub(super) limit: i32,
+}
+
+/// Short-lived cache of latest-consistency object listings. Entries expire
+/// after `ttl` and every entry for a bucket is dropped when it is written.
+#[derive(Debug)]
+pub(super) struct ObjectListingCache {
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<HashMap<ObjectListingCacheKey, (Instant, ObjectListing)>>,
+}
+
+impl ObjectListingCache {
+    pub(super) fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub(super) fn get(&self, key: &ObjectListingCacheKey) -> Option<ObjectListing> {
+        let mut entries = self.entries.lock().expect("object listing cache lock");
+        match entries.get(key) {
+            Some((stored_at, listing)) if stored_at.elapsed() < self.ttl => Some(listing.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub(super) fn insert(&self, key: ObjectListingCacheKey, listing: ObjectListing) {
+        let mut entries = self.entries.lock().expect("object listing cache lock");
+        if entries.len() >= self.max_entries && !entries.contains_key(&key) {
+            entries.retain(|_, (stored_at, _)| stored_at.elapsed() < self.ttl);
+            if entries.len() >= self.max_entries
+                && let Some(oldest) = entries
+                    .iter()
+                    .min_by_key(|(_, (stored_at, _))| *stored_at)
+                    .map(|(key, _)| key.clone())
+            {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(key, (Instant::now(), listing));
+    }
+
+    pub(super) fn invalidate_bucket(&self, bucket_id: i64) {
+        self.entries
+            .lock()
+            .expect("object listing cache lock")
+            .retain(|key, _| key.bucket_id != bucket_id);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::persistence::Object;
+use tonic::Status;
+
+/// HTTP-style `If-Match` / `If-None-Match` conditions on an object request.
+///
+/// Each condition is a comma-separated list of ETags, optionally quoted or
+/// weak (`W/"..."`), or `*` to match any current version.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectConditions {
+    pub if_match: Option<String>,
+    pub if_none_match: Option<String>,
+}
+
+/// Outcome of evaluating read conditions against the current version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionalRead {
+    Proceed,
+    /// `If-None-Match` matched: the caller's copy is current (HTTP 304).
+    NotModified,
+}
+
+impl ObjectConditions {
+    pub fn is_empty(&self) -> bool {
+        self.if_match.is_none() && self.if_none_match.is_none()
+    }
+
+    /// Checks the conditions for a write that would replace `current`.
+    ///
+    /// `If-Match` requires an existing version whose ETag matches, and
+    /// `If-None-Match` rejects the write when one matches, so
+    /// `If-None-Match: *` only creates keys that do not exist yet.
+    pub fn check_write(&self, current: Option<&Object>) -> Result<(), Status> {
+        let current_etag = current.map(|object| object.etag.as_str());
+        if let Some(condition) = self.if_match.as_deref()
+            && !current_etag.is_some_and(|etag| etag_condition_matches(condition, etag))
+        {
+            return Err(precondition_failed("If-Match"));
+        }
+        if let Some(condition) = self.if_none_match.as_deref()
+            && current_etag.is_some_and(|etag| etag_condition_matches(condition, etag))
+        {
+            return Err(precondition_failed("If-None-Match"));
+        }
+        Ok(())
+    }
+
+    /// Checks the conditions for a read of `object`.
+    pub fn check_read(&self, object: &Object) -> Result<ConditionalRead, Status> {
+        if let Some(condition) = self.if_match.as_deref()
+            && !etag_condition_matches(condition, &object.etag)
+        {
+            return Err(precondition_failed("If-Match"));
+        }
+        if let Some(condition) = self.if_none_match.as_deref()
+            && etag_condition_matches(condition, &object.etag)
+        {
+            return Ok(ConditionalRead::NotModified);
+        }
+        Ok(ConditionalRead::Proceed)
+    }
+}
+
+/// Whether a comma-separated `If-Match`/`If-None-Match` style condition
+/// names `current_etag`, ignoring quotes and weak prefixes; `*` matches any.
+pub fn etag_condition_matches(condition: &str, current_etag: &str) -> bool {
+    condition.split(',').map(str::trim).any(|candidate| {
+        let candidate = candidate.strip_prefix("W/").unwrap_or(candidate);
+        candidate == "*" || candidate.trim().trim_matches('"') == current_etag
+    })
+}
+
+pub(super) fn precondition_failed(condition: &str) -> Status {
+    Status::failed_precondition(format!(
+        "PreconditionFailed: {condition} condition did not hold"
+    ))
+}
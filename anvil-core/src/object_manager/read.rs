@@ -1,4 +1,5 @@
 use super::*;
+use crate::bucket_policy::{BucketPolicy, PolicyEffect};
 use crate::core_store::ReadLogicalRangeRequest;
 use crate::query_planner::{
     AuthzCandidateReader, AuthzDecision, BoundaryCandidateReader, IndexCandidateReader,
@@ -135,9 +136,16 @@ impl ObjectManager {
                         .read_current_object_metadata(&bucket, &object_key)
                         .await
                 };
+                let Some(object) = object.map_err(|e| Status::internal(e.to_string()))? else {
+                    if let Some(replica) = self
+                        .read_object_from_replicas(&bucket, &object_key, range)
+                        .await?
+                    {
+                        return Ok(replica);
+                    }
+                    return Err(Status::not_found("Object not found"));
+                };
                 object
-                    .map_err(|e| Status::internal(e.to_string()))?
-                    .ok_or_else(|| Status::not_found("Object not found"))?
             }
         };
         let mut followed_link = None;
@@ -152,10 +160,32 @@ impl ObjectManager {
             followed_link = Some(link);
         }
 
+        let response_bytes = range
+            .map(|range| range.end_exclusive.saturating_sub(range.start))
+            .unwrap_or_else(|| u64::try_from(object.size).unwrap_or_default());
+        self.record_bucket_request(&bucket, "get", Some(response_bytes));
+
+        let range_start = range.map(|range| range.start).unwrap_or(0);
+        let stream = self.stream_object_payload(&bucket, &object, range);
+        Ok(ObjectReadResult {
+            object,
+            stream,
+            followed_link,
+            range_start,
+        })
+    }
+
+    /// Streams the stored bytes of `object`, or of `range` within it, in
+    /// 64 KiB chunks. Read failures surface as a trailing error item.
+    pub(super) fn stream_object_payload(
+        &self,
+        bucket: &Bucket,
+        object: &Object,
+        range: Option<CoreByteRange>,
+    ) -> Pin<Box<dyn Stream<Item = Result<Vec<u8>, Status>> + Send + 'static>> {
         let (tx, rx) = mpsc::channel(4);
         let app_state = self.clone();
         let object_clone = object.clone();
-        let range_start = range.map(|range| range.start).unwrap_or(0);
         let logical_authz_scope = AuthzScopeRef {
             anvil_storage_tenant_id: bucket.tenant_id.to_string(),
             authz_realm_id: format!("bucket:{}", bucket.name),
@@ -242,12 +272,7 @@ impl ObjectManager {
             }
         });
 
-        Ok(ObjectReadResult {
-            object,
-            stream: Box::pin(ReceiverStream::new(rx)),
-            followed_link,
-            range_start,
-        })
+        Box::pin(ReceiverStream::new(rx))
     }
 
     pub async fn delete_object(
@@ -291,12 +316,13 @@ impl ObjectManager {
                 visibility.persistence_options(),
             )
             .await
-            .map_err(|e| Status::internal(e.to_string()))?
+            .map_err(object_mutation_status)?
             .ok_or_else(|| Status::not_found("Object not found"))?;
         if transaction_id.is_none() {
             if visibility.defers_write_maintenance() {
                 self.schedule_deferred_object_maintenance(bucket.clone(), object_key);
             }
+            self.invalidate_listing_cache(bucket.id);
             if visibility.requires_watch_visible() {
                 self.publish_object_watch_event(tenant_id, &bucket, &delete_marker, "delete", true)
                     .await?;
@@ -328,6 +354,8 @@ impl ObjectManager {
             }
         }
 
+        self.record_bucket_request(&bucket, "delete", None);
+
         Ok(delete_marker)
     }
 
@@ -379,12 +407,13 @@ impl ObjectManager {
                 visibility.persistence_options(),
             )
             .await
-            .map_err(|e| Status::internal(e.to_string()))?
+            .map_err(object_mutation_status)?
             .ok_or_else(|| Status::not_found("Object version not found"))?;
         if transaction_id.is_none() {
             if visibility.defers_write_maintenance() {
                 self.schedule_deferred_object_maintenance(bucket.clone(), object_key);
             }
+            self.invalidate_listing_cache(bucket.id);
             if visibility.requires_watch_visible() {
                 self.publish_object_watch_event(
                     tenant_id,
@@ -422,9 +451,60 @@ impl ObjectManager {
             }
         }
 
+        self.record_bucket_request(&bucket, "delete", None);
+
         Ok(deleted)
     }
 
+    /// Replaces the object-lock settings on the current version of
+    /// `object_key`. An active retention period can be extended but not
+    /// shortened or removed; a legal hold can be set and lifted freely.
+    pub async fn put_object_retention(
+        &self,
+        claims: &auth::Claims,
+        bucket_name: &str,
+        object_key: &str,
+        retention: Option<ObjectRetention>,
+    ) -> Result<Object, Status> {
+        self.validate_write_request(claims, bucket_name, object_key)
+            .await?;
+        if let Some(retention) = &retention {
+            validate_retention(retention)?;
+        }
+        let bucket = self
+            .get_tenant_bucket(claims.tenant_id, bucket_name)
+            .await?;
+        let current = self
+            .core_store
+            .read_current_object_metadata(&bucket, object_key)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .filter(|object| object.deleted_at.is_none())
+            .ok_or_else(|| Status::not_found("Object not found"))?;
+        let now = chrono::Utc::now();
+        if let Some(locked_until) = current
+            .retention
+            .and_then(|retention| retention.retain_until)
+            .filter(|until| *until > now)
+        {
+            let requested = retention.and_then(|retention| retention.retain_until);
+            if requested.is_none_or(|until| until < locked_until) {
+                return Err(Status::permission_denied(format!(
+                    "AccessDenied: retention on {object_key} cannot be shortened before {}",
+                    locked_until.to_rfc3339()
+                )));
+            }
+        }
+        let updated = self
+            .persistence
+            .set_object_retention(bucket.id, object_key, retention)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| Status::not_found("Object not found"))?;
+        self.invalidate_listing_cache(bucket.id);
+        Ok(updated)
+    }
+
     pub async fn head_object(
         &self,
         claims: Option<auth::Claims>,
@@ -723,16 +803,43 @@ impl ObjectManager {
             .authorized_bucket_reader_claims(claims.as_ref(), &bucket, consistency.authz_revision())
             .await?;
 
-        self.planner_backed_object_listing(
-            &reader_claims,
-            &bucket,
-            prefix,
-            start_after,
+        let cache = self
+            .listing_cache
+            .as_ref()
+            .filter(|_| consistency == ObjectReadConsistency::Latest);
+        let cache_key = ObjectListingCacheKey {
+            bucket_id: bucket.id,
+            principal: reader_claims.sub.clone(),
+            prefix: prefix.to_string(),
+            delimiter: delimiter.to_string(),
+            start_after: start_after.to_string(),
             limit,
-            delimiter,
-            consistency,
-        )
-        .await
+        };
+        if let Some(cache) = cache {
+            if let Some(listing) = cache.get(&cache_key) {
+                self.observability
+                    .increment_counter(OBJECT_LIST_CACHE_RESULTS, &[("result", "hit")]);
+                return Ok(listing);
+            }
+            self.observability
+                .increment_counter(OBJECT_LIST_CACHE_RESULTS, &[("result", "miss")]);
+        }
+
+        let listing = self
+            .planner_backed_object_listing(
+                &reader_claims,
+                &bucket,
+                prefix,
+                start_after,
+                limit,
+                delimiter,
+                consistency,
+            )
+            .await?;
+        if let Some(cache) = cache {
+            cache.insert(cache_key, listing.clone());
+        }
+        Ok(listing)
     }
 
     async fn planner_backed_object_listing(
@@ -756,7 +863,12 @@ impl ObjectManager {
         .map_err(|e| Status::internal(e.to_string()))?;
         objects.retain(|object| {
             object.key.starts_with(prefix)
-                && object.key.as_str() > start_after
+                && crate::metadata_journal::key_listed_after(
+                    &object.key,
+                    prefix,
+                    delimiter,
+                    start_after,
+                )
                 && !validation::is_reserved_internal_key(&object.key)
                 && object.deleted_at.is_none()
         });
@@ -1093,6 +1205,9 @@ impl ObjectManager {
             .map_err(|e| Status::internal(e.to_string()))
     }
 
+    /// Copies an object by reusing its shard map. The copy keeps the source's
+    /// content type and user metadata unless `metadata` replaces them.
+    #[allow(clippy::too_many_arguments)]
     pub async fn copy_object(
         &self,
         claims: auth::Claims,
@@ -1101,6 +1216,7 @@ impl ObjectManager {
         source_version_id: Option<uuid::Uuid>,
         destination_bucket_name: &str,
         destination_object_key: &str,
+        metadata: Option<CopyObjectMetadata>,
         transaction_id: Option<&str>,
     ) -> Result<Object, Status> {
         self.validate_write_request(&claims, destination_bucket_name, destination_object_key)
@@ -1116,8 +1232,20 @@ impl ObjectManager {
         let destination_bucket = self
             .get_tenant_bucket(claims.tenant_id, destination_bucket_name)
             .await?;
+        self.ensure_object_not_locked(&destination_bucket, destination_object_key)
+            .await?;
+        self.enforce_bucket_quota(
+            &destination_bucket,
+            destination_object_key,
+            u64::try_from(source_object.size).unwrap_or_default(),
+        )
+        .await?;
         let transaction_principal =
             crate::object_manager::transaction_principal_from_claims(&claims);
+        let (content_type, user_meta) = match metadata {
+            Some(metadata) => (metadata.content_type, metadata.user_metadata),
+            None => (source_object.content_type, source_object.user_meta),
+        };
 
         let copied = self
             .persistence
@@ -1128,8 +1256,8 @@ impl ObjectManager {
                 &source_object.content_hash,
                 source_object.size,
                 &source_object.etag,
-                source_object.content_type.as_deref(),
-                source_object.user_meta,
+                content_type.as_deref(),
+                user_meta,
                 source_object.shard_map,
                 None,
                 transaction_id,
@@ -1137,7 +1265,7 @@ impl ObjectManager {
                 source_object.storage_class,
             )
             .await
-            .map_err(|e| Status::internal(e.to_string()))?;
+            .map_err(object_mutation_status)?;
         if transaction_id.is_none() {
             self.publish_object_watch_event(
                 claims.tenant_id,
@@ -1152,6 +1280,90 @@ impl ObjectManager {
         Ok(copied)
     }
 
+    /// Copies an object into another tenant's bucket by reusing its shard map.
+    /// Callers must have authorised the operator; tenant authz is not consulted.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn admin_copy_object(
+        &self,
+        source_tenant_id: i64,
+        source_bucket_name: &str,
+        source_object_key: &str,
+        source_version_id: Option<uuid::Uuid>,
+        destination_tenant_id: i64,
+        destination_bucket_name: &str,
+        destination_object_key: &str,
+        principal: &str,
+    ) -> Result<Object, Status> {
+        if validation::is_reserved_internal_key(source_object_key)
+            || validation::is_reserved_internal_key(destination_object_key)
+        {
+            self.record_reserved_namespace_rejection("admin_copy_object");
+            return Err(Status::permission_denied("UnauthorizedReservedNamespace"));
+        }
+        if !validation::is_valid_object_key(destination_object_key) {
+            return Err(Status::invalid_argument("Invalid object key"));
+        }
+        let source_bucket = self
+            .get_tenant_bucket(source_tenant_id, source_bucket_name)
+            .await?;
+        let source_object = match source_version_id {
+            Some(version_id) => {
+                self.core_store
+                    .read_object_version_metadata(&source_bucket, source_object_key, version_id)
+                    .await
+            }
+            None => {
+                self.core_store
+                    .read_current_object_metadata(&source_bucket, source_object_key)
+                    .await
+            }
+        }
+        .map_err(|e| Status::internal(e.to_string()))?
+        .filter(|object| object.deleted_at.is_none())
+        .ok_or_else(|| Status::not_found("Source object not found"))?;
+        let destination_bucket = self
+            .get_tenant_bucket(destination_tenant_id, destination_bucket_name)
+            .await?;
+        self.ensure_object_not_locked(&destination_bucket, destination_object_key)
+            .await?;
+        self.enforce_bucket_quota(
+            &destination_bucket,
+            destination_object_key,
+            u64::try_from(source_object.size).unwrap_or_default(),
+        )
+        .await?;
+
+        let copied = self
+            .persistence
+            .create_object_with_storage_class(
+                destination_tenant_id,
+                destination_bucket.id,
+                destination_object_key,
+                &source_object.content_hash,
+                source_object.size,
+                &source_object.etag,
+                source_object.content_type.as_deref(),
+                source_object.user_meta,
+                source_object.shard_map,
+                None,
+                None,
+                Some(principal),
+                source_object.storage_class,
+            )
+            .await
+            .map_err(object_mutation_status)?;
+        self.publish_object_watch_event(
+            destination_tenant_id,
+            &destination_bucket,
+            &copied,
+            "copy",
+            false,
+        )
+        .await?;
+
+        Ok(copied)
+    }
+
     pub async fn compose_object(
         &self,
         claims: auth::Claims,
@@ -1165,6 +1377,11 @@ impl ObjectManager {
                 "ComposeObject requires at least one source",
             ));
         }
+        let destination_bucket = self
+            .get_tenant_bucket(claims.tenant_id, destination_bucket_name)
+            .await?;
+        self.ensure_object_not_locked(&destination_bucket, destination_object_key)
+            .await?;
 
         let state = ComposeStreamState {
             manager: self.clone(),
@@ -1260,6 +1477,13 @@ impl ObjectManager {
                 transaction_principal: transaction_id
                     .map(|_| crate::object_manager::transaction_principal_from_claims(&claims)),
                 storage_class_id: None,
+                expected_size: None,
+                expected_md5: None,
+                expected_sha256: None,
+                md5_etag: false,
+                etag: None,
+                conditions: Default::default(),
+                retention: None,
                 visibility: ObjectWriteVisibility::strict(),
             },
         )
@@ -1302,6 +1526,9 @@ impl ObjectManager {
             .await
             .map_err(|e| Status::internal(e.to_string()))?
             .ok_or_else(|| Status::not_found("Bucket not found for this tenant"))?;
+        if bucket.tenant_id != tenant_id {
+            return Err(Status::not_found("Bucket not found for this tenant"));
+        }
 
         if bucket.region != self.region {
             return Err(self.remote_bucket_status(&bucket.region));
@@ -1492,6 +1719,30 @@ impl ObjectManager {
         object_key: &str,
         authz_revision: Option<i64>,
     ) -> Result<(), Status> {
+        // A policy Deny names every principal, so it overrides the caller's
+        // own grants as well as the public-read flag.
+        let policy_effect = match bucket.policy_json.as_deref() {
+            Some(policy_json) => match BucketPolicy::from_json(&bucket.name, policy_json) {
+                Ok(policy) => policy.object_read_effect(&bucket.name, object_key),
+                Err(err) => {
+                    tracing::warn!(
+                        bucket = bucket.name.as_str(),
+                        error = %err,
+                        "ignoring unreadable bucket policy"
+                    );
+                    None
+                }
+            },
+            None => None,
+        };
+        match policy_effect {
+            Some(PolicyEffect::Deny) => {
+                return Err(Status::permission_denied("Permission denied"));
+            }
+            Some(PolicyEffect::Allow) => return Ok(()),
+            None => {}
+        }
+
         if let Some(claims) = claims
             && self
                 .object_read_allowed_for_bucket(claims, bucket, object_key, authz_revision)
@@ -1567,6 +1818,7 @@ impl ObjectManager {
         event_type: &str,
         is_delete_marker: bool,
     ) -> Result<(), Status> {
+        self.invalidate_listing_cache(bucket.id);
         let mut event = self
             .persistence
             .create_object_watch_event(
@@ -1588,7 +1840,8 @@ impl ObjectManager {
         Ok(())
     }
 
-    pub(super) async fn validate_write_request(
+    /// Checks that `claims` may write `object_key` in `bucket_name`.
+    pub async fn validate_write_request(
         &self,
         claims: &auth::Claims,
         bucket_name: &str,
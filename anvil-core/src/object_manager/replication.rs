@@ -0,0 +1,468 @@
+use super::*;
+use crate::anvil_api::internal_proxy_service_client::InternalProxyServiceClient;
+use crate::anvil_api::{
+    ProxyHeader, ProxyRequestChunk, ProxyRequestHeader, ProxyResponseChunk, proxy_request_chunk,
+    proxy_response_chunk,
+};
+use crate::peer_channels::PeerChannelPool;
+use crate::persistence::ObjectCreateOptions;
+use crate::tasks::TaskType;
+
+/// Internal proxy method that stores a replica pushed from the home region.
+pub const REPLICA_PUT_METHOD: &str = "REPLICA_PUT";
+/// Internal proxy method that reads the current replica of an object.
+pub const REPLICA_GET_METHOD: &str = "REPLICA_GET";
+/// JSON `Bucket` descriptor of the home bucket a replica belongs to.
+pub const REPLICA_BUCKET_HEADER: &str = "x-anvil-replica-bucket";
+/// JSON `Object` row describing the replicated version.
+pub const REPLICA_OBJECT_HEADER: &str = "x-anvil-replica-object";
+/// Optional `<start>-<end_exclusive>` byte range for replica reads.
+pub const REPLICA_RANGE_HEADER: &str = "x-anvil-replica-range";
+
+const REPLICATION_PRINCIPAL: &str = "anvil:replication";
+const REPLICATION_TASK_PRIORITY: i32 = 50;
+const REPLICATION_AUTHZ_TTL_SECS: i64 = 3600;
+
+/// Peer connectivity used to push objects to, and read them back from,
+/// the regions listed in a bucket's `replicate_to`.
+#[derive(Debug, Clone)]
+pub struct ReplicationTransport {
+    pub peer_channels: PeerChannelPool,
+    pub internal_bearer_token: String,
+}
+
+impl ObjectManager {
+    /// Enables cross-region replication through `transport`.
+    pub fn with_replication(mut self, transport: ReplicationTransport) -> Self {
+        self.replication = Some(transport);
+        self
+    }
+
+    /// Queues a `ReplicateObject` task per replica region of `bucket` for the
+    /// version that was just written. Each version gets its own task, so an
+    /// overwrite landing while an earlier transfer runs is still sent.
+    pub(super) async fn enqueue_object_replication(&self, bucket: &Bucket, object: &Object) {
+        for region in &bucket.replicate_to {
+            if let Err(error) = self
+                .persistence
+                .enqueue_task_if_absent(
+                    TaskType::ReplicateObject,
+                    serde_json::json!({
+                        "tenant_id": bucket.tenant_id,
+                        "bucket_name": &bucket.name,
+                        "object_key": &object.key,
+                        "version_id": object.version_id,
+                        "region": region,
+                    }),
+                    REPLICATION_TASK_PRIORITY,
+                )
+                .await
+            {
+                tracing::warn!(
+                    tenant_id = bucket.tenant_id,
+                    bucket_name = %bucket.name,
+                    object_key = %object.key,
+                    region = %region,
+                    %error,
+                    "failed to enqueue object replication"
+                );
+            }
+        }
+    }
+
+    /// Streams `version_id` of an object to `region` and returns whether
+    /// anything was sent. The transfer is skipped when that version is no
+    /// longer current: a later write queued its own task, and a deleted key
+    /// must not be revived in the replica region.
+    pub async fn replicate_object(
+        &self,
+        tenant_id: i64,
+        bucket_name: &str,
+        object_key: &str,
+        version_id: uuid::Uuid,
+        region: &str,
+    ) -> Result<bool, Status> {
+        let bucket = self.get_tenant_bucket(tenant_id, bucket_name).await?;
+        let Some(object) = self
+            .core_store
+            .read_current_object_metadata(&bucket, object_key)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+        else {
+            return Ok(false);
+        };
+        if object.version_id != version_id || object.kind != object_links::ObjectEntryKind::Blob {
+            return Ok(false);
+        }
+
+        let headers = vec![
+            replica_json_header(REPLICA_BUCKET_HEADER, &bucket)?,
+            replica_json_header(REPLICA_OBJECT_HEADER, &object)?,
+        ];
+        let payload = self.stream_object_payload(&bucket, &object, None);
+        let mut response = self
+            .send_replica_request(
+                region,
+                REPLICA_PUT_METHOD,
+                &bucket,
+                object_key,
+                headers,
+                Some(payload),
+            )
+            .await?;
+        let header = read_replica_response_header(&mut response).await?;
+        if header.status != 200 {
+            return Err(Status::internal(format!(
+                "replica region {region} answered with status {}",
+                header.status
+            )));
+        }
+        Ok(true)
+    }
+
+    /// Stores a replica of `source` from a bucket homed in another region.
+    /// The replica keeps the source ETag, content type, metadata and storage
+    /// class, and gets its own version id in this region.
+    pub async fn put_replica_object(
+        &self,
+        bucket: Bucket,
+        source: Object,
+        data_stream: impl Stream<Item = Result<Vec<u8>, Status>> + Unpin,
+    ) -> Result<Object, Status> {
+        if bucket.region == self.region {
+            return Err(Status::failed_precondition(
+                "bucket is homed in this region and cannot receive replicas",
+            ));
+        }
+        if !bucket.replicate_to.contains(&self.region) {
+            return Err(Status::failed_precondition(
+                "bucket does not list this region as a replica target",
+            ));
+        }
+        if !validation::is_valid_object_key(&source.key) {
+            return Err(Status::invalid_argument("Invalid object key"));
+        }
+        let replica = self
+            .persistence
+            .ensure_replica_bucket(&bucket)
+            .await
+            .map_err(|e| Status::failed_precondition(e.to_string()))?;
+        let (temp_path, total_bytes, stream_hash, _stream_md5) = self
+            .storage
+            .stream_to_temp_file(data_stream)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        if total_bytes != source.size {
+            discard_rejected_payload(&temp_path).await;
+            return Err(Status::data_loss(format!(
+                "replica of {} carried {total_bytes} bytes, expected {}",
+                source.key, source.size
+            )));
+        }
+        let total_bytes_u64 =
+            u64::try_from(total_bytes).map_err(|_| Status::internal("Negative payload size"))?;
+        let boundary_values = self
+            .object_write_boundary_values_from_hints(
+                replica.tenant_id,
+                &replica.name,
+                &source.key,
+                source.content_type.as_deref(),
+                source.user_meta.as_ref(),
+                total_bytes_u64,
+            )
+            .await?;
+        let (content_hash, shard_map, storage_class_id) = self
+            .store_staged_payload(
+                replica.tenant_id,
+                &replica,
+                &source.key,
+                &temp_path,
+                total_bytes_u64,
+                &stream_hash,
+                boundary_values,
+                source.storage_class.as_deref(),
+            )
+            .await?;
+        let object = self
+            .persistence
+            .create_object_with_storage_class_with_options(
+                replica.tenant_id,
+                replica.id,
+                &source.key,
+                &content_hash,
+                total_bytes,
+                &source.etag,
+                source.content_type.as_deref(),
+                source.user_meta,
+                shard_map,
+                None,
+                None,
+                None,
+                Some(storage_class_id),
+                ObjectCreateOptions {
+                    requested_storage_class: source
+                        .requested_storage_class
+                        .as_deref()
+                        .and_then(StorageClass::s3_class_name),
+                    ..ObjectCreateOptions::deferred()
+                },
+            )
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        self.invalidate_listing_cache(replica.id);
+        Ok(object)
+    }
+
+    /// Reads the current replica held in this region for a bucket homed
+    /// elsewhere.
+    pub async fn get_replica_object(
+        &self,
+        tenant_id: i64,
+        bucket_name: &str,
+        object_key: &str,
+        range: Option<CoreByteRange>,
+    ) -> Result<ObjectReadResult, Status> {
+        let bucket = bucket_journal::read_current_bucket(&self.storage, tenant_id, bucket_name)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .filter(|bucket| bucket.region != self.region)
+            .ok_or_else(|| Status::not_found("No replica bucket in this region"))?;
+        let object = self
+            .core_store
+            .read_current_object_metadata(&bucket, object_key)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| Status::not_found("Object replica not found"))?;
+        let stream = self.stream_object_payload(&bucket, &object, range);
+        Ok(ObjectReadResult {
+            object,
+            stream,
+            followed_link: None,
+            range_start: range.map(|range| range.start).unwrap_or(0),
+        })
+    }
+
+    /// Serves a current-version read from the first replica region that
+    /// holds the key. Returns `None` when the bucket has no replicas, when
+    /// this region has any history for the key (a delete here must not be
+    /// undone by a replica that has not seen it), or when no replica has the
+    /// object.
+    pub(super) async fn read_object_from_replicas(
+        &self,
+        bucket: &Bucket,
+        object_key: &str,
+        range: Option<CoreByteRange>,
+    ) -> Result<Option<ObjectReadResult>, Status> {
+        if self.replication.is_none() || bucket.replicate_to.is_empty() {
+            return Ok(None);
+        }
+        if self
+            .core_store
+            .object_key_has_history(bucket, object_key)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+        {
+            return Ok(None);
+        }
+        for region in &bucket.replicate_to {
+            let mut headers = Vec::new();
+            if let Some(range) = range {
+                headers.push(ProxyHeader {
+                    name: REPLICA_RANGE_HEADER.to_string(),
+                    value: format!("{}-{}", range.start, range.end_exclusive).into_bytes(),
+                });
+            }
+            let result = async {
+                let mut response = self
+                    .send_replica_request(
+                        region,
+                        REPLICA_GET_METHOD,
+                        bucket,
+                        object_key,
+                        headers,
+                        None,
+                    )
+                    .await?;
+                let header = read_replica_response_header(&mut response).await?;
+                let object: Object = replica_json_value(&header.headers, REPLICA_OBJECT_HEADER)?;
+                let stream = response.map(|chunk| -> Result<Vec<u8>, Status> {
+                    match chunk?.part {
+                        Some(proxy_response_chunk::Part::Body(bytes)) => Ok(bytes),
+                        Some(proxy_response_chunk::Part::Header(_)) => {
+                            Err(Status::internal("replica response carried a second header"))
+                        }
+                        None => Ok(Vec::new()),
+                    }
+                });
+                Ok::<_, Status>(ObjectReadResult {
+                    object,
+                    stream: Box::pin(stream),
+                    followed_link: None,
+                    range_start: range.map(|range| range.start).unwrap_or(0),
+                })
+            }
+            .await;
+            match result {
+                Ok(result) => return Ok(Some(result)),
+                Err(status) if status.code() == tonic::Code::NotFound => {}
+                Err(status) => {
+                    tracing::warn!(
+                        bucket_name = %bucket.name,
+                        object_key,
+                        region = %region,
+                        error = %status,
+                        "replica read failed"
+                    );
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    async fn send_replica_request(
+        &self,
+        region: &str,
+        method: &str,
+        bucket: &Bucket,
+        object_key: &str,
+        headers: Vec<ProxyHeader>,
+        body: Option<Pin<Box<dyn Stream<Item = Result<Vec<u8>, Status>> + Send + 'static>>>,
+    ) -> Result<tonic::Streaming<ProxyResponseChunk>, Status> {
+        let transport = self
+            .replication
+            .as_ref()
+            .ok_or_else(|| Status::unavailable("object replication is not configured"))?;
+        let token = transport.internal_bearer_token.trim();
+        if token.is_empty() {
+            return Err(Status::unavailable(
+                "object replication requires an internal node bearer token",
+            ));
+        }
+        let endpoint = self
+            .persistence
+            .region_object_endpoint(region)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| {
+                Status::unavailable(format!("no active object node in region {region}"))
+            })?;
+        let claims = auth::Claims {
+            sub: REPLICATION_PRINCIPAL.to_string(),
+            exp: usize::try_from(chrono::Utc::now().timestamp() + REPLICATION_AUTHZ_TTL_SECS)
+                .unwrap_or(usize::MAX),
+            tenant_id: bucket.tenant_id,
+            jti: None,
+        };
+        let header = ProxyRequestHeader {
+            request_id: uuid::Uuid::new_v4().to_string(),
+            idempotency_key: String::new(),
+            principal_id: claims.sub.clone(),
+            tenant_id: bucket.tenant_id.to_string(),
+            bucket_name: bucket.name.clone(),
+            object_key: object_key.to_string(),
+            method: method.to_string(),
+            canonical_host: String::new(),
+            canonical_path: String::new(),
+            bucket_locator_generation: 0,
+            headers,
+            authz_context: crate::services::internal_proxy::encode_proxy_authz_context(&claims)?,
+        };
+
+        let (tx, rx) = mpsc::channel(8);
+        tokio::spawn(async move {
+            if tx
+                .send(ProxyRequestChunk {
+                    part: Some(proxy_request_chunk::Part::Header(header)),
+                })
+                .await
+                .is_err()
+            {
+                return;
+            }
+            let Some(mut body) = body else {
+                return;
+            };
+            while let Some(chunk) = body.next().await {
+                match chunk {
+                    Ok(bytes) => {
+                        if tx
+                            .send(ProxyRequestChunk {
+                                part: Some(proxy_request_chunk::Part::Body(bytes)),
+                            })
+                            .await
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                    Err(error) => {
+                        tracing::warn!(%error, "failed to read object payload for replication");
+                        break;
+                    }
+                }
+            }
+        });
+
+        let channel = transport
+            .peer_channels
+            .channel(&endpoint)
+            .await
+            .map_err(|e| Status::unavailable(e.to_string()))?;
+        let mut request = tonic::Request::new(ReceiverStream::new(rx));
+        request.metadata_mut().insert(
+            "authorization",
+            format!("Bearer {token}")
+                .parse()
+                .map_err(|_| Status::internal("Failed to encode internal proxy token"))?,
+        );
+        crate::middleware::propagate_request_id(&mut request);
+        match InternalProxyServiceClient::new(channel)
+            .proxy_object(request)
+            .await
+        {
+            Ok(response) => Ok(response.into_inner()),
+            Err(status) => {
+                if matches!(
+                    status.code(),
+                    tonic::Code::Unavailable | tonic::Code::Unknown
+                ) {
+                    transport.peer_channels.invalidate(&endpoint).await;
+                }
+                Err(status)
+            }
+        }
+    }
+}
+
+async fn read_replica_response_header(
+    response: &mut tonic::Streaming<ProxyResponseChunk>,
+) -> Result<crate::anvil_api::ProxyResponseHeader, Status> {
+    match response.message().await? {
+        Some(ProxyResponseChunk {
+            part: Some(proxy_response_chunk::Part::Header(header)),
+        }) => Ok(header),
+        _ => Err(Status::internal(
+            "replica response did not start with a header",
+        )),
+    }
+}
+
+fn replica_json_header(name: &str, value: &impl serde::Serialize) -> Result<ProxyHeader, Status> {
+    Ok(ProxyHeader {
+        name: name.to_string(),
+        value: serde_json::to_vec(value).map_err(|e| Status::internal(e.to_string()))?,
+    })
+}
+
+/// Decodes a JSON header written by [`replica_json_header`].
+pub fn replica_json_value<T: serde::de::DeserializeOwned>(
+    headers: &[ProxyHeader],
+    name: &str,
+) -> Result<T, Status> {
+    let header = headers
+        .iter()
+        .find(|header| header.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| Status::invalid_argument(format!("missing {name} header")))?;
+    serde_json::from_slice(&header.value)
+        .map_err(|e| Status::invalid_argument(format!("invalid {name} header: {e}")))
+}
@@ -1,7 +1,9 @@
 use super::*;
 use crate::{
-    access_control, config::Config, core_store::CoreStore, storage::Storage, system_realm,
+    access_control, config::Config, core_store::CoreStore, persistence::BucketUsage,
+    storage::Storage, system_realm,
 };
+use sha2::Digest as _;
 use tempfile::{TempDir, tempdir};
 
 fn test_config(storage_path: &std::path::Path) -> Config {
@@ -152,10 +154,12 @@ async fn seeded_core_store_link() -> (TempDir, ObjectManager, Bucket, Object, Ob
         created_at: now,
         deleted_at: None,
         storage_class: None,
+        requested_storage_class: None,
         user_meta: None,
         shard_map: None,
         checksum: None,
         link: Some(link_target),
+        retention: None,
     };
     manager
         .core_store
@@ -408,6 +412,250 @@ fn strict_write_visibility_preserves_previous_synchronous_behaviour() {
     assert!(options.enqueue_metadata_compaction);
 }
 
+#[tokio::test]
+async fn put_object_rejects_payloads_that_do_not_match_declared_digests() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("digests").await;
+    let payload = b"hello digest".to_vec();
+    let md5: [u8; 16] = md5::Md5::digest(&payload).into();
+    let sha256 = hex::encode(sha2::Sha256::digest(&payload));
+
+    let bad_md5 = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "bad-md5.txt",
+            tokio_stream::iter(vec![Ok(payload.clone())]),
+            ObjectWriteOptions {
+                expected_md5: Some([0; 16]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(bad_md5.code(), tonic::Code::InvalidArgument);
+    assert!(bad_md5.message().starts_with("BadDigest"));
+
+    let bad_sha256 = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "bad-sha256.txt",
+            tokio_stream::iter(vec![Ok(payload.clone())]),
+            ObjectWriteOptions {
+                expected_sha256: Some("0".repeat(64)),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(bad_sha256.message().starts_with("BadDigest"));
+
+    let stored = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "good.txt",
+            tokio_stream::iter(vec![Ok(payload)]),
+            ObjectWriteOptions {
+                expected_md5: Some(md5),
+                expected_sha256: Some(sha256),
+                md5_etag: true,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(stored.etag, hex::encode(md5));
+    assert_ne!(stored.etag, stored.content_hash);
+}
+
+#[tokio::test]
+async fn put_object_records_and_verifies_additional_checksums() {
+    use crate::object_checksum::{ChecksumAlgorithm, ChecksumHasher, ObjectChecksum};
+
+    let (_temp, manager, bucket, claims) = seeded_object_manager("checksums").await;
+    let mut hasher = ChecksumHasher::new(ChecksumAlgorithm::Crc32c);
+    hasher.update(b"hello checksum");
+    let crc32c = hasher.finish();
+
+    let stored = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "crc.txt",
+            tokio_stream::iter(vec![Ok(b"hello ".to_vec()), Ok(b"checksum".to_vec())]),
+            ObjectWriteOptions {
+                checksum_algorithm: Some(ChecksumAlgorithm::Crc32c),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        stored.checksum.as_deref().and_then(ObjectChecksum::decode),
+        Some(crc32c)
+    );
+    let (current, _) = manager
+        .get_object(
+            Some(claims.clone()),
+            bucket.name.clone(),
+            "crc.txt".to_string(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(current.checksum, stored.checksum);
+
+    let mismatch = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "crc.txt",
+            tokio_stream::iter(vec![Ok(b"other payload".to_vec())]),
+            ObjectWriteOptions {
+                expected_checksum: Some(crc32c),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(mismatch.code(), tonic::Code::InvalidArgument);
+    assert!(mismatch.message().starts_with("BadDigest"));
+}
+
+#[tokio::test]
+async fn bucket_quota_rejects_writes_past_the_limit() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("quota").await;
+    manager
+        .persistence
+        .set_bucket_quota(bucket.tenant_id, &bucket.name, Some(10), Some(2))
+        .await
+        .unwrap();
+    async fn put(
+        manager: &ObjectManager,
+        claims: &auth::Claims,
+        bucket: &Bucket,
+        key: &str,
+        payload: &[u8],
+    ) -> Result<Object, Status> {
+        manager
+            .put_object(
+                claims,
+                &bucket.name,
+                key,
+                tokio_stream::iter(vec![Ok(payload.to_vec())]),
+                ObjectWriteOptions::default(),
+            )
+            .await
+    }
+
+    put(&manager, &claims, &bucket, "a.txt", b"aaaa")
+        .await
+        .unwrap();
+    put(&manager, &claims, &bucket, "b.txt", b"bbbb")
+        .await
+        .unwrap();
+
+    let over_count = put(&manager, &claims, &bucket, "c.txt", b"c")
+        .await
+        .unwrap_err();
+    assert_eq!(over_count.code(), tonic::Code::ResourceExhausted);
+    let over_bytes = put(&manager, &claims, &bucket, "a.txt", b"aaaaaaa")
+        .await
+        .unwrap_err();
+    assert_eq!(over_bytes.code(), tonic::Code::ResourceExhausted);
+
+    // Overwrites are charged against the replaced object, not on top of it.
+    put(&manager, &claims, &bucket, "a.txt", b"aaaaaa")
+        .await
+        .unwrap();
+    let usage = manager
+        .persistence
+        .bucket_usage(&bucket, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        usage,
+        BucketUsage {
+            used_bytes: 10,
+            object_count: 2,
+        }
+    );
+
+    let over_count = manager
+        .copy_object(
+            claims.clone(),
+            &bucket.name,
+            "a.txt",
+            None,
+            &bucket.name,
+            "c.txt",
+            None,
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(over_count.code(), tonic::Code::ResourceExhausted);
+    let upload = manager
+        .initiate_multipart_upload(&claims, &bucket.name, "b.txt", None, None)
+        .await
+        .unwrap();
+    let part = manager
+        .upload_part(
+            &claims,
+            &bucket.name,
+            "b.txt",
+            upload.upload_id,
+            1,
+            tokio_stream::iter(vec![Ok(b"bbbbbbb".to_vec())]),
+            UploadPartOptions::default(),
+        )
+        .await
+        .unwrap();
+    let over_bytes = manager
+        .complete_multipart_upload(
+            &claims,
+            &bucket.name,
+            "b.txt",
+            upload.upload_id,
+            vec![CompleteMultipartPart {
+                part_number: 1,
+                etag: part.etag,
+            }],
+            None,
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(over_bytes.code(), tonic::Code::ResourceExhausted);
+
+    // Deletes release their share of the quota.
+    manager
+        .delete_object(
+            &claims,
+            &bucket.name,
+            "b.txt",
+            None,
+            None,
+            ObjectWriteVisibility::default(),
+        )
+        .await
+        .unwrap();
+    let usage = manager
+        .persistence
+        .bucket_usage(&bucket, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        usage,
+        BucketUsage {
+            used_bytes: 6,
+            object_count: 1,
+        }
+    );
+}
+
 #[tokio::test]
 async fn small_inline_object_versions_dedupe_and_reference_count_payload() {
     let (_temp, manager, bucket, claims) = seeded_object_manager("inline-dedupe").await;
@@ -614,44 +862,1926 @@ async fn erasure_coded_object_versions_dedupe_and_reference_count_blocks() {
 }
 
 #[tokio::test]
-async fn object_link_metadata_head_and_read_use_core_store_metadata() {
-    let (_temp, manager, bucket, target, link, claims) = seeded_core_store_link().await;
+async fn garbage_collection_reclaims_shards_once_no_version_references_the_block() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("shard-gc").await;
+    let payload = vec![0xCD; 80 * 1024];
+    let key = "payloads/collected.bin";
+    let mut versions = Vec::new();
+    for _ in 0..2 {
+        versions.push(
+            manager
+                .put_object(
+                    &claims,
+                    &bucket.name,
+                    key,
+                    tokio_stream::iter(vec![Ok(payload.clone())]),
+                    ObjectWriteOptions {
+                        content_type: Some("application/octet-stream".to_string()),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap(),
+        );
+    }
 
-    let current = manager
-        .read_object_link(Some(claims.clone()), &bucket.name, &link.key, None)
+    manager
+        .delete_object_version(
+            &claims,
+            &bucket.name,
+            key,
+            versions[0].version_id,
+            None,
+            None,
+            ObjectWriteVisibility::default(),
+        )
         .await
         .unwrap();
-    assert_eq!(current.link_key, link.key);
-    assert_eq!(current.target_key, target.key);
-    assert_eq!(current.generation, 1);
-
-    let versioned = manager
-        .read_object_link(
+    let still_referenced = manager
+        .core_store
+        .collect_orphaned_shards(Duration::ZERO, false)
+        .await
+        .unwrap();
+    assert_eq!(still_referenced.orphaned_shards, 0);
+    let result = manager
+        .get_object(
             Some(claims.clone()),
+            bucket.name.clone(),
+            key.to_string(),
+            Some(versions[1].version_id),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(collect_stream_bytes(result.1).await.unwrap(), payload);
+
+    manager
+        .delete_object_version(
+            &claims,
             &bucket.name,
-            &link.key,
-            Some(link.version_id),
+            key,
+            versions[1].version_id,
+            None,
+            None,
+            ObjectWriteVisibility::default(),
         )
         .await
         .unwrap();
-    assert_eq!(versioned.link_key, link.key);
-    assert_eq!(versioned.target_key, target.key);
+    let within_grace = manager
+        .core_store
+        .collect_orphaned_shards(Duration::from_secs(3600), false)
+        .await
+        .unwrap();
+    assert_eq!(within_grace.orphaned_shards, 0);
+    assert!(within_grace.pending_releases > 0);
 
-    let head = manager
-        .head_object(Some(claims.clone()), &bucket.name, &link.key, None)
+    let dry_run = manager
+        .core_store
+        .collect_orphaned_shards(Duration::ZERO, true)
         .await
         .unwrap();
-    assert_eq!(head.key, target.key);
-    assert_eq!(head.version_id, target.version_id);
-    assert_eq!(head.size, target.size);
-    assert!(head.etag.starts_with("link-follow-"));
+    assert!(dry_run.orphaned_shards > 0);
+    assert!(dry_run.reclaimable_bytes > 0);
+    assert_eq!(dry_run.deleted_shards, 0);
+
+    let collected = manager
+        .core_store
+        .collect_orphaned_shards(Duration::ZERO, false)
+        .await
+        .unwrap();
+    assert_eq!(collected.orphaned_shards, dry_run.orphaned_shards);
+    assert_eq!(collected.reclaimable_bytes, dry_run.reclaimable_bytes);
+    assert_eq!(collected.deleted_shards, dry_run.orphaned_shards);
+    assert_eq!(
+        collected.scanned_shards - collected.deleted_shards,
+        manager
+            .core_store
+            .collect_orphaned_shards(Duration::ZERO, false)
+            .await
+            .unwrap()
+            .scanned_shards
+    );
+}
+
+#[tokio::test]
+async fn identical_payloads_under_different_keys_share_stored_blocks() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("payload-dedup").await;
+    let payload = vec![0x5A; 80 * 1024];
+    let mut objects = Vec::new();
+    for key in ["payloads/original.bin", "payloads/duplicate.bin"] {
+        objects.push(
+            manager
+                .put_object(
+                    &claims,
+                    &bucket.name,
+                    key,
+                    tokio_stream::iter(vec![Ok(payload.clone())]),
+                    ObjectWriteOptions::default(),
+                )
+                .await
+                .unwrap(),
+        );
+    }
+    assert_eq!(objects[0].content_hash, objects[1].content_hash);
+    assert_eq!(objects[0].shard_map, objects[1].shard_map);
+    let summaries = manager
+        .core_store
+        .payload_reference_summaries_for_object(&objects[1])
+        .await
+        .unwrap();
+    assert!(!summaries.is_empty());
+    assert!(summaries.iter().all(|summary| summary.reference_count == 2));
 
+    manager
+        .delete_object_version(
+            &claims,
+            &bucket.name,
+            &objects[0].key,
+            objects[0].version_id,
+            None,
+            None,
+            ObjectWriteVisibility::default(),
+        )
+        .await
+        .unwrap();
+    let still_referenced = manager
+        .core_store
+        .collect_orphaned_shards(Duration::ZERO, false)
+        .await
+        .unwrap();
+    assert_eq!(still_referenced.orphaned_shards, 0);
     let result = manager
-        .get_object(Some(claims), bucket.name, link.key, None, None)
+        .get_object(
+            Some(claims.clone()),
+            bucket.name.clone(),
+            objects[1].key.clone(),
+            None,
+            None,
+        )
         .await
         .unwrap();
-    let body = collect_stream_bytes(result.1).await.unwrap();
-    assert_eq!(body, b"linked payload");
-    assert_eq!(result.0.key, target.key);
-    assert_eq!(result.2, 0);
+    assert_eq!(collect_stream_bytes(result.1).await.unwrap(), payload);
+
+    manager
+        .delete_object_version(
+            &claims,
+            &bucket.name,
+            &objects[1].key,
+            objects[1].version_id,
+            None,
+            None,
+            ObjectWriteVisibility::default(),
+        )
+        .await
+        .unwrap();
+    let collected = manager
+        .core_store
+        .collect_orphaned_shards(Duration::ZERO, false)
+        .await
+        .unwrap();
+    assert!(collected.deleted_shards > 0);
+}
+
+#[tokio::test]
+async fn payload_of_a_write_rejected_at_commit_is_released_for_collection() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("uncommitted-payload").await;
+    let key = "payloads/contended.bin";
+    let (chunks, body) = mpsc::channel(1);
+    let conditional_put = tokio::spawn({
+        let manager = manager.clone();
+        let claims = claims.clone();
+        let bucket_name = bucket.name.clone();
+        async move {
+            manager
+                .put_object(
+                    &claims,
+                    &bucket_name,
+                    key,
+                    ReceiverStream::new(body),
+                    ObjectWriteOptions {
+                        conditions: ObjectConditions {
+                            if_none_match: Some("*".to_string()),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                )
+                .await
+        }
+    });
+    // The second send only completes once the upload has passed its early
+    // precondition check and started reading the body.
+    chunks.send(Ok(vec![0xAB; 40 * 1024])).await.unwrap();
+    chunks.send(Ok(vec![0xAB; 40 * 1024])).await.unwrap();
+    let winner = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(vec![0xEF; 80 * 1024])]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+    drop(chunks);
+    let rejected = conditional_put
+        .await
+        .unwrap()
+        .expect_err("the commit-time precondition check must fail");
+    assert_eq!(rejected.code(), tonic::Code::FailedPrecondition);
+
+    let collected = manager
+        .core_store
+        .collect_orphaned_shards(Duration::ZERO, false)
+        .await
+        .unwrap();
+    assert!(collected.deleted_shards > 0);
+    let current = manager
+        .get_object(
+            Some(claims.clone()),
+            bucket.name.clone(),
+            key.to_string(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(current.0.version_id, winner.version_id);
+    assert_eq!(
+        collect_stream_bytes(current.1).await.unwrap(),
+        vec![0xEF; 80 * 1024]
+    );
+}
+
+#[tokio::test]
+async fn dropping_a_read_stream_mid_object_ends_the_read_task() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("abandoned-read").await;
+    let key = "payloads/abandoned.bin";
+    manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(vec![0x5A; 1024 * 1024])]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    let metrics = tokio::runtime::Handle::current().metrics();
+    let idle_tasks = metrics.num_alive_tasks();
+    let (_, mut stream) = manager
+        .get_object(
+            Some(claims.clone()),
+            bucket.name.clone(),
+            key.to_string(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let first = stream.next().await.unwrap().unwrap();
+    assert!(
+        first.len() < 1024 * 1024,
+        "the read must still be in flight"
+    );
+    drop(stream);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while metrics.num_alive_tasks() > idle_tasks {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .expect("the read task must exit once its client is gone");
+}
+
+#[tokio::test]
+async fn object_link_metadata_head_and_read_use_core_store_metadata() {
+    let (_temp, manager, bucket, target, link, claims) = seeded_core_store_link().await;
+
+    let current = manager
+        .read_object_link(Some(claims.clone()), &bucket.name, &link.key, None)
+        .await
+        .unwrap();
+    assert_eq!(current.link_key, link.key);
+    assert_eq!(current.target_key, target.key);
+    assert_eq!(current.generation, 1);
+
+    let versioned = manager
+        .read_object_link(
+            Some(claims.clone()),
+            &bucket.name,
+            &link.key,
+            Some(link.version_id),
+        )
+        .await
+        .unwrap();
+    assert_eq!(versioned.link_key, link.key);
+    assert_eq!(versioned.target_key, target.key);
+
+    let head = manager
+        .head_object(Some(claims.clone()), &bucket.name, &link.key, None)
+        .await
+        .unwrap();
+    assert_eq!(head.key, target.key);
+    assert_eq!(head.version_id, target.version_id);
+    assert_eq!(head.size, target.size);
+    assert!(head.etag.starts_with("link-follow-"));
+
+    let result = manager
+        .get_object(Some(claims), bucket.name, link.key, None, None)
+        .await
+        .unwrap();
+    let body = collect_stream_bytes(result.1).await.unwrap();
+    assert_eq!(body, b"linked payload");
+    assert_eq!(result.0.key, target.key);
+    assert_eq!(result.2, 0);
+}
+
+#[tokio::test]
+async fn per_bucket_request_metrics_increment_independently() {
+    let (_temp, manager, first_bucket, claims) = seeded_object_manager("metrics").await;
+    // Bucket names are only unique within a tenant, so another tenant's
+    // same-named bucket must be counted separately.
+    let other_tenant = manager
+        .persistence
+        .create_tenant("tenant-b", "tenant-b")
+        .await
+        .unwrap();
+    let second_bucket = manager
+        .persistence
+        .create_bucket(other_tenant.id, &first_bucket.name, "test-region")
+        .await
+        .unwrap();
+    let other_claims = auth::Claims {
+        sub: "other-app".to_string(),
+        exp: usize::MAX,
+        tenant_id: other_tenant.id,
+        jti: None,
+    };
+    access_control::grant_storage_tenant_owner(
+        &manager.persistence,
+        other_tenant.id,
+        &other_claims.sub,
+        "test",
+        "object manager metrics seed",
+    )
+    .await
+    .unwrap();
+    access_control::grant_bucket_defaults(
+        &manager.persistence,
+        &second_bucket,
+        &other_claims.sub,
+        "test",
+        "object manager metrics seed",
+    )
+    .await
+    .unwrap();
+
+    for (claims, payload) in [
+        (&claims, b"first".to_vec()),
+        (&claims, b"second".to_vec()),
+        (&other_claims, b"third!!".to_vec()),
+    ] {
+        manager
+            .put_object(
+                claims,
+                &first_bucket.name,
+                "metrics/object.txt",
+                tokio_stream::iter(vec![Ok(payload)]),
+                ObjectWriteOptions::default(),
+            )
+            .await
+            .unwrap();
+    }
+    let result = manager
+        .get_object(
+            Some(other_claims.clone()),
+            second_bucket.name.clone(),
+            "metrics/object.txt".to_string(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(collect_stream_bytes(result.1).await.unwrap(), b"third!!");
+
+    let snapshot = manager.observability.snapshot();
+    let sample = |metric: &str, bucket: &Bucket, operation: &str| {
+        snapshot
+            .get(&crate::observability::MetricKey {
+                name: metric.to_string(),
+                labels: [
+                    (
+                        "bucket".to_string(),
+                        format!("{}/{}", bucket.tenant_id, bucket.name),
+                    ),
+                    ("operation".to_string(), operation.to_string()),
+                ]
+                .into_iter()
+                .collect(),
+            })
+            .cloned()
+            .unwrap_or_default()
+    };
+    assert_eq!(sample(OBJECT_REQUEST_COUNT, &first_bucket, "put").count, 2);
+    assert_eq!(sample(OBJECT_REQUEST_COUNT, &second_bucket, "put").count, 1);
+    assert_eq!(sample(OBJECT_REQUEST_COUNT, &first_bucket, "get").count, 0);
+    assert_eq!(sample(OBJECT_REQUEST_COUNT, &second_bucket, "get").count, 1);
+    assert_eq!(
+        sample(OBJECT_REQUEST_BYTES, &first_bucket, "put").sum_bytes,
+        11
+    );
+    assert_eq!(
+        sample(OBJECT_REQUEST_BYTES, &second_bucket, "put").sum_bytes,
+        7
+    );
+    assert_eq!(
+        sample(OBJECT_REQUEST_BYTES, &second_bucket, "get").sum_bytes,
+        7
+    );
+}
+
+#[tokio::test]
+async fn get_object_resolves_same_named_buckets_within_the_caller_tenant() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("shared-name").await;
+    let key = "tenant/secret.txt";
+    manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(b"tenant-a".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    let other_tenant = manager
+        .persistence
+        .create_tenant("tenant-b", "tenant-b")
+        .await
+        .unwrap();
+    let other_bucket = manager
+        .persistence
+        .create_bucket(other_tenant.id, &bucket.name, "test-region")
+        .await
+        .unwrap();
+    let other_claims = auth::Claims {
+        sub: "other-app".to_string(),
+        exp: usize::MAX,
+        tenant_id: other_tenant.id,
+        jti: None,
+    };
+    access_control::grant_storage_tenant_owner(
+        &manager.persistence,
+        other_tenant.id,
+        &other_claims.sub,
+        "test",
+        "cross tenant seed",
+    )
+    .await
+    .unwrap();
+    access_control::grant_bucket_defaults(
+        &manager.persistence,
+        &other_bucket,
+        &other_claims.sub,
+        "test",
+        "cross tenant seed",
+    )
+    .await
+    .unwrap();
+
+    let missing = manager
+        .get_object(
+            Some(other_claims.clone()),
+            bucket.name.clone(),
+            key.to_string(),
+            None,
+            None,
+        )
+        .await
+        .err()
+        .expect("another tenant's same-named bucket must not be readable");
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+
+    let routed = manager
+        .get_object_with_link_mode_for_tenant(
+            Some(other_claims.clone()),
+            Some(claims.tenant_id),
+            bucket.name.clone(),
+            key.to_string(),
+            None,
+            None,
+            ObjectLinkReadMode::Follow,
+            ObjectReadConsistency::Latest,
+        )
+        .await
+        .err()
+        .expect("claims must not be usable against another tenant route");
+    assert_eq!(routed.code(), tonic::Code::PermissionDenied);
+
+    manager
+        .put_object(
+            &other_claims,
+            &other_bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(b"tenant-b".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+    for (caller, expected) in [(&claims, b"tenant-a"), (&other_claims, b"tenant-b")] {
+        let result = manager
+            .get_object(
+                Some(caller.clone()),
+                bucket.name.clone(),
+                key.to_string(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(collect_stream_bytes(result.1).await.unwrap(), expected);
+    }
+}
+
+#[tokio::test]
+async fn admin_copy_object_moves_objects_between_tenants_under_destination_authz() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("migration-source").await;
+    manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "models/weights.bin",
+            tokio_stream::iter(vec![Ok(b"weights".to_vec())]),
+            ObjectWriteOptions {
+                user_metadata: Some(serde_json::json!({"family": "llama"})),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let destination_tenant = manager
+        .persistence
+        .create_tenant("tenant-b", "tenant-b")
+        .await
+        .unwrap();
+    let destination_bucket = manager
+        .persistence
+        .create_bucket(destination_tenant.id, "migration-target", "test-region")
+        .await
+        .unwrap();
+    let destination_claims = auth::Claims {
+        sub: "migrated-app".to_string(),
+        exp: usize::MAX,
+        tenant_id: destination_tenant.id,
+        jti: None,
+    };
+    access_control::grant_storage_tenant_owner(
+        &manager.persistence,
+        destination_tenant.id,
+        &destination_claims.sub,
+        "test",
+        "admin copy seed",
+    )
+    .await
+    .unwrap();
+    access_control::grant_bucket_defaults(
+        &manager.persistence,
+        &destination_bucket,
+        &destination_claims.sub,
+        "test",
+        "admin copy seed",
+    )
+    .await
+    .unwrap();
+
+    let copied = manager
+        .admin_copy_object(
+            claims.tenant_id,
+            &bucket.name,
+            "models/weights.bin",
+            None,
+            destination_tenant.id,
+            &destination_bucket.name,
+            "imported/weights.bin",
+            "admin-principal",
+        )
+        .await
+        .unwrap();
+    assert_eq!(copied.size, 7);
+
+    let (object, stream, _) = manager
+        .get_object(
+            Some(destination_claims.clone()),
+            destination_bucket.name.clone(),
+            "imported/weights.bin".to_string(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(collect_stream_bytes(stream).await.unwrap(), b"weights");
+    assert_eq!(
+        object.user_meta,
+        Some(serde_json::json!({"family": "llama"}))
+    );
+
+    let stranger = auth::Claims {
+        sub: "unrelated-app".to_string(),
+        ..destination_claims.clone()
+    };
+    let denied = manager
+        .get_object(
+            Some(stranger),
+            destination_bucket.name.clone(),
+            "imported/weights.bin".to_string(),
+            None,
+            None,
+        )
+        .await
+        .err()
+        .expect("copied objects follow the destination bucket's grants");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+
+    let missing = manager
+        .admin_copy_object(
+            claims.tenant_id,
+            &bucket.name,
+            "models/absent.bin",
+            None,
+            destination_tenant.id,
+            &destination_bucket.name,
+            "imported/absent.bin",
+            "admin-principal",
+        )
+        .await
+        .err()
+        .expect("missing sources are reported");
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+}
+
+#[tokio::test]
+async fn put_object_records_phase_timings_only_when_enabled() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("put-timings").await;
+    let phase_count = |manager: &ObjectManager, phase: &str| {
+        manager
+            .observability
+            .snapshot()
+            .get(&crate::observability::MetricKey {
+                name: OBJECT_PUT_PHASE_LATENCY.to_string(),
+                labels: [("phase".to_string(), phase.to_string())]
+                    .into_iter()
+                    .collect(),
+            })
+            .map_or(0, |sample| sample.count)
+    };
+
+    manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "timings/quiet.txt",
+            tokio_stream::iter(vec![Ok(b"quiet".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(phase_count(&manager, "total"), 0);
+
+    let manager = manager.with_put_timing_breakdown(true);
+    manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "timings/profiled.txt",
+            tokio_stream::iter(vec![Ok(b"profiled".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+    for phase in [
+        "authorize",
+        "receive",
+        "payload_write",
+        "metadata_commit",
+        "follow_up",
+        "total",
+    ] {
+        assert_eq!(phase_count(&manager, phase), 1, "missing {phase} timing");
+    }
+}
+
+#[tokio::test]
+async fn list_objects_serves_repeated_queries_from_cache_until_a_write() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("list-cache").await;
+    let manager = manager.with_listing_cache(Duration::from_secs(60), 16);
+    let cache_count = |manager: &ObjectManager, result: &str| {
+        manager
+            .observability
+            .snapshot()
+            .get(&crate::observability::MetricKey {
+                name: OBJECT_LIST_CACHE_RESULTS.to_string(),
+                labels: [("result".to_string(), result.to_string())]
+                    .into_iter()
+                    .collect(),
+            })
+            .map_or(0, |sample| sample.count)
+    };
+    let put = |key: &'static str| {
+        manager.put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(b"cached".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+    };
+    let list = || {
+        manager.list_objects_for_tenant(
+            Some(claims.clone()),
+            None,
+            &bucket.name,
+            "docs/",
+            "",
+            100,
+            "",
+            ObjectReadConsistency::Latest,
+        )
+    };
+
+    put("docs/a.txt").await.unwrap();
+    let (first, _) = list().await.unwrap();
+    let (second, _) = list().await.unwrap();
+    assert_eq!(first.len(), 1);
+    assert_eq!(second.len(), 1);
+    assert_eq!(cache_count(&manager, "miss"), 1);
+    assert_eq!(cache_count(&manager, "hit"), 1);
+
+    put("docs/b.txt").await.unwrap();
+    let (after_put, _) = list().await.unwrap();
+    assert_eq!(
+        after_put
+            .iter()
+            .map(|object| object.key.as_str())
+            .collect::<Vec<_>>(),
+        vec!["docs/a.txt", "docs/b.txt"]
+    );
+    assert_eq!(cache_count(&manager, "miss"), 2);
+    assert_eq!(cache_count(&manager, "hit"), 1);
+}
+
+#[tokio::test]
+async fn bucket_policy_grants_anonymous_reads_only_under_allowed_prefixes() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("policy-models").await;
+    for key in [
+        "public/config.json",
+        "public/embargoed/weights.bin",
+        "private/weights.bin",
+    ] {
+        manager
+            .put_object(
+                &claims,
+                &bucket.name,
+                key,
+                tokio_stream::iter(vec![Ok(b"model".to_vec())]),
+                ObjectWriteOptions::default(),
+            )
+            .await
+            .unwrap();
+    }
+    let policy = serde_json::json!({
+        "Version": "2012-10-17",
+        "Statement": [
+            {
+                "Effect": "Allow",
+                "Principal": "*",
+                "Action": "s3:GetObject",
+                "Resource": "arn:aws:s3:::policy-models/public/*"
+            },
+            {
+                "Effect": "Deny",
+                "Principal": "*",
+                "Action": "s3:GetObject",
+                "Resource": "arn:aws:s3:::policy-models/public/embargoed/*"
+            }
+        ]
+    });
+    manager
+        .persistence
+        .set_bucket_policy(claims.tenant_id, &bucket.name, Some(policy.to_string()))
+        .await
+        .unwrap();
+    let anonymous_read = |key: &'static str| {
+        manager.get_object_with_link_mode_for_tenant(
+            None,
+            Some(claims.tenant_id),
+            bucket.name.clone(),
+            key.to_string(),
+            None,
+            None,
+            ObjectLinkReadMode::Follow,
+            ObjectReadConsistency::Latest,
+        )
+    };
+
+    let allowed = anonymous_read("public/config.json").await.unwrap();
+    assert_eq!(
+        collect_stream_bytes(allowed.stream).await.unwrap(),
+        b"model"
+    );
+    for key in ["public/embargoed/weights.bin", "private/weights.bin"] {
+        let denied = anonymous_read(key)
+            .await
+            .err()
+            .expect("policy must not grant anonymous reads outside public/");
+        assert_eq!(denied.code(), tonic::Code::PermissionDenied, "{key}");
+    }
+
+    let owner_read = |key: &'static str| {
+        manager.get_object(
+            Some(claims.clone()),
+            bucket.name.clone(),
+            key.to_string(),
+            None,
+            None,
+        )
+    };
+    owner_read("private/weights.bin").await.unwrap();
+    let denied = owner_read("public/embargoed/weights.bin")
+        .await
+        .err()
+        .expect("a policy deny must override the owner's grants");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+}
+
+#[tokio::test]
+async fn put_object_lowercases_user_metadata_and_enforces_the_size_limit() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("user-meta").await;
+    let put = |key: &'static str, user_metadata: JsonValue| {
+        manager.put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(b"meta".to_vec())]),
+            ObjectWriteOptions {
+                user_metadata: Some(user_metadata),
+                ..Default::default()
+            },
+        )
+    };
+
+    put("tagged.json", serde_json::json!({"Model-Family": "llama"}))
+        .await
+        .unwrap();
+    let head = manager
+        .head_object(Some(claims.clone()), &bucket.name, "tagged.json", None)
+        .await
+        .unwrap();
+    assert_eq!(
+        head.user_meta,
+        Some(serde_json::json!({"model-family": "llama"}))
+    );
+
+    let oversized = "x".repeat(MAX_USER_METADATA_BYTES);
+    let error = put("oversized.json", serde_json::json!({"note": oversized}))
+        .await
+        .expect_err("metadata over 2 KB must be rejected");
+    assert_eq!(error.code(), tonic::Code::InvalidArgument);
+    assert!(error.message().starts_with("MetadataTooLarge"), "{error:?}");
+}
+
+#[tokio::test]
+async fn put_object_infers_content_type_from_the_key_when_absent() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("content-types").await;
+    for (key, content_type, expected) in [
+        ("model/config.json", None, Some("application/json")),
+        (
+            "model/weights.safetensors",
+            None,
+            Some("application/octet-stream"),
+        ),
+        ("model/README", None, None),
+        ("model/notes.json", Some("text/plain"), Some("text/plain")),
+    ] {
+        manager
+            .put_object(
+                &claims,
+                &bucket.name,
+                key,
+                tokio_stream::iter(vec![Ok(b"{}".to_vec())]),
+                ObjectWriteOptions {
+                    content_type: content_type.map(ToString::to_string),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let head = manager
+            .head_object(Some(claims.clone()), &bucket.name, key, None)
+            .await
+            .unwrap();
+        assert_eq!(head.content_type.as_deref(), expected, "{key}");
+    }
+}
+
+#[tokio::test]
+async fn put_object_rejects_bodies_that_do_not_match_declared_size() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("declared-size").await;
+    let key = "uploads/declared.bin";
+
+    for (declared, chunks) in [
+        (10, vec![Ok(b"short".to_vec())]),
+        (4, vec![Ok(b"too".to_vec()), Ok(b" long".to_vec())]),
+    ] {
+        let error = manager
+            .put_object(
+                &claims,
+                &bucket.name,
+                key,
+                tokio_stream::iter(chunks),
+                ObjectWriteOptions {
+                    expected_size: Some(declared),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect_err("mismatched declared size must be rejected");
+        assert_eq!(error.code(), tonic::Code::InvalidArgument);
+        assert!(error.message().starts_with("IncompleteBody"));
+    }
+    let missing = manager
+        .head_object(Some(claims.clone()), &bucket.name, key, None)
+        .await
+        .expect_err("rejected uploads must not create an object");
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+
+    let object = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(b"exact".to_vec())]),
+            ObjectWriteOptions {
+                expected_size: Some(5),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(object.size, 5);
+}
+
+#[tokio::test]
+async fn put_object_rejects_objects_over_the_size_cap() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("size-cap").await;
+    let manager = manager.with_max_object_size(8);
+    let key = "uploads/capped.bin";
+
+    for (declared, chunks) in [
+        (Some(9), vec![Ok(b"nine byte".to_vec())]),
+        (
+            None,
+            vec![
+                Ok(b"four".to_vec()),
+                Ok(b"four".to_vec()),
+                Ok(b"over".to_vec()),
+                Err(Status::internal("body read past the size cap")),
+            ],
+        ),
+    ] {
+        let error = manager
+            .put_object(
+                &claims,
+                &bucket.name,
+                key,
+                tokio_stream::iter(chunks),
+                ObjectWriteOptions {
+                    expected_size: declared,
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect_err("oversize objects must be rejected");
+        assert_eq!(error.code(), tonic::Code::InvalidArgument);
+        assert!(error.message().starts_with("EntityTooLarge"), "{error:?}");
+    }
+    let missing = manager
+        .head_object(Some(claims.clone()), &bucket.name, key, None)
+        .await
+        .expect_err("rejected uploads must not create an object");
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+
+    let object = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(b"eight by".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(object.size, 8);
+}
+
+#[tokio::test]
+async fn locked_objects_cannot_be_deleted_or_overwritten_until_retention_ends() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("object-lock").await;
+    let key = "records/ledger.json";
+    let put = |payload: &'static [u8], retention| {
+        manager.put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(payload.to_vec())]),
+            ObjectWriteOptions {
+                retention,
+                ..Default::default()
+            },
+        )
+    };
+    let delete = || {
+        manager.delete_object(
+            &claims,
+            &bucket.name,
+            key,
+            None,
+            None,
+            ObjectWriteVisibility::default(),
+        )
+    };
+
+    let retain_until = chrono::Utc::now() + chrono::Duration::seconds(2);
+    let locked = put(
+        b"v1",
+        Some(ObjectRetention {
+            retain_until: Some(retain_until),
+            legal_hold: false,
+        }),
+    )
+    .await
+    .unwrap();
+    let head = manager
+        .head_object(Some(claims.clone()), &bucket.name, key, None)
+        .await
+        .unwrap();
+    assert_eq!(head.retention, locked.retention);
+
+    let denied = delete()
+        .await
+        .expect_err("locked objects cannot be deleted");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+    assert!(denied.message().starts_with("AccessDenied"), "{denied:?}");
+    let denied = manager
+        .delete_object_version(
+            &claims,
+            &bucket.name,
+            key,
+            locked.version_id,
+            None,
+            None,
+            ObjectWriteVisibility::default(),
+        )
+        .await
+        .expect_err("locked versions cannot be removed");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+    let denied = put(b"v2", None)
+        .await
+        .expect_err("locked objects cannot be overwritten");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+    let denied = manager
+        .put_object_retention(&claims, &bucket.name, key, None)
+        .await
+        .expect_err("active retention cannot be removed");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+
+    tokio::time::sleep(
+        (retain_until - chrono::Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+    .await;
+    let held = manager
+        .put_object_retention(
+            &claims,
+            &bucket.name,
+            key,
+            Some(ObjectRetention {
+                retain_until: None,
+                legal_hold: true,
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(held.version_id, locked.version_id);
+    let denied = delete().await.expect_err("legal hold blocks deletes");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+
+    manager
+        .put_object_retention(&claims, &bucket.name, key, None)
+        .await
+        .unwrap();
+    let delete_marker = delete().await.unwrap();
+    assert!(delete_marker.deleted_at.is_some());
+}
+
+#[tokio::test]
+async fn bucket_default_retention_protects_new_objects_for_the_configured_days() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("default-retention").await;
+    manager
+        .persistence
+        .set_bucket_default_retention(bucket.tenant_id, &bucket.name, Some(30))
+        .await
+        .unwrap();
+    let put = |key: &'static str, retention| {
+        manager.put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(b"ledger".to_vec())]),
+            ObjectWriteOptions {
+                retention,
+                ..Default::default()
+            },
+        )
+    };
+
+    let before = chrono::Utc::now();
+    let defaulted = put("records/defaulted.json", None).await.unwrap();
+    let retain_until = defaulted
+        .retention
+        .and_then(|retention| retention.retain_until)
+        .expect("the bucket default applies to writes without retention");
+    assert!(retain_until >= before + chrono::Duration::days(30));
+    assert!(retain_until <= chrono::Utc::now() + chrono::Duration::days(30));
+    let denied = manager
+        .delete_object(
+            &claims,
+            &bucket.name,
+            "records/defaulted.json",
+            None,
+            None,
+            ObjectWriteVisibility::default(),
+        )
+        .await
+        .expect_err("objects under the bucket default cannot be deleted");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+    let denied = put("records/defaulted.json", None)
+        .await
+        .expect_err("objects under the bucket default cannot be overwritten");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+
+    let requested = ObjectRetention {
+        retain_until: None,
+        legal_hold: true,
+    };
+    let overridden = put("records/held.json", Some(requested)).await.unwrap();
+    assert_eq!(overridden.retention, Some(requested));
+}
+
+#[tokio::test]
+async fn copy_compose_and_multipart_complete_cannot_replace_a_locked_object() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("object-lock-writes").await;
+    let key = "records/held.json";
+    let locked = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(b"v1".to_vec())]),
+            ObjectWriteOptions {
+                retention: Some(ObjectRetention {
+                    retain_until: None,
+                    legal_hold: true,
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let source_key = "records/source.json";
+    manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            source_key,
+            tokio_stream::iter(vec![Ok(b"copy".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+    let denied = manager
+        .copy_object(
+            claims.clone(),
+            &bucket.name,
+            source_key,
+            None,
+            &bucket.name,
+            key,
+            None,
+            None,
+        )
+        .await
+        .expect_err("locked objects cannot be copied over");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+    let denied = manager
+        .compose_object(
+            claims.clone(),
+            vec![ComposeSource {
+                bucket_name: bucket.name.clone(),
+                object_key: source_key.to_string(),
+                version_id: None,
+            }],
+            &bucket.name,
+            key,
+            None,
+        )
+        .await
+        .expect_err("locked objects cannot be composed over");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+    let upload = manager
+        .initiate_multipart_upload(&claims, &bucket.name, key, None, None)
+        .await
+        .unwrap();
+    let part = manager
+        .upload_part(
+            &claims,
+            &bucket.name,
+            key,
+            upload.upload_id,
+            1,
+            tokio_stream::iter(vec![Ok(b"v2".to_vec())]),
+            UploadPartOptions::default(),
+        )
+        .await
+        .unwrap();
+    let denied = manager
+        .complete_multipart_upload(
+            &claims,
+            &bucket.name,
+            key,
+            upload.upload_id,
+            vec![CompleteMultipartPart {
+                part_number: 1,
+                etag: part.etag,
+            }],
+            None,
+            None,
+        )
+        .await
+        .expect_err("locked objects cannot be replaced by a multipart upload");
+    assert_eq!(denied.code(), tonic::Code::PermissionDenied);
+    let current = manager
+        .head_object(Some(claims.clone()), &bucket.name, key, None)
+        .await
+        .unwrap();
+    assert_eq!(current.version_id, locked.version_id);
+}
+
+#[tokio::test]
+async fn complete_multipart_upload_rejects_small_non_final_parts() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("multipart-min").await;
+    let manager = manager.with_multipart_min_part_size(8);
+    let key = "uploads/assembled.bin";
+
+    let upload_parts = |payloads: Vec<&'static [u8]>| {
+        let manager = manager.clone();
+        let bucket_name = bucket.name.clone();
+        let claims = claims.clone();
+        async move {
+            let upload = manager
+                .initiate_multipart_upload(&claims, &bucket_name, key, None, None)
+                .await
+                .unwrap();
+            let mut parts = Vec::new();
+            for (index, payload) in payloads.into_iter().enumerate() {
+                let part_number = i32::try_from(index + 1).unwrap();
+                let uploaded = manager
+                    .upload_part(
+                        &claims,
+                        &bucket_name,
+                        key,
+                        upload.upload_id,
+                        part_number,
+                        tokio_stream::iter(vec![Ok(payload.to_vec())]),
+                        UploadPartOptions::default(),
+                    )
+                    .await
+                    .unwrap();
+                parts.push(CompleteMultipartPart {
+                    part_number,
+                    etag: uploaded.etag,
+                });
+            }
+            (upload.upload_id, parts)
+        }
+    };
+
+    let (upload_id, parts) = upload_parts(vec![b"tiny", b"tail"]).await;
+    let error = manager
+        .complete_multipart_upload(&claims, &bucket.name, key, upload_id, parts, None, None)
+        .await
+        .expect_err("small non-final parts must be rejected");
+    assert_eq!(error.code(), tonic::Code::InvalidArgument);
+    assert!(error.message().starts_with("EntityTooSmall"));
+
+    let (upload_id, parts) = upload_parts(vec![b"eight by", b"!"]).await;
+    let object = manager
+        .complete_multipart_upload(&claims, &bucket.name, key, upload_id, parts, None, None)
+        .await
+        .unwrap();
+    assert_eq!(object.size, 9);
+}
+
+#[tokio::test]
+async fn multipart_completion_records_s3_compatible_etag() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("multipart-etag").await;
+    let manager = manager.with_multipart_min_part_size(8);
+    let key = "uploads/etag.bin";
+    let payloads: [&[u8]; 2] = [b"first part", b"second"];
+
+    let upload = manager
+        .initiate_multipart_upload(&claims, &bucket.name, key, None, None)
+        .await
+        .unwrap();
+    let mut parts = Vec::new();
+    let mut part_md5s = Vec::new();
+    for (index, payload) in payloads.iter().enumerate() {
+        let part_number = i32::try_from(index + 1).unwrap();
+        let uploaded = manager
+            .upload_part(
+                &claims,
+                &bucket.name,
+                key,
+                upload.upload_id,
+                part_number,
+                tokio_stream::iter(vec![Ok(payload.to_vec())]),
+                UploadPartOptions::default(),
+            )
+            .await
+            .unwrap();
+        let md5: [u8; 16] = md5::Md5::digest(payload).into();
+        assert_eq!(uploaded.etag, hex::encode(md5));
+        assert!(uploaded.payload_hash.starts_with("sha256:"));
+        part_md5s.extend_from_slice(&md5);
+        parts.push(CompleteMultipartPart {
+            part_number,
+            etag: format!("\"{}\"", uploaded.etag),
+        });
+    }
+
+    let object = manager
+        .complete_multipart_upload(
+            &claims,
+            &bucket.name,
+            key,
+            upload.upload_id,
+            parts,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let expected = format!("{}-2", hex::encode(md5::Md5::digest(&part_md5s)));
+    assert_eq!(object.etag, expected);
+    assert!(object.content_hash.starts_with("sha256:"));
+    assert_ne!(object.etag, object.content_hash);
+}
+
+#[tokio::test]
+async fn upload_part_rejects_parts_that_do_not_match_declared_digests() {
+    use crate::object_checksum::{ChecksumAlgorithm, ChecksumHasher, ObjectChecksum};
+
+    let (_temp, manager, bucket, claims) = seeded_object_manager("multipart-digest").await;
+    let key = "uploads/digest.bin";
+    let payload = b"part payload";
+    let mut hasher = ChecksumHasher::new(ChecksumAlgorithm::Crc32c);
+    hasher.update(payload);
+    let crc32c = hasher.finish();
+    let upload = manager
+        .initiate_multipart_upload(&claims, &bucket.name, key, None, None)
+        .await
+        .unwrap();
+    let upload_part = |options: UploadPartOptions| {
+        manager.upload_part(
+            &claims,
+            &bucket.name,
+            key,
+            upload.upload_id,
+            1,
+            tokio_stream::iter(vec![Ok(payload.to_vec())]),
+            options,
+        )
+    };
+
+    for options in [
+        UploadPartOptions {
+            expected_md5: Some([0; 16]),
+            ..Default::default()
+        },
+        UploadPartOptions {
+            expected_sha256: Some("0".repeat(64)),
+            ..Default::default()
+        },
+        UploadPartOptions {
+            expected_checksum: ObjectChecksum::from_digest(ChecksumAlgorithm::Crc32c, &[0; 4]),
+            ..Default::default()
+        },
+    ] {
+        let mismatch = upload_part(options)
+            .await
+            .err()
+            .expect("a part that does not match its declared digest must be rejected");
+        assert_eq!(mismatch.code(), tonic::Code::InvalidArgument);
+        assert!(mismatch.message().starts_with("BadDigest"));
+    }
+    let upload_row = manager
+        .persistence
+        .get_active_multipart_upload(claims.tenant_id, bucket.id, key, upload.upload_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(
+        manager
+            .persistence
+            .list_multipart_parts(upload_row.id)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+
+    let md5: [u8; 16] = md5::Md5::digest(payload).into();
+    let uploaded = upload_part(UploadPartOptions {
+        expected_md5: Some(md5),
+        expected_sha256: Some(hex::encode(sha2::Sha256::digest(payload))),
+        expected_checksum: Some(crc32c),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    assert_eq!(uploaded.etag, hex::encode(md5));
+    assert_eq!(uploaded.checksum, Some(crc32c));
+    let parts = manager
+        .persistence
+        .list_multipart_parts(upload_row.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        parts[0]
+            .checksum
+            .as_deref()
+            .and_then(ObjectChecksum::decode),
+        Some(crc32c)
+    );
+}
+
+#[tokio::test]
+async fn put_object_enforces_if_match_and_if_none_match_conditions() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("conditional").await;
+    let key = "docs/conditional.txt";
+    let put = |payload: &'static [u8], conditions: ObjectConditions| {
+        let manager = manager.clone();
+        let bucket_name = bucket.name.clone();
+        let claims = claims.clone();
+        async move {
+            manager
+                .put_object(
+                    &claims,
+                    &bucket_name,
+                    key,
+                    tokio_stream::iter(vec![Ok(payload.to_vec())]),
+                    ObjectWriteOptions {
+                        conditions,
+                        ..Default::default()
+                    },
+                )
+                .await
+        }
+    };
+    let create_only = || ObjectConditions {
+        if_none_match: Some("*".to_string()),
+        ..Default::default()
+    };
+
+    let created = put(b"first", create_only()).await.unwrap();
+    let error = put(b"second", create_only())
+        .await
+        .expect_err("If-None-Match: * must not overwrite an existing key");
+    assert_eq!(error.code(), tonic::Code::FailedPrecondition);
+    assert!(error.message().starts_with("PreconditionFailed"));
+
+    let stale = put(
+        b"second",
+        ObjectConditions {
+            if_match: Some("\"not-the-etag\"".to_string()),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect_err("If-Match with a stale ETag must be rejected");
+    assert_eq!(stale.code(), tonic::Code::FailedPrecondition);
+
+    let updated = put(
+        b"second",
+        ObjectConditions {
+            if_match: Some(format!("\"{}\"", created.etag)),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    assert_ne!(updated.version_id, created.version_id);
+
+    let conditions = ObjectConditions {
+        if_none_match: Some(format!("W/\"{}\"", updated.etag)),
+        ..Default::default()
+    };
+    assert_eq!(
+        conditions.check_read(&updated).unwrap(),
+        ConditionalRead::NotModified
+    );
+    assert_eq!(
+        conditions.check_read(&created).unwrap(),
+        ConditionalRead::Proceed
+    );
+    let mismatch = ObjectConditions {
+        if_match: Some(created.etag.clone()),
+        ..Default::default()
+    };
+    assert_eq!(
+        mismatch.check_read(&updated).unwrap_err().code(),
+        tonic::Code::FailedPrecondition
+    );
+}
+
+#[tokio::test]
+async fn conditional_commit_rejects_a_version_replaced_since_the_check() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("conditional-cas").await;
+    let key = "docs/cas.txt";
+    let put = |payload: &'static [u8]| {
+        manager.put_object(
+            &claims,
+            &bucket.name,
+            key,
+            tokio_stream::iter(vec![Ok(payload.to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+    };
+    let checked = put(b"first").await.unwrap();
+    put(b"second").await.unwrap();
+
+    let error = manager
+        .persistence
+        .create_object_with_storage_class_with_options(
+            bucket.tenant_id,
+            bucket.id,
+            key,
+            &checked.content_hash,
+            checked.size,
+            &checked.etag,
+            None,
+            None,
+            checked.shard_map.clone(),
+            None,
+            None,
+            None,
+            checked.storage_class.clone(),
+            crate::persistence::ObjectCreateOptions {
+                expected_current: ExpectedCurrentVersion::Version(checked.version_id),
+                ..Default::default()
+            },
+        )
+        .await
+        .expect_err("the commit must see that the checked version was replaced");
+    assert!(crate::persistence::ObjectChangedError::find(&error).is_some());
+}
+
+#[tokio::test]
+async fn delimiter_listing_resumes_after_a_returned_common_prefix() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("delimiter-resume").await;
+    for key in [
+        "photos/2024.01/a.jpg",
+        "photos/2024.01/b.jpg",
+        "photos/📷 raw.txt",
+    ] {
+        manager
+            .put_object(
+                &claims,
+                &bucket.name,
+                key,
+                tokio_stream::iter(vec![Ok(b"x".to_vec())]),
+                ObjectWriteOptions::default(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let (objects, prefixes) = manager
+        .list_objects(Some(claims.clone()), &bucket.name, "photos/", "", 1, "/")
+        .await
+        .unwrap();
+    assert!(objects.is_empty());
+    assert_eq!(prefixes, vec!["photos/2024.01/"]);
+
+    let (objects, prefixes) = manager
+        .list_objects(
+            Some(claims.clone()),
+            &bucket.name,
+            "photos/",
+            "photos/2024.01/",
+            10,
+            "/",
+        )
+        .await
+        .unwrap();
+    assert!(prefixes.is_empty());
+    assert_eq!(
+        objects
+            .iter()
+            .map(|object| object.key.as_str())
+            .collect::<Vec<_>>(),
+        vec!["photos/📷 raw.txt"]
+    );
+}
+
+#[tokio::test]
+async fn lifecycle_sweep_expires_old_objects_under_rule_prefixes_in_batches() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("scratch").await;
+    for key in ["tmp/a", "tmp/b", "tmp/c", "keep/d"] {
+        manager
+            .put_object(
+                &claims,
+                &bucket.name,
+                key,
+                tokio_stream::iter(vec![Ok(b"scratch".to_vec())]),
+                ObjectWriteOptions::default(),
+            )
+            .await
+            .unwrap();
+    }
+    let rules = crate::bucket_lifecycle::encode_lifecycle_rules(&[
+        crate::bucket_lifecycle::LifecycleRule {
+            prefix: "tmp/".to_string(),
+            expiration_days: 7,
+        },
+    ])
+    .unwrap();
+    manager
+        .persistence
+        .set_bucket_lifecycle(claims.tenant_id, &bucket.name, rules)
+        .await
+        .unwrap();
+
+    let fresh = manager
+        .run_lifecycle_sweep(&LifecycleSweepCursor::default(), 10, chrono::Utc::now())
+        .await
+        .unwrap();
+    assert_eq!((fresh.scanned_objects, fresh.expired_objects), (3, 0));
+    assert_eq!(fresh.next, None);
+
+    let later = chrono::Utc::now() + chrono::Duration::days(8);
+    let first = manager
+        .run_lifecycle_sweep(&LifecycleSweepCursor::default(), 2, later)
+        .await
+        .unwrap();
+    assert_eq!((first.scanned_objects, first.expired_objects), (2, 2));
+    let next = first
+        .next
+        .expect("a partial batch must hand over its cursor");
+    assert_eq!(
+        next,
+        LifecycleSweepCursor {
+            bucket_id: bucket.id,
+            rule: 0,
+            start_after: "tmp/b".to_string(),
+        }
+    );
+    let second = manager.run_lifecycle_sweep(&next, 2, later).await.unwrap();
+    assert_eq!((second.scanned_objects, second.expired_objects), (1, 1));
+    assert_eq!(second.next, None);
+
+    for key in ["tmp/a", "tmp/b", "tmp/c"] {
+        let expired = manager
+            .head_object(Some(claims.clone()), &bucket.name, key, None)
+            .await
+            .expect_err("expired objects must be deleted");
+        assert_eq!(expired.code(), tonic::Code::NotFound, "{key}");
+    }
+    manager
+        .head_object(Some(claims.clone()), &bucket.name, "keep/d", None)
+        .await
+        .expect("objects outside every rule prefix are kept");
+}
+
+#[tokio::test]
+async fn lifecycle_expiry_keeps_a_version_written_after_the_listing() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("scratch-race").await;
+    let put = |body: &'static [u8]| {
+        manager.put_object(
+            &claims,
+            &bucket.name,
+            "tmp/report",
+            tokio_stream::iter(vec![Ok(body.to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+    };
+    put(b"stale").await.unwrap();
+    let (listed, _) = manager
+        .persistence
+        .list_objects(bucket.id, "tmp/", "", 10, "")
+        .await
+        .unwrap();
+    let fresh = put(b"fresh").await.unwrap();
+
+    assert!(!manager.expire_object(&bucket, &listed[0]).await.unwrap());
+    let current = manager
+        .head_object(Some(claims.clone()), &bucket.name, "tmp/report", None)
+        .await
+        .expect("a version written after the listing must not be expired");
+    assert_eq!(current.version_id, fresh.version_id);
+
+    assert!(manager.expire_object(&bucket, &current).await.unwrap());
+}
+
+#[tokio::test]
+async fn put_object_enqueues_one_replication_task_per_replica_region() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("replicated").await;
+    manager
+        .persistence
+        .set_bucket_replication(
+            bucket.tenant_id,
+            &bucket.name,
+            vec!["eu-west-1".to_string(), "ap-south-1".to_string()],
+        )
+        .await
+        .unwrap();
+    for body in [b"first".to_vec(), b"second".to_vec()] {
+        manager
+            .put_object(
+                &claims,
+                &bucket.name,
+                "doc.txt",
+                tokio_stream::iter(vec![Ok(body)]),
+                ObjectWriteOptions::default(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let mut transfers = manager
+        .persistence
+        .list_tasks()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|task| task.task_type == crate::tasks::TaskType::ReplicateObject)
+        .map(|task| {
+            assert_eq!(task.payload["object_key"], "doc.txt");
+            (
+                task.payload["region"].as_str().unwrap().to_string(),
+                task.payload["version_id"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    transfers.sort();
+    transfers.dedup();
+    // Each overwrite is queued on its own, even while the first is pending.
+    assert_eq!(transfers.len(), 4);
+    assert_eq!(
+        transfers
+            .iter()
+            .map(|(region, _)| region.as_str())
+            .collect::<Vec<_>>(),
+        ["ap-south-1", "ap-south-1", "eu-west-1", "eu-west-1"]
+    );
+}
+
+#[tokio::test]
+async fn deleted_keys_are_not_read_back_from_replica_regions() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("replica-tombstone").await;
+    assert!(
+        !manager
+            .core_store
+            .object_key_has_history(&bucket, "doc.txt")
+            .await
+            .unwrap()
+    );
+    manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "doc.txt",
+            tokio_stream::iter(vec![Ok(b"gone soon".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+    manager
+        .delete_object(
+            &claims,
+            &bucket.name,
+            "doc.txt",
+            None,
+            None,
+            ObjectWriteVisibility::default(),
+        )
+        .await
+        .unwrap();
+
+    // The local tombstone is what stops a stale replica from answering.
+    assert!(
+        manager
+            .core_store
+            .object_key_has_history(&bucket, "doc.txt")
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn replica_puts_reject_a_bucket_that_conflicts_with_a_local_bucket() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("replica-clash").await;
+    let source = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "doc.txt",
+            tokio_stream::iter(vec![Ok(b"bytes".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+    let impostor = Bucket {
+        id: bucket.id + 100,
+        region: "eu-west-1".to_string(),
+        replicate_to: vec!["test-region".to_string()],
+        ..bucket.clone()
+    };
+
+    let status = manager
+        .put_replica_object(
+            impostor,
+            source,
+            tokio_stream::iter(vec![Ok(b"bytes".to_vec())]),
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+}
+
+#[tokio::test]
+async fn replica_objects_are_stored_under_the_home_bucket_and_read_back() {
+    let (_temp, manager, bucket, claims) = seeded_object_manager("replica-source").await;
+    let source = manager
+        .put_object(
+            &claims,
+            &bucket.name,
+            "doc.txt",
+            tokio_stream::iter(vec![Ok(b"replicated bytes".to_vec())]),
+            ObjectWriteOptions::default(),
+        )
+        .await
+        .unwrap();
+    let remote_bucket = Bucket {
+        id: bucket.id + 100,
+        name: "mirrored".to_string(),
+        region: "eu-west-1".to_string(),
+        replicate_to: vec!["test-region".to_string()],
+        ..bucket.clone()
+    };
+
+    let replica = manager
+        .put_replica_object(
+            remote_bucket.clone(),
+            source.clone(),
+            tokio_stream::iter(vec![Ok(b"replicated bytes".to_vec())]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(replica.bucket_id, remote_bucket.id);
+    assert_eq!(replica.etag, source.etag);
+
+    let read = manager
+        .get_replica_object(bucket.tenant_id, "mirrored", "doc.txt", None)
+        .await
+        .unwrap();
+    let bytes = read.stream.map(|chunk| chunk.unwrap()).concat().await;
+    assert_eq!(bytes, b"replicated bytes");
+
+    let status = manager
+        .get_object(
+            Some(claims.clone()),
+            "mirrored".to_string(),
+            "doc.txt".to_string(),
+            None,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(status.code(), tonic::Code::FailedPrecondition);
 }
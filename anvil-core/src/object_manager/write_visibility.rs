@@ -1,4 +1,6 @@
-use crate::persistence::ObjectCreateOptions;
+use super::ObjectConditions;
+use crate::object_checksum::{ChecksumAlgorithm, ObjectChecksum};
+use crate::persistence::{ExpectedCurrentVersion, ObjectCreateOptions, ObjectRetention};
 use serde_json::Value as JsonValue;
 
 #[derive(Debug, Clone, Default)]
@@ -8,9 +10,61 @@ pub struct ObjectWriteOptions {
     pub transaction_id: Option<String>,
     pub transaction_principal: Option<String>,
     pub storage_class_id: Option<String>,
+    /// Declared payload size. When set, the write is rejected unless the
+    /// streamed body is exactly this many bytes.
+    pub expected_size: Option<u64>,
+    /// Declared MD5 of the payload (S3 `Content-MD5`). When set, the write is
+    /// rejected with `BadDigest` unless the streamed body hashes to it.
+    pub expected_md5: Option<[u8; 16]>,
+    /// Declared lowercase hex SHA-256 of the payload (S3
+    /// `x-amz-content-sha256`), checked the same way as `expected_md5`.
+    pub expected_sha256: Option<String>,
+    /// Additional checksum to compute over the payload and record with the
+    /// version (S3 `x-amz-checksum-algorithm`).
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+    /// Declared additional checksum (S3 `x-amz-checksum-<algorithm>`). When
+    /// set, it selects the algorithm to record and the write is rejected with
+    /// `BadDigest` unless the payload matches it.
+    pub expected_checksum: Option<ObjectChecksum>,
+    /// Record the payload's hex MD5 as the object ETag, which S3 clients
+    /// compare against for single-part uploads. Otherwise the ETag is the
+    /// content hash.
+    pub md5_etag: bool,
+    /// Precomputed ETag to record, taking precedence over `md5_etag`.
+    /// Multipart completion uses this for the S3 `<md5>-<parts>` form.
+    pub etag: Option<String>,
+    /// `If-Match` / `If-None-Match` conditions checked against the current
+    /// version before the payload is received and again before commit.
+    pub conditions: ObjectConditions,
+    /// Object-lock settings to record on the new version.
+    pub retention: Option<ObjectRetention>,
     pub visibility: ObjectWriteVisibility,
 }
 
+/// Options for one multipart part. The declared digests are checked like the
+/// ones on [`ObjectWriteOptions`].
+#[derive(Debug, Clone, Default)]
+pub struct UploadPartOptions {
+    pub transaction_id: Option<String>,
+    pub transaction_principal: Option<String>,
+    /// Declared MD5 of the part (S3 `Content-MD5`).
+    pub expected_md5: Option<[u8; 16]>,
+    /// Declared lowercase hex SHA-256 of the part (S3 `x-amz-content-sha256`).
+    pub expected_sha256: Option<String>,
+    /// Additional checksum to compute over the part and record with it.
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+    /// Declared additional checksum of the part (S3 `x-amz-checksum-<algorithm>`).
+    pub expected_checksum: Option<ObjectChecksum>,
+}
+
+/// Metadata recorded on a copy in place of the source's (S3
+/// `x-amz-metadata-directive: REPLACE`).
+#[derive(Debug, Clone, Default)]
+pub struct CopyObjectMetadata {
+    pub content_type: Option<String>,
+    pub user_metadata: Option<JsonValue>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IndexMaintenanceVisibility {
     Deferred,
@@ -101,6 +155,10 @@ impl ObjectWriteVisibility {
                 self.indexes,
                 IndexMaintenanceVisibility::Enqueued | IndexMaintenanceVisibility::CaughtUp
             ),
+            retention: None,
+            checksum: None,
+            requested_storage_class: None,
+            expected_current: ExpectedCurrentVersion::Any,
         }
     }
 
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
@@ -19,6 +19,14 @@ pub const WATCH_STREAM_LAG: &str = "watch_stream_lag";
 pub const PARTITION_RECOVERY_DURATION: &str = "partition_recovery_duration";
 pub const COMPACTION_BACKLOG: &str = "compaction_backlog";
 pub const REPAIR_FINDINGS: &str = "repair_findings";
+pub const OBJECT_REQUEST_COUNT: &str = "object_request_count";
+pub const OBJECT_REQUEST_BYTES: &str = "object_request_bytes";
+pub const OBJECT_PUT_PHASE_LATENCY: &str = "object_put_phase_latency";
+pub const OBJECT_LIST_CACHE_RESULTS: &str = "object_list_cache_results";
+
+/// Label value used for buckets that are not admitted by the bucket label policy.
+pub const OTHER_BUCKET_LABEL: &str = "__other__";
+pub const DEFAULT_BUCKET_LABEL_LIMIT: usize = 256;
 
 pub const REQUIRED_METRICS: &[&str] = &[
     OBJECT_WRITE_LATENCY,
@@ -43,6 +51,29 @@ pub const REQUIRED_METRICS: &[&str] = &[
 #[derive(Clone, Debug, Default)]
 pub struct Observability {
     inner: Arc<Mutex<MetricState>>,
+    bucket_labels: Arc<BucketLabelPolicy>,
+}
+
+/// Guards the cardinality of the `bucket` label on per-bucket request metrics.
+///
+/// Buckets are named `<tenant id>/<bucket name>`, since bucket names are only
+/// unique within a tenant. A non-empty allowlist labels only the listed
+/// buckets. Otherwise the first `max_buckets` distinct buckets observed by
+/// this node keep their own label. Every other bucket is reported as
+/// [`OTHER_BUCKET_LABEL`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BucketLabelPolicy {
+    pub allowlist: BTreeSet<String>,
+    pub max_buckets: usize,
+}
+
+impl Default for BucketLabelPolicy {
+    fn default() -> Self {
+        Self {
+            allowlist: BTreeSet::new(),
+            max_buckets: DEFAULT_BUCKET_LABEL_LIMIT,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -58,11 +89,15 @@ pub struct MetricSnapshot {
     pub sum_nanos: u128,
     pub min_nanos: Option<u128>,
     pub max_nanos: Option<u128>,
+    pub sum_bytes: u128,
+    pub min_bytes: Option<u64>,
+    pub max_bytes: Option<u64>,
 }
 
 #[derive(Debug, Default)]
 struct MetricState {
     metrics: BTreeMap<MetricKey, MetricSnapshot>,
+    labelled_buckets: BTreeSet<String>,
 }
 
 #[derive(Debug)]
@@ -74,6 +109,37 @@ pub struct LatencyGuard {
 }
 
 impl Observability {
+    pub fn with_bucket_label_policy(policy: BucketLabelPolicy) -> Self {
+        Self {
+            inner: Arc::default(),
+            bucket_labels: Arc::new(policy),
+        }
+    }
+
+    /// Returns the `bucket` label value, `<tenant id>/<bucket name>`, for a
+    /// request against tenant `tenant_id`'s bucket `bucket_name`, admitting
+    /// new buckets until the policy limit is reached.
+    pub fn bucket_label(&self, tenant_id: i64, bucket_name: &str) -> String {
+        let bucket = format!("{tenant_id}/{bucket_name}");
+        let policy = &self.bucket_labels;
+        if !policy.allowlist.is_empty() {
+            return if policy.allowlist.contains(&bucket) {
+                bucket
+            } else {
+                OTHER_BUCKET_LABEL.to_string()
+            };
+        }
+        let mut state = self.inner.lock().expect("observability mutex poisoned");
+        if state.labelled_buckets.contains(&bucket) {
+            return bucket;
+        }
+        if state.labelled_buckets.len() < policy.max_buckets {
+            state.labelled_buckets.insert(bucket.clone());
+            return bucket;
+        }
+        OTHER_BUCKET_LABEL.to_string()
+    }
+
     pub fn increment_counter(&self, metric_name: &str, labels: &[(&str, &str)]) {
         let key = metric_key(metric_name, labels);
         let mut state = self.inner.lock().expect("observability mutex poisoned");
@@ -106,6 +172,17 @@ impl Observability {
         sample.value = i64::try_from(elapsed).unwrap_or(i64::MAX);
     }
 
+    pub fn record_bytes(&self, metric_name: &str, labels: &[(&str, &str)], bytes: u64) {
+        let key = metric_key(metric_name, labels);
+        let mut state = self.inner.lock().expect("observability mutex poisoned");
+        let sample = state.metrics.entry(key).or_default();
+        sample.count = sample.count.saturating_add(1);
+        sample.sum_bytes = sample.sum_bytes.saturating_add(u128::from(bytes));
+        sample.min_bytes = Some(sample.min_bytes.map_or(bytes, |current| current.min(bytes)));
+        sample.max_bytes = Some(sample.max_bytes.map_or(bytes, |current| current.max(bytes)));
+        sample.value = i64::try_from(bytes).unwrap_or(i64::MAX);
+    }
+
     pub fn set_gauge(&self, metric_name: &str, labels: &[(&str, &str)], value: i64) {
         let key = metric_key(metric_name, labels);
         let mut state = self.inner.lock().expect("observability mutex poisoned");
@@ -252,6 +329,41 @@ mod tests {
         assert_eq!(sample.max_nanos, Some(Duration::from_millis(9).as_nanos()));
     }
 
+    #[test]
+    fn byte_samples_record_count_bounds_and_sum() {
+        let observability = Observability::default();
+        let labels = [("bucket", "media"), ("operation", "put")];
+        observability.record_bytes(OBJECT_REQUEST_BYTES, &labels, 10);
+        observability.record_bytes(OBJECT_REQUEST_BYTES, &labels, 4);
+
+        let snapshot = observability.snapshot();
+        let sample = &snapshot[&metric_key(OBJECT_REQUEST_BYTES, &labels)];
+        assert_eq!(sample.count, 2);
+        assert_eq!(sample.sum_bytes, 14);
+        assert_eq!(sample.min_bytes, Some(4));
+        assert_eq!(sample.max_bytes, Some(10));
+    }
+
+    #[test]
+    fn bucket_labels_are_capped_by_policy() {
+        let observability = Observability::with_bucket_label_policy(BucketLabelPolicy {
+            allowlist: BTreeSet::new(),
+            max_buckets: 2,
+        });
+        assert_eq!(observability.bucket_label(1, "a"), "1/a");
+        assert_eq!(observability.bucket_label(2, "a"), "2/a");
+        assert_eq!(observability.bucket_label(1, "c"), OTHER_BUCKET_LABEL);
+        assert_eq!(observability.bucket_label(1, "a"), "1/a");
+
+        let allowlisted = Observability::with_bucket_label_policy(BucketLabelPolicy {
+            allowlist: BTreeSet::from(["1/billing".to_string()]),
+            max_buckets: 0,
+        });
+        assert_eq!(allowlisted.bucket_label(1, "billing"), "1/billing");
+        assert_eq!(allowlisted.bucket_label(2, "billing"), OTHER_BUCKET_LABEL);
+        assert_eq!(allowlisted.bucket_label(1, "scratch"), OTHER_BUCKET_LABEL);
+    }
+
     #[test]
     fn latency_guard_records_on_drop() {
         let observability = Observability::default();
@@ -0,0 +1,111 @@
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OnceCell};
+use tonic::transport::{Channel, Endpoint};
+
+const PEER_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// gRPC channels to peer nodes, keyed by endpoint and connected on first use.
+/// A tonic `Channel` multiplexes requests over one HTTP/2 connection, so
+/// callers clone the cached channel instead of dialling the peer per request.
+#[derive(Debug, Clone, Default)]
+pub struct PeerChannelPool {
+    channels: Arc<Mutex<HashMap<String, Arc<OnceCell<Channel>>>>>,
+}
+
+impl PeerChannelPool {
+    pub async fn channel(&self, endpoint: &str) -> Result<Channel> {
+        // The pool lock only covers the lookup. Dialling happens on the
+        // peer's own cell, so an unreachable peer does not stall requests to
+        // the others while concurrent first requests to one peer still share
+        // a single connection.
+        let cell = self
+            .channels
+            .lock()
+            .await
+            .entry(endpoint.to_string())
+            .or_default()
+            .clone();
+        let channel = cell
+            .get_or_try_init(|| async {
+                Endpoint::from_shared(endpoint.to_string())?
+                    .connect_timeout(PEER_CONNECT_TIMEOUT)
+                    .connect()
+                    .await
+                    .with_context(|| format!("connect peer at {endpoint}"))
+            })
+            .await?;
+        Ok(channel.clone())
+    }
+
+    /// Drops the cached channel so the next request reconnects.
+    pub async fn invalidate(&self, endpoint: &str) {
+        self.channels.lock().await.remove(endpoint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn peers_share_one_connection_until_invalidated() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let accept_count = accepted.clone();
+        tokio::spawn(async move {
+            let mut sockets = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                accept_count.fetch_add(1, Ordering::SeqCst);
+                sockets.push(socket);
+            }
+        });
+
+        let pool = PeerChannelPool::default();
+        let (first, second) = tokio::join!(pool.channel(&endpoint), pool.channel(&endpoint));
+        first.unwrap();
+        second.unwrap();
+        for _ in 0..8 {
+            pool.channel(&endpoint).await.unwrap();
+        }
+        assert_eq!(accepted_after_settling(&accepted).await, 1);
+
+        pool.invalidate(&endpoint).await;
+        pool.channel(&endpoint).await.unwrap();
+        assert_eq!(accepted_after_settling(&accepted).await, 2);
+    }
+
+    #[tokio::test]
+    async fn an_unreachable_peer_does_not_block_other_peers() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let mut sockets = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                sockets.push(socket);
+            }
+        });
+
+        let pool = PeerChannelPool::default();
+        // A non-routable address keeps its dial pending until the timeout.
+        let unreachable = tokio::spawn({
+            let pool = pool.clone();
+            async move { pool.channel("http://10.255.255.1:50051").await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        tokio::time::timeout(Duration::from_secs(1), pool.channel(&endpoint))
+            .await
+            .expect("a pending dial held the pool lock")
+            .unwrap();
+        unreachable.abort();
+    }
+
+    async fn accepted_after_settling(accepted: &AtomicUsize) -> usize {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        accepted.load(Ordering::SeqCst)
+    }
+}
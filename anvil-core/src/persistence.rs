@@ -50,6 +50,7 @@ pub struct Persistence {
     object_metadata_compaction_frame_threshold: u64,
     object_metadata_compaction_bytes_threshold: u64,
     task_lease_ttl_secs: u64,
+    task_max_attempts: i32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -101,6 +102,7 @@ pub(crate) struct HfIngestion {
     pub(crate) requester_app_id: i64,
     pub(crate) repo: String,
     pub(crate) revision: String,
+    pub(crate) repo_type: crate::tasks::HFRepoType,
     pub(crate) target_bucket: String,
     pub(crate) target_region: String,
     pub(crate) target_prefix: String,
@@ -148,6 +150,34 @@ pub struct Bucket {
     pub region: String,
     pub created_at: DateTime<Utc>,
     pub is_public_read: bool,
+    /// S3-style bucket policy document, stored as validated JSON.
+    #[serde(default)]
+    pub policy_json: Option<String>,
+    /// Storage quota in bytes across current objects; `None` is unlimited.
+    #[serde(default)]
+    pub max_bytes: Option<u64>,
+    /// Quota on the number of current objects; `None` is unlimited.
+    #[serde(default)]
+    pub max_objects: Option<u64>,
+    /// Regions that receive an asynchronous copy of every object written
+    /// here. Empty keeps objects in the bucket's home region only.
+    #[serde(default)]
+    pub replicate_to: Vec<String>,
+    /// JSON array of [`crate::bucket_lifecycle::LifecycleRule`]s that expire
+    /// current objects by age. `None` keeps objects until they are deleted.
+    #[serde(default)]
+    pub lifecycle_rules: Option<String>,
+    /// Object-lock retention, in days, given to new objects whose write does
+    /// not set its own. `None` leaves new objects unprotected.
+    #[serde(default)]
+    pub default_retention_days: Option<u32>,
+}
+
+/// Bytes and object count across a bucket's current objects.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BucketUsage {
+    pub used_bytes: u64,
+    pub object_count: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -170,8 +200,13 @@ pub struct Object {
     pub key: String,
     #[serde(default)]
     pub kind: object_links::ObjectEntryKind,
+    /// CoreStore hash of the payload (`sha256:<hex>`); identifies the bytes.
     pub content_hash: String,
     pub size: i64,
+    /// Client-facing ETag. S3 single-part writes record the hex MD5 and
+    /// multipart completions record `<md5 of part md5s>-<parts>`, so it is
+    /// not always derivable from `content_hash`; native writes reuse the
+    /// content hash.
     pub etag: String,
     pub content_type: Option<String>,
     pub version_id: uuid::Uuid,
@@ -183,11 +218,79 @@ pub struct Object {
     pub created_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
     pub storage_class: Option<String>,
+    /// S3 storage class the writer asked for, reported back unchanged even
+    /// when several names share a tier or the tier fell back to the default
+    /// class. `storage_class` holds the class the bytes were written with.
+    #[serde(default)]
+    pub requested_storage_class: Option<String>,
     pub user_meta: Option<JsonValue>,
     pub shard_map: Option<JsonValue>,
     pub checksum: Option<Vec<u8>>,
     #[serde(default)]
     pub link: Option<object_links::ObjectLinkTarget>,
+    #[serde(default)]
+    pub retention: Option<ObjectRetention>,
+}
+
+/// Object-lock settings of one object version. While the lock is active the
+/// version cannot be deleted, and its key cannot be overwritten.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectRetention {
+    pub retain_until: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub legal_hold: bool,
+}
+
+impl ObjectRetention {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.legal_hold || self.retain_until.is_some_and(|until| until > now)
+    }
+}
+
+impl Object {
+    /// Whether this version is currently protected by object lock.
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.retention
+            .is_some_and(|retention| retention.is_active(now))
+    }
+}
+
+/// Raised when a delete would remove an object version that is still under
+/// retention or legal hold.
+#[derive(Debug, thiserror::Error)]
+#[error("object {key} is protected by object lock")]
+pub struct ObjectLockedError {
+    pub key: String,
+}
+
+impl ObjectLockedError {
+    /// Finds an object-lock refusal anywhere in `error`'s cause chain.
+    pub fn find(error: &anyhow::Error) -> Option<&Self> {
+        error.chain().find_map(|cause| cause.downcast_ref::<Self>())
+    }
+}
+
+/// Raised when a conditional write finds that the key's current version is
+/// no longer the one its conditions were checked against.
+#[derive(Debug, thiserror::Error)]
+#[error("object {key} changed before the conditional write committed")]
+pub struct ObjectChangedError {
+    pub key: String,
+}
+
+impl ObjectChangedError {
+    /// Finds a lost conditional-write race anywhere in `error`'s cause chain.
+    pub fn find(error: &anyhow::Error) -> Option<&Self> {
+        error.chain().find_map(|cause| cause.downcast_ref::<Self>())
+    }
+}
+
+/// Raised when deleting a tenant that still owns buckets or apps.
+#[derive(Debug, thiserror::Error)]
+#[error("tenant still owns {buckets} bucket(s) and {apps} app(s)")]
+pub struct TenantNotEmptyError {
+    pub buckets: usize,
+    pub apps: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -258,6 +361,9 @@ pub struct MultipartUploadPart {
     pub size: i64,
     pub etag: String,
     pub created_at: DateTime<Utc>,
+    /// Additional checksum of the part, in the `ObjectChecksum` encoding.
+    #[serde(default)]
+    pub checksum: Option<Vec<u8>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -483,6 +589,27 @@ pub struct AppDetails {
     pub id: i64,
     pub client_secret_encrypted: Vec<u8>,
     pub tenant_id: i64,
+    /// Secret replaced by the last graced rotation, if it was kept.
+    pub previous_client_secret: Option<PreviousAppSecret>,
+}
+
+impl AppDetails {
+    /// Encrypted secrets that authenticate this app at `now`, newest first.
+    pub fn accepted_client_secrets(&self, now: DateTime<Utc>) -> Vec<&[u8]> {
+        let mut secrets = vec![self.client_secret_encrypted.as_slice()];
+        if let Some(previous) = &self.previous_client_secret
+            && now < previous.expires_at
+        {
+            secrets.push(previous.client_secret_encrypted.as_slice());
+        }
+        secrets
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreviousAppSecret {
+    pub client_secret_encrypted: Vec<u8>,
+    pub expires_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -513,6 +640,7 @@ pub struct HfIngestionJob {
     pub requester_app_id: i64,
     pub repo: String,
     pub revision: String,
+    pub repo_type: crate::tasks::HFRepoType,
     pub target_bucket: String,
     pub target_region: String,
     pub target_prefix: String,
@@ -616,7 +744,8 @@ mod tasks;
 mod tenancy;
 
 use helpers::*;
-pub use objects::ObjectCreateOptions;
+pub use models::ResolvedTensor;
+pub use objects::{ExpectedCurrentVersion, ObjectCreateOptions};
 
 #[cfg(test)]
 mod tests;
@@ -294,6 +294,47 @@ impl Persistence {
         .await
     }
 
+    pub async fn set_node_descriptor_cordoned(
+        &self,
+        node_id: &str,
+        expected_generation: u64,
+        cordoned: bool,
+    ) -> crate::mesh_lifecycle::LifecycleResult<crate::mesh_lifecycle::NodeDescriptor> {
+        let node = crate::mesh_lifecycle::list_nodes(&self.storage, None, None)
+            .await?
+            .into_iter()
+            .find(|node| node.node_id == node_id)
+            .ok_or_else(|| crate::mesh_lifecycle::LifecycleError::NotFound {
+                resource_kind: "node",
+                resource_id: node_id.to_string(),
+            })?;
+        let record_key = format!("{}/{}/{}", node.region, node.cell_id, node.node_id);
+        let partition = crate::mesh_lifecycle::lifecycle_control_partition(
+            crate::mesh_lifecycle::NODE_DESCRIPTOR_STREAM_FAMILY,
+            &record_key,
+        );
+        let permit = self
+            .mesh_control_write_permit_for_stream(
+                crate::mesh_lifecycle::NODE_DESCRIPTOR_STREAM_FAMILY,
+                &partition,
+            )
+            .await
+            .map_err(|err| {
+                crate::mesh_lifecycle::LifecycleError::InvalidArgument(err.to_string())
+            })?;
+        crate::mesh_lifecycle::set_node_cordoned_with_control(
+            &self.storage,
+            node_id,
+            expected_generation,
+            cordoned,
+            crate::mesh_lifecycle::LifecycleControlWriteAuthority {
+                permit: &permit,
+                signing_key: &self.partition_owner_signing_key,
+            },
+        )
+        .await
+    }
+
     pub async fn node_runtime_ownership_blockers(
         &self,
         node_id: &str,
@@ -483,6 +524,32 @@ impl Persistence {
         crate::mesh_lifecycle::list_nodes(&self.storage, region_filter, cell_filter).await
     }
 
+    /// Picks the gRPC endpoint of an active object node in `region`, lowest
+    /// node id first so every caller converges on the same peer.
+    pub async fn region_object_endpoint(
+        &self,
+        region: &str,
+    ) -> crate::mesh_lifecycle::LifecycleResult<Option<String>> {
+        let mut nodes = self.list_node_descriptors(Some(region), None).await?;
+        nodes.sort_by(|left, right| left.node_id.cmp(&right.node_id));
+        Ok(nodes.into_iter().find_map(|node| {
+            let serves_objects = node.state == crate::mesh_lifecycle::LifecycleState::Active
+                && node
+                    .capabilities
+                    .contains(&crate::mesh_lifecycle::NodeCapability::Object)
+                && !node.public_api_addr.trim().is_empty();
+            serves_objects.then(|| {
+                if node.public_api_addr.starts_with("http://")
+                    || node.public_api_addr.starts_with("https://")
+                {
+                    node.public_api_addr
+                } else {
+                    format!("http://{}", node.public_api_addr)
+                }
+            })
+        }))
+    }
+
     pub async fn create_host_alias_descriptor(
         &self,
         routing_config: &crate::routing::RoutingConfig,
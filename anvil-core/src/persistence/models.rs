@@ -46,6 +46,16 @@ impl Persistence {
         model_journal::list_tensors(&self.storage, artifact_id, limit, offset).await
     }
 
+    pub async fn query_tensors(
+        &self,
+        artifact_id: &str,
+        query: &model_journal::TensorQuery,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::anvil_api::TensorIndexRow>> {
+        model_journal::query_tensors(&self.storage, artifact_id, query, limit, offset).await
+    }
+
     pub async fn get_tensor_metadata(
         &self,
         artifact_id: &str,
@@ -61,16 +71,44 @@ impl Persistence {
         model_journal::get_model_artifact(&self.storage, artifact_id).await
     }
 
+    pub async fn get_model_artifact_location(
+        &self,
+        artifact_id: &str,
+    ) -> Result<Option<model_journal::ModelArtifactLocation>> {
+        model_journal::get_model_artifact_location(&self.storage, artifact_id).await
+    }
+
     pub async fn get_tensor_metadata_recursive(
         &self,
         artifact_id: &str,
         tensor_name: &str,
     ) -> Result<Option<crate::anvil_api::TensorIndexRow>> {
+        Ok(self
+            .resolve_tensor(artifact_id, tensor_name)
+            .await?
+            .map(|resolved| resolved.tensor))
+    }
+
+    /// Finds `tensor_name` in `artifact_id` or, failing that, along its chain of
+    /// base artifacts, and reports which artifact (and therefore which object)
+    /// holds the bytes.
+    pub async fn resolve_tensor(
+        &self,
+        artifact_id: &str,
+        tensor_name: &str,
+    ) -> Result<Option<ResolvedTensor>> {
         let mut current = artifact_id.to_string();
         let mut seen = HashSet::new();
         while seen.insert(current.clone()) {
             if let Some(tensor) = self.get_tensor_metadata(&current, tensor_name).await? {
-                return Ok(Some(tensor));
+                let location = model_journal::get_model_artifact_location(&self.storage, &current)
+                    .await?
+                    .ok_or_else(|| anyhow!("model artifact {current} has no registered object"))?;
+                return Ok(Some(ResolvedTensor {
+                    artifact_id: current,
+                    location,
+                    tensor,
+                }));
             }
             let Some(manifest) = self.get_model_artifact(&current).await? else {
                 break;
@@ -83,3 +121,23 @@ impl Persistence {
         Ok(None)
     }
 }
+
+/// A tensor index row together with the artifact it was found in.
+#[derive(Debug, Clone)]
+pub struct ResolvedTensor {
+    pub artifact_id: String,
+    pub location: model_journal::ModelArtifactLocation,
+    pub tensor: crate::anvil_api::TensorIndexRow,
+}
+
+impl ResolvedTensor {
+    /// Object key of the file holding the tensor.
+    pub fn object_key(&self) -> String {
+        let file_path = self.tensor.file_path.trim_start_matches('/');
+        if file_path.is_empty() {
+            self.location.key.clone()
+        } else {
+            format!("{}/{}", self.location.key.trim_end_matches('/'), file_path)
+        }
+    }
+}
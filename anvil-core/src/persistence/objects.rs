@@ -1,4 +1,30 @@
 use super::*;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    sync::LazyLock,
+};
+use tokio::sync::Mutex as TokioMutex;
+
+const OBJECT_KEY_WRITE_LOCK_STRIPES: usize = 256;
+
+static OBJECT_KEY_WRITE_LOCKS: LazyLock<Vec<TokioMutex<()>>> = LazyLock::new(|| {
+    (0..OBJECT_KEY_WRITE_LOCK_STRIPES)
+        .map(|_| TokioMutex::new(()))
+        .collect()
+});
+
+/// Serialises metadata commits for one object key on this node. Partition
+/// ownership already keeps other nodes out, so holding the lock from id
+/// allocation through projection makes the last committed write the latest
+/// version: its id, timestamp, journal position and head row all agree.
+fn object_key_write_lock(bucket_id: i64, key: &str) -> &'static TokioMutex<()> {
+    let mut hasher = DefaultHasher::new();
+    bucket_id.hash(&mut hasher);
+    key.hash(&mut hasher);
+    let stripe = (hasher.finish() as usize) % OBJECT_KEY_WRITE_LOCK_STRIPES;
+    &OBJECT_KEY_WRITE_LOCKS[stripe]
+}
 
 #[derive(Debug, Clone, Copy)]
 pub struct ObjectCreateOptions {
@@ -6,6 +32,40 @@ pub struct ObjectCreateOptions {
     pub exact_authz_revision: bool,
     pub enqueue_index_maintenance: bool,
     pub enqueue_metadata_compaction: bool,
+    /// Object-lock settings recorded on the new version.
+    pub retention: Option<ObjectRetention>,
+    /// Additional payload checksum recorded on the new version.
+    pub checksum: Option<crate::object_checksum::ObjectChecksum>,
+    /// S3 storage class the writer named, recorded as given.
+    pub requested_storage_class: Option<&'static str>,
+    /// Current version the write was validated against; checked under the
+    /// key write lock so conditional writes cannot race.
+    pub expected_current: ExpectedCurrentVersion,
+}
+
+/// The current version a conditional write expects to replace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExpectedCurrentVersion {
+    /// Unconditional write.
+    #[default]
+    Any,
+    /// The key must have no current version.
+    Absent,
+    /// The key's current version must still be this one.
+    Version(uuid::Uuid),
+}
+
+impl ExpectedCurrentVersion {
+    pub fn of(current: Option<&Object>) -> Self {
+        current.map_or(Self::Absent, |object| Self::Version(object.version_id))
+    }
+
+    fn holds_for(self, current: Option<&Object>) -> bool {
+        match self {
+            Self::Any => true,
+            expected => expected == Self::of(current),
+        }
+    }
 }
 
 impl ObjectCreateOptions {
@@ -15,6 +75,10 @@ impl ObjectCreateOptions {
             exact_authz_revision: false,
             enqueue_index_maintenance: false,
             enqueue_metadata_compaction: false,
+            retention: None,
+            checksum: None,
+            requested_storage_class: None,
+            expected_current: ExpectedCurrentVersion::Any,
         }
     }
 
@@ -24,6 +88,10 @@ impl ObjectCreateOptions {
             exact_authz_revision: true,
             enqueue_index_maintenance: true,
             enqueue_metadata_compaction: true,
+            retention: None,
+            checksum: None,
+            requested_storage_class: None,
+            expected_current: ExpectedCurrentVersion::Any,
         }
     }
 }
@@ -190,6 +258,30 @@ impl Persistence {
             authz_revision,
             delete_marker: false,
         });
+        let _key_guard = object_key_write_lock(bucket_id, key).lock().await;
+        // Checked under the key lock so a concurrent retention update or
+        // conditional write cannot land between the check and the commit.
+        let current = metadata_journal::read_current_object(
+            &self.storage,
+            &bucket,
+            &self.partition_owner_signing_key,
+            key,
+        )
+        .await?;
+        if let Some(current) = &current
+            && current.is_locked(Utc::now())
+        {
+            return Err(ObjectLockedError {
+                key: key.to_string(),
+            }
+            .into());
+        }
+        if !options.expected_current.holds_for(current.as_ref()) {
+            return Err(ObjectChangedError {
+                key: key.to_string(),
+            }
+            .into());
+        }
         let step_start = std::time::Instant::now();
         let object = Object {
             id: metadata_journal::next_object_id(
@@ -215,10 +307,12 @@ impl Persistence {
             created_at: Utc::now(),
             deleted_at: None,
             storage_class,
+            requested_storage_class: options.requested_storage_class.map(ToString::to_string),
             user_meta,
             shard_map,
-            checksum: None,
+            checksum: options.checksum.map(|checksum| checksum.encode()),
             link: None,
+            retention: options.retention,
         };
         crate::emit_test_timing(
             "persistence.create_object next_object_id",
@@ -470,10 +564,12 @@ impl Persistence {
             created_at: now,
             deleted_at: None,
             storage_class: None,
+            requested_storage_class: None,
             user_meta,
             shard_map: None,
             checksum: None,
             link: Some(link),
+            retention: None,
         };
         let permit = self
             .object_metadata_write_permit(bucket.tenant_id, bucket.id)
@@ -701,6 +797,7 @@ impl Persistence {
             created_at: now,
             deleted_at: Some(now),
             storage_class: None,
+            requested_storage_class: None,
             user_meta,
             shard_map: None,
             checksum: None,
@@ -712,6 +809,7 @@ impl Persistence {
                 created_at: current_link.created_at,
                 created_by: current_link.created_by.clone(),
             }),
+            retention: None,
         };
         let permit = self
             .object_metadata_write_permit(bucket.tenant_id, bucket.id)
@@ -857,6 +955,34 @@ impl Persistence {
         .await
     }
 
+    /// Reads the bucket's maintained usage counter. The current object at
+    /// `replacing_key` is left out so an overwrite is only charged for its
+    /// new size.
+    pub async fn bucket_usage(
+        &self,
+        bucket: &Bucket,
+        replacing_key: Option<&str>,
+    ) -> Result<BucketUsage> {
+        let mut usage = metadata_journal::read_current_object_usage(&self.storage, bucket).await?;
+        let Some(replacing_key) = replacing_key else {
+            return Ok(usage);
+        };
+        if let Some(current) = metadata_journal::read_current_object(
+            &self.storage,
+            bucket,
+            &self.partition_owner_signing_key,
+            replacing_key,
+        )
+        .await?
+        {
+            usage.used_bytes = usage
+                .used_bytes
+                .saturating_sub(u64::try_from(current.size).unwrap_or_default());
+            usage.object_count = usage.object_count.saturating_sub(1);
+        }
+        Ok(usage)
+    }
+
     pub async fn list_objects(
         &self,
         bucket_id: i64,
@@ -918,6 +1044,7 @@ impl Persistence {
         else {
             return Ok(None);
         };
+        let _key_guard = object_key_write_lock(bucket_id, key).lock().await;
         let Some(base) = metadata_journal::read_current_object(
             &self.storage,
             &bucket,
@@ -929,6 +1056,12 @@ impl Persistence {
             return Ok(None);
         };
         let now = Utc::now();
+        if base.is_locked(now) {
+            return Err(ObjectLockedError { key: base.key }.into());
+        }
+        if !options.expected_current.holds_for(Some(&base)) {
+            return Err(ObjectChangedError { key: base.key }.into());
+        }
         let object = Object {
             id: metadata_journal::next_object_id(
                 &self.storage,
@@ -943,6 +1076,7 @@ impl Persistence {
             etag: String::new(),
             created_at: now,
             deleted_at: Some(now),
+            retention: None,
             ..base
         };
         let permit = self
@@ -1036,6 +1170,9 @@ impl Persistence {
         else {
             return Ok(None);
         };
+        if object.is_locked(Utc::now()) {
+            return Err(ObjectLockedError { key: object.key }.into());
+        }
         object.id = metadata_journal::next_object_id(
             &self.storage,
             &bucket,
@@ -1081,6 +1218,53 @@ impl Persistence {
         Ok(Some(object))
     }
 
+    /// Replaces the object-lock settings of the current version of `key`.
+    /// Returns `None` when the key has no current version.
+    pub async fn set_object_retention(
+        &self,
+        bucket_id: i64,
+        key: &str,
+        retention: Option<ObjectRetention>,
+    ) -> Result<Option<Object>> {
+        let Some(bucket) =
+            bucket_journal::read_current_bucket_by_id(&self.storage, bucket_id).await?
+        else {
+            return Ok(None);
+        };
+        let _key_guard = object_key_write_lock(bucket_id, key).lock().await;
+        let Some(mut object) = metadata_journal::read_current_object(
+            &self.storage,
+            &bucket,
+            &self.partition_owner_signing_key,
+            key,
+        )
+        .await?
+        .filter(|object| object.deleted_at.is_none()) else {
+            return Ok(None);
+        };
+        object.id = metadata_journal::next_object_id(
+            &self.storage,
+            &bucket,
+            &self.partition_owner_signing_key,
+        )
+        .await?;
+        object.mutation_id = uuid::Uuid::new_v4();
+        object.retention = retention;
+        let permit = self
+            .object_metadata_write_permit(bucket.tenant_id, bucket.id)
+            .await?;
+        metadata_journal::append_object_mutation_with_permit(
+            &self.storage,
+            &bucket,
+            &object,
+            metadata_journal::ObjectJournalMutation::Retention,
+            &permit,
+            &self.partition_owner_signing_key,
+        )
+        .await?;
+        Ok(Some(object))
+    }
+
     pub async fn list_object_versions(
         &self,
         bucket_id: i64,
@@ -58,6 +58,11 @@ impl Persistence {
             } else {
                 config.task_lease_ttl_secs
             },
+            task_max_attempts: if config.task_max_attempts == 0 {
+                task_journal::DEFAULT_TASK_MAX_ATTEMPTS
+            } else {
+                i32::try_from(config.task_max_attempts).unwrap_or(i32::MAX)
+            },
         })
     }
 
@@ -160,6 +165,40 @@ impl Persistence {
         Ok(())
     }
 
+    /// Marks a deleted tenant's name as tombstoned in the mesh directory so
+    /// routing stops resolving it.
+    pub(super) async fn tombstone_mesh_tenant_name(&self, tenant: &Tenant) -> Result<()> {
+        let tenant_name = mesh_directory::TenantName::canonicalize(&tenant.name)?;
+        let Some(existing) =
+            mesh_directory::read_tenant_name_descriptor(&self.storage, &tenant_name).await?
+        else {
+            return Ok(());
+        };
+        if existing.tenant_id.as_str() != tenant.id.to_string()
+            || existing.status == mesh_directory::TenantNameStatus::Tombstoned
+        {
+            return Ok(());
+        }
+        let permit = self
+            .mesh_control_write_permit(
+                mesh_directory::RoutingRecordFamily::TenantName,
+                &existing.partition(),
+            )
+            .await?;
+        mesh_directory::tombstone_tenant_name(
+            &self.storage,
+            &tenant_name,
+            existing.generation,
+            Utc::now().to_rfc3339(),
+            mesh_directory::MeshControlWriteAuthority {
+                permit: &permit,
+                signing_key: &self.partition_owner_signing_key,
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
     pub(super) async fn write_mesh_bucket_locator(&self, bucket: &Bucket) -> Result<()> {
         let now = bucket.created_at.to_rfc3339();
         let mesh_id = mesh_directory::MeshId::new(self.mesh_id.clone())?;
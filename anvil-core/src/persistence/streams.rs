@@ -90,6 +90,7 @@ impl Persistence {
         object_ref: CoreObjectRef,
         size: i64,
         etag: &str,
+        checksum: Option<Vec<u8>>,
     ) -> Result<MultipartUploadPartMutation> {
         let (tenant_id, bucket_id) =
             multipart_journal::find_multipart_upload_partition(&self.storage, upload_row_id)
@@ -105,6 +106,7 @@ impl Persistence {
             object_ref,
             size,
             etag,
+            checksum,
             &permit,
             &self.partition_owner_signing_key,
         )
@@ -118,6 +120,7 @@ impl Persistence {
         object_ref: CoreObjectRef,
         size: i64,
         etag: &str,
+        checksum: Option<Vec<u8>>,
         transaction_id: &str,
         transaction_principal: &str,
     ) -> Result<MultipartUploadPartMutation> {
@@ -140,6 +143,7 @@ impl Persistence {
             object_ref,
             size,
             etag,
+            checksum,
             &permit,
             &self.partition_owner_signing_key,
             transaction_id,
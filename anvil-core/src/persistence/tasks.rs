@@ -10,6 +10,43 @@ impl Persistence {
         Ok(())
     }
 
+    pub async fn collect_orphaned_shards(
+        &self,
+        grace_period: std::time::Duration,
+        dry_run: bool,
+    ) -> Result<crate::core_store::CoreShardGcReport> {
+        self.core_store()
+            .await?
+            .collect_orphaned_shards(grace_period, dry_run)
+            .await
+    }
+
+    /// Scrubs this node's shards and records a repair finding, scoped to the
+    /// node, for every shard quarantined. Quarantine keeps reads working
+    /// through reconstruction, but the block has lost a shard of redundancy
+    /// until it is rebuilt, so the loss must not only reach the logs.
+    pub async fn scrub_local_shards(&self) -> Result<crate::core_store::CoreShardScrubReport> {
+        let report = self.core_store().await?.scrub_local_shards().await?;
+        for shard in &report.quarantined {
+            let finding = quarantined_shard_finding(self.owner_node_id(), shard)?;
+            if let Err(error) = repair_finding::write_repair_finding(
+                &self.storage,
+                finding,
+                &self.partition_owner_signing_key,
+            )
+            .await
+            {
+                tracing::warn!(
+                    block_id_hash = %shard.block_id_hash,
+                    quarantine_path = %shard.quarantine_path,
+                    error = %error,
+                    "failed to record repair finding for quarantined shard"
+                );
+            }
+        }
+        Ok(report)
+    }
+
     pub async fn enqueue_task(
         &self,
         task_type: crate::tasks::TaskType,
@@ -411,6 +448,72 @@ impl Persistence {
         task_journal::list_tasks(&self.storage).await
     }
 
+    pub async fn list_dead_tasks(&self) -> Result<Vec<TaskRecord>> {
+        Ok(self
+            .list_tasks()
+            .await?
+            .into_iter()
+            .filter(|task| task.status == crate::tasks::TaskStatus::Dead)
+            .collect())
+    }
+
+    /// Returns a failed or dead task to the queue with a fresh attempt
+    /// budget, so a failed task runs again without waiting for its retry
+    /// delay. Returns false when the task does not exist or is in any other
+    /// status.
+    pub async fn retry_task(&self, task_id: i64) -> Result<bool> {
+        self.requeue_task(
+            task_id,
+            &[
+                crate::tasks::TaskStatus::Failed,
+                crate::tasks::TaskStatus::Dead,
+            ],
+        )
+        .await
+    }
+
+    async fn requeue_task(
+        &self,
+        task_id: i64,
+        requeueable: &[crate::tasks::TaskStatus],
+    ) -> Result<bool> {
+        let _write_guard = self.task_queue_write_lock.lock().await;
+        let mut last_error = None;
+        for _ in 0..5 {
+            let permit = match self.task_queue_write_permit().await {
+                Ok(permit) => permit,
+                Err(error) if is_retryable_partition_fence_error(&error) => {
+                    last_error = Some(error);
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
+            match task_journal::requeue_task_with_permit(
+                &self.storage,
+                task_id,
+                requeueable,
+                &permit,
+                &self.partition_owner_signing_key,
+            )
+            .await
+            {
+                Ok(requeued) => {
+                    if requeued {
+                        self.notify_task_enqueued();
+                    }
+                    return Ok(requeued);
+                }
+                Err(error) if is_retryable_partition_fence_error(&error) => {
+                    last_error = Some(error);
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| anyhow!("task requeue retry exhausted")))
+    }
+
     pub async fn update_task_status(
         &self,
         task_id: i64,
@@ -465,6 +568,7 @@ impl Persistence {
                 &self.storage,
                 task_id,
                 error,
+                self.task_max_attempts,
                 &permit,
                 &self.partition_owner_signing_key,
             )
@@ -560,6 +664,7 @@ impl Persistence {
         requester_app_id: i64,
         repo: &str,
         revision: Option<&str>,
+        repo_type: crate::tasks::HFRepoType,
         target_bucket: &str,
         target_region: &str,
         target_prefix: Option<&str>,
@@ -574,6 +679,7 @@ impl Persistence {
             requester_app_id,
             repo,
             revision,
+            repo_type,
             target_bucket,
             target_region,
             target_prefix,
@@ -656,6 +762,18 @@ impl Persistence {
         .await
     }
 
+    pub async fn hf_update_item_size(&self, id: i64, size: i64) -> Result<()> {
+        let permit = self.hf_write_permit().await?;
+        hf_journal::update_item_size_with_permit(
+            &self.storage,
+            id,
+            size,
+            &permit,
+            &self.partition_owner_signing_key,
+        )
+        .await
+    }
+
     pub async fn hf_update_item_success(&self, id: i64, size: i64, etag: &str) -> Result<()> {
         let permit = self.hf_write_permit().await?;
         hf_journal::update_item_success_with_permit(
@@ -676,6 +794,14 @@ impl Persistence {
         hf_journal::get_ingestion_items(&self.storage, ingestion_id).await
     }
 
+    pub(crate) async fn hf_get_ingestion_items_detailed(
+        &self,
+        ingestion_id: i64,
+        state: Option<crate::tasks::HFIngestionItemState>,
+    ) -> Result<Vec<HfIngestionItem>> {
+        hf_journal::get_ingestion_items_detailed(&self.storage, ingestion_id, state).await
+    }
+
     pub async fn hf_get_all_items_for_prefix(
         &self,
         tenant_id: i64,
@@ -694,6 +820,8 @@ impl Persistence {
         i64,
         i64,
         i64,
+        i64,
+        i64,
         Option<String>,
         Option<DateTime<Utc>>,
         Option<DateTime<Utc>>,
@@ -757,3 +885,55 @@ fn authz_materialization_mutation_id(
     mutation_id.copy_from_slice(&hash[..16]);
     mutation_id
 }
+
+fn quarantined_shard_finding(
+    node_id: &str,
+    shard: &crate::core_store::CoreQuarantinedShard,
+) -> Result<repair_finding::RepairFindingWrite> {
+    let created_at_nanos = Utc::now()
+        .timestamp_nanos_opt()
+        .ok_or_else(|| anyhow!("current timestamp is out of range"))?;
+    let finding_seed = format!("{node_id}:{}:{}", shard.quarantine_path, created_at_nanos);
+    let finding_hash = crate::formats::hash32(finding_seed.as_bytes());
+    // Node ids fall back to listen addresses, which are not safe path components.
+    let scope_id = node_id
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    Ok(repair_finding::RepairFindingWrite {
+        finding_id: format!("shard-{}", hex::encode(&finding_hash[..8])),
+        scope_kind: "node".to_string(),
+        scope_id,
+        repair_task_id: "shard-scrub".to_string(),
+        // The scrub is node-local and runs outside any leased task.
+        lease_fence_token: 1,
+        severity: repair_finding::RepairFindingSeverity::Error,
+        status: repair_finding::RepairFindingStatus::RequiresOperatorReview,
+        code: "corrupt_block_shard".to_string(),
+        message: "block shard failed its integrity check and was quarantined; the block has \
+                  reduced redundancy until the shard is rebuilt"
+            .to_string(),
+        subjects: vec![repair_finding::RepairSubjectRef {
+            subject_kind: "block_shard".to_string(),
+            subject_id: shard.block_id_hash.clone(),
+            generation: None,
+            cursor: None,
+            expected_hash: None,
+            actual_hash: None,
+        }],
+        proposed_action: repair_finding::RepairActionKind::VerifyOnly,
+        evidence: serde_json::json!({
+            "node_id": node_id,
+            "block_id_hash": shard.block_id_hash,
+            "quarantine_path": shard.quarantine_path,
+            "error": shard.error,
+        }),
+        created_at_nanos,
+    })
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::rate_limit::TenantRateLimit;
 use prost::Message;
 use std::sync::LazyLock;
 use tokio::sync::Mutex as TokioMutex;
@@ -6,7 +7,9 @@ use tokio::sync::Mutex as TokioMutex;
 static CONTROL_PLANE_MUTATION_LOCK: LazyLock<TokioMutex<()>> =
     LazyLock::new(|| TokioMutex::new(()));
 // The active global bucket-partition owner sequences numeric bucket IDs locally.
-// Ownership fences reject concurrent allocators on other nodes.
+// Ownership fences reject concurrent allocators on other nodes. Tenant deletion
+// also holds it, after CONTROL_PLANE_MUTATION_LOCK, so a bucket cannot be
+// created for a tenant that is being deleted.
 static BUCKET_ID_ALLOCATION_LOCK: LazyLock<TokioMutex<()>> = LazyLock::new(|| TokioMutex::new(()));
 
 #[derive(Clone, PartialEq, Message)]
@@ -32,6 +35,12 @@ impl Persistence {
             .tenant_by_name(name))
     }
 
+    pub async fn get_tenant_by_id(&self, tenant_id: i64) -> Result<Option<Tenant>> {
+        Ok(control_journal::read_control_state(&self.storage)
+            .await?
+            .tenant_by_id(tenant_id))
+    }
+
     pub async fn list_tenants(&self) -> Result<Vec<Tenant>> {
         Ok(control_journal::read_control_state(&self.storage)
             .await?
@@ -46,6 +55,15 @@ impl Persistence {
 
     pub async fn create_tenant(&self, name: &str, idempotency_key: &str) -> Result<Tenant> {
         let _guard = CONTROL_PLANE_MUTATION_LOCK.lock().await;
+        if let Some(existing) = mesh_directory::read_tenant_name_descriptor(
+            &self.storage,
+            &mesh_directory::TenantName::canonicalize(name)?,
+        )
+        .await?
+            && existing.status == mesh_directory::TenantNameStatus::Tombstoned
+        {
+            bail!("tenant name {name} belonged to a deleted tenant and cannot be reused");
+        }
         let permit = self.control_write_permit().await?;
         let tenant = control_journal::create_tenant_with_permit(
             &self.storage,
@@ -59,6 +77,103 @@ impl Persistence {
         Ok(tenant)
     }
 
+    /// Removes a tenant that no longer owns any buckets or apps, failing with
+    /// [`TenantNotEmptyError`] otherwise. The tenant's name stays reserved in
+    /// the mesh directory, so it cannot be handed to a new tenant.
+    pub async fn delete_tenant(&self, tenant_id: i64) -> Result<()> {
+        let _guard = CONTROL_PLANE_MUTATION_LOCK.lock().await;
+        let _allocation_guard = BUCKET_ID_ALLOCATION_LOCK.lock().await;
+        let state = control_journal::read_control_state(&self.storage).await?;
+        let tenant = state
+            .tenants()
+            .into_iter()
+            .find(|tenant| tenant.id == tenant_id)
+            .ok_or_else(|| anyhow!("tenant not found"))?;
+        let buckets = bucket_journal::read_current_buckets(&self.storage, tenant_id)
+            .await?
+            .len();
+        let apps = state.apps_for_tenant(tenant_id).len();
+        if buckets > 0 || apps > 0 {
+            return Err(TenantNotEmptyError { buckets, apps }.into());
+        }
+        let permit = self.control_write_permit().await?;
+        control_journal::delete_tenant_with_permit(
+            &self.storage,
+            tenant_id,
+            &permit,
+            &self.partition_owner_signing_key,
+        )
+        .await?;
+        self.tombstone_mesh_tenant_name(&tenant).await
+    }
+
+    pub async fn get_tenant_max_buckets(&self, tenant_id: i64) -> Result<Option<u64>> {
+        Ok(control_journal::read_control_state(&self.storage)
+            .await?
+            .tenant_max_buckets(tenant_id))
+    }
+
+    /// Bucket-count overrides of every tenant that has one.
+    pub async fn list_tenant_max_buckets(&self) -> Result<BTreeMap<i64, u64>> {
+        Ok(control_journal::read_control_state(&self.storage)
+            .await?
+            .tenant_bucket_limits())
+    }
+
+    /// Overrides the configured bucket-count limit for one tenant. `None`
+    /// restores the default and `Some(0)` lifts the limit entirely.
+    pub async fn set_tenant_max_buckets(
+        &self,
+        tenant_id: i64,
+        max_buckets: Option<u64>,
+    ) -> Result<()> {
+        let _guard = CONTROL_PLANE_MUTATION_LOCK.lock().await;
+        let permit = self.control_write_permit().await?;
+        control_journal::set_tenant_max_buckets_with_permit(
+            &self.storage,
+            tenant_id,
+            max_buckets,
+            &permit,
+            &self.partition_owner_signing_key,
+        )
+        .await
+    }
+
+    pub async fn get_tenant_rate_limit(&self, tenant_id: i64) -> Result<Option<TenantRateLimit>> {
+        Ok(control_journal::read_control_state(&self.storage)
+            .await?
+            .tenant_rate_limit(tenant_id))
+    }
+
+    /// Request rate limits of every tenant that has one.
+    pub async fn list_tenant_rate_limits(&self) -> Result<BTreeMap<i64, TenantRateLimit>> {
+        Ok(control_journal::read_control_state(&self.storage)
+            .await?
+            .tenant_rate_limits())
+    }
+
+    /// Sets the request rate limit for one tenant. `None` removes it, leaving
+    /// the tenant unlimited.
+    pub async fn set_tenant_rate_limit(
+        &self,
+        tenant_id: i64,
+        rate_limit: Option<TenantRateLimit>,
+    ) -> Result<()> {
+        if let Some(rate_limit) = rate_limit {
+            rate_limit.validate()?;
+        }
+        let _guard = CONTROL_PLANE_MUTATION_LOCK.lock().await;
+        let permit = self.control_write_permit().await?;
+        control_journal::set_tenant_rate_limit_with_permit(
+            &self.storage,
+            tenant_id,
+            rate_limit,
+            &permit,
+            &self.partition_owner_signing_key,
+        )
+        .await
+    }
+
     pub async fn create_app(
         &self,
         tenant_id: i64,
@@ -98,13 +213,55 @@ impl Persistence {
             .apps_for_tenant(tenant_id))
     }
 
-    pub async fn update_app_secret(&self, app_id: i64, new_encrypted_secret: &[u8]) -> Result<()> {
+    /// Replaces an app's secret and sets (or, with `None`, clears) the
+    /// previous secret that is still accepted until it expires.
+    pub async fn update_app_secret(
+        &self,
+        app_id: i64,
+        new_encrypted_secret: &[u8],
+        previous: Option<&PreviousAppSecret>,
+    ) -> Result<()> {
         let _guard = CONTROL_PLANE_MUTATION_LOCK.lock().await;
         let permit = self.control_write_permit().await?;
         control_journal::update_app_secret_with_permit(
             &self.storage,
             app_id,
             new_encrypted_secret,
+            previous,
+            &permit,
+            &self.partition_owner_signing_key,
+        )
+        .await
+    }
+
+    /// Rotates an app's secret. With a grace period the outgoing secret keeps
+    /// authenticating until `now + grace`; without one it stops immediately.
+    pub async fn rotate_app_secret(
+        &self,
+        app_id: i64,
+        new_encrypted_secret: &[u8],
+        grace: Option<Duration>,
+    ) -> Result<()> {
+        let _guard = CONTROL_PLANE_MUTATION_LOCK.lock().await;
+        let previous = match grace {
+            Some(grace) => {
+                let current = control_journal::read_control_state(&self.storage)
+                    .await?
+                    .app_details_by_id(app_id)
+                    .ok_or_else(|| anyhow!("app not found"))?;
+                Some(PreviousAppSecret {
+                    client_secret_encrypted: current.client_secret_encrypted,
+                    expires_at: Utc::now() + grace,
+                })
+            }
+            None => None,
+        };
+        let permit = self.control_write_permit().await?;
+        control_journal::update_app_secret_with_permit(
+            &self.storage,
+            app_id,
+            new_encrypted_secret,
+            previous.as_ref(),
             &permit,
             &self.partition_owner_signing_key,
         )
@@ -128,6 +285,31 @@ impl Persistence {
         tenant_id: i64,
         name: &str,
         region: &str,
+    ) -> Result<Bucket, tonic::Status> {
+        self.create_bucket_inner(tenant_id, name, region, None)
+            .await
+    }
+
+    /// Creates a bucket if the tenant is below its bucket limit (see
+    /// [`Self::ensure_tenant_bucket_capacity`]). The count is taken under the
+    /// bucket allocation lock, so concurrent creates cannot overshoot it.
+    pub async fn create_bucket_within_limit(
+        &self,
+        tenant_id: i64,
+        name: &str,
+        region: &str,
+        default_max_buckets: u64,
+    ) -> Result<Bucket, tonic::Status> {
+        self.create_bucket_inner(tenant_id, name, region, Some(default_max_buckets))
+            .await
+    }
+
+    async fn create_bucket_inner(
+        &self,
+        tenant_id: i64,
+        name: &str,
+        region: &str,
+        default_max_buckets: Option<u64>,
     ) -> Result<Bucket, tonic::Status> {
         let total_start = std::time::Instant::now();
         let step_start = std::time::Instant::now();
@@ -150,6 +332,13 @@ impl Persistence {
             allocation_start.elapsed(),
         );
         let step_start = std::time::Instant::now();
+        if control_journal::read_control_state(&self.storage)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?
+            .is_tenant_deleted(tenant_id)
+        {
+            return Err(tonic::Status::not_found("Tenant not found"));
+        }
         if bucket_journal::read_current_bucket(&self.storage, tenant_id, name)
             .await
             .map_err(|e| tonic::Status::internal(e.to_string()))?
@@ -163,6 +352,10 @@ impl Persistence {
             "persistence.create_bucket read_current_bucket",
             step_start.elapsed(),
         );
+        if let Some(default_max_buckets) = default_max_buckets {
+            self.ensure_tenant_bucket_capacity(tenant_id, default_max_buckets)
+                .await?;
+        }
         let step_start = std::time::Instant::now();
         let bucket = Bucket {
             id: bucket_journal::next_bucket_id(&self.storage)
@@ -173,6 +366,12 @@ impl Persistence {
             region: region.to_string(),
             created_at: Utc::now(),
             is_public_read: false,
+            policy_json: None,
+            max_bytes: None,
+            max_objects: None,
+            replicate_to: Vec::new(),
+            lifecycle_rules: None,
+            default_retention_days: None,
         };
         crate::emit_test_timing(
             "persistence.create_bucket next_bucket_id",
@@ -239,6 +438,33 @@ impl Persistence {
         Ok(bucket)
     }
 
+    /// Rejects a new bucket once the tenant owns as many as its limit: its own
+    /// override, or `default_max_buckets` when it has none. Zero is unlimited.
+    pub async fn ensure_tenant_bucket_capacity(
+        &self,
+        tenant_id: i64,
+        default_max_buckets: u64,
+    ) -> Result<(), tonic::Status> {
+        let limit = self
+            .get_tenant_max_buckets(tenant_id)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?
+            .unwrap_or(default_max_buckets);
+        if limit == 0 {
+            return Ok(());
+        }
+        let buckets = bucket_journal::read_current_buckets(&self.storage, tenant_id)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?
+            .len() as u64;
+        if buckets >= limit {
+            return Err(tonic::Status::resource_exhausted(format!(
+                "Tenant has reached its limit of {limit} buckets"
+            )));
+        }
+        Ok(())
+    }
+
     pub async fn get_bucket_by_name(&self, tenant_id: i64, name: &str) -> Result<Option<Bucket>> {
         if let Some(bucket) = self.cache.get_bucket(tenant_id, name).await {
             return Ok(Some(bucket));
@@ -277,6 +503,185 @@ impl Persistence {
         Ok(out)
     }
 
+    pub async fn set_bucket_policy(
+        &self,
+        tenant_id: i64,
+        bucket_name: &str,
+        policy_json: Option<String>,
+    ) -> Result<Bucket> {
+        let mut out = bucket_journal::read_current_bucket(&self.storage, tenant_id, bucket_name)
+            .await?
+            .ok_or_else(|| anyhow!("bucket not found"))?;
+        out.policy_json = policy_json;
+        let tenant_permit = self.bucket_tenant_write_permit(out.tenant_id).await?;
+        let global_permit = self.bucket_global_write_permit().await?;
+        bucket_journal::append_bucket_mutation_with_permits(
+            &self.storage,
+            &out,
+            BucketJournalMutation::Update,
+            &tenant_permit,
+            &global_permit,
+            &self.partition_owner_signing_key,
+        )
+        .await?;
+        self.cache.invalidate_bucket(tenant_id, bucket_name).await;
+        Ok(out)
+    }
+
+    pub async fn set_bucket_quota(
+        &self,
+        tenant_id: i64,
+        bucket_name: &str,
+        max_bytes: Option<u64>,
+        max_objects: Option<u64>,
+    ) -> Result<Bucket> {
+        let mut out = bucket_journal::read_current_bucket(&self.storage, tenant_id, bucket_name)
+            .await?
+            .ok_or_else(|| anyhow!("bucket not found"))?;
+        out.max_bytes = max_bytes;
+        out.max_objects = max_objects;
+        let tenant_permit = self.bucket_tenant_write_permit(out.tenant_id).await?;
+        let global_permit = self.bucket_global_write_permit().await?;
+        bucket_journal::append_bucket_mutation_with_permits(
+            &self.storage,
+            &out,
+            BucketJournalMutation::Update,
+            &tenant_permit,
+            &global_permit,
+            &self.partition_owner_signing_key,
+        )
+        .await?;
+        self.cache.invalidate_bucket(tenant_id, bucket_name).await;
+        Ok(out)
+    }
+
+    pub async fn set_bucket_replication(
+        &self,
+        tenant_id: i64,
+        bucket_name: &str,
+        replicate_to: Vec<String>,
+    ) -> Result<Bucket> {
+        let mut out = bucket_journal::read_current_bucket(&self.storage, tenant_id, bucket_name)
+            .await?
+            .ok_or_else(|| anyhow!("bucket not found"))?;
+        out.replicate_to = replicate_to;
+        let tenant_permit = self.bucket_tenant_write_permit(out.tenant_id).await?;
+        let global_permit = self.bucket_global_write_permit().await?;
+        bucket_journal::append_bucket_mutation_with_permits(
+            &self.storage,
+            &out,
+            BucketJournalMutation::Update,
+            &tenant_permit,
+            &global_permit,
+            &self.partition_owner_signing_key,
+        )
+        .await?;
+        self.cache.invalidate_bucket(tenant_id, bucket_name).await;
+        Ok(out)
+    }
+
+    pub async fn set_bucket_lifecycle(
+        &self,
+        tenant_id: i64,
+        bucket_name: &str,
+        lifecycle_rules: Option<String>,
+    ) -> Result<Bucket> {
+        let mut out = bucket_journal::read_current_bucket(&self.storage, tenant_id, bucket_name)
+            .await?
+            .ok_or_else(|| anyhow!("bucket not found"))?;
+        out.lifecycle_rules = lifecycle_rules;
+        let tenant_permit = self.bucket_tenant_write_permit(out.tenant_id).await?;
+        let global_permit = self.bucket_global_write_permit().await?;
+        bucket_journal::append_bucket_mutation_with_permits(
+            &self.storage,
+            &out,
+            BucketJournalMutation::Update,
+            &tenant_permit,
+            &global_permit,
+            &self.partition_owner_signing_key,
+        )
+        .await?;
+        self.cache.invalidate_bucket(tenant_id, bucket_name).await;
+        Ok(out)
+    }
+
+    pub async fn set_bucket_default_retention(
+        &self,
+        tenant_id: i64,
+        bucket_name: &str,
+        default_retention_days: Option<u32>,
+    ) -> Result<Bucket> {
+        let mut out = bucket_journal::read_current_bucket(&self.storage, tenant_id, bucket_name)
+            .await?
+            .ok_or_else(|| anyhow!("bucket not found"))?;
+        out.default_retention_days = default_retention_days;
+        let tenant_permit = self.bucket_tenant_write_permit(out.tenant_id).await?;
+        let global_permit = self.bucket_global_write_permit().await?;
+        bucket_journal::append_bucket_mutation_with_permits(
+            &self.storage,
+            &out,
+            BucketJournalMutation::Update,
+            &tenant_permit,
+            &global_permit,
+            &self.partition_owner_signing_key,
+        )
+        .await?;
+        self.cache.invalidate_bucket(tenant_id, bucket_name).await;
+        Ok(out)
+    }
+
+    /// Records a bucket homed in another region so this region can hold
+    /// replicas of its objects. The row keeps the home region, so ordinary
+    /// reads and writes here are still routed to the home region.
+    pub async fn ensure_replica_bucket(&self, bucket: &Bucket) -> Result<Bucket> {
+        let _allocation_guard = BUCKET_ID_ALLOCATION_LOCK.lock().await;
+        if let Some(existing) =
+            bucket_journal::read_current_bucket(&self.storage, bucket.tenant_id, &bucket.name)
+                .await?
+            && (existing.id != bucket.id || existing.region != bucket.region)
+        {
+            bail!(
+                "bucket {} in this region is not a replica of bucket id {} homed in {}",
+                bucket.name,
+                bucket.id,
+                bucket.region
+            );
+        }
+        if let Some(existing) =
+            bucket_journal::read_current_bucket_by_id(&self.storage, bucket.id).await?
+        {
+            if existing.tenant_id != bucket.tenant_id
+                || existing.name != bucket.name
+                || existing.region != bucket.region
+            {
+                bail!(
+                    "bucket id {} is already used by another bucket in this region",
+                    bucket.id
+                );
+            }
+            return Ok(existing);
+        }
+        let replica = Bucket {
+            replicate_to: Vec::new(),
+            ..bucket.clone()
+        };
+        let tenant_permit = self.bucket_tenant_write_permit(replica.tenant_id).await?;
+        let global_permit = self.bucket_global_write_permit().await?;
+        bucket_journal::append_bucket_mutation_with_permits(
+            &self.storage,
+            &replica,
+            BucketJournalMutation::Create,
+            &tenant_permit,
+            &global_permit,
+            &self.partition_owner_signing_key,
+        )
+        .await?;
+        self.cache
+            .invalidate_bucket(replica.tenant_id, &replica.name)
+            .await;
+        Ok(replica)
+    }
+
     pub async fn soft_delete_bucket(&self, tenant_id: i64, name: &str) -> Result<Option<Bucket>> {
         let deleted = bucket_journal::read_current_bucket(&self.storage, tenant_id, name).await?;
         if let Some(bucket) = &deleted {
@@ -376,6 +781,21 @@ impl Persistence {
         Ok(buckets)
     }
 
+    /// Buckets homed in `region` that carry lifecycle rules, ordered by id.
+    pub async fn list_lifecycle_buckets(&self, region: &str) -> Result<Vec<Bucket>> {
+        let mut buckets = Vec::new();
+        for tenant in self.list_tenants().await? {
+            buckets.extend(
+                bucket_journal::read_current_buckets(&self.storage, tenant.id)
+                    .await?
+                    .into_iter()
+                    .filter(|bucket| bucket.region == region && bucket.lifecycle_rules.is_some()),
+            );
+        }
+        buckets.sort_by_key(|bucket| bucket.id);
+        Ok(buckets)
+    }
+
     pub async fn active_index_policy_snapshot_hash(
         &self,
         tenant_id: i64,
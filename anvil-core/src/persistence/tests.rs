@@ -456,6 +456,37 @@ async fn tenant_and_bucket_creation_materialise_mesh_directory_locators() {
     );
 }
 
+#[tokio::test]
+async fn tenant_deletion_and_bucket_creation_never_both_succeed() {
+    let temp = tempdir().unwrap();
+    let persistence = Persistence::new(&test_config(temp.path()), None).unwrap();
+    let tenant = persistence
+        .create_tenant("tenant-a", "unused")
+        .await
+        .unwrap();
+
+    let (deleted, created) = tokio::join!(
+        persistence.delete_tenant(tenant.id),
+        persistence.create_bucket(tenant.id, "docs", "eu-west-1"),
+    );
+    match (deleted, created) {
+        (Ok(()), Err(status)) => assert_eq!(status.code(), tonic::Code::NotFound),
+        (Err(err), Ok(_)) => assert!(err.is::<TenantNotEmptyError>(), "{err}"),
+        (deleted, created) => panic!("unexpected outcome: {deleted:?} / {created:?}"),
+    }
+
+    let emptied = persistence
+        .create_tenant("tenant-b", "unused")
+        .await
+        .unwrap();
+    persistence.delete_tenant(emptied.id).await.unwrap();
+    let status = persistence
+        .create_bucket(emptied.id, "docs", "eu-west-1")
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
+
 #[tokio::test]
 async fn region_drain_blocks_bucket_creation_and_completion_with_active_locator() {
     let temp = tempdir().unwrap();
@@ -1043,6 +1074,7 @@ async fn persistence_replays_anvil_owned_state_after_fresh_instance_body() {
             payload_ref("part-hash-a", 4),
             4,
             "part-etag-a",
+            None,
         )
         .await
         .unwrap();
@@ -1324,6 +1356,62 @@ async fn persistence_replays_anvil_owned_state_after_fresh_instance_body() {
     assert_eq!(replayed.hf_list_keys(tenant.id).await.unwrap().len(), 1);
 }
 
+#[tokio::test]
+async fn resolve_tensor_follows_base_artifacts_to_the_holding_object() {
+    let temp = tempdir().unwrap();
+    let persistence = Persistence::new(&test_config(temp.path()), None).unwrap();
+
+    let base = model_manifest();
+    persistence
+        .create_model_artifact("artifact-a", 7, "models/base/", &base)
+        .await
+        .unwrap();
+    persistence
+        .create_model_tensors(
+            "artifact-a",
+            &[crate::anvil_api::TensorIndexRow {
+                tensor_name: "embed.weight".to_string(),
+                file_path: "model.safetensors".to_string(),
+                file_offset: 128,
+                byte_length: 64,
+                dtype: crate::anvil_api::DType::F32 as i32,
+                shape: vec![4, 4],
+                layout: "row_major".to_string(),
+                block_bytes: 0,
+                blocks: Vec::new(),
+            }],
+        )
+        .await
+        .unwrap();
+    let mut delta = model_manifest();
+    delta.artifact_id = "artifact-b".to_string();
+    delta.base_artifact_id = "artifact-a".to_string();
+    persistence
+        .create_model_artifact("artifact-b", 7, "models/delta", &delta)
+        .await
+        .unwrap();
+
+    let resolved = persistence
+        .resolve_tensor("artifact-b", "embed.weight")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(resolved.artifact_id, "artifact-a");
+    assert_eq!(resolved.location.bucket_id, 7);
+    assert_eq!(resolved.object_key(), "models/base/model.safetensors");
+    assert_eq!(
+        (resolved.tensor.file_offset, resolved.tensor.byte_length),
+        (128, 64)
+    );
+    assert!(
+        persistence
+            .resolve_tensor("artifact-b", "missing")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
 #[tokio::test]
 async fn persistence_compacts_object_metadata_and_restarts_from_manifest() {
     let temp = tempdir().unwrap();
@@ -1637,6 +1725,76 @@ async fn persistence_serializes_concurrent_task_queue_writes() {
     assert_eq!(ids.len(), 12);
 }
 
+#[tokio::test]
+async fn concurrent_writes_to_one_key_leave_a_single_latest_version() {
+    let temp = tempdir().unwrap();
+    let persistence = Persistence::new(&test_config(temp.path()), None).unwrap();
+    register_active_mesh_placement(&persistence).await;
+    let tenant = persistence
+        .create_tenant("tenant-a", "unused")
+        .await
+        .unwrap();
+    let bucket = persistence
+        .create_bucket(tenant.id, "docs", "test-region")
+        .await
+        .unwrap();
+
+    let writes = (0..8).map(|writer| {
+        let persistence = persistence.clone();
+        async move {
+            persistence
+                .create_object(
+                    tenant.id,
+                    bucket.id,
+                    "contended.txt",
+                    &format!("hash-{writer}"),
+                    1,
+                    &format!("etag-{writer}"),
+                    Some("text/plain"),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+        }
+    });
+    for result in futures_util::future::join_all(writes).await {
+        result.unwrap();
+    }
+
+    let versions = persistence
+        .list_object_versions(bucket.id, "contended.txt", "", None, 100)
+        .await
+        .unwrap()
+        .versions;
+    assert_eq!(versions.len(), 8);
+    let latest = versions
+        .iter()
+        .filter(|version| version.is_latest)
+        .collect::<Vec<_>>();
+    assert_eq!(latest.len(), 1);
+    let latest = &latest[0].object;
+    assert!(
+        versions
+            .iter()
+            .all(|version| version.object.created_at <= latest.created_at
+                && version.object.id <= latest.id)
+    );
+    let current = persistence
+        .get_object(bucket.id, "contended.txt")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(current.version_id, latest.version_id);
+    let (listed, _) = persistence
+        .list_objects(bucket.id, "", "", 100, "")
+        .await
+        .unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].version_id, latest.version_id);
+}
+
 #[test]
 fn task_queue_retries_coremeta_target_conflicts() {
     assert!(is_retryable_partition_fence_error(&anyhow!(
@@ -1764,7 +1922,14 @@ async fn persistence_global_journal_writes_use_current_fence_tokens() {
             .unwrap()
             .upload;
         persistence
-            .upsert_multipart_part(upload.id, 1, payload_ref("part-hash", 12), 12, "part-etag")
+            .upsert_multipart_part(
+                upload.id,
+                1,
+                payload_ref("part-hash", 12),
+                12,
+                "part-etag",
+                None,
+            )
             .await
             .unwrap();
         persistence
@@ -14,18 +14,20 @@ impl PlacementManager {
         count: usize,
     ) -> Vec<PeerId> {
         let nodes = cluster_state.read().await;
-        if nodes.is_empty() {
+        // Peers that have missed heartbeats may be gone, so never place on them.
+        let live: Vec<_> = nodes.iter().filter(|(_, info)| !info.stale).collect();
+        if live.is_empty() {
             return vec![];
         }
 
-        let mut scores: Vec<([u8; 32], PeerId)> = nodes
-            .keys()
-            .map(|peer_id| {
+        let mut scores: Vec<([u8; 32], PeerId)> = live
+            .into_iter()
+            .map(|(peer_id, _)| {
                 let mut hasher = Hasher::new();
                 // Hash both the object key and the peer id to get a unique score
                 hasher.update(object_key.as_bytes());
                 hasher.update(&peer_id.to_bytes());
-                (hasher.finalize().into(), peer_id.clone())
+                (hasher.finalize().into(), *peer_id)
             })
             .collect();
 
@@ -49,25 +51,31 @@ mod tests {
     use std::sync::Arc;
     use tokio::sync::RwLock;
 
-    #[tokio::test]
-    async fn test_placement_determinism_and_balancing() {
-        let manager = PlacementManager::default();
+    async fn cluster_with_peers(count: usize) -> ClusterState {
         let cluster_state: ClusterState = Arc::new(RwLock::new(HashMap::new()));
-
-        // Add some nodes to the cluster state
-        let peers: Vec<PeerId> = (0..10).map(|_| PeerId::random()).collect();
         {
             let mut state = cluster_state.write().await;
-            for peer in &peers {
+            for _ in 0..count {
                 state.insert(
-                    peer.clone(),
+                    PeerId::random(),
                     PeerInfo {
                         p2p_addrs: vec![],
                         grpc_addr: String::new(),
+                        zone: None,
+                        free_bytes: None,
+                        last_seen: chrono::Utc::now(),
+                        stale: false,
                     },
                 );
             }
         }
+        cluster_state
+    }
+
+    #[tokio::test]
+    async fn test_placement_determinism_and_balancing() {
+        let manager = PlacementManager::default();
+        let cluster_state = cluster_with_peers(10).await;
 
         let object_key1 = uuid::Uuid::new_v4().to_string();
         // Calculate placement twice for the same key
@@ -100,4 +108,24 @@ mod tests {
             "Placement should vary across a batch of different keys"
         );
     }
+
+    #[tokio::test]
+    async fn placement_skips_stale_peers() {
+        let manager = PlacementManager::default();
+        let cluster_state = cluster_with_peers(4).await;
+        let stale = {
+            let mut nodes = cluster_state.write().await;
+            let (peer_id, info) = nodes.iter_mut().next().unwrap();
+            info.stale = true;
+            *peer_id
+        };
+
+        for i in 0..32 {
+            let placement = manager
+                .calculate_placement(&format!("object-key-{i}"), &cluster_state, 4)
+                .await;
+            assert_eq!(placement.len(), 3);
+            assert!(!placement.contains(&stale), "stale peer received a shard");
+        }
+    }
 }
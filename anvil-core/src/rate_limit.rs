@@ -0,0 +1,193 @@
+use crate::persistence::Persistence;
+use anyhow::{Result, bail};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Token-bucket request limit for one tenant: `requests_per_second` tokens
+/// refill continuously and at most `burst` can be saved up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TenantRateLimit {
+    pub requests_per_second: u32,
+    pub burst: u32,
+}
+
+impl TenantRateLimit {
+    pub fn validate(&self) -> Result<()> {
+        if self.requests_per_second == 0 {
+            bail!("requests_per_second must be greater than zero");
+        }
+        if self.burst == 0 {
+            bail!("burst must be greater than zero");
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    limit: TenantRateLimit,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(limit: TenantRateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: f64::from(limit.burst),
+            refilled_at: now,
+        }
+    }
+
+    fn take(&mut self, now: Instant) -> Result<(), Duration> {
+        let rate = f64::from(self.limit.requests_per_second);
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * rate).min(f64::from(self.limit.burst));
+        self.refilled_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / rate))
+        }
+    }
+}
+
+/// Per-tenant token buckets shared by the gRPC interceptor and the S3
+/// gateway. Tenants without a configured limit are never throttled. Buckets
+/// live in this process only, so each node enforces the limit on its own.
+#[derive(Debug, Default)]
+pub struct TenantRateLimiter {
+    buckets: Mutex<HashMap<i64, TokenBucket>>,
+}
+
+impl TenantRateLimiter {
+    /// Takes one request token for `tenant_id`. When none is left, returns
+    /// how long the caller should wait before retrying.
+    pub fn check(&self, tenant_id: i64) -> Result<(), Duration> {
+        self.check_at(tenant_id, Instant::now())
+    }
+
+    fn check_at(&self, tenant_id: i64, now: Instant) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        match buckets.get_mut(&tenant_id) {
+            Some(bucket) => bucket.take(now),
+            None => Ok(()),
+        }
+    }
+
+    /// Applies a limit change for one tenant; `None` lifts the limit.
+    pub fn set_limit(&self, tenant_id: i64, limit: Option<TenantRateLimit>) {
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        match limit {
+            Some(limit) => {
+                if buckets
+                    .get(&tenant_id)
+                    .is_none_or(|bucket| bucket.limit != limit)
+                {
+                    buckets.insert(tenant_id, TokenBucket::full(limit, Instant::now()));
+                }
+            }
+            None => {
+                buckets.remove(&tenant_id);
+            }
+        }
+    }
+
+    /// Replaces every configured limit. Tenants whose limit is unchanged keep
+    /// their remaining tokens.
+    pub fn replace_limits(&self, limits: BTreeMap<i64, TenantRateLimit>) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        buckets.retain(|tenant_id, bucket| limits.get(tenant_id) == Some(&bucket.limit));
+        for (tenant_id, limit) in limits {
+            buckets
+                .entry(tenant_id)
+                .or_insert_with(|| TokenBucket::full(limit, now));
+        }
+    }
+}
+
+/// Whole seconds to advertise in a `Retry-After` header, never less than one.
+pub fn retry_after_secs(retry_after: Duration) -> u64 {
+    retry_after.as_secs_f64().ceil().max(1.0) as u64
+}
+
+/// Reloads tenant rate limits from the control journal every `interval`, so
+/// limits changed through another node take effect here too.
+pub async fn refresh_tenant_rate_limits(
+    persistence: Persistence,
+    limiter: std::sync::Arc<TenantRateLimiter>,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // Limits are loaded when the node starts; skip the immediate first tick.
+    ticker.tick().await;
+    loop {
+        ticker.tick().await;
+        match persistence.list_tenant_rate_limits().await {
+            Ok(limits) => limiter.replace_limits(limits),
+            Err(error) => warn!(%error, "Failed to reload tenant rate limits"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(requests_per_second: u32, burst: u32) -> TenantRateLimit {
+        TenantRateLimit {
+            requests_per_second,
+            burst,
+        }
+    }
+
+    #[test]
+    fn tenants_are_throttled_after_their_burst_and_refill_over_time() {
+        let limiter = TenantRateLimiter::default();
+        limiter.set_limit(7, Some(limit(2, 3)));
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.check_at(7, start).is_ok());
+        }
+        let retry_after = limiter.check_at(7, start).unwrap_err();
+        assert_eq!(retry_after, Duration::from_millis(500));
+        assert_eq!(retry_after_secs(retry_after), 1);
+        assert!(limiter.check_at(8, start).is_ok(), "unlimited tenant");
+
+        let later = start + Duration::from_millis(500);
+        assert!(limiter.check_at(7, later).is_ok());
+        assert!(limiter.check_at(7, later).is_err());
+    }
+
+    #[test]
+    fn replacing_limits_keeps_unchanged_buckets_and_drops_removed_ones() {
+        let limiter = TenantRateLimiter::default();
+        limiter.set_limit(1, Some(limit(1, 1)));
+        limiter.set_limit(2, Some(limit(1, 1)));
+        let now = Instant::now();
+        assert!(limiter.check_at(1, now).is_ok());
+        assert!(limiter.check_at(2, now).is_ok());
+
+        limiter.replace_limits(BTreeMap::from([(1, limit(1, 1))]));
+
+        assert!(
+            limiter.check_at(1, now).is_err(),
+            "unchanged limits keep spent tokens"
+        );
+        assert!(limiter.check_at(2, now).is_ok());
+        assert!(limiter.check_at(2, now).is_ok());
+    }
+
+    #[test]
+    fn limits_need_a_positive_rate_and_burst() {
+        assert!(limit(10, 20).validate().is_ok());
+        assert!(limit(0, 20).validate().is_err());
+        assert!(limit(10, 0).validate().is_err());
+    }
+}
@@ -0,0 +1,132 @@
+//! Minimal reader for the safetensors header so stored checkpoints can be
+//! indexed without reading tensor data.
+//!
+//! A safetensors file starts with an 8-byte little-endian header length `N`,
+//! followed by `N` bytes of JSON mapping tensor names to dtype, shape, and
+//! `data_offsets` relative to the first byte after the header.
+
+use crate::anvil_api::{DType, TensorIndexRow};
+use anyhow::{Result, anyhow, bail};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Length of the little-endian header-size prefix.
+pub const HEADER_LEN_PREFIX_BYTES: u64 = 8;
+
+/// Headers larger than this are rejected rather than buffered.
+pub const MAX_HEADER_BYTES: u64 = 100 * 1024 * 1024;
+
+const METADATA_KEY: &str = "__metadata__";
+
+#[derive(Deserialize)]
+struct TensorEntry {
+    dtype: String,
+    shape: Vec<u64>,
+    data_offsets: [u64; 2],
+}
+
+/// Decodes the header length from the first eight bytes of a file.
+pub fn header_len(prefix: &[u8]) -> Result<u64> {
+    let bytes: [u8; 8] = prefix
+        .get(..HEADER_LEN_PREFIX_BYTES as usize)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| anyhow!("safetensors file is shorter than its header length prefix"))?;
+    let len = u64::from_le_bytes(bytes);
+    if len > MAX_HEADER_BYTES {
+        bail!("safetensors header of {len} bytes exceeds the {MAX_HEADER_BYTES} byte limit");
+    }
+    Ok(len)
+}
+
+/// Builds tensor index rows from a header. `file_offset` in each row is
+/// absolute within the file; `file_path` is left for the caller to fill.
+pub fn tensor_index_rows(header: &[u8], header_len: u64) -> Result<Vec<TensorIndexRow>> {
+    let mut entries: BTreeMap<String, serde_json::Value> = serde_json::from_slice(header)
+        .map_err(|error| anyhow!("invalid safetensors header: {error}"))?;
+    entries.remove(METADATA_KEY);
+    let data_start = HEADER_LEN_PREFIX_BYTES + header_len;
+    entries
+        .into_iter()
+        .map(|(tensor_name, value)| {
+            let entry: TensorEntry = serde_json::from_value(value)
+                .map_err(|error| anyhow!("invalid safetensors entry {tensor_name:?}: {error}"))?;
+            let [begin, end] = entry.data_offsets;
+            if end < begin {
+                bail!("safetensors entry {tensor_name:?} has inverted data_offsets");
+            }
+            let shape = entry
+                .shape
+                .iter()
+                .map(|dim| u32::try_from(*dim))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| anyhow!("safetensors entry {tensor_name:?} has an oversized dim"))?;
+            Ok(TensorIndexRow {
+                tensor_name,
+                file_path: String::new(),
+                file_offset: data_start + begin,
+                byte_length: end - begin,
+                dtype: dtype_from_str(&entry.dtype) as i32,
+                shape,
+                layout: "row_major".to_string(),
+                block_bytes: 0,
+                blocks: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+fn dtype_from_str(dtype: &str) -> DType {
+    match dtype {
+        "F16" => DType::F16,
+        "BF16" => DType::Bf16,
+        "F32" => DType::F32,
+        "F64" => DType::F64,
+        "I8" => DType::I8,
+        "I16" => DType::I16,
+        "I32" => DType::I32,
+        "I64" => DType::I64,
+        "U8" => DType::U8,
+        _ => DType::DtypeUnspecified,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_len_reads_little_endian_prefix() {
+        let mut bytes = 42_u64.to_le_bytes().to_vec();
+        bytes.extend_from_slice(b"{}");
+        assert_eq!(header_len(&bytes).unwrap(), 42);
+        assert!(header_len(&bytes[..4]).is_err());
+        assert!(header_len(&(MAX_HEADER_BYTES + 1).to_le_bytes()).is_err());
+    }
+
+    #[test]
+    fn tensor_index_rows_use_absolute_offsets_and_skip_metadata() {
+        let header = br#"{
+            "__metadata__": {"format": "pt"},
+            "b.bias": {"dtype": "BF16", "shape": [4], "data_offsets": [64, 72]},
+            "a.weight": {"dtype": "F32", "shape": [4, 4], "data_offsets": [0, 64]}
+        }"#;
+        let rows = tensor_index_rows(header, header.len() as u64).unwrap();
+        let data_start = 8 + header.len() as u64;
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].tensor_name, "a.weight");
+        assert_eq!(rows[0].file_offset, data_start);
+        assert_eq!(rows[0].byte_length, 64);
+        assert_eq!(rows[0].dtype, DType::F32 as i32);
+        assert_eq!(rows[0].shape, vec![4, 4]);
+        assert_eq!(rows[1].tensor_name, "b.bias");
+        assert_eq!(rows[1].file_offset, data_start + 64);
+        assert_eq!(rows[1].dtype, DType::Bf16 as i32);
+    }
+
+    #[test]
+    fn tensor_index_rows_reject_inverted_offsets() {
+        let header = br#"{"t": {"dtype": "F32", "shape": [1], "data_offsets": [8, 4]}}"#;
+        assert!(tensor_index_rows(header, header.len() as u64).is_err());
+    }
+}
@@ -30,6 +30,7 @@ use personaldb_protocol::{
     PublicKeyTrustRecord, SignaturePurpose,
 };
 use serde_json::json;
+use std::collections::HashMap;
 use tonic::{Request, Response, Status};
 
 mod rpc_mapping;
@@ -74,11 +75,267 @@ impl AdminService for AppState {
                 tenant_id: tenant.id.to_string(),
                 name: tenant.name,
                 home_region,
+                max_buckets: None,
+                rate_limit: None,
             }),
             audit_event_id,
         }))
     }
 
+    async fn set_tenant_bucket_limit(
+        &self,
+        request: Request<SetTenantBucketLimitRequest>,
+    ) -> Result<Response<TenantAdminResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ManageTenants).await?;
+        let req = request.into_inner();
+        let context = require_admin_action_context(req.context.as_ref())?;
+        let tenant_id = resolve_tenant_id(self, &req.tenant_id).await?;
+        let tenant = self
+            .persistence
+            .list_tenants()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .into_iter()
+            .find(|tenant| tenant.id == tenant_id)
+            .ok_or_else(|| Status::not_found("Tenant not found"))?;
+        self.persistence
+            .set_tenant_max_buckets(tenant_id, req.max_buckets)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let rate_limit = self
+            .persistence
+            .get_tenant_rate_limit(tenant_id)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            "admin.tenant.bucket_limit.set",
+            &format!("tenant:{tenant_id}"),
+            json!({
+                "resource_kind": "tenant",
+                "tenant_id": tenant_id,
+                "tenant_name": &tenant.name,
+                "max_buckets": req.max_buckets,
+            }),
+        )
+        .await?;
+        Ok(Response::new(TenantAdminResponse {
+            request_id: context.request_id.clone(),
+            tenant: Some(TenantAdminDescriptor {
+                tenant_id: tenant_id.to_string(),
+                name: tenant.name,
+                home_region: tenant_home_region(self, tenant_id).await?,
+                max_buckets: req.max_buckets,
+                rate_limit: rate_limit.map(tenant_rate_limit_to_proto),
+            }),
+            audit_event_id,
+        }))
+    }
+
+    async fn set_tenant_rate_limit(
+        &self,
+        request: Request<SetTenantRateLimitRequest>,
+    ) -> Result<Response<TenantAdminResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ManageTenants).await?;
+        let req = request.into_inner();
+        let context = require_admin_action_context(req.context.as_ref())?;
+        let tenant_id = resolve_tenant_id(self, &req.tenant_id).await?;
+        let rate_limit = req
+            .rate_limit
+            .map(|limit| crate::rate_limit::TenantRateLimit {
+                requests_per_second: limit.requests_per_second,
+                burst: limit.burst,
+            });
+        if let Some(rate_limit) = rate_limit {
+            rate_limit
+                .validate()
+                .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        }
+        let tenant = self
+            .persistence
+            .list_tenants()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .into_iter()
+            .find(|tenant| tenant.id == tenant_id)
+            .ok_or_else(|| Status::not_found("Tenant not found"))?;
+        self.persistence
+            .set_tenant_rate_limit(tenant_id, rate_limit)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        self.tenant_rate_limiter.set_limit(tenant_id, rate_limit);
+        let max_buckets = self
+            .persistence
+            .get_tenant_max_buckets(tenant_id)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            "admin.tenant.rate_limit.set",
+            &format!("tenant:{tenant_id}"),
+            json!({
+                "resource_kind": "tenant",
+                "tenant_id": tenant_id,
+                "tenant_name": &tenant.name,
+                "requests_per_second": rate_limit.map(|limit| limit.requests_per_second),
+                "burst": rate_limit.map(|limit| limit.burst),
+            }),
+        )
+        .await?;
+        Ok(Response::new(TenantAdminResponse {
+            request_id: context.request_id.clone(),
+            tenant: Some(TenantAdminDescriptor {
+                tenant_id: tenant_id.to_string(),
+                name: tenant.name,
+                home_region: tenant_home_region(self, tenant_id).await?,
+                max_buckets,
+                rate_limit: rate_limit.map(tenant_rate_limit_to_proto),
+            }),
+            audit_event_id,
+        }))
+    }
+
+    async fn list_tenants(
+        &self,
+        request: Request<ListTenantsRequest>,
+    ) -> Result<Response<ListTenantsResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ManageTenants).await?;
+        let req = request.into_inner();
+        let page = req.page.as_ref();
+        let limit = page_limit(page);
+        let mut tenants = self
+            .persistence
+            .list_tenants()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        tenants.sort_by_key(|tenant| tenant.id);
+        let max_buckets = self
+            .persistence
+            .list_tenant_max_buckets()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let rate_limits = self
+            .persistence
+            .list_tenant_rate_limits()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let tenant_ids = tenants
+            .iter()
+            .map(|tenant| tenant.id.to_string())
+            .collect::<Vec<_>>();
+        let revision =
+            admin_cursor::collection_revision(tenant_ids.iter().map(|id| (id.as_str(), 0)));
+        let binding = AdminCursorBinding {
+            scope: "admin.list_tenants.v1",
+            filters: &[],
+            principal: &principal,
+            limit,
+            revision: &revision,
+            sort: "tenant_id.asc",
+        };
+        let after =
+            admin_cursor::decode_page_cursor(page, &binding, self.config.jwt_secret.as_bytes())?
+                .map(|cursor| {
+                    cursor
+                        .parse::<i64>()
+                        .map_err(|_| Status::invalid_argument("invalid page cursor"))
+                })
+                .transpose()?;
+        let mut tenants_page = Vec::new();
+        for tenant in tenants
+            .into_iter()
+            .filter(|tenant| after.is_none_or(|after| tenant.id > after))
+            .take(limit + 1)
+        {
+            tenants_page.push(TenantAdminDescriptor {
+                tenant_id: tenant.id.to_string(),
+                home_region: tenant_home_region(self, tenant.id).await?,
+                name: tenant.name,
+                max_buckets: max_buckets.get(&tenant.id).copied(),
+                rate_limit: rate_limits
+                    .get(&tenant.id)
+                    .copied()
+                    .map(tenant_rate_limit_to_proto),
+            });
+        }
+        let mut tenants = tenants_page;
+        let has_more = tenants.len() > limit;
+        if has_more {
+            tenants.truncate(limit);
+        }
+        let next_cursor = if has_more {
+            tenants.last().map_or(Ok(String::new()), |tenant| {
+                admin_cursor::encode_next_cursor(
+                    &tenant.tenant_id,
+                    &binding,
+                    self.config.jwt_secret.as_bytes(),
+                )
+            })?
+        } else {
+            String::new()
+        };
+        Ok(Response::new(ListTenantsResponse {
+            page: Some(PageResponse {
+                next_cursor,
+                has_more,
+            }),
+            tenants,
+        }))
+    }
+
+    async fn delete_tenant(
+        &self,
+        request: Request<DeleteTenantRequest>,
+    ) -> Result<Response<AdminMutationResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ManageTenants).await?;
+        let req = request.into_inner();
+        let context = require_admin_action_context(req.context.as_ref())?;
+        let tenant_id = resolve_tenant_id(self, &req.tenant_id).await?;
+        let tenant = self
+            .persistence
+            .list_tenants()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .into_iter()
+            .find(|tenant| tenant.id == tenant_id)
+            .ok_or_else(|| Status::not_found("Tenant not found"))?;
+        self.persistence
+            .delete_tenant(tenant_id)
+            .await
+            .map_err(|err| {
+                if err.is::<persistence::TenantNotEmptyError>() {
+                    Status::failed_precondition(err.to_string())
+                } else {
+                    Status::internal(err.to_string())
+                }
+            })?;
+        self.tenant_rate_limiter.set_limit(tenant_id, None);
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            "admin.tenant.delete",
+            &format!("tenant:{tenant_id}"),
+            json!({
+                "resource_kind": "tenant",
+                "tenant_id": tenant_id,
+                "tenant_name": &tenant.name,
+            }),
+        )
+        .await?;
+        Ok(Response::new(AdminMutationResponse {
+            request_id: context.request_id.clone(),
+            resource_id: tenant_id.to_string(),
+            generation: 0,
+            audit_event_id,
+            idempotent_replay: false,
+        }))
+    }
+
     async fn create_application(
         &self,
         request: Request<CreateApplicationRequest>,
@@ -137,10 +394,19 @@ impl AdminService for AppState {
             .into_iter()
             .find(|app| app.name == req.app_name)
             .ok_or_else(|| Status::not_found("Application not found"))?;
+        let grace = match req.grace_secs {
+            0 => None,
+            secs => Some(
+                i64::try_from(secs)
+                    .ok()
+                    .and_then(chrono::Duration::try_seconds)
+                    .ok_or_else(|| Status::invalid_argument("grace_secs is out of range"))?,
+            ),
+        };
         let client_secret = generated_client_secret();
         let encrypted_secret = encrypt_admin_client_secret(self, &client_secret)?;
         self.persistence
-            .update_app_secret(app.id, &encrypted_secret)
+            .rotate_app_secret(app.id, &encrypted_secret, grace)
             .await
             .map_err(|err| Status::internal(err.to_string()))?;
         let audit_event_id = record_admin_audit_event(
@@ -155,6 +421,7 @@ impl AdminService for AppState {
                 "app_id": app.id,
                 "app_name": &app.name,
                 "client_id": &app.client_id,
+                "grace_secs": req.grace_secs,
             }),
         )
         .await?;
@@ -597,6 +864,232 @@ impl AdminService for AppState {
         }))
     }
 
+    async fn set_bucket_quota_admin(
+        &self,
+        request: Request<SetBucketQuotaAdminRequest>,
+    ) -> Result<Response<BucketQuotaResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ManageBuckets).await?;
+        let req = request.into_inner();
+        let context = require_mutation_context(req.context.as_ref(), false)?;
+        let tenant_id = resolve_tenant_id(self, &req.tenant_id).await?;
+        self.persistence
+            .get_bucket_by_name(tenant_id, &req.bucket_name)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .ok_or_else(|| Status::not_found("Bucket not found"))?;
+        let bucket = self
+            .persistence
+            .set_bucket_quota(tenant_id, &req.bucket_name, req.max_bytes, req.max_objects)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let usage = self
+            .persistence
+            .bucket_usage(&bucket, None)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            "admin.bucket.quota.set",
+            &bucket_resource_id(tenant_id, &bucket.name),
+            json!({
+                "resource_kind": "bucket",
+                "tenant_id": tenant_id,
+                "bucket_id": bucket.id,
+                "bucket_name": &bucket.name,
+                "region": &bucket.region,
+                "max_bytes": bucket.max_bytes,
+                "max_objects": bucket.max_objects,
+                "used_bytes": usage.used_bytes,
+                "object_count": usage.object_count,
+            }),
+        )
+        .await?;
+        Ok(Response::new(BucketQuotaResponse {
+            request_id: context.request_id.clone(),
+            max_bytes: bucket.max_bytes,
+            max_objects: bucket.max_objects,
+            used_bytes: usage.used_bytes,
+            object_count: usage.object_count,
+            bucket: Some(bucket_to_proto(bucket)),
+            audit_event_id,
+        }))
+    }
+
+    async fn set_bucket_replication_admin(
+        &self,
+        request: Request<SetBucketReplicationAdminRequest>,
+    ) -> Result<Response<BucketReplicationResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ManageBuckets).await?;
+        let req = request.into_inner();
+        let context = require_mutation_context(req.context.as_ref(), false)?;
+        let tenant_id = resolve_tenant_id(self, &req.tenant_id).await?;
+        let existing = self
+            .persistence
+            .get_bucket_by_name(tenant_id, &req.bucket_name)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .ok_or_else(|| Status::not_found("Bucket not found"))?;
+        let mut replicate_to = Vec::with_capacity(req.replicate_to.len());
+        for region in req.replicate_to {
+            let region = region.trim().to_string();
+            if !crate::validation::is_valid_region_name(&region) {
+                return Err(Status::invalid_argument(format!(
+                    "invalid replica region {region:?}"
+                )));
+            }
+            if region == existing.region {
+                return Err(Status::invalid_argument(format!(
+                    "{region} is the bucket's home region"
+                )));
+            }
+            if !replicate_to.contains(&region) {
+                replicate_to.push(region);
+            }
+        }
+        let bucket = self
+            .persistence
+            .set_bucket_replication(tenant_id, &req.bucket_name, replicate_to)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            "admin.bucket.replication.set",
+            &bucket_resource_id(tenant_id, &bucket.name),
+            json!({
+                "resource_kind": "bucket",
+                "tenant_id": tenant_id,
+                "bucket_id": bucket.id,
+                "bucket_name": &bucket.name,
+                "region": &bucket.region,
+                "replicate_to": &bucket.replicate_to,
+            }),
+        )
+        .await?;
+        Ok(Response::new(BucketReplicationResponse {
+            request_id: context.request_id.clone(),
+            replicate_to: bucket.replicate_to.clone(),
+            bucket: Some(bucket_to_proto(bucket)),
+            audit_event_id,
+        }))
+    }
+
+    async fn set_bucket_lifecycle_admin(
+        &self,
+        request: Request<SetBucketLifecycleAdminRequest>,
+    ) -> Result<Response<BucketLifecycleResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ManageBuckets).await?;
+        let req = request.into_inner();
+        let context = require_mutation_context(req.context.as_ref(), false)?;
+        let tenant_id = resolve_tenant_id(self, &req.tenant_id).await?;
+        let rules = req
+            .rules
+            .iter()
+            .map(|rule| crate::bucket_lifecycle::LifecycleRule {
+                prefix: rule.prefix.clone(),
+                expiration_days: rule.expiration_days,
+            })
+            .collect::<Vec<_>>();
+        let lifecycle_rules = crate::bucket_lifecycle::encode_lifecycle_rules(&rules)
+            .map_err(|err| Status::invalid_argument(err.to_string()))?;
+        self.persistence
+            .get_bucket_by_name(tenant_id, &req.bucket_name)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .ok_or_else(|| Status::not_found("Bucket not found"))?;
+        let bucket = self
+            .persistence
+            .set_bucket_lifecycle(tenant_id, &req.bucket_name, lifecycle_rules)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            "admin.bucket.lifecycle.set",
+            &bucket_resource_id(tenant_id, &bucket.name),
+            json!({
+                "resource_kind": "bucket",
+                "tenant_id": tenant_id,
+                "bucket_id": bucket.id,
+                "bucket_name": &bucket.name,
+                "lifecycle_rules": &rules,
+            }),
+        )
+        .await?;
+        Ok(Response::new(BucketLifecycleResponse {
+            request_id: context.request_id.clone(),
+            bucket: Some(bucket_to_proto(bucket)),
+            rules: req.rules,
+            audit_event_id,
+        }))
+    }
+
+    async fn admin_copy_object(
+        &self,
+        request: Request<AdminCopyObjectRequest>,
+    ) -> Result<Response<AdminCopyObjectResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ManageBuckets).await?;
+        let req = request.into_inner();
+        let context = require_admin_action_context(req.context.as_ref())?;
+        let source_tenant_id = resolve_tenant_id(self, &req.source_tenant_id).await?;
+        let destination_tenant_id = resolve_tenant_id(self, &req.destination_tenant_id).await?;
+        let source_version_id = req
+            .source_version_id
+            .as_deref()
+            .filter(|value| !value.is_empty())
+            .map(uuid::Uuid::parse_str)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("Invalid source_version_id"))?;
+        let copied = self
+            .object_manager
+            .admin_copy_object(
+                source_tenant_id,
+                &req.source_bucket_name,
+                &req.source_object_key,
+                source_version_id,
+                destination_tenant_id,
+                &req.destination_bucket_name,
+                &req.destination_object_key,
+                &principal.principal_id,
+            )
+            .await?;
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            "admin.object.copy",
+            &format!(
+                "{}:object:{}",
+                bucket_resource_id(destination_tenant_id, &req.destination_bucket_name),
+                req.destination_object_key
+            ),
+            json!({
+                "resource_kind": "object",
+                "source_tenant_id": source_tenant_id,
+                "source_bucket_name": &req.source_bucket_name,
+                "source_object_key": &req.source_object_key,
+                "source_version_id": source_version_id.map(|id| id.to_string()),
+                "destination_tenant_id": destination_tenant_id,
+                "destination_bucket_name": &req.destination_bucket_name,
+                "destination_object_key": &req.destination_object_key,
+                "version_id": copied.version_id.to_string(),
+                "size": copied.size,
+            }),
+        )
+        .await?;
+        Ok(Response::new(AdminCopyObjectResponse {
+            request_id: context.request_id.clone(),
+            version_id: copied.version_id.to_string(),
+            etag: copied.etag,
+            size: copied.size,
+            audit_event_id,
+        }))
+    }
+
     async fn create_host_alias(
         &self,
         request: Request<CreateHostAliasAdminRequest>,
@@ -1033,6 +1526,7 @@ impl AdminService for AppState {
             state: lifecycle_state_to_proto(region.state),
             generation: region.generation,
             audit_event_id,
+            shard_migrations_queued: 0,
         }))
     }
 
@@ -1241,6 +1735,7 @@ impl AdminService for AppState {
             state: lifecycle_state_to_proto(cell.state),
             generation: cell.generation,
             audit_event_id,
+            shard_migrations_queued: 0,
         }))
     }
 
@@ -1447,6 +1942,7 @@ impl AdminService for AppState {
                 .map_err(lifecycle_status)?,
             state: CoreLifecycleState::Joining,
             drain: None,
+            cordoned: false,
             last_heartbeat_at: None,
             created_at: now.clone(),
             updated_at: now,
@@ -1459,6 +1955,48 @@ impl AdminService for AppState {
         }))
     }
 
+    async fn get_cluster_state(
+        &self,
+        request: Request<GetClusterStateRequest>,
+    ) -> Result<Response<ClusterStateResponse>, Status> {
+        require_admin(&request, self, SystemAdminRelation::ManageNodes).await?;
+        // Membership must stay visible while lifecycle storage is unreadable,
+        // since that is exactly when operators need it; peers then simply
+        // lack their node identity.
+        let descriptors: HashMap<String, mesh_lifecycle::NodeDescriptor> = self
+            .persistence
+            .list_node_descriptors(None, None)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|node| (node.libp2p_peer_id.clone(), node))
+            .collect();
+        let mut peers: Vec<ClusterPeer> = self
+            .cluster
+            .read()
+            .await
+            .iter()
+            .map(|(peer_id, info)| {
+                let peer_id = peer_id.to_base58();
+                let node = descriptors.get(&peer_id);
+                ClusterPeer {
+                    node_id: node.map(|node| node.node_id.clone()).unwrap_or_default(),
+                    region: node.map(|node| node.region.clone()).unwrap_or_default(),
+                    cell_id: node.map(|node| node.cell_id.clone()).unwrap_or_default(),
+                    peer_id,
+                    grpc_addr: info.grpc_addr.clone(),
+                    zone: info.zone.clone(),
+                    free_bytes: info.free_bytes,
+                    last_seen: info.last_seen.to_rfc3339(),
+                    stale: info.stale,
+                    local: info.grpc_addr == self.config.public_api_addr,
+                }
+            })
+            .collect();
+        peers.sort_by(|left, right| left.peer_id.cmp(&right.peer_id));
+        Ok(Response::new(ClusterStateResponse { peers }))
+    }
+
     async fn activate_node(
         &self,
         request: Request<ActivateNodeRequest>,
@@ -1541,12 +2079,53 @@ impl AdminService for AppState {
             node_audit_details(&node),
         )
         .await?;
+        let shard_migrations_queued = crate::worker::queue_node_shard_migrations(
+            &self.persistence,
+            &self.core_store,
+            &node.node_id,
+        )
+        .await
+        .map_err(|err| {
+            Status::internal(format!(
+                "node {} is draining but its shard migrations were not queued: {err}",
+                node.node_id
+            ))
+        })?;
         Ok(Response::new(DrainOperationResponse {
             request_id: context.request_id.clone(),
             resource_id: node.node_id,
             state: lifecycle_state_to_proto(node.state),
             generation: node.generation,
             audit_event_id,
+            shard_migrations_queued,
+        }))
+    }
+
+    async fn get_node_drain_progress(
+        &self,
+        request: Request<GetNodeDrainProgressRequest>,
+    ) -> Result<Response<NodeDrainProgressResponse>, Status> {
+        require_admin(&request, self, SystemAdminRelation::ManageNodes).await?;
+        let req = request.into_inner();
+        let node = self
+            .persistence
+            .list_node_descriptors(None, None)
+            .await
+            .map_err(lifecycle_status)?
+            .into_iter()
+            .find(|node| node.node_id == req.node_id)
+            .ok_or_else(|| Status::not_found(format!("node {} not found", req.node_id)))?;
+        let progress =
+            crate::worker::node_shard_migration_progress(&self.persistence, &node.node_id)
+                .await
+                .map_err(|err| Status::internal(err.to_string()))?;
+        Ok(Response::new(NodeDrainProgressResponse {
+            node_id: node.node_id,
+            state: lifecycle_state_to_proto(node.state),
+            generation: node.generation,
+            pending_shard_migrations: progress.pending,
+            completed_shard_migrations: progress.completed,
+            failed_shard_migrations: progress.failed,
         }))
     }
 
@@ -1583,6 +2162,43 @@ impl AdminService for AppState {
         }))
     }
 
+    async fn set_node_placement_eligible(
+        &self,
+        request: Request<SetNodePlacementEligibleRequest>,
+    ) -> Result<Response<NodeResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ManageNodes).await?;
+        let req = request.into_inner();
+        let context = require_mutation_context(req.context.as_ref(), false)?;
+        let node = self
+            .persistence
+            .set_node_descriptor_cordoned(
+                &req.node_id,
+                context.expected_generation,
+                !req.placement_eligible,
+            )
+            .await
+            .map_err(lifecycle_status)?;
+        let action = if node.cordoned {
+            "admin.node.cordon"
+        } else {
+            "admin.node.uncordon"
+        };
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            action,
+            &format!("node:{}", node.node_id),
+            node_audit_details(&node),
+        )
+        .await?;
+        Ok(Response::new(NodeResponse {
+            request_id: context.request_id.clone(),
+            node: Some(node_descriptor_to_proto(node)),
+            audit_event_id,
+        }))
+    }
+
     async fn remove_node(
         &self,
         request: Request<RemoveNodeRequest>,
@@ -1834,6 +2450,168 @@ impl AdminService for AppState {
         Ok(Response::new(response))
     }
 
+    async fn run_garbage_collection(
+        &self,
+        request: Request<RunGarbageCollectionRequest>,
+    ) -> Result<Response<GarbageCollectionResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::RunRepair).await?;
+        let req = request.into_inner();
+        let context = require_admin_action_context(req.context.as_ref())?;
+        let grace_period_secs = if req.grace_period_secs == 0 {
+            self.config.shard_gc_grace_period_secs
+        } else {
+            req.grace_period_secs
+        };
+        let report = self
+            .persistence
+            .collect_orphaned_shards(
+                std::time::Duration::from_secs(grace_period_secs),
+                req.dry_run,
+            )
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            "admin.gc.run",
+            self.persistence.owner_node_id(),
+            json!({
+                "dry_run": report.dry_run,
+                "grace_period_secs": grace_period_secs,
+                "orphaned_shards": report.orphaned_shards,
+                "reclaimable_bytes": report.reclaimable_bytes,
+                "deleted_shards": report.deleted_shards,
+            }),
+        )
+        .await?;
+
+        Ok(Response::new(GarbageCollectionResponse {
+            request_id: context.request_id.clone(),
+            dry_run: report.dry_run,
+            scanned_shards: report.scanned_shards,
+            orphaned_shards: report.orphaned_shards,
+            reclaimable_bytes: report.reclaimable_bytes,
+            deleted_shards: report.deleted_shards,
+            pending_releases: report.pending_releases,
+            audit_event_id,
+        }))
+    }
+
+    async fn list_tasks(
+        &self,
+        request: Request<ListTasksRequest>,
+    ) -> Result<Response<ListTasksResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::ViewDiagnostics).await?;
+        let req = request.into_inner();
+        let page = req.page.as_ref();
+        let limit = page_limit(page);
+        let status = none_if_empty(&req.status)
+            .map(str::parse::<crate::tasks::TaskStatus>)
+            .transpose()
+            .map_err(Status::invalid_argument)?;
+        let mut tasks = self
+            .persistence
+            .list_tasks()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        tasks.retain(|task| status.is_none_or(|status| task.status == status));
+        tasks.sort_by_key(|task| task.id);
+
+        // Tasks change state constantly, so cursors are keyset positions on
+        // the task id rather than snapshots of the whole queue.
+        let filters = [("status", req.status.trim())];
+        let binding = AdminCursorBinding {
+            scope: "admin.list_tasks.v1",
+            filters: &filters,
+            principal: &principal,
+            limit,
+            revision: "",
+            sort: "task_id.asc",
+        };
+        let cursor =
+            admin_cursor::decode_page_cursor(page, &binding, self.config.jwt_secret.as_bytes())?;
+        let mut tasks = tasks
+            .into_iter()
+            .filter(|task| {
+                cursor
+                    .as_deref()
+                    .is_none_or(|cursor| task_cursor_position(task.id).as_str() > cursor)
+            })
+            .take(limit + 1)
+            .map(|task| TaskSummary {
+                task_id: task.id,
+                task_type: task.task_type.as_str().to_string(),
+                status: task.status.as_str().to_string(),
+                priority: task.priority,
+                attempts: task.attempts,
+                last_error: task.last_error.unwrap_or_default(),
+                scheduled_at: task.scheduled_at.to_rfc3339(),
+                updated_at: task.updated_at.to_rfc3339(),
+            })
+            .collect::<Vec<_>>();
+        let has_more = tasks.len() > limit;
+        if has_more {
+            tasks.truncate(limit);
+        }
+        let next_cursor = if has_more {
+            tasks.last().map_or(Ok(String::new()), |task| {
+                admin_cursor::encode_next_cursor(
+                    &task_cursor_position(task.task_id),
+                    &binding,
+                    self.config.jwt_secret.as_bytes(),
+                )
+            })?
+        } else {
+            String::new()
+        };
+        Ok(Response::new(ListTasksResponse {
+            page: Some(PageResponse {
+                next_cursor,
+                has_more,
+            }),
+            tasks,
+        }))
+    }
+
+    async fn retry_task(
+        &self,
+        request: Request<RetryTaskRequest>,
+    ) -> Result<Response<AdminMutationResponse>, Status> {
+        let principal = require_admin(&request, self, SystemAdminRelation::RunRepair).await?;
+        let req = request.into_inner();
+        let context = require_admin_action_context(req.context.as_ref())?;
+        let retried = self
+            .persistence
+            .retry_task(req.task_id)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        if !retried {
+            return Err(Status::failed_precondition(format!(
+                "task {} is not failed or dead-lettered",
+                req.task_id
+            )));
+        }
+        let resource_id = format!("task:{}", req.task_id);
+        let audit_event_id = record_admin_audit_event(
+            self,
+            &principal,
+            context,
+            "admin.task.retry",
+            &resource_id,
+            json!({ "task_id": req.task_id }),
+        )
+        .await?;
+
+        Ok(Response::new(AdminMutationResponse {
+            request_id: context.request_id.clone(),
+            resource_id,
+            generation: 0,
+            audit_event_id,
+            idempotent_replay: false,
+        }))
+    }
+
     async fn list_diagnostics(
         &self,
         request: Request<ListDiagnosticsRequest>,
@@ -607,6 +607,11 @@ pub(super) fn diagnostic_position(diagnostic: &DiagnosticRecord) -> String {
     )
 }
 
+/// Zero-padded so cursor positions compare in task id order.
+pub(super) fn task_cursor_position(task_id: i64) -> String {
+    format!("{task_id:020}")
+}
+
 pub(super) async fn mesh_lifecycle_diagnostics(
     state: &AppState,
 ) -> Result<Vec<DiagnosticRecord>, Status> {
@@ -938,6 +943,15 @@ pub(super) fn app_resource_id(tenant_id: i64, app_name: &str) -> String {
     format!("tenant:{tenant_id}:app:{app_name}")
 }
 
+pub(super) fn tenant_rate_limit_to_proto(
+    rate_limit: crate::rate_limit::TenantRateLimit,
+) -> TenantRateLimit {
+    TenantRateLimit {
+        requests_per_second: rate_limit.requests_per_second,
+        burst: rate_limit.burst,
+    }
+}
+
 pub(super) fn validate_policy_parts(action: &str, resource: &str) -> Result<(), Status> {
     let action = action.trim();
     let resource = resource.trim();
@@ -1081,22 +1095,46 @@ pub(super) async fn rotate_application_secret_envelopes(
                 continue;
             };
             stats.app_secrets_examined += 1;
-            match state
+            // A secret still inside its rotation grace window is re-encrypted
+            // alongside the current one so the window survives key rotation.
+            let previous = details
+                .previous_client_secret
+                .as_ref()
+                .filter(|previous| previous.expires_at > chrono::Utc::now());
+            let rotated_previous = match previous {
+                Some(previous) => state
+                    .secret_keyring
+                    .reencrypt_if_needed(&previous.client_secret_encrypted)
+                    .map_err(|err| Status::internal(err.to_string()))?
+                    .map(
+                        |client_secret_encrypted| crate::persistence::PreviousAppSecret {
+                            client_secret_encrypted,
+                            expires_at: previous.expires_at,
+                        },
+                    ),
+                None => None,
+            };
+            let rotated = state
                 .secret_keyring
                 .reencrypt_if_needed(&details.client_secret_encrypted)
-                .map_err(|err| Status::internal(err.to_string()))?
-            {
-                Some(rotated) => {
-                    stats.app_secrets_rotated += 1;
-                    if !dry_run {
-                        state
-                            .persistence
-                            .update_app_secret(details.id, &rotated)
-                            .await
-                            .map_err(|err| Status::internal(err.to_string()))?;
-                    }
-                }
-                None => stats.already_active += 1,
+                .map_err(|err| Status::internal(err.to_string()))?;
+            if rotated.is_none() && rotated_previous.is_none() {
+                stats.already_active += 1;
+                continue;
+            }
+            stats.app_secrets_rotated += 1;
+            if !dry_run {
+                state
+                    .persistence
+                    .update_app_secret(
+                        details.id,
+                        rotated
+                            .as_deref()
+                            .unwrap_or(&details.client_secret_encrypted),
+                        rotated_previous.as_ref().or(previous),
+                    )
+                    .await
+                    .map_err(|err| Status::internal(err.to_string()))?;
             }
         }
     }
@@ -1194,6 +1232,7 @@ pub(super) fn node_audit_details(node: &mesh_lifecycle::NodeDescriptor) -> serde
         "capacity_json_hash": &node.capacity_json_hash,
         "state": node.state,
         "drain": &node.drain,
+        "cordoned": node.cordoned,
         "generation": node.generation,
     })
 }
@@ -1370,6 +1409,20 @@ pub(super) async fn resolve_tenant_id(state: &AppState, tenant_ref: &str) -> Res
         .ok_or_else(|| Status::not_found("Tenant not found"))
 }
 
+/// The home region recorded in a tenant's locator, or empty when the tenant
+/// has none.
+pub(super) async fn tenant_home_region(state: &AppState, tenant_id: i64) -> Result<String, Status> {
+    let tenant_id = mesh_directory::TenantId::new(tenant_id.to_string())
+        .map_err(|err| Status::internal(err.to_string()))?;
+    Ok(
+        mesh_directory::read_tenant_locator_descriptor(&state.storage, &tenant_id)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .map(|locator| locator.home_region.to_string())
+            .unwrap_or_default(),
+    )
+}
+
 pub(super) async fn resolve_tenant_app(
     state: &AppState,
     tenant_id: i64,
@@ -1582,6 +1635,7 @@ pub(super) fn node_descriptor_to_proto(value: mesh_lifecycle::NodeDescriptor) ->
         capacity_json_hash: value.capacity_json_hash,
         state: lifecycle_state_to_proto(value.state),
         drain: value.drain.map(node_drain_descriptor_to_proto),
+        cordoned: value.cordoned,
         last_heartbeat_at: value.last_heartbeat_at.unwrap_or_default(),
         created_at: value.created_at,
         updated_at: value.updated_at,
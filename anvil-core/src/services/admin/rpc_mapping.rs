@@ -3,6 +3,10 @@ use crate::system_realm::SystemAdminRelation;
 pub fn admin_rpc_relation_mapping() -> &'static [(&'static str, SystemAdminRelation)] {
     &[
         ("CreateTenant", SystemAdminRelation::ManageTenants),
+        ("SetTenantBucketLimit", SystemAdminRelation::ManageTenants),
+        ("SetTenantRateLimit", SystemAdminRelation::ManageTenants),
+        ("ListTenants", SystemAdminRelation::ManageTenants),
+        ("DeleteTenant", SystemAdminRelation::ManageTenants),
         ("CreateApplication", SystemAdminRelation::ManageApps),
         ("RotateApplicationSecret", SystemAdminRelation::ManageApps),
         (
@@ -42,6 +46,16 @@ pub fn admin_rpc_relation_mapping() -> &'static [(&'static str, SystemAdminRelat
             "SetBucketPublicAccessAdmin",
             SystemAdminRelation::ManageBuckets,
         ),
+        ("SetBucketQuotaAdmin", SystemAdminRelation::ManageBuckets),
+        (
+            "SetBucketReplicationAdmin",
+            SystemAdminRelation::ManageBuckets,
+        ),
+        (
+            "SetBucketLifecycleAdmin",
+            SystemAdminRelation::ManageBuckets,
+        ),
+        ("AdminCopyObject", SystemAdminRelation::ManageBuckets),
         ("CreateHostAlias", SystemAdminRelation::ManageHostAliases),
         ("ActivateHostAlias", SystemAdminRelation::ManageHostAliases),
         ("SuspendHostAlias", SystemAdminRelation::ManageHostAliases),
@@ -63,12 +77,18 @@ pub fn admin_rpc_relation_mapping() -> &'static [(&'static str, SystemAdminRelat
         ("GetLocalNodeDescriptor", SystemAdminRelation::ManageNodes),
         ("ActivateNode", SystemAdminRelation::ManageNodes),
         ("DrainNode", SystemAdminRelation::ManageNodes),
+        ("GetNodeDrainProgress", SystemAdminRelation::ManageNodes),
         ("ForceOfflineNode", SystemAdminRelation::ManageNodes),
+        ("SetNodePlacementEligible", SystemAdminRelation::ManageNodes),
         ("RemoveNode", SystemAdminRelation::ManageNodes),
         ("ListNodes", SystemAdminRelation::ManageNodes),
+        ("GetClusterState", SystemAdminRelation::ManageNodes),
         ("ListRoutingRecords", SystemAdminRelation::ManageRouting),
         ("RepairRoutingRecord", SystemAdminRelation::ManageRouting),
         ("RunRepair", SystemAdminRelation::RunRepair),
+        ("RunGarbageCollection", SystemAdminRelation::RunRepair),
+        ("ListTasks", SystemAdminRelation::ViewDiagnostics),
+        ("RetryTask", SystemAdminRelation::RunRepair),
         ("ListDiagnostics", SystemAdminRelation::ViewDiagnostics),
         ("ListAuditEvents", SystemAdminRelation::ViewAuditLog),
         ("ListStorageClasses", SystemAdminRelation::ViewSystem),
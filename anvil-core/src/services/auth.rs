@@ -196,23 +196,29 @@ impl AuthService for AppState {
             .map_err(|e| Status::internal(e.to_string()))?
             .ok_or_else(|| Status::unauthenticated("Invalid client ID"))?;
 
-        let decrypted_secret = self
-            .secret_keyring
-            .decrypt(&app_details.client_secret_encrypted)
-            .map_err(|_| Status::unauthenticated("Invalid client secret"))?;
-
-        if !constant_time_eq::constant_time_eq(
-            decrypted_secret.as_slice(),
-            req.client_secret.as_bytes(),
-        ) {
+        // A secret rotated with a grace period stays valid alongside its
+        // replacement until the grace window closes.
+        let secret_matches = app_details
+            .accepted_client_secrets(chrono::Utc::now())
+            .into_iter()
+            .filter_map(|encrypted| self.secret_keyring.decrypt(encrypted).ok())
+            .any(|decrypted_secret| {
+                constant_time_eq::constant_time_eq(
+                    decrypted_secret.as_slice(),
+                    req.client_secret.as_bytes(),
+                )
+            });
+        if !secret_matches {
             return Err(Status::unauthenticated("Invalid client secret"));
         }
 
         // Tokens identify the principal and Anvil storage tenant. Authorisation
         // is resolved from Zanzibar relations at request time, not token scopes.
+        let ttl_secs =
+            auth::access_token_ttl_secs(self.config.access_token_ttl_secs, req.requested_ttl_secs);
         let token = self
             .jwt_manager
-            .mint_token(app_details.id.to_string(), app_details.tenant_id)
+            .mint_token_with_ttl(app_details.id.to_string(), app_details.tenant_id, ttl_secs)
             .map_err(|e| Status::internal(e.to_string()))?;
         tracing::info!(
             "[AuthService] Returning access token for app_id={}",
@@ -220,10 +226,24 @@ impl AuthService for AppState {
         );
         Ok(Response::new(GetAccessTokenResponse {
             access_token: token,
-            expires_in: 3600,
+            expires_in: ttl_secs as i64,
         }))
     }
 
+    async fn introspect_token(
+        &self,
+        request: Request<IntrospectTokenRequest>,
+    ) -> Result<Response<IntrospectTokenResponse>, Status> {
+        request
+            .extensions()
+            .get::<auth::Claims>()
+            .ok_or_else(|| Status::unauthenticated("Missing claims"))?;
+        Ok(Response::new(introspect_token_response(
+            &self.jwt_manager,
+            &request.get_ref().token,
+        )))
+    }
+
     async fn create_application_credential(
         &self,
         request: Request<CreateApplicationCredentialRequest>,
@@ -294,7 +314,7 @@ impl AuthService for AppState {
             .encrypt(client_secret.as_bytes())
             .map_err(|e| Status::internal(e.to_string()))?;
         self.persistence
-            .update_app_secret(app.id, &encrypted_secret)
+            .rotate_app_secret(app.id, &encrypted_secret, None)
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
         let audit_event_id = crate::services::audit::record_tenant_audit_event(
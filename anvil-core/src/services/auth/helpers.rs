@@ -1,6 +1,21 @@
 use super::*;
 use base64::Engine;
 
+pub(super) fn introspect_token_response(
+    jwt_manager: &auth::JwtManager,
+    token: &str,
+) -> IntrospectTokenResponse {
+    match jwt_manager.verify_token(token.trim()) {
+        Ok(claims) => IntrospectTokenResponse {
+            active: true,
+            sub: claims.sub,
+            tenant_id: claims.tenant_id,
+            exp: claims.exp as i64,
+        },
+        Err(_) => IntrospectTokenResponse::default(),
+    }
+}
+
 pub(super) fn authz_resource(namespace: &str, object_id: &str, relation: &str) -> String {
     format!("{}/{}#{}", namespace, object_id, relation)
 }
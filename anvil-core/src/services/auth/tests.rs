@@ -42,3 +42,31 @@ fn authz_watch_cursor_split_round_trips() {
     let (low, high) = split_u128(cursor);
     assert_eq!(join_u128(low, high), cursor);
 }
+
+#[test]
+fn introspection_reports_fresh_tokens_active_and_expired_tokens_inactive() {
+    let jwt_manager = auth::JwtManager::new("introspection-secret".to_string());
+    let fresh = jwt_manager.mint_token("42".to_string(), 7).unwrap();
+    let response = introspect_token_response(&jwt_manager, &fresh);
+    assert!(response.active);
+    assert_eq!(response.sub, "42");
+    assert_eq!(response.tenant_id, 7);
+    assert!(response.exp > chrono::Utc::now().timestamp());
+
+    let expired = jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &auth::Claims {
+            sub: "42".to_string(),
+            exp: (chrono::Utc::now().timestamp() - 3600) as usize,
+            tenant_id: 7,
+            jti: None,
+        },
+        &jsonwebtoken::EncodingKey::from_secret(b"introspection-secret"),
+    )
+    .unwrap();
+    assert_eq!(
+        introspect_token_response(&jwt_manager, &expired),
+        IntrospectTokenResponse::default()
+    );
+    assert!(!introspect_token_response(&jwt_manager, "not-a-token").active);
+}
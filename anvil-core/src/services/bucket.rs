@@ -1,6 +1,7 @@
 use crate::anvil_api::bucket_service_server::BucketService;
 use crate::anvil_api::*;
 use crate::bucket_journal::BucketJournalMutation;
+use crate::bucket_policy::BucketPolicyUpdate;
 use crate::{
     AppState, auth, bucket_journal, mesh_lifecycle,
     permissions::AnvilAction,
@@ -26,13 +27,18 @@ impl BucketService for AppState {
         &self,
         request: Request<CreateBucketRequest>,
     ) -> Result<Response<CreateBucketResponse>, Status> {
-        tracing::debug!("[service] ENTERING create_bucket");
         let claims = request
             .extensions()
             .get::<auth::Claims>()
             .ok_or_else(|| Status::unauthenticated("Missing claims"))?;
 
         let req = request.get_ref();
+        tracing::debug!(
+            tenant_id = claims.tenant_id,
+            bucket = %req.bucket_name,
+            region = %req.region,
+            "create_bucket"
+        );
         let transaction_id = bucket_transaction_id(req.options.as_ref())?;
         let bucket = if let Some(transaction_id) = transaction_id {
             self.create_bucket_in_transaction(claims, req, transaction_id)
@@ -47,7 +53,7 @@ impl BucketService for AppState {
             bucket
         };
 
-        tracing::debug!("[service] EXITING create_bucket");
+        tracing::trace!(bucket_id = bucket.id, "create_bucket finished");
         Ok(Response::new(CreateBucketResponse {
             bucket_id: bucket.id,
         }))
@@ -82,11 +88,11 @@ impl BucketService for AppState {
         &self,
         request: Request<ListBucketsRequest>,
     ) -> Result<Response<ListBucketsResponse>, Status> {
-        tracing::debug!("[service] ENTERING list_buckets");
         let claims = request
             .extensions()
             .get::<auth::Claims>()
             .ok_or_else(|| Status::unauthenticated("Missing claims"))?;
+        tracing::debug!(tenant_id = claims.tenant_id, "list_buckets");
 
         let buckets = self.bucket_manager.list_buckets(claims).await?;
 
@@ -102,10 +108,7 @@ impl BucketService for AppState {
             })
             .collect();
 
-        tracing::debug!(
-            "[service] EXITING list_buckets, found {} buckets",
-            response_buckets.len()
-        );
+        tracing::trace!(count = response_buckets.len(), "list_buckets finished");
         Ok(Response::new(ListBucketsResponse {
             buckets: response_buckets,
         }))
@@ -142,25 +145,79 @@ impl BucketService for AppState {
         let req = request.get_ref();
         let transaction_id = bucket_transaction_id(req.options.as_ref())?;
 
-        // Bucket policy is projected into Anvil's native public-read flag; all
-        // object-level enforcement still flows through the normal authorisation path.
-        let policy: serde_json::Value = serde_json::from_str(&req.policy_json)
-            .map_err(|e| Status::invalid_argument(format!("Invalid policy JSON: {}", e)))?;
-        let is_public_read = policy["is_public_read"].as_bool().unwrap_or(false);
+        // Legacy `{"is_public_read": ..}` bodies are projected into Anvil's native
+        // public-read flag; S3-style documents are validated and stored on the bucket.
+        let update = BucketPolicyUpdate::parse(&req.bucket_name, &req.policy_json)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
 
         if let Some(transaction_id) = transaction_id {
-            self.put_bucket_policy_in_transaction(claims, req, is_public_read, transaction_id)
+            self.put_bucket_policy_in_transaction(claims, req, &update, transaction_id)
+                .await?;
+        } else {
+            let bucket = match update {
+                BucketPolicyUpdate::PublicRead(is_public_read) => {
+                    self.bucket_manager
+                        .set_bucket_public_access(claims, &req.bucket_name, is_public_read)
+                        .await?
+                }
+                BucketPolicyUpdate::Document(policy_json) => {
+                    self.bucket_manager
+                        .set_bucket_policy_document(claims, &req.bucket_name, policy_json)
+                        .await?
+                }
+            };
+            self.publish_bucket_metadata_event(claims.tenant_id, &bucket, "policy_update", false)
+                .await?;
+        }
+
+        Ok(Response::new(PutBucketPolicyResponse {}))
+    }
+
+    async fn delete_bucket_policy(
+        &self,
+        request: Request<DeleteBucketPolicyRequest>,
+    ) -> Result<Response<DeleteBucketPolicyResponse>, Status> {
+        let claims = request
+            .extensions()
+            .get::<auth::Claims>()
+            .ok_or_else(|| Status::unauthenticated("Missing claims"))?;
+        let req = request.get_ref();
+        let transaction_id = bucket_transaction_id(req.options.as_ref())?;
+        if let Some(transaction_id) = transaction_id {
+            self.delete_bucket_policy_in_transaction(claims, req, transaction_id)
                 .await?;
         } else {
             let bucket = self
                 .bucket_manager
-                .set_bucket_public_access(claims, &req.bucket_name, is_public_read)
+                .delete_bucket_policy(claims, &req.bucket_name)
                 .await?;
             self.publish_bucket_metadata_event(claims.tenant_id, &bucket, "policy_update", false)
                 .await?;
         }
 
-        Ok(Response::new(PutBucketPolicyResponse {}))
+        Ok(Response::new(DeleteBucketPolicyResponse {}))
+    }
+
+    async fn put_bucket_object_lock_configuration(
+        &self,
+        request: Request<PutBucketObjectLockConfigurationRequest>,
+    ) -> Result<Response<PutBucketObjectLockConfigurationResponse>, Status> {
+        let claims = request
+            .extensions()
+            .get::<auth::Claims>()
+            .ok_or_else(|| Status::unauthenticated("Missing claims"))?;
+        let req = request.get_ref();
+
+        let bucket = self
+            .bucket_manager
+            .set_bucket_default_retention(claims, &req.bucket_name, req.default_retention_days)
+            .await?;
+        self.publish_bucket_metadata_event(claims.tenant_id, &bucket, "object_lock_update", false)
+            .await?;
+
+        Ok(Response::new(PutBucketObjectLockConfigurationResponse {
+            default_retention_days: bucket.default_retention_days,
+        }))
     }
 
     async fn watch_bucket_metadata(
@@ -288,6 +345,9 @@ impl AppState {
                 "A bucket with that name already exists.",
             ));
         }
+        self.bucket_manager
+            .ensure_bucket_capacity(claims.tenant_id)
+            .await?;
         let bucket = crate::persistence::Bucket {
             id: bucket_journal::next_bucket_id(&self.storage)
                 .await
@@ -297,6 +357,12 @@ impl AppState {
             region: req.region.clone(),
             created_at: chrono::Utc::now(),
             is_public_read: false,
+            policy_json: None,
+            max_bytes: None,
+            max_objects: None,
+            replicate_to: Vec::new(),
+            lifecycle_rules: None,
+            default_retention_days: None,
         };
         self.stage_bucket_metadata_transaction(
             claims,
@@ -349,7 +415,37 @@ impl AppState {
         &self,
         claims: &auth::Claims,
         req: &PutBucketPolicyRequest,
-        is_public_read: bool,
+        update: &BucketPolicyUpdate,
+        transaction_id: &str,
+    ) -> Result<crate::persistence::Bucket, Status> {
+        crate::access_control::require_action(
+            &self.storage,
+            &self.persistence,
+            claims,
+            AnvilAction::BucketWrite,
+            &req.bucket_name,
+        )
+        .await?;
+        let mut bucket =
+            bucket_journal::read_current_bucket(&self.storage, claims.tenant_id, &req.bucket_name)
+                .await
+                .map_err(|err| Status::internal(err.to_string()))?
+                .ok_or_else(|| Status::not_found("Bucket not found"))?;
+        update.apply(&mut bucket);
+        self.stage_bucket_metadata_transaction(
+            claims,
+            &bucket,
+            BucketJournalMutation::Update,
+            transaction_id,
+        )
+        .await?;
+        Ok(bucket)
+    }
+
+    async fn delete_bucket_policy_in_transaction(
+        &self,
+        claims: &auth::Claims,
+        req: &DeleteBucketPolicyRequest,
         transaction_id: &str,
     ) -> Result<crate::persistence::Bucket, Status> {
         crate::access_control::require_action(
@@ -365,7 +461,8 @@ impl AppState {
                 .await
                 .map_err(|err| Status::internal(err.to_string()))?
                 .ok_or_else(|| Status::not_found("Bucket not found"))?;
-        bucket.is_public_read = is_public_read;
+        bucket.policy_json = None;
+        bucket.is_public_read = false;
         self.stage_bucket_metadata_transaction(
             claims,
             &bucket,
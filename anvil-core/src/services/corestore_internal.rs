@@ -14,6 +14,9 @@ use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Request, Response, Status};
 
+/// Largest `ShardChunk` payload `GetShard` streams from a shard file.
+const GET_SHARD_CHUNK_BYTES: usize = 256 * 1024;
+
 #[tonic::async_trait]
 impl BlockStoreInternal for AppState {
     type GetShardStream =
@@ -70,9 +73,9 @@ impl BlockStoreInternal for AppState {
         } else {
             None
         };
-        let bytes = self
+        let mut reader = self
             .core_store
-            .read_internal_shard_range(CoreInternalGetShard {
+            .open_internal_shard_reader(CoreInternalGetShard {
                 block_id: req.block_id.clone(),
                 shard_index: u16::try_from(req.shard_index)
                     .map_err(|_| Status::invalid_argument("shard_index exceeds u16"))?,
@@ -90,15 +93,40 @@ impl BlockStoreInternal for AppState {
             .map_err(internal_status)?;
         let (tx, rx) = mpsc::channel(2);
         tokio::spawn(async move {
-            let _ = tx
-                .send(Ok(ShardChunk {
-                    block_id: req.block_id,
-                    shard_index: req.shard_index,
-                    offset: req.range_start,
-                    data: bytes,
-                    eof: true,
-                }))
-                .await;
+            let chunk = |offset, data, eof| ShardChunk {
+                block_id: req.block_id.clone(),
+                shard_index: req.shard_index,
+                offset,
+                data,
+                eof,
+            };
+            // The last chunk is held back until the shard checksums pass, so
+            // a reader never sees eof on bytes that failed verification.
+            let mut offset = req.range_start;
+            let mut held: Option<Vec<u8>> = None;
+            loop {
+                match reader.next_chunk(GET_SHARD_CHUNK_BYTES).await {
+                    Ok(Some(data)) => {
+                        if let Some(previous) = held.replace(data) {
+                            let len = previous.len() as u64;
+                            if tx.send(Ok(chunk(offset, previous, false))).await.is_err() {
+                                return;
+                            }
+                            offset += len;
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(error) => {
+                        let _ = tx.send(Err(internal_status(error))).await;
+                        return;
+                    }
+                }
+            }
+            let last = match reader.finish().await {
+                Ok(()) => Ok(chunk(offset, held.unwrap_or_default(), true)),
+                Err(error) => Err(internal_status(error)),
+            };
+            let _ = tx.send(last).await;
         });
         Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
     }
@@ -1632,3 +1660,73 @@ fn ensure_local_proxy_target(local_region: &str, target_region: &str) -> Result<
 fn now_unix_nanos() -> i64 {
     chrono::Utc::now().timestamp_nanos_opt().unwrap_or(i64::MAX)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    async fn test_state() -> (tempfile::TempDir, AppState) {
+        let temp = tempfile::tempdir().unwrap();
+        let config = Config {
+            jwt_secret: "test-secret".to_string(),
+            anvil_secret_encryption_key:
+                "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string(),
+            cluster_secret: Some("test-cluster-secret".to_string()),
+            cluster_listen_addr: "/ip4/127.0.0.1/udp/0/quic-v1".to_string(),
+            public_api_addr: "127.0.0.1:0".to_string(),
+            api_listen_addr: "127.0.0.1:0".to_string(),
+            region: "local".to_string(),
+            bootstrap_system_admin_subject_kind: "app".to_string(),
+            bootstrap_system_admin_subject_id: "admin-principal".to_string(),
+            bootstrap_addrs: Vec::new(),
+            init_cluster: false,
+            enable_mdns: false,
+            storage_path: temp.path().join("storage").to_string_lossy().into_owned(),
+            ..Config::default()
+        };
+        let state = AppState::new(
+            config,
+            None,
+            crate::test_support::personaldb_protocol_keyring(),
+        )
+        .await
+        .unwrap();
+        (temp, state)
+    }
+
+    #[tokio::test]
+    async fn client_tokens_cannot_call_internal_node_rpcs() {
+        let (_temp, state) = test_state().await;
+        let token = state
+            .jwt_manager
+            .mint_token("tenant-app".to_string(), 1)
+            .unwrap();
+        let claims = state.jwt_manager.verify_token(&token).unwrap();
+        let mut request = Request::new(CoreMetaReadRowsRequest {
+            header: Some(InternalRequestHeader {
+                request_id: "forged-request".to_string(),
+                trace_id: String::new(),
+                source_node_id: claims.sub.clone(),
+                membership_epoch: 1,
+                source_node_fence: 0,
+                signature: vec![1, 2, 3],
+            }),
+            ..Default::default()
+        });
+        request.extensions_mut().insert(claims);
+
+        let error = ensure_internal_node_request(&state, &request)
+            .await
+            .expect_err("client tokens must not authorise internal RPCs");
+
+        assert_eq!(error.code(), tonic::Code::PermissionDenied);
+        assert!(error.message().contains("manage_nodes"));
+
+        let anonymous = Request::new(CoreMetaReadRowsRequest::default());
+        let error = ensure_internal_node_request(&state, &anonymous)
+            .await
+            .expect_err("internal RPCs require authenticated node claims");
+        assert_eq!(error.code(), tonic::Code::Unauthenticated);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{AppState, access_control, auth, permissions::AnvilAction, tasks::TaskType};
+use crate::{
+    AppState, access_control, auth, permissions::AnvilAction, tasks::TaskType, validation,
+};
 use tonic::{Request, Response, Status};
 
 use crate::anvil_api as api;
@@ -125,13 +127,31 @@ impl api::hf_ingestion_service_server::HfIngestionService for AppState {
         &self,
         request: Request<api::StartHfIngestionRequest>,
     ) -> Result<Response<api::StartHfIngestionResponse>, Status> {
-        tracing::info!(?request, "ENTERED start_ingestion");
         let (_metadata, extensions, req) = request.into_parts();
+        // The request metadata carries the caller's bearer token, so only
+        // the ingestion target is logged.
+        tracing::debug!(
+            repo = %req.repo,
+            revision = %req.revision,
+            target_bucket = %req.target_bucket,
+            target_prefix = %req.target_prefix,
+            "start_ingestion"
+        );
         if req.key_name.is_empty() || req.repo.is_empty() || req.target_bucket.is_empty() {
             return Err(Status::invalid_argument(
                 "key_name, repo and target_bucket required",
             ));
         }
+        if !validation::is_valid_hf_repo_id(&req.repo) {
+            return Err(Status::invalid_argument(
+                "repo must be a Hugging Face repository id such as `namespace/name` or `name`",
+            ));
+        }
+
+        let repo_type = req
+            .repo_type
+            .parse::<crate::tasks::HFRepoType>()
+            .map_err(Status::invalid_argument)?;
 
         let claims = auth::try_get_claims_from_extensions(&extensions)
             .ok_or_else(|| Status::unauthenticated("Missing authentication claims"))?;
@@ -144,7 +164,7 @@ impl api::hf_ingestion_service_server::HfIngestionService for AppState {
         )
         .await?;
 
-        tracing::info!("Authorization successful for start_ingestion");
+        tracing::trace!(tenant_id = claims.tenant_id, "start_ingestion authorized");
         // Lookup key id
         let Some((key_id, _enc)) = self
             .persistence
@@ -178,6 +198,7 @@ impl api::hf_ingestion_service_server::HfIngestionService for AppState {
                 } else {
                     Some(req.revision.as_str())
                 },
+                repo_type,
                 &req.target_bucket,
                 &req.target_region,
                 if req.target_prefix.is_empty() {
@@ -234,6 +255,8 @@ impl api::hf_ingestion_service_server::HfIngestionService for AppState {
             downloading,
             stored,
             failed,
+            bytes_done,
+            bytes_total,
             err,
             started_at,
             finished_at,
@@ -257,6 +280,8 @@ impl api::hf_ingestion_service_server::HfIngestionService for AppState {
             finished_at: finished_at
                 .map(|d: chrono::DateTime<chrono::Utc>| d.to_rfc3339())
                 .unwrap_or_default(),
+            bytes_done: bytes_done as u64,
+            bytes_total: bytes_total as u64,
         }))
     }
 
@@ -293,4 +318,57 @@ impl api::hf_ingestion_service_server::HfIngestionService for AppState {
             .map_err(|e: anyhow::Error| Status::internal(e.to_string()))?;
         Ok(Response::new(api::CancelHfIngestionResponse {}))
     }
+
+    async fn get_ingestion_items(
+        &self,
+        request: Request<api::GetHfIngestionItemsRequest>,
+    ) -> Result<Response<api::GetHfIngestionItemsResponse>, Status> {
+        let (_metadata, extensions, req) = request.into_parts();
+        let claims = auth::try_get_claims_from_extensions(&extensions)
+            .ok_or_else(|| Status::unauthenticated("Missing authentication claims"))?;
+        access_control::require_action(
+            &self.storage,
+            &self.persistence,
+            &claims,
+            AnvilAction::HfIngestionRead,
+            &req.ingestion_id,
+        )
+        .await?;
+
+        let id: i64 = req
+            .ingestion_id
+            .parse()
+            .map_err(|_| Status::invalid_argument("invalid id"))?;
+        let state = if req.state.trim().is_empty() {
+            None
+        } else {
+            Some(
+                req.state
+                    .parse::<crate::tasks::HFIngestionItemState>()
+                    .map_err(Status::invalid_argument)?,
+            )
+        };
+        self.persistence
+            .hf_get_ingestion_job(id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .filter(|job| job.tenant_id == claims.tenant_id)
+            .ok_or_else(|| Status::not_found("ingestion not found"))?;
+        let items = self
+            .persistence
+            .hf_get_ingestion_items_detailed(id, state)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .into_iter()
+            .map(|item| api::HfIngestionItem {
+                path: item.path,
+                state: item.state.as_str().to_string(),
+                size: item.size.map(|size| size.max(0) as u64),
+                error: item.error.unwrap_or_default(),
+                started_at: item.started_at.map(|d| d.to_rfc3339()).unwrap_or_default(),
+                finished_at: item.finished_at.map(|d| d.to_rfc3339()).unwrap_or_default(),
+            })
+            .collect();
+        Ok(Response::new(api::GetHfIngestionItemsResponse { items }))
+    }
 }
@@ -1,7 +1,9 @@
 use crate::anvil_api::internal_proxy_service_server::InternalProxyService;
 use crate::anvil_api::*;
 use crate::object_manager::{
-    ObjectLinkReadMode, ObjectReadConsistency, ObjectWriteOptions, ObjectWriteVisibility,
+    ObjectConditions, ObjectLinkReadMode, ObjectReadConsistency, ObjectWriteOptions,
+    ObjectWriteVisibility, REPLICA_BUCKET_HEADER, REPLICA_GET_METHOD, REPLICA_OBJECT_HEADER,
+    REPLICA_PUT_METHOD, REPLICA_RANGE_HEADER, replica_json_value,
 };
 use crate::{AppState, auth, system_realm};
 use futures_util::StreamExt;
@@ -50,6 +52,8 @@ impl InternalProxyService for AppState {
             "HEAD" => proxy_get_or_head(self, header, original_claims, true).await,
             "PUT" => proxy_put(self, header, original_claims, stream).await,
             "DELETE" => proxy_delete(self, header, original_claims).await,
+            REPLICA_PUT_METHOD => proxy_replica_put(self, header, stream).await,
+            REPLICA_GET_METHOD => proxy_replica_get(self, header).await,
             method => Err(Status::invalid_argument(format!(
                 "unsupported proxy method {method}"
             ))),
@@ -203,6 +207,10 @@ async fn proxy_put(
                 transaction_id: None,
                 transaction_principal: None,
                 storage_class_id: None,
+                conditions: ObjectConditions {
+                    if_match: proxy_header_string(&header.headers, "if-match"),
+                    if_none_match: proxy_header_string(&header.headers, "if-none-match"),
+                },
                 ..Default::default()
             },
         )
@@ -262,6 +270,103 @@ async fn proxy_delete(
     )
 }
 
+async fn proxy_replica_put(
+    state: &AppState,
+    header: ProxyRequestHeader,
+    stream: tonic::Streaming<ProxyRequestChunk>,
+) -> Result<Response<<AppState as InternalProxyService>::ProxyObjectStream>, Status> {
+    let tenant_id = parse_proxy_tenant_id(&header)?;
+    let bucket: crate::persistence::Bucket =
+        replica_json_value(&header.headers, REPLICA_BUCKET_HEADER)?;
+    let source: crate::persistence::Object =
+        replica_json_value(&header.headers, REPLICA_OBJECT_HEADER)?;
+    if bucket.tenant_id != tenant_id
+        || bucket.name != header.bucket_name
+        || source.key != header.object_key
+    {
+        return Err(Status::invalid_argument(
+            "replica descriptors do not match the proxy request",
+        ));
+    }
+    let data_stream = stream.map(|chunk_result| match chunk_result {
+        Ok(chunk) => match chunk.part {
+            Some(proxy_request_chunk::Part::Body(bytes)) => Ok(bytes),
+            Some(proxy_request_chunk::Part::Header(_)) => Err(Status::invalid_argument(
+                "proxy request may contain only one header chunk",
+            )),
+            None => Ok(Vec::new()),
+        },
+        Err(status) => Err(status),
+    });
+    let object = state
+        .object_manager
+        .put_replica_object(bucket, source, data_stream)
+        .await?;
+
+    unary_proxy_response(
+        header.request_id,
+        200,
+        true,
+        object_response_headers(&object),
+    )
+}
+
+async fn proxy_replica_get(
+    state: &AppState,
+    header: ProxyRequestHeader,
+) -> Result<Response<<AppState as InternalProxyService>::ProxyObjectStream>, Status> {
+    let tenant_id = parse_proxy_tenant_id(&header)?;
+    let range = proxy_header_string(&header.headers, REPLICA_RANGE_HEADER)
+        .map(|value| {
+            value
+                .split_once('-')
+                .and_then(|(start, end)| Some((start.parse().ok()?, end.parse().ok()?)))
+                .map(|(start, end_exclusive)| crate::core_store::CoreByteRange {
+                    start,
+                    end_exclusive,
+                })
+                .ok_or_else(|| Status::invalid_argument("invalid replica range"))
+        })
+        .transpose()?;
+    let result = state
+        .object_manager
+        .get_replica_object(tenant_id, &header.bucket_name, &header.object_key, range)
+        .await?;
+    let mut headers = object_response_headers(&result.object);
+    headers.push(proxy_header(
+        REPLICA_OBJECT_HEADER,
+        &serde_json::to_vec(&result.object).map_err(|e| Status::internal(e.to_string()))?,
+    ));
+
+    let (tx, rx) = mpsc::channel(4);
+    tokio::spawn(async move {
+        if tx
+            .send(Ok(proxy_header_chunk(ProxyResponseHeader {
+                request_id: header.request_id,
+                status: 200,
+                headers,
+                trailers: Vec::new(),
+                committed: false,
+            })))
+            .await
+            .is_err()
+        {
+            return;
+        }
+        let mut data_stream = result.stream;
+        while let Some(chunk) = data_stream.next().await {
+            let chunk = chunk.map(|bytes| ProxyResponseChunk {
+                part: Some(proxy_response_chunk::Part::Body(bytes)),
+            });
+            let failed = chunk.is_err();
+            if tx.send(chunk).await.is_err() || failed {
+                break;
+            }
+        }
+    });
+    Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+}
+
 fn unary_proxy_response(
     request_id: String,
     status: u32,
@@ -322,6 +322,18 @@ impl MeshControlService for AppState {
             )
             .await
             .map_err(mesh_status)?;
+        crate::worker::queue_node_shard_migrations(
+            &self.persistence,
+            &self.core_store,
+            &req.node_id,
+        )
+        .await
+        .map_err(|err| {
+            Status::internal(format!(
+                "node {} is draining but its shard migrations were not queued: {err}",
+                req.node_id
+            ))
+        })?;
         Ok(Response::new(mesh_write_response(
             request_id,
             req.node_id,
@@ -10,6 +10,7 @@ pub mod huggingface;
 pub mod index;
 pub mod internal_proxy;
 pub mod mesh_control;
+pub mod model;
 pub mod object;
 pub mod personaldb;
 pub mod registry;
@@ -35,7 +36,7 @@ use crate::anvil_api::{
     index_service_server::IndexServiceServer,
     internal_proxy_service_server::InternalProxyServiceServer,
     mesh_control_service_server::MeshControlServiceServer,
-    object_service_server::ObjectServiceServer,
+    model_service_server::ModelServiceServer, object_service_server::ObjectServiceServer,
     personal_db_service_server::PersonalDbServiceServer,
     registry_service_server::RegistryServiceServer, repair_service_server::RepairServiceServer,
     root_register_internal_server::RootRegisterInternalServer,
@@ -72,7 +73,7 @@ pub fn create_grpc_router(state: AppState, auth_interceptor: AuthInterceptorFn)
         let f = auth_interceptor.clone();
         move |req| f.call(req)
     };
-    tonic::service::Routes::new(AuthServiceServer::with_interceptor(
+    let routes = tonic::service::Routes::new(AuthServiceServer::with_interceptor(
         state.clone(),
         auth_closure.clone(),
     ))
@@ -154,8 +155,40 @@ pub fn create_grpc_router(state: AppState, auth_interceptor: AuthInterceptorFn)
     ))
     .add_service(HfIngestionServiceServer::with_interceptor(
         state.clone(),
-        auth_closure,
+        auth_closure.clone(),
     ))
+    .add_service(ModelServiceServer::with_interceptor(
+        state.clone(),
+        auth_closure,
+    ));
+    if state.config.grpc_reflection {
+        add_reflection_services(routes)
+    } else {
+        routes
+    }
+}
+
+/// Registers both reflection protocol versions: `grpcurl` asks for v1 and
+/// falls back to v1alpha, which older clients still speak exclusively.
+#[cfg(feature = "reflection")]
+fn add_reflection_services(routes: Routes) -> Routes {
+    let builder = || {
+        tonic_reflection::server::Builder::configure()
+            .register_encoded_file_descriptor_set(crate::anvil_api::FILE_DESCRIPTOR_SET)
+    };
+    match (builder().build_v1(), builder().build_v1alpha()) {
+        (Ok(v1), Ok(v1alpha)) => routes.add_service(v1).add_service(v1alpha),
+        (Err(error), _) | (_, Err(error)) => {
+            tracing::error!(%error, "failed to build the gRPC reflection service");
+            routes
+        }
+    }
+}
+
+#[cfg(not(feature = "reflection"))]
+fn add_reflection_services(routes: Routes) -> Routes {
+    tracing::warn!("gRPC reflection was requested but this build lacks the reflection feature");
+    routes
 }
 
 pub fn create_admin_grpc_router(state: AppState, auth_interceptor: AuthInterceptorFn) -> Routes {
@@ -179,3 +212,16 @@ pub fn create_axum_router(grpc_router: Routes) -> axum::Router {
         .route_layer(axum::middleware::from_fn(middleware::request_id_mw))
         .route_layer(axum::middleware::from_fn(middleware::save_uri_mw))
 }
+
+#[cfg(all(test, feature = "reflection"))]
+mod tests {
+    #[test]
+    fn reflection_services_build_from_the_compiled_descriptors() {
+        let builder = || {
+            tonic_reflection::server::Builder::configure()
+                .register_encoded_file_descriptor_set(crate::anvil_api::FILE_DESCRIPTOR_SET)
+        };
+        builder().build_v1().unwrap();
+        builder().build_v1alpha().unwrap();
+    }
+}
@@ -0,0 +1,696 @@
+use crate::anvil_api::model_service_server::ModelService;
+use crate::anvil_api::*;
+use crate::core_store::CoreByteRange;
+use crate::model_journal::TensorQuery;
+use crate::object_manager::ObjectManager;
+use crate::persistence::Bucket;
+use crate::{AppState, access_control, auth};
+use futures_util::StreamExt;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+const DEFAULT_TENSOR_PAGE_SIZE: u32 = 100;
+const MAX_TENSOR_PAGE_SIZE: u32 = 1000;
+/// Most contiguous byte runs a GetTensor slice may expand to.
+const MAX_TENSOR_SLICE_RUNS: usize = 1 << 20;
+
+type TensorChunkStream =
+    std::pin::Pin<Box<dyn futures_core::Stream<Item = Result<GetTensorChunk, Status>> + Send>>;
+
+/// Bytes of one tensor to stream: `ranges` are sorted, disjoint and relative
+/// to the start of `tensor` in the object holding it.
+struct TensorRead {
+    bucket_name: String,
+    object_key: String,
+    tensor: TensorIndexRow,
+    ranges: Vec<(u64, u64)>,
+}
+
+impl TensorRead {
+    fn len(&self) -> u64 {
+        self.ranges.iter().map(|(start, end)| end - start).sum()
+    }
+}
+
+#[tonic::async_trait]
+impl ModelService for AppState {
+    type GetTensorStream = TensorChunkStream;
+    type GetTensorsStream = TensorChunkStream;
+    type GetTensorDataStream = TensorChunkStream;
+
+    async fn put_model_manifest(
+        &self,
+        request: Request<PutModelManifestRequest>,
+    ) -> Result<Response<PutModelManifestResponse>, Status> {
+        let claims = auth::try_get_claims_from_extensions(request.extensions())
+            .ok_or_else(|| Status::unauthenticated("Missing authentication claims"))?;
+        let req = request.into_inner();
+        let manifest = req
+            .manifest
+            .ok_or_else(|| Status::invalid_argument("manifest is required"))?;
+        let object = req
+            .object
+            .ok_or_else(|| Status::invalid_argument("object is required"))?;
+        if manifest.artifact_id.is_empty() || object.bucket.is_empty() || object.key.is_empty() {
+            return Err(Status::invalid_argument(
+                "manifest.artifact_id, object.bucket and object.key are required",
+            ));
+        }
+        validate_tensor_index(&req.index)?;
+
+        let buckets = self
+            .persistence
+            .list_buckets_for_tenant(claims.tenant_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let bucket = buckets
+            .iter()
+            .find(|bucket| bucket.name == object.bucket)
+            .ok_or_else(|| Status::not_found("bucket not found"))?;
+        access_control::require_object_permission(
+            &self.storage,
+            &claims,
+            bucket,
+            &object.key,
+            "put",
+        )
+        .await?;
+        // Artifact ids are global; one registered from another tenant's
+        // bucket cannot be taken over.
+        if let Some(existing) = self
+            .persistence
+            .get_model_artifact_location(&manifest.artifact_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            && !buckets.iter().any(|bucket| bucket.id == existing.bucket_id)
+        {
+            return Err(Status::already_exists("artifact id is already registered"));
+        }
+
+        let artifact_id = manifest.artifact_id.clone();
+        self.persistence
+            .create_model_artifact(&artifact_id, bucket.id, &object.key, &manifest)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        self.persistence
+            .create_model_tensors(&artifact_id, &req.index)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        Ok(Response::new(PutModelManifestResponse {
+            artifact_id,
+            status: "registered".to_string(),
+        }))
+    }
+
+    async fn list_tensors(
+        &self,
+        request: Request<ListTensorsRequest>,
+    ) -> Result<Response<ListTensorsResponse>, Status> {
+        let claims = auth::try_get_claims_from_extensions(request.extensions())
+            .ok_or_else(|| Status::unauthenticated("Missing authentication claims"))?;
+        let req = request.into_inner();
+        if req.artifact_id.is_empty() {
+            return Err(Status::invalid_argument("artifact_id is required"));
+        }
+        self.readable_artifact_bucket(&claims, &req.artifact_id)
+            .await?;
+        let query = TensorQuery {
+            prefix: req.prefix,
+            ..TensorQuery::default()
+        };
+        let (tensors, next_page_token) = self
+            .tensor_page(&req.artifact_id, &query, req.limit, &req.page_token)
+            .await?;
+        Ok(Response::new(ListTensorsResponse {
+            tensors,
+            next_page_token,
+        }))
+    }
+
+    async fn query_tensors(
+        &self,
+        request: Request<QueryTensorsRequest>,
+    ) -> Result<Response<QueryTensorsResponse>, Status> {
+        let claims = auth::try_get_claims_from_extensions(request.extensions())
+            .ok_or_else(|| Status::unauthenticated("Missing authentication claims"))?;
+        let req = request.into_inner();
+        if req.artifact_id.is_empty() {
+            return Err(Status::invalid_argument("artifact_id is required"));
+        }
+        self.readable_artifact_bucket(&claims, &req.artifact_id)
+            .await?;
+        let query = TensorQuery {
+            dtype: (req.dtype != DType::DtypeUnspecified as i32).then_some(req.dtype),
+            min_bytes: req.min_bytes,
+            ..TensorQuery::default()
+        };
+        let (tensors, next_page_token) = self
+            .tensor_page(&req.artifact_id, &query, req.limit, &req.page_token)
+            .await?;
+        Ok(Response::new(QueryTensorsResponse {
+            tensors,
+            next_page_token,
+        }))
+    }
+
+    async fn get_tensor(
+        &self,
+        request: Request<GetTensorRequest>,
+    ) -> Result<Response<Self::GetTensorStream>, Status> {
+        let claims = auth::try_get_claims_from_extensions(request.extensions())
+            .ok_or_else(|| Status::unauthenticated("Missing authentication claims"))?;
+        let req = request.into_inner();
+        if req.artifact_id.is_empty() || req.tensor_name.is_empty() {
+            return Err(Status::invalid_argument(
+                "artifact_id and tensor_name are required",
+            ));
+        }
+        let mut read = self
+            .tensor_read(&claims, &req.artifact_id, &req.tensor_name)
+            .await?;
+        if !req.slice_begin.is_empty() || !req.slice_extent.is_empty() {
+            read.ranges = tensor_slice_ranges(&read.tensor, &req.slice_begin, &req.slice_extent)?;
+        }
+        Ok(Response::new(stream_tensor_reads(
+            self.object_manager.clone(),
+            claims,
+            vec![read],
+        )))
+    }
+
+    async fn get_tensors(
+        &self,
+        request: Request<GetTensorsRequest>,
+    ) -> Result<Response<Self::GetTensorsStream>, Status> {
+        let claims = auth::try_get_claims_from_extensions(request.extensions())
+            .ok_or_else(|| Status::unauthenticated("Missing authentication claims"))?;
+        let req = request.into_inner();
+        if req.artifact_id.is_empty() || req.tensor_names.is_empty() {
+            return Err(Status::invalid_argument(
+                "artifact_id and tensor_names are required",
+            ));
+        }
+        let mut reads = Vec::with_capacity(req.tensor_names.len());
+        for tensor_name in &req.tensor_names {
+            reads.push(
+                self.tensor_read(&claims, &req.artifact_id, tensor_name)
+                    .await?,
+            );
+        }
+        Ok(Response::new(stream_tensor_reads(
+            self.object_manager.clone(),
+            claims,
+            reads,
+        )))
+    }
+
+    async fn get_tensor_data(
+        &self,
+        request: Request<GetTensorDataRequest>,
+    ) -> Result<Response<Self::GetTensorDataStream>, Status> {
+        let claims = auth::try_get_claims_from_extensions(request.extensions())
+            .ok_or_else(|| Status::unauthenticated("Missing authentication claims"))?;
+        let req = request.into_inner();
+        if req.artifact_id.is_empty() || req.tensor_name.is_empty() {
+            return Err(Status::invalid_argument(
+                "artifact_id and tensor_name are required",
+            ));
+        }
+        let read = self
+            .tensor_read(&claims, &req.artifact_id, &req.tensor_name)
+            .await?;
+        Ok(Response::new(stream_tensor_reads(
+            self.object_manager.clone(),
+            claims,
+            vec![read],
+        )))
+    }
+}
+
+impl AppState {
+    /// Bucket holding `artifact_id`, provided it belongs to the caller's
+    /// tenant and the caller may read the artifact's object. Artifacts of
+    /// other tenants read as missing.
+    async fn readable_artifact_bucket(
+        &self,
+        claims: &auth::Claims,
+        artifact_id: &str,
+    ) -> Result<Bucket, Status> {
+        let location = self
+            .persistence
+            .get_model_artifact_location(artifact_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| Status::not_found("artifact not found"))?;
+        let bucket = self
+            .persistence
+            .list_buckets_for_tenant(claims.tenant_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .into_iter()
+            .find(|bucket| bucket.id == location.bucket_id)
+            .ok_or_else(|| Status::not_found("artifact not found"))?;
+        access_control::require_object_permission(
+            &self.storage,
+            claims,
+            &bucket,
+            &location.key,
+            "get",
+        )
+        .await?;
+        Ok(bucket)
+    }
+
+    /// Locates all of `tensor_name`, following base artifacts, in an object
+    /// the caller may read.
+    async fn tensor_read(
+        &self,
+        claims: &auth::Claims,
+        artifact_id: &str,
+        tensor_name: &str,
+    ) -> Result<TensorRead, Status> {
+        let resolved = self
+            .persistence
+            .resolve_tensor(artifact_id, tensor_name)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .ok_or_else(|| Status::not_found("tensor not found"))?;
+        // Artifacts are addressed by bucket id; only the caller's own buckets
+        // resolve, so another tenant's artifact reads as missing.
+        let bucket = self
+            .persistence
+            .list_buckets_for_tenant(claims.tenant_id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?
+            .into_iter()
+            .find(|bucket| bucket.id == resolved.location.bucket_id)
+            .ok_or_else(|| Status::not_found("tensor not found"))?;
+        let object_key = resolved.object_key();
+        access_control::require_object_permission(
+            &self.storage,
+            claims,
+            &bucket,
+            &object_key,
+            "get",
+        )
+        .await?;
+        let byte_length = resolved.tensor.byte_length;
+        resolved
+            .tensor
+            .file_offset
+            .checked_add(byte_length)
+            .ok_or_else(|| Status::failed_precondition("tensor byte range overflows"))?;
+        Ok(TensorRead {
+            bucket_name: bucket.name,
+            object_key,
+            tensor: resolved.tensor,
+            ranges: if byte_length == 0 {
+                Vec::new()
+            } else {
+                vec![(0, byte_length)]
+            },
+        })
+    }
+
+    /// One page of an artifact's tensor index. Page tokens are the decimal
+    /// offset of the next row.
+    async fn tensor_page(
+        &self,
+        artifact_id: &str,
+        query: &TensorQuery,
+        limit: u32,
+        page_token: &str,
+    ) -> Result<(Vec<TensorIndexRow>, String), Status> {
+        let offset = parse_tensor_page_token(page_token)?;
+        let limit = match limit {
+            0 => DEFAULT_TENSOR_PAGE_SIZE,
+            limit => limit.min(MAX_TENSOR_PAGE_SIZE),
+        };
+        let mut tensors = self
+            .persistence
+            .query_tensors(artifact_id, query, i64::from(limit) + 1, offset as i64)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let next_page_token = if tensors.len() > limit as usize {
+            tensors.truncate(limit as usize);
+            (offset + u64::from(limit)).to_string()
+        } else {
+            String::new()
+        };
+        Ok((tensors, next_page_token))
+    }
+}
+
+/// Streams `reads` back to back. Chunk offsets count from the start of the
+/// first tensor and the final chunk carries eof.
+fn stream_tensor_reads(
+    object_manager: ObjectManager,
+    claims: auth::Claims,
+    reads: Vec<TensorRead>,
+) -> TensorChunkStream {
+    let (tx, rx) = mpsc::channel(4);
+    tokio::spawn(async move {
+        let mut offset = 0_u64;
+        for read in reads {
+            let expected = read.len();
+            let (Some(first), Some(last)) = (read.ranges.first(), read.ranges.last()) else {
+                continue;
+            };
+            let span_start = first.0;
+            let opened = object_manager
+                .get_object(
+                    Some(claims.clone()),
+                    read.bucket_name.clone(),
+                    read.object_key.clone(),
+                    None,
+                    Some(CoreByteRange {
+                        start: read.tensor.file_offset + span_start,
+                        end_exclusive: read.tensor.file_offset + last.1,
+                    }),
+                )
+                .await;
+            let mut data_stream = match opened {
+                Ok((_object, stream, _range_start)) => stream,
+                Err(error) => {
+                    let _ = tx.send(Err(error)).await;
+                    return;
+                }
+            };
+            let mut position = span_start;
+            let mut next_range = 0;
+            let mut sent = 0_u64;
+            while let Some(chunk_result) = data_stream.next().await {
+                let chunk = match chunk_result {
+                    Ok(chunk) => chunk,
+                    Err(error) => {
+                        let _ = tx.send(Err(error)).await;
+                        return;
+                    }
+                };
+                let data = select_tensor_bytes(&read.ranges, &mut next_range, position, &chunk);
+                position += chunk.len() as u64;
+                if data.is_empty() {
+                    continue;
+                }
+                let len = data.len() as u64;
+                if tx
+                    .send(Ok(GetTensorChunk {
+                        data,
+                        offset,
+                        eof: false,
+                    }))
+                    .await
+                    .is_err()
+                {
+                    return; // Client disconnected
+                }
+                offset = offset.saturating_add(len);
+                sent += len;
+            }
+            if sent != expected {
+                let _ = tx
+                    .send(Err(Status::data_loss(format!(
+                        "tensor read returned {sent} of {expected} bytes"
+                    ))))
+                    .await;
+                return;
+            }
+        }
+        let _ = tx
+            .send(Ok(GetTensorChunk {
+                data: Vec::new(),
+                offset,
+                eof: true,
+            }))
+            .await;
+    });
+    Box::pin(ReceiverStream::new(rx))
+}
+
+/// Bytes of `chunk`, which starts at tensor offset `position`, that fall in
+/// `ranges`. `next_range` is the first range not yet fully consumed and
+/// carries over between chunks.
+fn select_tensor_bytes(
+    ranges: &[(u64, u64)],
+    next_range: &mut usize,
+    position: u64,
+    chunk: &[u8],
+) -> Vec<u8> {
+    let chunk_end = position + chunk.len() as u64;
+    let mut out = Vec::new();
+    while let Some(&(start, end)) = ranges.get(*next_range) {
+        if start >= chunk_end {
+            break;
+        }
+        let from = start.max(position);
+        let to = end.min(chunk_end);
+        if from < to {
+            out.extend_from_slice(&chunk[(from - position) as usize..(to - position) as usize]);
+        }
+        if end > chunk_end {
+            break;
+        }
+        *next_range += 1;
+    }
+    out
+}
+
+fn dtype_size(dtype: i32) -> Option<u64> {
+    Some(match DType::try_from(dtype).ok()? {
+        DType::I8 | DType::U8 => 1,
+        DType::F16 | DType::Bf16 | DType::I16 => 2,
+        DType::F32 | DType::I32 => 4,
+        DType::F64 | DType::I64 => 8,
+        DType::DtypeUnspecified => return None,
+    })
+}
+
+/// Byte runs, relative to the tensor start, of the hyper-rectangle that
+/// starts at `begin` and spans `extent` elements in each dimension of a
+/// row-major tensor. Adjacent runs are merged.
+fn tensor_slice_ranges(
+    tensor: &TensorIndexRow,
+    begin: &[u32],
+    extent: &[u32],
+) -> Result<Vec<(u64, u64)>, Status> {
+    if !tensor.layout.is_empty() && tensor.layout != "row_major" {
+        return Err(Status::failed_precondition(
+            "only row_major tensors can be sliced",
+        ));
+    }
+    let element = dtype_size(tensor.dtype)
+        .ok_or_else(|| Status::failed_precondition("tensor dtype has no element size"))?;
+    let shape = &tensor.shape;
+    if begin.len() != shape.len() || extent.len() != shape.len() {
+        return Err(Status::invalid_argument(
+            "slice_begin and slice_extent need one entry per tensor dimension",
+        ));
+    }
+    let elements = shape
+        .iter()
+        .try_fold(1_u64, |total, dim| total.checked_mul(u64::from(*dim)));
+    if elements.and_then(|elements| elements.checked_mul(element)) != Some(tensor.byte_length) {
+        return Err(Status::failed_precondition(
+            "tensor shape does not match its byte length",
+        ));
+    }
+    for ((dim, begin), extent) in shape.iter().zip(begin).zip(extent) {
+        if u64::from(*begin) + u64::from(*extent) > u64::from(*dim) {
+            return Err(Status::out_of_range("slice exceeds the tensor shape"));
+        }
+    }
+    if extent.contains(&0) {
+        return Ok(Vec::new());
+    }
+
+    let mut strides = vec![element; shape.len()];
+    for dim in (0..shape.len().saturating_sub(1)).rev() {
+        strides[dim] = strides[dim + 1] * u64::from(shape[dim + 1]);
+    }
+    // Trailing dimensions taken whole are contiguous with the last partial
+    // one, so each run covers `extent[inner] * strides[inner]` bytes.
+    let mut inner = shape.len();
+    while inner > 0 && begin[inner - 1] == 0 && extent[inner - 1] == shape[inner - 1] {
+        inner -= 1;
+    }
+    if inner == 0 {
+        return Ok(vec![(0, tensor.byte_length)]);
+    }
+    let inner = inner - 1;
+    let run = u64::from(extent[inner]) * strides[inner];
+    let base = (0..=inner)
+        .map(|dim| u64::from(begin[dim]) * strides[dim])
+        .sum::<u64>();
+    let mut index = vec![0_u32; inner];
+    let mut ranges: Vec<(u64, u64)> = Vec::new();
+    loop {
+        let start = base
+            + index
+                .iter()
+                .zip(&strides)
+                .map(|(index, stride)| u64::from(*index) * stride)
+                .sum::<u64>();
+        match ranges.last_mut() {
+            Some(last) if last.1 == start => last.1 = start + run,
+            _ => {
+                if ranges.len() == MAX_TENSOR_SLICE_RUNS {
+                    return Err(Status::invalid_argument(
+                        "slice spans too many separate byte ranges",
+                    ));
+                }
+                ranges.push((start, start + run));
+            }
+        }
+        let mut dim = inner;
+        loop {
+            if dim == 0 {
+                return Ok(ranges);
+            }
+            dim -= 1;
+            index[dim] += 1;
+            if index[dim] < extent[dim] {
+                break;
+            }
+            index[dim] = 0;
+        }
+    }
+}
+
+/// Rejects index rows a reader could not resolve unambiguously.
+fn validate_tensor_index(index: &[TensorIndexRow]) -> Result<(), Status> {
+    let mut names = std::collections::HashSet::new();
+    for row in index {
+        if row.tensor_name.is_empty() {
+            return Err(Status::invalid_argument("tensor_name is required"));
+        }
+        if !names.insert(row.tensor_name.as_str()) {
+            return Err(Status::invalid_argument(format!(
+                "tensor {} is listed twice",
+                row.tensor_name
+            )));
+        }
+        if row.file_offset.checked_add(row.byte_length).is_none() {
+            return Err(Status::invalid_argument(format!(
+                "tensor {} byte range overflows",
+                row.tensor_name
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn parse_tensor_page_token(page_token: &str) -> Result<u64, Status> {
+    if page_token.is_empty() {
+        return Ok(0);
+    }
+    page_token
+        .parse::<u64>()
+        .ok()
+        .filter(|offset| i64::try_from(*offset).is_ok())
+        .ok_or_else(|| Status::invalid_argument("invalid page_token"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f32_tensor(shape: &[u32]) -> TensorIndexRow {
+        TensorIndexRow {
+            tensor_name: "weight".to_string(),
+            dtype: DType::F32 as i32,
+            byte_length: shape.iter().map(|dim| u64::from(*dim)).product::<u64>() * 4,
+            shape: shape.to_vec(),
+            layout: "row_major".to_string(),
+            ..TensorIndexRow::default()
+        }
+    }
+
+    #[test]
+    fn slices_expand_to_merged_row_major_byte_runs() {
+        let matrix = f32_tensor(&[4, 8]);
+        // Whole rows are one contiguous run.
+        assert_eq!(
+            tensor_slice_ranges(&matrix, &[1, 0], &[2, 8]).unwrap(),
+            [(32, 96)]
+        );
+        // A column band yields one run per row.
+        assert_eq!(
+            tensor_slice_ranges(&matrix, &[0, 2], &[3, 2]).unwrap(),
+            [(8, 16), (40, 48), (72, 80)]
+        );
+        assert_eq!(
+            tensor_slice_ranges(&matrix, &[0, 0], &[4, 8]).unwrap(),
+            [(0, 128)]
+        );
+        assert!(
+            tensor_slice_ranges(&matrix, &[0, 0], &[0, 8])
+                .unwrap()
+                .is_empty()
+        );
+
+        let cube = f32_tensor(&[2, 3, 4]);
+        assert_eq!(
+            tensor_slice_ranges(&cube, &[0, 1, 0], &[2, 2, 4]).unwrap(),
+            [(16, 48), (64, 96)]
+        );
+    }
+
+    #[test]
+    fn slices_outside_the_shape_or_rank_are_rejected() {
+        let matrix = f32_tensor(&[4, 8]);
+        let code = |begin: &[u32], extent: &[u32]| {
+            tensor_slice_ranges(&matrix, begin, extent)
+                .unwrap_err()
+                .code()
+        };
+        assert_eq!(code(&[3, 0], &[2, 8]), tonic::Code::OutOfRange);
+        assert_eq!(code(&[0], &[4]), tonic::Code::InvalidArgument);
+
+        let unsized_dtype = TensorIndexRow {
+            dtype: DType::DtypeUnspecified as i32,
+            ..matrix.clone()
+        };
+        assert_eq!(
+            tensor_slice_ranges(&unsized_dtype, &[0, 0], &[1, 1])
+                .unwrap_err()
+                .code(),
+            tonic::Code::FailedPrecondition
+        );
+    }
+
+    #[test]
+    fn selected_bytes_follow_ranges_across_chunks() {
+        let ranges = [(2, 4), (6, 9)];
+        let mut next_range = 0;
+        let mut selected = Vec::new();
+        // The span read starts at the first range.
+        for (position, chunk) in [
+            (2_u64, &[2_u8, 3, 4][..]),
+            (5, &[5, 6, 7][..]),
+            (8, &[8][..]),
+        ] {
+            selected.extend(select_tensor_bytes(
+                &ranges,
+                &mut next_range,
+                position,
+                chunk,
+            ));
+        }
+        assert_eq!(selected, [2, 3, 6, 7, 8]);
+        assert_eq!(next_range, ranges.len());
+    }
+
+    #[test]
+    fn tensor_page_tokens_are_row_offsets() {
+        assert_eq!(parse_tensor_page_token("").unwrap(), 0);
+        assert_eq!(parse_tensor_page_token("200").unwrap(), 200);
+        for invalid in ["-1", "next", "18446744073709551615"] {
+            assert_eq!(
+                parse_tensor_page_token(invalid).unwrap_err().code(),
+                tonic::Code::InvalidArgument,
+                "{invalid}"
+            );
+        }
+    }
+}
@@ -14,6 +14,103 @@ pub(super) fn parse_user_metadata_json(value: &str) -> Result<Option<serde_json:
     Ok(Some(parsed))
 }
 
+/// gRPC request metadata carrying the declared size of a streamed object body.
+pub const OBJECT_CONTENT_LENGTH_METADATA: &str = "x-anvil-content-length";
+
+pub(super) fn declared_content_length(
+    metadata: &tonic::metadata::MetadataMap,
+) -> Result<Option<u64>, Status> {
+    let Some(value) = metadata.get(OBJECT_CONTENT_LENGTH_METADATA) else {
+        return Ok(None);
+    };
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Some)
+        .ok_or_else(|| {
+            Status::invalid_argument(format!("Invalid {OBJECT_CONTENT_LENGTH_METADATA} metadata"))
+        })
+}
+
+/// Evaluates CopyObject source preconditions with S3 copy-source semantics.
+pub(super) fn enforce_copy_source_preconditions(
+    req: &CopyObjectRequest,
+    source: &crate::persistence::Object,
+) -> Result<(), Status> {
+    let etag_matches = |condition: &str| {
+        condition
+            .split(',')
+            .map(|candidate| candidate.trim().trim_matches('"'))
+            .any(|candidate| candidate == "*" || candidate == source.etag)
+    };
+    let condition_time = |value: &str, field: &str| {
+        chrono::DateTime::parse_from_rfc3339(value)
+            .map(|time| time.timestamp())
+            .map_err(|_| Status::invalid_argument(format!("{field} must be an RFC 3339 timestamp")))
+    };
+    let last_modified = source.created_at.timestamp();
+    let failed = || Status::failed_precondition("Copy source precondition failed");
+
+    if let Some(condition) = req.source_if_match.as_deref()
+        && !etag_matches(condition)
+    {
+        return Err(failed());
+    }
+    if let Some(condition) = req.source_if_unmodified_since.as_deref()
+        && last_modified > condition_time(condition, "source_if_unmodified_since")?
+    {
+        return Err(failed());
+    }
+    if let Some(condition) = req.source_if_none_match.as_deref()
+        && etag_matches(condition)
+    {
+        return Err(failed());
+    }
+    if let Some(condition) = req.source_if_modified_since.as_deref()
+        && last_modified <= condition_time(condition, "source_if_modified_since")?
+    {
+        return Err(failed());
+    }
+    Ok(())
+}
+
+/// Reads object-lock settings from the wire form. An empty message with no
+/// date and no legal hold means no retention.
+pub(super) fn parse_object_retention(
+    value: Option<ObjectRetention>,
+) -> Result<Option<crate::persistence::ObjectRetention>, Status> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let retain_until = value
+        .retain_until
+        .as_deref()
+        .filter(|until| !until.is_empty())
+        .map(|until| {
+            chrono::DateTime::parse_from_rfc3339(until)
+                .map(|until| until.with_timezone(&chrono::Utc))
+                .map_err(|_| Status::invalid_argument("retain_until must be an RFC 3339 timestamp"))
+        })
+        .transpose()?;
+    if retain_until.is_none() && !value.legal_hold {
+        return Ok(None);
+    }
+    Ok(Some(crate::persistence::ObjectRetention {
+        retain_until,
+        legal_hold: value.legal_hold,
+    }))
+}
+
+pub(super) fn object_retention_to_proto(
+    retention: Option<crate::persistence::ObjectRetention>,
+) -> Option<ObjectRetention> {
+    retention.map(|retention| ObjectRetention {
+        retain_until: retention.retain_until.map(|until| until.to_rfc3339()),
+        legal_hold: retention.legal_hold,
+    })
+}
+
 pub(super) fn json_object_string(value: Option<&serde_json::Value>) -> String {
     value
         .map(|value| value.to_string())
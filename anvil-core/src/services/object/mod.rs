@@ -39,6 +39,7 @@ mod watch;
 pub(crate) use batch_helpers::enforce_write_precondition;
 use batch_helpers::*;
 use boundary_rpc::*;
+pub use common::OBJECT_CONTENT_LENGTH_METADATA;
 use common::*;
 use link_helpers::*;
 use native_mutation::*;
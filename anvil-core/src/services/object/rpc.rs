@@ -137,24 +137,38 @@ impl ObjectService for AppState {
             .get::<auth::Claims>()
             .cloned()
             .ok_or_else(|| Status::unauthenticated("Missing claims"))?;
+        let expected_size = declared_content_length(request.metadata())?;
 
         let mut stream = request.into_inner();
 
-        let (bucket_name, object_key, mutation_context, content_type, user_metadata, storage_class) =
-            match stream.next().await {
-                Some(Ok(chunk)) => match chunk.data {
-                    Some(put_object_request::Data::Metadata(meta)) => (
-                        meta.bucket_name,
-                        meta.object_key,
-                        meta.mutation_context,
-                        meta.content_type,
-                        parse_user_metadata_json(&meta.user_metadata_json)?,
-                        meta.storage_class,
-                    ),
-                    _ => return Err(Status::invalid_argument("First chunk must be metadata")),
-                },
-                _ => return Err(Status::invalid_argument("Empty stream")),
-            };
+        let (
+            bucket_name,
+            object_key,
+            mutation_context,
+            content_type,
+            user_metadata,
+            storage_class,
+            retention,
+            conditions,
+        ) = match stream.next().await {
+            Some(Ok(chunk)) => match chunk.data {
+                Some(put_object_request::Data::Metadata(meta)) => (
+                    meta.bucket_name,
+                    meta.object_key,
+                    meta.mutation_context,
+                    meta.content_type,
+                    parse_user_metadata_json(&meta.user_metadata_json)?,
+                    meta.storage_class,
+                    parse_object_retention(meta.retention)?,
+                    object_manager::ObjectConditions {
+                        if_match: meta.if_match,
+                        if_none_match: meta.if_none_match,
+                    },
+                ),
+                _ => return Err(Status::invalid_argument("First chunk must be metadata")),
+            },
+            _ => return Err(Status::invalid_argument("Empty stream")),
+        };
         validate_native_mutation_context(self, &claims, &bucket_name, mutation_context.as_ref())
             .await?;
         let transaction_id = native_transaction_id(mutation_context.as_ref())?;
@@ -203,6 +217,13 @@ impl ObjectService for AppState {
                     transaction_principal: transaction_id
                         .map(|_| crate::object_manager::transaction_principal_from_claims(&claims)),
                     storage_class_id: storage_class,
+                    expected_size,
+                    expected_md5: None,
+                    expected_sha256: None,
+                    md5_etag: false,
+                    etag: None,
+                    conditions,
+                    retention,
                     visibility: write_visibility,
                 },
             )
@@ -256,6 +277,12 @@ impl ObjectService for AppState {
             )
             .await?;
         let object = result.object;
+        let conditions = object_manager::ObjectConditions {
+            if_match: req.if_match,
+            if_none_match: req.if_none_match,
+        };
+        let not_modified =
+            conditions.check_read(&object)? == object_manager::ConditionalRead::NotModified;
         let mut data_stream = result.stream;
         let mut logical_offset = result.range_start;
 
@@ -268,6 +295,8 @@ impl ObjectService for AppState {
                 version_id: object.version_id.to_string(),
                 user_metadata_json: json_object_string(object.user_meta.as_ref()),
                 storage_class: object_storage_class(&object),
+                etag: object.etag.clone(),
+                not_modified,
             };
             if tx
                 .send(Ok(GetObjectResponse {
@@ -280,6 +309,9 @@ impl ObjectService for AppState {
             {
                 return; // Client disconnected
             }
+            if not_modified {
+                return;
+            }
 
             while let Some(chunk_result) = data_stream.next().await {
                 let chunk = match chunk_result {
@@ -440,6 +472,7 @@ impl ObjectService for AppState {
             content_type: object.content_type.unwrap_or_default(),
             user_metadata_json: json_object_string(object.user_meta.as_ref()),
             storage_class,
+            retention: object_retention_to_proto(object.retention),
         }))
     }
 
@@ -506,6 +539,24 @@ impl ObjectService for AppState {
             String::new()
         };
 
+        // Every listed object belongs to the bucket's owning tenant.
+        let owner = match objects.first() {
+            Some(object) => {
+                let tenant = self
+                    .persistence
+                    .get_tenant_by_id(object.tenant_id)
+                    .await
+                    .map_err(|e| Status::internal(e.to_string()))?
+                    .ok_or_else(|| {
+                        Status::internal(format!("owner tenant {} not found", object.tenant_id))
+                    })?;
+                Some(crate::anvil_api::ObjectOwner {
+                    id: tenant.id.to_string(),
+                    display_name: tenant.name,
+                })
+            }
+            None => None,
+        };
         let response_objects = objects
             .into_iter()
             .map(|o| {
@@ -518,6 +569,7 @@ impl ObjectService for AppState {
                     content_type: o.content_type.unwrap_or_default(),
                     user_metadata_json: json_object_string(o.user_meta.as_ref()),
                     storage_class,
+                    owner: owner.clone(),
                 }
             })
             .collect();
@@ -675,6 +727,23 @@ impl ObjectService for AppState {
             AnvilAction::ObjectWrite,
         )
         .await?;
+        let source_version_id = parse_optional_version_id(req.source_version_id.as_deref())?;
+        if req.source_if_match.is_some()
+            || req.source_if_none_match.is_some()
+            || req.source_if_modified_since.is_some()
+            || req.source_if_unmodified_since.is_some()
+        {
+            let source = self
+                .object_manager
+                .head_object(
+                    Some(claims.clone()),
+                    &req.source_bucket_name,
+                    &req.source_object_key,
+                    source_version_id,
+                )
+                .await?;
+            enforce_copy_source_preconditions(&req, &source)?;
+        }
 
         let object = self
             .object_manager
@@ -682,9 +751,10 @@ impl ObjectService for AppState {
                 claims,
                 &req.source_bucket_name,
                 &req.source_object_key,
-                parse_optional_version_id(req.source_version_id.as_deref())?,
+                source_version_id,
                 &req.destination_bucket_name,
                 &req.destination_object_key,
+                None,
                 transaction_id,
             )
             .await?;
@@ -711,6 +781,31 @@ impl ObjectService for AppState {
         Ok(Response::new(response))
     }
 
+    async fn put_object_retention(
+        &self,
+        request: Request<PutObjectRetentionRequest>,
+    ) -> Result<Response<PutObjectRetentionResponse>, Status> {
+        let claims = request
+            .extensions()
+            .get::<auth::Claims>()
+            .cloned()
+            .ok_or_else(|| Status::unauthenticated("Missing claims"))?;
+        let req = request.into_inner();
+        let object = self
+            .object_manager
+            .put_object_retention(
+                &claims,
+                &req.bucket_name,
+                &req.object_key,
+                parse_object_retention(req.retention)?,
+            )
+            .await?;
+        Ok(Response::new(PutObjectRetentionResponse {
+            version_id: object.version_id.to_string(),
+            retention: object_retention_to_proto(object.retention),
+        }))
+    }
+
     async fn compose_object(
         &self,
         request: Request<ComposeObjectRequest>,
@@ -1182,6 +1277,13 @@ impl ObjectService for AppState {
                                     )
                                 }),
                                 storage_class_id: op.storage_class,
+                                expected_size: None,
+                                expected_md5: None,
+                                expected_sha256: None,
+                                md5_etag: false,
+                                etag: None,
+                                conditions: Default::default(),
+                                retention: None,
                                 visibility: write_visibility,
                             },
                         )
@@ -1566,8 +1668,11 @@ impl ObjectService for AppState {
                 upload_id,
                 metadata.part_number,
                 data_stream,
-                transaction_id,
-                transaction_principal.as_deref(),
+                object_manager::UploadPartOptions {
+                    transaction_id: transaction_id.map(str::to_string),
+                    transaction_principal,
+                    ..Default::default()
+                },
             )
             .await?;
         let authz_revision = latest_authz_revision(self, claims.tenant_id).await?;
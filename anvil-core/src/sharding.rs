@@ -1,28 +1,35 @@
 use crate::crypto::EncryptionKeyring;
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use reed_solomon_erasure::galois_8::Field;
 use reed_solomon_erasure::{Error, ReedSolomon};
 
-// Define our sharding configuration.
-// For now, we'll use a fixed 4+2 configuration (4 data shards, 2 parity shards).
-// This means we can lose any 2 shards and still reconstruct the data.
+// Default sharding configuration: 4 data shards and 2 parity shards, so any 2
+// shards can be lost and the data still reconstructed. Deployments can pick a
+// different layout through `Config::data_shards` / `Config::parity_shards`.
 const DATA_SHARDS: usize = 4;
 const PARITY_SHARDS: usize = 2;
 
 #[derive(Debug, Clone)]
 pub struct ShardManager {
     codec: ReedSolomon<Field>,
+    data_shards: usize,
+    parity_shards: usize,
 }
 
 impl ShardManager {
     pub fn new() -> Self {
-        let codec = ReedSolomon::new(DATA_SHARDS, PARITY_SHARDS).unwrap();
-        Self { codec }
+        Self::new_with_config(DATA_SHARDS, PARITY_SHARDS).unwrap()
     }
 
-    pub fn new_with_config(data_shards: usize, parity_shards: usize) -> Self {
-        let codec = ReedSolomon::new(data_shards, parity_shards).unwrap();
-        Self { codec }
+    pub fn new_with_config(data_shards: usize, parity_shards: usize) -> Result<Self> {
+        let codec = ReedSolomon::new(data_shards, parity_shards).map_err(|err| {
+            anyhow!("invalid shard layout {data_shards}+{parity_shards}: {err:?}")
+        })?;
+        Ok(Self {
+            codec,
+            data_shards,
+            parity_shards,
+        })
     }
 
     /// Encrypts and encodes a single data stripe into data + parity shards.
@@ -64,11 +71,15 @@ impl ShardManager {
     }
 
     pub fn data_shards(&self) -> usize {
-        DATA_SHARDS
+        self.data_shards
+    }
+
+    pub fn parity_shards(&self) -> usize {
+        self.parity_shards
     }
 
     pub fn total_shards(&self) -> usize {
-        DATA_SHARDS + PARITY_SHARDS
+        self.data_shards + self.parity_shards
     }
 }
 
@@ -122,4 +133,14 @@ mod tests {
             "Reconstructed data does not match"
         );
     }
+
+    #[test]
+    fn configured_layouts_report_their_own_shard_counts() {
+        let manager = ShardManager::new_with_config(8, 3).unwrap();
+        assert_eq!(manager.data_shards(), 8);
+        assert_eq!(manager.parity_shards(), 3);
+        assert_eq!(manager.total_shards(), 11);
+
+        assert!(ShardManager::new_with_config(0, 2).is_err());
+    }
 }
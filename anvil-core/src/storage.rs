@@ -2,7 +2,7 @@ use anyhow::{Context, Result};
 use futures_util::StreamExt;
 use sha2::Digest;
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 use tracing::info;
@@ -11,6 +11,21 @@ const STORAGE_DIR: &str = "anvil-data";
 const CORESTORE_DIR: &str = "corestore";
 const CORESTORE_STAGING_DIR: &str = "staging";
 const CORESTORE_TMP_DIR: &str = "tmp";
+const STORAGE_SELF_TEST_PREFIX: &str = ".anvil-storage-self-test-";
+const STORAGE_SELF_TEST_PAYLOAD: &[u8] = b"anvil storage self-test";
+const CORESTORE_SHARD_EXTENSION: &str = "anb";
+
+/// A shard file held in this node's local block cache. Shards are grouped in
+/// directories named after the SHA-256 of their block id, which is what
+/// `block_id_hash` carries; it is not a hash of the shard contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredShard {
+    pub block_id_hash: String,
+    pub path: PathBuf,
+    pub size_bytes: u64,
+    pub modified: SystemTime,
+}
+
 #[derive(Debug, Clone)]
 pub struct Storage {
     storage_path: PathBuf,
@@ -25,8 +40,11 @@ impl Storage {
     pub async fn new_at(storage_path: impl AsRef<Path>) -> Result<Self> {
         let storage_path = storage_path.as_ref().to_path_buf();
         let temp_path = core_store_staging_tmp_path(&storage_path);
-        fs::create_dir_all(&storage_path).await?;
-        fs::create_dir_all(&temp_path).await?;
+        for path in [&storage_path, &temp_path] {
+            fs::create_dir_all(path)
+                .await
+                .with_context(|| format!("create storage directory {}", path.display()))?;
+        }
         Ok(Self {
             storage_path,
             temp_path,
@@ -44,6 +62,38 @@ impl Storage {
         })
     }
 
+    /// Writes, reads back and removes a probe file in the storage root and the
+    /// upload scratch directory so a misconfigured `storage_path` fails at
+    /// startup instead of on the first upload.
+    pub async fn self_test(&self) -> Result<()> {
+        for dir in [&self.storage_path, &self.temp_path] {
+            let probe_path = dir.join(format!(
+                "{STORAGE_SELF_TEST_PREFIX}{}",
+                uuid::Uuid::new_v4().simple()
+            ));
+            let result = async {
+                fs::write(&probe_path, STORAGE_SELF_TEST_PAYLOAD).await?;
+                let read_back = fs::read(&probe_path).await?;
+                fs::remove_file(&probe_path).await?;
+                if read_back != STORAGE_SELF_TEST_PAYLOAD {
+                    anyhow::bail!("probe file contents did not round-trip");
+                }
+                Ok(())
+            }
+            .await;
+            if result.is_err() {
+                let _ = fs::remove_file(&probe_path).await;
+            }
+            result.with_context(|| {
+                format!(
+                    "storage self-test failed for {}; check that storage_path exists and is writable by the anvil process",
+                    dir.display()
+                )
+            })?;
+        }
+        Ok(())
+    }
+
     pub fn temp_dir_path(&self) -> &Path {
         &self.temp_path
     }
@@ -68,6 +118,49 @@ impl Storage {
         self.core_store_blocks_path().join("local-cache")
     }
 
+    /// Lists every shard file stored under the local block cache.
+    pub async fn list_stored_shards(&self) -> Result<Vec<StoredShard>> {
+        let mut shards = Vec::new();
+        let mut pending = vec![self.core_store_local_block_cache_path()];
+        while let Some(dir) = pending.pop() {
+            let mut entries = match fs::read_dir(&dir).await {
+                Ok(entries) => entries,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => {
+                    return Err(err)
+                        .with_context(|| format!("list shard directory {}", dir.display()));
+                }
+            };
+            while let Some(entry) = entries.next_entry().await? {
+                let path = entry.path();
+                let metadata = entry.metadata().await?;
+                if metadata.is_dir() {
+                    pending.push(path);
+                    continue;
+                }
+                if path.extension().and_then(|ext| ext.to_str()) != Some(CORESTORE_SHARD_EXTENSION)
+                {
+                    continue;
+                }
+                let Some(block_id_hash) = path
+                    .parent()
+                    .and_then(Path::file_name)
+                    .and_then(|name| name.to_str())
+                else {
+                    continue;
+                };
+                shards.push(StoredShard {
+                    block_id_hash: block_id_hash.to_string(),
+                    size_bytes: metadata.len(),
+                    modified: metadata.modified()?,
+                    path,
+                });
+            }
+        }
+        shards.sort_by(|left, right| left.path.cmp(&right.path));
+        Ok(shards)
+    }
+
     pub fn core_store_admission_path(&self) -> PathBuf {
         self.storage_path.join("admission")
     }
@@ -102,10 +195,12 @@ impl Storage {
         self.temp_path.join(upload_id)
     }
 
+    /// Stages the stream into a scratch file and returns its path, length,
+    /// hex SHA-256 and raw MD5 digest.
     pub async fn stream_to_temp_file(
         &self,
         mut data_stream: impl futures_util::Stream<Item = Result<Vec<u8>, tonic::Status>> + Unpin,
-    ) -> Result<(PathBuf, i64, String)> {
+    ) -> Result<(PathBuf, i64, String, [u8; 16])> {
         info!("stream_to_temp_file called");
         let upload_id = uuid::Uuid::new_v4().to_string();
         // Class C scratch: callers must route durable bytes into CoreStore before publishing refs.
@@ -121,6 +216,7 @@ impl Storage {
         );
 
         let mut overall_hasher = sha2::Sha256::new();
+        let mut md5_hasher = md5::Md5::new();
         let mut total_bytes = 0;
         let mut chunk_count = 0u64;
         let mut write_duration = std::time::Duration::ZERO;
@@ -131,6 +227,7 @@ impl Storage {
             file.write_all(&chunk).await?;
             write_duration += started_at.elapsed();
             overall_hasher.update(&chunk);
+            md5_hasher.update(&chunk);
             total_bytes += chunk.len() as i64;
             chunk_count = chunk_count.saturating_add(1);
         }
@@ -170,7 +267,12 @@ impl Storage {
             %content_hash,
             "stream_to_temp_file finished"
         );
-        Ok((temp_path, total_bytes, content_hash))
+        Ok((
+            temp_path,
+            total_bytes,
+            content_hash,
+            md5_hasher.finalize().into(),
+        ))
     }
 }
 
@@ -198,3 +300,83 @@ pub fn ensure_operator_path_outside_storage(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn self_test_passes_and_leaves_no_probe_files() {
+        let temp = tempdir().unwrap();
+        let storage = Storage::new_at(temp.path()).await.unwrap();
+
+        storage.self_test().await.unwrap();
+
+        for dir in [temp.path(), storage.temp_dir_path()] {
+            let leftovers = std::fs::read_dir(dir)
+                .unwrap()
+                .filter_map(|entry| entry.ok())
+                .filter(|entry| {
+                    entry
+                        .file_name()
+                        .to_string_lossy()
+                        .starts_with(STORAGE_SELF_TEST_PREFIX)
+                })
+                .count();
+            assert_eq!(leftovers, 0, "{}", dir.display());
+        }
+    }
+
+    #[tokio::test]
+    async fn list_stored_shards_reports_shard_files_by_block_directory() {
+        let temp = tempdir().unwrap();
+        let storage = Storage::new_at(temp.path()).await.unwrap();
+        assert!(storage.list_stored_shards().await.unwrap().is_empty());
+
+        let block_dir = storage
+            .core_store_local_block_cache_path()
+            .join("set-0")
+            .join("local-0")
+            .join("block-id")
+            .join("ab")
+            .join("abcd");
+        std::fs::create_dir_all(&block_dir).unwrap();
+        std::fs::write(block_dir.join("shard-00000-block.anb"), b"shard").unwrap();
+        std::fs::write(block_dir.join("shard-00000-block.anb.tmp"), b"partial").unwrap();
+
+        let shards = storage.list_stored_shards().await.unwrap();
+        assert_eq!(shards.len(), 1);
+        assert_eq!(shards[0].block_id_hash, "abcd");
+        assert_eq!(shards[0].size_bytes, 5);
+        assert_eq!(shards[0].path, block_dir.join("shard-00000-block.anb"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn self_test_reports_read_only_storage_path() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempdir().unwrap();
+        let storage = Storage::new_at(temp.path()).await.unwrap();
+        std::fs::set_permissions(temp.path(), std::fs::Permissions::from_mode(0o555)).unwrap();
+        if std::fs::write(temp.path().join("root-bypasses-permissions"), b"").is_ok() {
+            // Running as root ignores directory permissions; nothing to assert.
+            std::fs::set_permissions(temp.path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+            return;
+        }
+
+        let error = storage
+            .self_test()
+            .await
+            .expect_err("read-only storage path must fail the self-test");
+        std::fs::set_permissions(temp.path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let message = format!("{error:#}");
+        assert!(message.contains("storage self-test failed"), "{message}");
+        assert!(
+            message.contains(temp.path().to_string_lossy().as_ref()),
+            "{message}"
+        );
+    }
+}
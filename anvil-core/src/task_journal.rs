@@ -24,6 +24,9 @@ const TASK_CURRENT_ROW_KEY_PREFIX: &str = "task_queue_current/by_id";
 const TASK_CURRENT_ROW_ROOT_KEY: &str = "task_queue_current:global";
 const TASK_CURRENT_ROW_MAX_PROTO_BYTES: usize = 16 * 1024;
 const TASK_QUEUE_AUDIT_RECORD_KIND: &str = "task_queue_audit";
+pub(crate) const DEFAULT_TASK_MAX_ATTEMPTS: i32 = 10;
+const TASK_RETRY_BASE_DELAY_SECS: i64 = 10;
+const TASK_RETRY_MAX_DELAY_SECS: i64 = 60 * 60;
 
 #[derive(Debug, Clone)]
 enum TaskJournalBody {
@@ -43,9 +46,14 @@ enum TaskJournalBody {
         task_id: i64,
         error: String,
         attempts: i32,
+        status: TaskStatus,
         scheduled_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
     },
+    Requeued {
+        task_id: i64,
+        updated_at: DateTime<Utc>,
+    },
 }
 
 #[derive(Debug, Clone, Default)]
@@ -86,6 +94,7 @@ enum TaskJournalEventKindProto {
     Claimed = 2,
     StatusUpdated = 3,
     Failed = 4,
+    Requeued = 5,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -140,6 +149,9 @@ enum TaskTypeProto {
     RebalanceShard = 5,
     HfIngestion = 6,
     AuthzMaterialization = 7,
+    GarbageCollect = 8,
+    ReplicateObject = 9,
+    LifecycleSweep = 10,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, prost::Enumeration)]
@@ -149,6 +161,7 @@ enum TaskStatusProto {
     Running = 2,
     Completed = 3,
     Failed = 4,
+    Dead = 5,
 }
 
 #[derive(Clone, PartialEq, Message)]
@@ -431,7 +444,7 @@ fn is_retryable_task_id_collision(error: &anyhow::Error) -> bool {
 
 #[cfg(test)]
 async fn claim_pending_tasks(storage: &Storage, limit: i64) -> Result<Vec<TaskRecord>> {
-    claim_pending_tasks_inner(storage, limit, 0, None).await
+    claim_pending_tasks_inner(storage, limit, None, 0, None).await
 }
 
 pub(crate) async fn claim_pending_tasks_with_permit(
@@ -446,15 +459,19 @@ pub(crate) async fn claim_pending_tasks_with_permit(
     claim_pending_tasks_inner(
         storage,
         limit,
+        Some(&permit.owner_node_id),
         permit.fence_token,
         Some(partition_precondition),
     )
     .await
 }
 
+/// Claims due tasks in priority order. Tasks whose payload names a `node_id`
+/// act on that node's local disks, so only that node may claim them.
 async fn claim_pending_tasks_inner(
     storage: &Storage,
     limit: i64,
+    claiming_node_id: Option<&str>,
     fence_token: u64,
     partition_precondition: Option<CoreMutationPrecondition>,
 ) -> Result<Vec<TaskRecord>> {
@@ -470,6 +487,8 @@ async fn claim_pending_tasks_inner(
         .filter(|task| {
             matches!(task.status, TaskStatus::Pending | TaskStatus::Failed)
                 && task.scheduled_at <= now
+                && task_node_id(&task.payload)
+                    .is_none_or(|node_id| claiming_node_id.is_none_or(|claimer| claimer == node_id))
         })
         .cloned()
         .collect::<Vec<_>>();
@@ -588,13 +607,14 @@ async fn update_task_status_inner(
 
 #[cfg(test)]
 async fn fail_task(storage: &Storage, task_id: i64, error: &str) -> Result<()> {
-    fail_task_inner(storage, task_id, error, 0, None).await
+    fail_task_inner(storage, task_id, error, DEFAULT_TASK_MAX_ATTEMPTS, 0, None).await
 }
 
 pub(crate) async fn fail_task_with_permit(
     storage: &Storage,
     task_id: i64,
     error: &str,
+    max_attempts: i32,
     permit: &PartitionWritePermit,
     partition_owner_signing_key: &[u8],
 ) -> Result<()> {
@@ -605,6 +625,7 @@ pub(crate) async fn fail_task_with_permit(
         storage,
         task_id,
         error,
+        max_attempts,
         permit.fence_token,
         Some(partition_precondition),
     )
@@ -615,6 +636,7 @@ async fn fail_task_inner(
     storage: &Storage,
     task_id: i64,
     error: &str,
+    max_attempts: i32,
     fence_token: u64,
     partition_precondition: Option<CoreMutationPrecondition>,
 ) -> Result<()> {
@@ -628,14 +650,19 @@ async fn fail_task_inner(
     };
     let attempts = task.attempts.saturating_add(1);
     let now = Utc::now();
-    let retry_delay = i64::from(attempts.saturating_mul(attempts).saturating_mul(10));
+    let status = if attempts >= max_attempts {
+        TaskStatus::Dead
+    } else {
+        TaskStatus::Failed
+    };
     append_task_event(
         storage,
         TaskJournalBody::Failed {
             task_id,
             error: error.to_string(),
             attempts,
-            scheduled_at: now + chrono::Duration::seconds(retry_delay),
+            status,
+            scheduled_at: now + chrono::Duration::seconds(task_retry_delay_secs(attempts)),
             updated_at: now,
         },
         fence_token,
@@ -644,6 +671,65 @@ async fn fail_task_inner(
     .await
 }
 
+/// Quadratic backoff capped at an hour so a long-failing task still retries
+/// at a useful cadence until it runs out of attempts.
+fn task_retry_delay_secs(attempts: i32) -> i64 {
+    i64::from(attempts)
+        .saturating_mul(i64::from(attempts))
+        .saturating_mul(TASK_RETRY_BASE_DELAY_SECS)
+        .min(TASK_RETRY_MAX_DELAY_SECS)
+}
+
+/// Returns a task whose status is one of `requeueable` to the queue, due
+/// now and with a fresh attempt budget. Returns false for any other task.
+pub(crate) async fn requeue_task_with_permit(
+    storage: &Storage,
+    task_id: i64,
+    requeueable: &[TaskStatus],
+    permit: &PartitionWritePermit,
+    partition_owner_signing_key: &[u8],
+) -> Result<bool> {
+    require_task_queue_permit(permit)?;
+    let partition_precondition =
+        partition_write_precondition(storage, permit, partition_owner_signing_key).await?;
+    requeue_task_inner(
+        storage,
+        task_id,
+        requeueable,
+        permit.fence_token,
+        Some(partition_precondition),
+    )
+    .await
+}
+
+async fn requeue_task_inner(
+    storage: &Storage,
+    task_id: i64,
+    requeueable: &[TaskStatus],
+    fence_token: u64,
+    partition_precondition: Option<CoreMutationPrecondition>,
+) -> Result<bool> {
+    let state = read_task_queue_state(storage).await?;
+    if !state
+        .tasks
+        .get(&task_id)
+        .is_some_and(|task| requeueable.contains(&task.status))
+    {
+        return Ok(false);
+    }
+    append_task_event(
+        storage,
+        TaskJournalBody::Requeued {
+            task_id,
+            updated_at: Utc::now(),
+        },
+        fence_token,
+        partition_precondition,
+    )
+    .await
+    .map(|_| true)
+}
+
 async fn read_task_queue_state(storage: &Storage) -> Result<TaskQueueState> {
     let meta = CoreMetaStore::open(storage.core_store_meta_path())?;
     let mut state = TaskQueueState::default();
@@ -816,19 +902,33 @@ fn task_after_event(meta: &CoreMetaStore, event: &TaskJournalBody) -> Result<Opt
             task_id,
             error,
             attempts,
+            status,
             scheduled_at,
             updated_at,
         } => {
             let Some(mut task) = read_current_task(meta, *task_id)? else {
                 return Ok(None);
             };
-            task.status = TaskStatus::Failed;
+            task.status = *status;
             task.last_error = Some(error.clone());
             task.attempts = *attempts;
             task.scheduled_at = *scheduled_at;
             task.updated_at = *updated_at;
             Ok(Some(task))
         }
+        TaskJournalBody::Requeued {
+            task_id,
+            updated_at,
+        } => {
+            let Some(mut task) = read_current_task(meta, *task_id)? else {
+                return Ok(None);
+            };
+            task.status = TaskStatus::Pending;
+            task.attempts = 0;
+            task.scheduled_at = *updated_at;
+            task.updated_at = *updated_at;
+            Ok(Some(task))
+        }
     }
 }
 
@@ -1099,16 +1199,26 @@ fn task_journal_body_to_proto(
             task_id,
             error,
             attempts,
+            status,
             scheduled_at,
             updated_at,
         } => {
             body.event = TaskJournalEventKindProto::Failed as i32;
             body.task_id = Some(*task_id);
+            body.status = Some(task_status_to_proto(*status) as i32);
             body.error = Some(error.clone());
             body.attempts = Some(*attempts);
             body.scheduled_at = Some(scheduled_at.to_rfc3339());
             body.updated_at = Some(updated_at.to_rfc3339());
         }
+        TaskJournalBody::Requeued {
+            task_id,
+            updated_at,
+        } => {
+            body.event = TaskJournalEventKindProto::Requeued as i32;
+            body.task_id = Some(*task_id);
+            body.updated_at = Some(updated_at.to_rfc3339());
+        }
     }
     Ok(body)
 }
@@ -1155,9 +1265,19 @@ fn task_journal_body_from_proto(proto: TaskJournalBodyProto) -> Result<TaskJourn
             attempts: proto
                 .attempts
                 .ok_or_else(|| anyhow!("CoreStore task failure audit body is missing attempts"))?,
+            // Failure events written before dead-lettering carry no status.
+            status: proto
+                .status
+                .map(task_status_from_proto_i32)
+                .transpose()?
+                .unwrap_or(TaskStatus::Failed),
             scheduled_at: parse_task_time(proto.scheduled_at.as_deref(), "scheduled_at")?,
             updated_at: parse_task_time(proto.updated_at.as_deref(), "updated_at")?,
         }),
+        TaskJournalEventKindProto::Requeued => Ok(TaskJournalBody::Requeued {
+            task_id: require_task_id(proto.task_id)?,
+            updated_at: parse_task_time(proto.updated_at.as_deref(), "updated_at")?,
+        }),
     }
 }
 
@@ -1209,6 +1329,9 @@ fn task_type_to_proto(task_type: TaskType) -> TaskTypeProto {
         TaskType::RebalanceShard => TaskTypeProto::RebalanceShard,
         TaskType::HFIngestion => TaskTypeProto::HfIngestion,
         TaskType::AuthzMaterialization => TaskTypeProto::AuthzMaterialization,
+        TaskType::GarbageCollect => TaskTypeProto::GarbageCollect,
+        TaskType::ReplicateObject => TaskTypeProto::ReplicateObject,
+        TaskType::LifecycleSweep => TaskTypeProto::LifecycleSweep,
     }
 }
 
@@ -1225,6 +1348,9 @@ fn task_type_from_proto_i32(value: i32) -> Result<TaskType> {
             TaskTypeProto::RebalanceShard => TaskType::RebalanceShard,
             TaskTypeProto::HfIngestion => TaskType::HFIngestion,
             TaskTypeProto::AuthzMaterialization => TaskType::AuthzMaterialization,
+            TaskTypeProto::GarbageCollect => TaskType::GarbageCollect,
+            TaskTypeProto::ReplicateObject => TaskType::ReplicateObject,
+            TaskTypeProto::LifecycleSweep => TaskType::LifecycleSweep,
         },
     )
 }
@@ -1235,6 +1361,7 @@ fn task_status_to_proto(status: TaskStatus) -> TaskStatusProto {
         TaskStatus::Running => TaskStatusProto::Running,
         TaskStatus::Completed => TaskStatusProto::Completed,
         TaskStatus::Failed => TaskStatusProto::Failed,
+        TaskStatus::Dead => TaskStatusProto::Dead,
     }
 }
 
@@ -1248,6 +1375,7 @@ fn task_status_from_proto_i32(value: i32) -> Result<TaskStatus> {
             TaskStatusProto::Running => TaskStatus::Running,
             TaskStatusProto::Completed => TaskStatus::Completed,
             TaskStatusProto::Failed => TaskStatus::Failed,
+            TaskStatusProto::Dead => TaskStatus::Dead,
         },
     )
 }
@@ -1485,6 +1613,10 @@ fn authz_materialization_key(payload: &JsonValue) -> Option<AuthzMaterialization
     })
 }
 
+fn task_node_id(payload: &JsonValue) -> Option<&str> {
+    payload.get("node_id")?.as_str()
+}
+
 fn json_i64(payload: &JsonValue, field: &str) -> Option<i64> {
     payload.get(field)?.as_i64()
 }
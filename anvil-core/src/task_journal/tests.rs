@@ -142,6 +142,35 @@ pub(crate) async fn task_journal_with_permit_writes_fenced_protobuf_payloads() {
     assert!(fences.iter().all(|fence| *fence == permit.fence_token));
 }
 
+#[tokio::test]
+async fn task_journal_leaves_tasks_for_another_node_unclaimed() {
+    let temp = tempdir().unwrap();
+    let storage = Storage::new_at(temp.path()).await.unwrap();
+    let owner = ready_owner(&storage, "node-a").await;
+    let permit = owner.write_permit().unwrap();
+
+    for node_id in ["node-b", "node-a"] {
+        enqueue_task_with_permit(
+            &storage,
+            TaskType::GarbageCollect,
+            json!({"node_id": node_id, "grace_period_secs": 60}),
+            100,
+            &permit,
+            KEY,
+        )
+        .await
+        .unwrap();
+    }
+
+    let claimed = claim_pending_tasks_with_permit(&storage, 10, &permit, KEY)
+        .await
+        .unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].payload["node_id"], "node-a");
+    let tasks = list_tasks(&storage).await.unwrap();
+    assert_eq!(tasks[0].status, TaskStatus::Pending);
+}
+
 #[tokio::test]
 pub(crate) async fn task_journal_deduplicates_live_tasks_but_allows_new_after_completion() {
     let temp = tempdir().unwrap();
@@ -458,9 +487,16 @@ pub(crate) async fn task_journal_reclaims_failed_tasks_after_retry_delay() {
     let first_claim = claim_pending_tasks_with_permit(&storage, 1, &permit, KEY)
         .await
         .unwrap();
-    fail_task_with_permit(&storage, first_claim[0].id, "try again", &permit, KEY)
-        .await
-        .unwrap();
+    fail_task_with_permit(
+        &storage,
+        first_claim[0].id,
+        "try again",
+        DEFAULT_TASK_MAX_ATTEMPTS,
+        &permit,
+        KEY,
+    )
+    .await
+    .unwrap();
     let not_ready = claim_pending_tasks_with_permit(&storage, 1, &permit, KEY)
         .await
         .unwrap();
@@ -478,6 +514,7 @@ pub(crate) async fn task_journal_reclaims_failed_tasks_after_retry_delay() {
             task_id: task.id,
             error: task.last_error.clone().unwrap(),
             attempts: task.attempts,
+            status: TaskStatus::Failed,
             scheduled_at: task.scheduled_at,
             updated_at: Utc::now(),
         },
@@ -496,6 +533,111 @@ pub(crate) async fn task_journal_reclaims_failed_tasks_after_retry_delay() {
     assert_eq!(retried[0].attempts, 1);
 }
 
+#[test]
+fn task_retry_delay_grows_quadratically_up_to_one_hour() {
+    assert_eq!(task_retry_delay_secs(1), 10);
+    assert_eq!(task_retry_delay_secs(3), 90);
+    assert_eq!(task_retry_delay_secs(19), 3600);
+    assert_eq!(task_retry_delay_secs(i32::MAX), 3600);
+}
+
+#[tokio::test]
+async fn task_journal_dead_letters_exhausted_tasks_until_requeued() {
+    let temp = tempdir().unwrap();
+    let storage = Storage::new_at(temp.path()).await.unwrap();
+    let owner = ready_owner(&storage, "node-a").await;
+    let permit = owner.write_permit().unwrap();
+
+    enqueue_task_with_permit(
+        &storage,
+        TaskType::HFIngestion,
+        json!({"ingestion_id": 3}),
+        100,
+        &permit,
+        KEY,
+    )
+    .await
+    .unwrap();
+    let task_id = list_tasks(&storage).await.unwrap()[0].id;
+    assert!(
+        !requeue_task_with_permit(&storage, task_id, &[TaskStatus::Dead], &permit, KEY)
+            .await
+            .unwrap(),
+        "only dead tasks can be requeued"
+    );
+
+    for expected in [TaskStatus::Failed, TaskStatus::Dead] {
+        fail_task_with_permit(&storage, task_id, "upstream 404", 2, &permit, KEY)
+            .await
+            .unwrap();
+        assert_eq!(list_tasks(&storage).await.unwrap()[0].status, expected);
+    }
+    let state = read_task_queue_state(&storage).await.unwrap();
+    assert!(!state.has_due_tasks(Utc::now() + chrono::Duration::days(1)));
+
+    assert!(
+        requeue_task_with_permit(&storage, task_id, &[TaskStatus::Dead], &permit, KEY)
+            .await
+            .unwrap()
+    );
+    let requeued = list_tasks(&storage).await.unwrap();
+    assert_eq!(requeued[0].status, TaskStatus::Pending);
+    assert_eq!(requeued[0].attempts, 0);
+    assert_eq!(requeued[0].last_error.as_deref(), Some("upstream 404"));
+    let claimed = claim_pending_tasks_with_permit(&storage, 1, &permit, KEY)
+        .await
+        .unwrap();
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].id, task_id);
+}
+
+#[tokio::test]
+async fn task_journal_retries_failed_tasks_immediately() {
+    let temp = tempdir().unwrap();
+    let storage = Storage::new_at(temp.path()).await.unwrap();
+    let owner = ready_owner(&storage, "node-a").await;
+    let permit = owner.write_permit().unwrap();
+
+    enqueue_task_with_permit(
+        &storage,
+        TaskType::DeleteBucket,
+        json!({"bucket_id": 7}),
+        100,
+        &permit,
+        KEY,
+    )
+    .await
+    .unwrap();
+    let task_id = list_tasks(&storage).await.unwrap()[0].id;
+    fail_task_with_permit(&storage, task_id, "bucket busy", 5, &permit, KEY)
+        .await
+        .unwrap();
+    let state = read_task_queue_state(&storage).await.unwrap();
+    assert!(
+        !state.has_due_tasks(Utc::now()),
+        "failed task is backing off"
+    );
+
+    let retryable = [TaskStatus::Failed, TaskStatus::Dead];
+    assert!(
+        requeue_task_with_permit(&storage, task_id, &retryable, &permit, KEY)
+            .await
+            .unwrap()
+    );
+    let retried = list_tasks(&storage).await.unwrap();
+    assert_eq!(retried[0].status, TaskStatus::Pending);
+    assert_eq!(retried[0].attempts, 0);
+    let state = read_task_queue_state(&storage).await.unwrap();
+    assert!(state.has_due_tasks(Utc::now()));
+
+    assert!(
+        !requeue_task_with_permit(&storage, task_id, &retryable, &permit, KEY)
+            .await
+            .unwrap(),
+        "pending tasks are not retried"
+    );
+}
+
 #[tokio::test]
 pub(crate) async fn task_journal_with_permit_rejects_stale_fence() {
     let temp = tempdir().unwrap();
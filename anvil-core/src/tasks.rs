@@ -8,6 +8,9 @@ pub enum TaskType {
     RebalanceShard,
     HFIngestion,
     AuthzMaterialization,
+    GarbageCollect,
+    ReplicateObject,
+    LifecycleSweep,
 }
 
 impl TaskType {
@@ -20,6 +23,9 @@ impl TaskType {
             Self::RebalanceShard => "REBALANCE_SHARD",
             Self::HFIngestion => "HF_INGESTION",
             Self::AuthzMaterialization => "AUTHZ_MATERIALIZATION",
+            Self::GarbageCollect => "GARBAGE_COLLECT",
+            Self::ReplicateObject => "REPLICATE_OBJECT",
+            Self::LifecycleSweep => "LIFECYCLE_SWEEP",
         }
     }
 }
@@ -31,6 +37,38 @@ pub enum TaskStatus {
     Running,
     Completed,
     Failed,
+    /// Exhausted its retry attempts; workers no longer claim it until an
+    /// operator requeues it.
+    Dead,
+}
+
+impl TaskStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Dead => "dead",
+        }
+    }
+}
+
+impl std::str::FromStr for TaskStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "pending" => Ok(Self::Pending),
+            "running" => Ok(Self::Running),
+            "completed" => Ok(Self::Completed),
+            "failed" => Ok(Self::Failed),
+            "dead" => Ok(Self::Dead),
+            other => Err(format!(
+                "invalid task status {other:?}; expected pending, running, completed, failed, or dead"
+            )),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -64,3 +102,67 @@ pub enum HFIngestionItemState {
     Failed,
     Skipped,
 }
+
+impl HFIngestionItemState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Downloading => "downloading",
+            Self::Stored => "stored",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        }
+    }
+}
+
+impl std::str::FromStr for HFIngestionItemState {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "queued" => Ok(Self::Queued),
+            "downloading" => Ok(Self::Downloading),
+            "stored" => Ok(Self::Stored),
+            "failed" => Ok(Self::Failed),
+            "skipped" => Ok(Self::Skipped),
+            other => Err(format!(
+                "invalid item state {other:?}; expected queued, downloading, stored, failed, or skipped"
+            )),
+        }
+    }
+}
+
+/// Kind of Hugging Face repository an ingestion downloads from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HFRepoType {
+    #[default]
+    Model,
+    Dataset,
+    Space,
+}
+
+impl HFRepoType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Model => "model",
+            Self::Dataset => "dataset",
+            Self::Space => "space",
+        }
+    }
+}
+
+impl std::str::FromStr for HFRepoType {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "" | "model" => Ok(Self::Model),
+            "dataset" => Ok(Self::Dataset),
+            "space" => Ok(Self::Space),
+            other => Err(format!(
+                "invalid repo type {other:?}; expected model, dataset, or space"
+            )),
+        }
+    }
+}
@@ -67,6 +67,26 @@ pub fn is_valid_region_name(name: &str) -> bool {
     REGION_NAME_REGEX.is_match(name)
 }
 
+/// Validates a Hugging Face repository id of the form `name` or `namespace/name`.
+pub fn is_valid_hf_repo_id(repo: &str) -> bool {
+    lazy_static! {
+        static ref HF_REPO_SEGMENT_REGEX: Regex =
+            Regex::new(r"^[A-Za-z0-9](?:[A-Za-z0-9._-]*[A-Za-z0-9])?$").unwrap();
+    }
+    if repo.is_empty() || repo.len() > 96 {
+        return false;
+    }
+    let segments = repo.split('/').collect::<Vec<_>>();
+    if segments.len() > 2 {
+        return false;
+    }
+    segments.iter().all(|segment| {
+        HF_REPO_SEGMENT_REGEX.is_match(segment)
+            && !segment.contains("--")
+            && !segment.contains("..")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,4 +168,20 @@ mod tests {
         assert!(!is_valid_region_name("ue"));
         assert!(!is_valid_region_name(&"a".repeat(64)));
     }
+
+    #[test]
+    fn test_hf_repo_ids() {
+        assert!(is_valid_hf_repo_id("gpt2"));
+        assert!(is_valid_hf_repo_id("openai/gpt-oss-20b"));
+        assert!(is_valid_hf_repo_id("org_name/model.v1"));
+        assert!(!is_valid_hf_repo_id(""));
+        assert!(!is_valid_hf_repo_id("openai/"));
+        assert!(!is_valid_hf_repo_id("/gpt2"));
+        assert!(!is_valid_hf_repo_id("a/b/c"));
+        assert!(!is_valid_hf_repo_id("openai/gpt oss"));
+        assert!(!is_valid_hf_repo_id("openai/-model"));
+        assert!(!is_valid_hf_repo_id("openai/model--x"));
+        assert!(!is_valid_hf_repo_id("openai/../model"));
+        assert!(!is_valid_hf_repo_id(&"a".repeat(97)));
+    }
 }
@@ -264,6 +264,12 @@ mod tests {
             region: "test-region".to_string(),
             created_at: Utc::now(),
             is_public_read: false,
+            policy_json: None,
+            max_bytes: None,
+            max_objects: None,
+            replicate_to: Vec::new(),
+            lifecycle_rules: None,
+            default_retention_days: None,
         }
     }
 
@@ -287,10 +293,12 @@ mod tests {
             created_at: Utc::now(),
             deleted_at: None,
             storage_class: None,
+            requested_storage_class: None,
             user_meta: None,
             shard_map: None,
             checksum: None,
             link: None,
+            retention: None,
         }
     }
 
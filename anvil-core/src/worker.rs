@@ -1,7 +1,8 @@
 use crate::auth::JwtManager;
 use crate::cluster::ClusterState;
+use crate::core_store::{CoreObjectRef, CoreStore};
 use crate::crypto::EncryptionKeyring;
-use crate::object_manager::ObjectManager;
+use crate::object_manager::{LifecycleSweepCursor, ObjectManager};
 use crate::partition_fence::{
     OWNERSHIP_CAS_CONFLICT, OWNERSHIP_HELD, OWNERSHIP_OWNER_MISMATCH, OWNERSHIP_STALE_FENCE,
 };
@@ -9,7 +10,7 @@ use crate::persistence::Object;
 use crate::persistence::Persistence;
 use crate::task_lease::{LEASE_CAS_CONFLICT, LEASE_HELD, LEASE_OWNER_MISMATCH, STALE_FENCE};
 use crate::tasks::{HFIngestionItemState, HFIngestionState, TaskStatus, TaskType};
-use anyhow::{Result, anyhow};
+use anyhow::{Result, anyhow, bail};
 use futures_util::{Stream, StreamExt};
 use serde::Deserialize;
 use serde_json::json;
@@ -32,6 +33,11 @@ const CLAIM_CONTENTION_BASE_DELAY: Duration = Duration::from_millis(250);
 const CLAIM_CONTENTION_MAX_DELAY: Duration = Duration::from_secs(8);
 const CLAIM_TRANSIENT_MAX_DELAY: Duration = Duration::from_secs(2);
 const CLAIM_FATAL_DELAY: Duration = Duration::from_secs(5);
+const LIFECYCLE_SWEEP_BATCH_SIZE: usize = 1000;
+const LIFECYCLE_SWEEP_PRIORITY: i32 = 100;
+const SHARD_REBALANCE_PRIORITY: i32 = 50;
+/// How often blocks read with shards missing are turned into repair tasks.
+pub const SHARD_REPAIR_QUEUE_INTERVAL: Duration = Duration::from_secs(10);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum WorkerClaimError {
@@ -144,6 +150,26 @@ struct ObjectMetadataCompactionPayload {
     bucket_id: i64,
 }
 
+#[derive(Deserialize)]
+struct GarbageCollectPayload {
+    node_id: String,
+    grace_period_secs: u64,
+}
+
+#[derive(Deserialize)]
+struct RebalanceShardPayload {
+    object_ref: CoreObjectRef,
+}
+
+#[derive(Deserialize)]
+struct ReplicateObjectPayload {
+    tenant_id: i64,
+    bucket_name: String,
+    object_key: String,
+    version_id: uuid::Uuid,
+    region: String,
+}
+
 #[derive(Deserialize)]
 struct IndexBuildPayload {
     tenant_id: i64,
@@ -267,6 +293,189 @@ pub async fn run(
     }
 }
 
+/// Enqueues a `GarbageCollect` task for this node every `interval`. The task
+/// names the node because it deletes files from that node's block cache; a
+/// pass that is still queued absorbs the next tick instead of stacking another
+/// one behind it.
+pub async fn schedule_garbage_collection(
+    persistence: Persistence,
+    interval: Duration,
+    grace_period_secs: u64,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // The first tick completes immediately; wait a full interval after startup.
+    ticker.tick().await;
+    loop {
+        ticker.tick().await;
+        if let Err(error) = persistence
+            .enqueue_task_if_absent(
+                TaskType::GarbageCollect,
+                json!({
+                    "node_id": persistence.owner_node_id(),
+                    "grace_period_secs": grace_period_secs,
+                }),
+                100,
+            )
+            .await
+        {
+            warn!(%error, "Failed to schedule orphaned shard garbage collection");
+        }
+    }
+}
+
+/// Queues a `RebalanceShard` task every `interval` for each block this node
+/// read with shards missing since the last tick. Reads reconstruct around the
+/// gap; the task puts the shards back so the block regains its redundancy.
+pub async fn schedule_shard_repairs(
+    persistence: Persistence,
+    core_store: CoreStore,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        for object_ref in core_store.take_degraded_blocks() {
+            if let Err(error) = persistence
+                .enqueue_task_if_absent(
+                    TaskType::RebalanceShard,
+                    json!({ "object_ref": object_ref }),
+                    SHARD_REBALANCE_PRIORITY,
+                )
+                .await
+            {
+                warn!(
+                    %error,
+                    block_id = %object_ref.encoding.block_id,
+                    "Failed to queue shard repair"
+                );
+            }
+        }
+    }
+}
+
+/// Counts of the `RebalanceShard` tasks a node drain queued, by outcome.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NodeDrainMigrationProgress {
+    pub pending: u64,
+    pub completed: u64,
+    /// Failed tasks retry on their own; dead ones wait for an operator.
+    pub failed: u64,
+}
+
+/// Queues a `RebalanceShard` task for every block with a shard recorded on
+/// `node_id`. A draining node no longer takes shards, so each task moves the
+/// node's shards to their current placement. Returns how many were queued.
+pub async fn queue_node_shard_migrations(
+    persistence: &Persistence,
+    core_store: &CoreStore,
+    node_id: &str,
+) -> Result<u64> {
+    let mut queued = 0;
+    for object_ref in core_store.object_refs_with_shards_on_node(node_id)? {
+        if persistence
+            .enqueue_task_if_absent(
+                TaskType::RebalanceShard,
+                json!({ "object_ref": object_ref, "drain_node_id": node_id }),
+                SHARD_REBALANCE_PRIORITY,
+            )
+            .await?
+        {
+            queued += 1;
+        }
+    }
+    Ok(queued)
+}
+
+/// Tallies the shard migrations [`queue_node_shard_migrations`] queued for
+/// `node_id`.
+pub async fn node_shard_migration_progress(
+    persistence: &Persistence,
+    node_id: &str,
+) -> Result<NodeDrainMigrationProgress> {
+    let mut progress = NodeDrainMigrationProgress::default();
+    for task in persistence.list_tasks().await? {
+        if task.task_type != TaskType::RebalanceShard
+            || task.payload.get("drain_node_id").and_then(|id| id.as_str()) != Some(node_id)
+        {
+            continue;
+        }
+        let count = match task.status {
+            TaskStatus::Pending | TaskStatus::Running => &mut progress.pending,
+            TaskStatus::Completed => &mut progress.completed,
+            TaskStatus::Failed | TaskStatus::Dead => &mut progress.failed,
+        };
+        *count += 1;
+    }
+    Ok(progress)
+}
+
+/// Starts a lifecycle expiration pass every `interval`. Each `LifecycleSweep`
+/// task handles one batch and queues the next with its cursor, so a tick that
+/// finds a pass still in flight is skipped.
+pub async fn schedule_lifecycle_sweep(persistence: Persistence, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // The first tick completes immediately; wait a full interval after startup.
+    ticker.tick().await;
+    loop {
+        ticker.tick().await;
+        let in_flight = match persistence.list_tasks().await {
+            Ok(tasks) => tasks.iter().any(|task| {
+                task.task_type == TaskType::LifecycleSweep
+                    && matches!(
+                        task.status,
+                        TaskStatus::Pending | TaskStatus::Running | TaskStatus::Failed
+                    )
+            }),
+            Err(error) => {
+                warn!(%error, "Failed to inspect the task queue for lifecycle sweeps");
+                continue;
+            }
+        };
+        if in_flight {
+            continue;
+        }
+        if let Err(error) = persistence
+            .enqueue_task_if_absent(
+                TaskType::LifecycleSweep,
+                json!(LifecycleSweepCursor::default()),
+                LIFECYCLE_SWEEP_PRIORITY,
+            )
+            .await
+        {
+            warn!(%error, "Failed to schedule a lifecycle expiration sweep");
+        }
+    }
+}
+
+/// Periodically verifies every shard stored on this node. The scrub runs
+/// in-process rather than through the shared task queue because each node can
+/// only check its own block cache.
+pub async fn schedule_shard_scrub(persistence: Persistence, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    // The first tick completes immediately; wait a full interval after startup.
+    ticker.tick().await;
+    loop {
+        ticker.tick().await;
+        match persistence.scrub_local_shards().await {
+            Ok(report) if report.corrupt_shards > 0 => warn!(
+                scanned_shards = report.scanned_shards,
+                corrupt_shards = report.corrupt_shards,
+                "Shard scrub quarantined corrupt shards; repair findings recorded for the affected blocks"
+            ),
+            Ok(report) => debug!(
+                scanned_shards = report.scanned_shards,
+                scanned_bytes = report.scanned_bytes,
+                "Shard scrub completed"
+            ),
+            Err(error) => warn!(%error, "Shard scrub failed"),
+        }
+    }
+}
+
 async fn recover_interrupted_tasks(persistence: &Persistence) -> Result<()> {
     let node_id = persistence.owner_node_id();
     let interrupted = persistence
@@ -340,6 +549,12 @@ async fn execute_task_with_lease(
         }
         TaskType::IndexBuild => handle_index_build(persistence, task).await?,
         TaskType::AuthzMaterialization => handle_authz_materialization(persistence, task).await?,
+        TaskType::GarbageCollect => handle_garbage_collect(persistence, task).await?,
+        TaskType::ReplicateObject => handle_replicate_object(object_manager, task).await?,
+        TaskType::RebalanceShard => handle_rebalance_shard(object_manager, task).await?,
+        TaskType::LifecycleSweep => {
+            handle_lifecycle_sweep(persistence, object_manager, task).await?
+        }
         TaskType::HFIngestion => {
             handle_hf_ingestion(persistence, object_manager, task, keyring).await?
         }
@@ -379,6 +594,98 @@ async fn handle_authz_materialization(
     Ok(())
 }
 
+async fn handle_garbage_collect(persistence: &Persistence, task: &Task) -> anyhow::Result<()> {
+    let payload: GarbageCollectPayload = serde_json::from_value(task.payload.clone())?;
+    if payload.node_id != persistence.owner_node_id() {
+        bail!(
+            "garbage collection task for node {} was claimed by node {}",
+            payload.node_id,
+            persistence.owner_node_id()
+        );
+    }
+    let report = persistence
+        .collect_orphaned_shards(Duration::from_secs(payload.grace_period_secs), false)
+        .await?;
+    info!(
+        scanned_shards = report.scanned_shards,
+        deleted_shards = report.deleted_shards,
+        reclaimed_bytes = report.reclaimable_bytes,
+        pending_releases = report.pending_releases,
+        "Orphaned shard garbage collection completed"
+    );
+    Ok(())
+}
+
+async fn handle_replicate_object(
+    object_manager: &ObjectManager,
+    task: &Task,
+) -> anyhow::Result<()> {
+    let payload: ReplicateObjectPayload = serde_json::from_value(task.payload.clone())?;
+    let replicated = object_manager
+        .replicate_object(
+            payload.tenant_id,
+            &payload.bucket_name,
+            &payload.object_key,
+            payload.version_id,
+            &payload.region,
+        )
+        .await
+        .map_err(|status| anyhow!(status.message().to_string()))?;
+    info!(
+        tenant_id = payload.tenant_id,
+        bucket_name = %payload.bucket_name,
+        object_key = %payload.object_key,
+        version_id = %payload.version_id,
+        region = %payload.region,
+        replicated,
+        "Object replication task completed"
+    );
+    Ok(())
+}
+
+async fn handle_rebalance_shard(object_manager: &ObjectManager, task: &Task) -> Result<()> {
+    let payload: RebalanceShardPayload = serde_json::from_value(task.payload.clone())?;
+    let report = object_manager
+        .rebalance_object_ref_shards(&payload.object_ref)
+        .await?;
+    info!(
+        block_id = %payload.object_ref.encoding.block_id,
+        checked_shards = report.checked_shards,
+        rebuilt_shards = report.rebuilt_shards,
+        relocated_shards = report.relocated_shards,
+        "Shard rebalance task completed"
+    );
+    Ok(())
+}
+
+async fn handle_lifecycle_sweep(
+    persistence: &Persistence,
+    object_manager: &ObjectManager,
+    task: &Task,
+) -> anyhow::Result<()> {
+    let cursor: LifecycleSweepCursor = serde_json::from_value(task.payload.clone())?;
+    let outcome = object_manager
+        .run_lifecycle_sweep(&cursor, LIFECYCLE_SWEEP_BATCH_SIZE, chrono::Utc::now())
+        .await
+        .map_err(|status| anyhow!(status.message().to_string()))?;
+    if let Some(next) = &outcome.next {
+        persistence
+            .enqueue_task_if_absent(
+                TaskType::LifecycleSweep,
+                json!(next),
+                LIFECYCLE_SWEEP_PRIORITY,
+            )
+            .await?;
+    }
+    info!(
+        scanned_objects = outcome.scanned_objects,
+        expired_objects = outcome.expired_objects,
+        pass_complete = outcome.next.is_none(),
+        "Lifecycle expiration sweep batch completed"
+    );
+    Ok(())
+}
+
 async fn handle_index_build(persistence: &Persistence, task: &Task) -> anyhow::Result<()> {
     let payload: IndexBuildPayload = serde_json::from_value(task.payload.clone())?;
     match persistence
@@ -473,6 +780,11 @@ async fn handle_hf_ingestion(
         let requester_app_id = job.requester_app_id;
         let repo_str = job.repo;
         let revision = job.revision;
+        let repo_type = match job.repo_type {
+            crate::tasks::HFRepoType::Model => RepoType::Model,
+            crate::tasks::HFRepoType::Dataset => RepoType::Dataset,
+            crate::tasks::HFRepoType::Space => RepoType::Space,
+        };
         let target_bucket = job.target_bucket;
         let _target_region = job.target_region;
         let target_prefix = job.target_prefix;
@@ -487,6 +799,7 @@ async fn handle_hf_ingestion(
         info!(
             repo = %repo_str,
             revision = %revision,
+            repo_type = job.repo_type.as_str(),
             "Fetched job details."
         );
 
@@ -510,7 +823,7 @@ async fn handle_hf_ingestion(
         let repo_details = (repo_str.clone(), revision.clone());
         let api_clone = api.clone();
         let siblings = tokio::task::spawn_blocking(move || {
-            let repo = Repo::with_revision(repo_details.0, RepoType::Model, repo_details.1);
+            let repo = Repo::with_revision(repo_details.0, repo_type, repo_details.1);
             let repo_client = api_clone.repo(repo);
             repo_client.info().map(|info| info.siblings)
         })
@@ -558,8 +871,11 @@ async fn handle_hf_ingestion(
             {
                 if let Some(bucket) = bucket_opt {
                     if let Ok(obj_opt) = persistence.get_object(bucket.id, &path).await {
-                        if obj_opt.is_some() {
+                        if let Some(existing) = obj_opt {
                             info!(path = %path, "Skipping existing file");
+                            persistence
+                                .hf_update_item_size(item_id, existing.size)
+                                .await?;
                             persistence
                                 .hf_update_item_state(item_id, HFIngestionItemState::Skipped, None)
                                 .await?;
@@ -580,11 +896,8 @@ async fn handle_hf_ingestion(
             let local_path_buf;
             info!("Downloading from Hugging Face");
             local_path_buf = tokio::task::spawn_blocking(move || {
-                let repo = Repo::with_revision(
-                    repo_details_clone.0,
-                    RepoType::Model,
-                    repo_details_clone.1,
-                );
+                let repo =
+                    Repo::with_revision(repo_details_clone.0, repo_type, repo_details_clone.1);
                 let repo_client = api_clone_2.repo(repo);
                 repo_client.get(&filename)
             })
@@ -592,9 +905,13 @@ async fn handle_hf_ingestion(
 
             let local_path = &local_path_buf;
             debug!(path = ?local_path, "Downloaded to");
+            let downloaded_size = tokio::fs::metadata(local_path).await?.len();
+            persistence
+                .hf_update_item_size(item_id, downloaded_size as i64)
+                .await?;
             // --- End Blocking ---
 
-            let _bucket = persistence
+            let bucket = persistence
                 .get_bucket_by_name(tenant_id, &target_bucket)
                 .await?
                 .ok_or_else(|| anyhow!("target bucket not found"))?;
@@ -641,6 +958,24 @@ async fn handle_hf_ingestion(
                         persistence
                             .hf_update_item_success(item_id, obj.size, &obj.etag)
                             .await?;
+                        if path.ends_with(".safetensors") {
+                            // The file is stored either way; a header we cannot
+                            // index only leaves its tensors unsearchable.
+                            if let Err(error) = index_safetensors_object(
+                                persistence,
+                                object_manager,
+                                &requester_claims,
+                                &bucket,
+                                &full_key,
+                                &obj,
+                                &repo_str,
+                                &revision,
+                            )
+                            .await
+                            {
+                                warn!(key = %full_key, %error, "Failed to index safetensors header");
+                            }
+                        }
                         break;
                     }
                     Err(e) if attempt < 3 => {
@@ -798,6 +1133,100 @@ async fn handle_hf_ingestion(
     result
 }
 
+/// Reads the header of a stored `.safetensors` object with two range reads and
+/// registers it as a model artifact whose tensors point back into the object.
+#[allow(clippy::too_many_arguments)]
+async fn index_safetensors_object(
+    persistence: &Persistence,
+    object_manager: &ObjectManager,
+    claims: &crate::auth::Claims,
+    bucket: &crate::persistence::Bucket,
+    key: &str,
+    object: &Object,
+    source_repo: &str,
+    revision: &str,
+) -> Result<usize> {
+    use crate::safetensors::{self, HEADER_LEN_PREFIX_BYTES};
+
+    let prefix = read_object_range(
+        object_manager,
+        claims,
+        &bucket.name,
+        key,
+        0,
+        HEADER_LEN_PREFIX_BYTES,
+    )
+    .await?;
+    let header_len = safetensors::header_len(&prefix)?;
+    let header = read_object_range(
+        object_manager,
+        claims,
+        &bucket.name,
+        key,
+        HEADER_LEN_PREFIX_BYTES,
+        HEADER_LEN_PREFIX_BYTES + header_len,
+    )
+    .await?;
+    let tensors = safetensors::tensor_index_rows(&header, header_len)?;
+
+    let artifact_id = format!("bucket-{}/{}", bucket.id, key);
+    let manifest = crate::anvil_api::ModelManifest {
+        schema_version: "1".to_string(),
+        artifact_id: artifact_id.clone(),
+        name: key.to_string(),
+        format: "safetensors".to_string(),
+        components: vec![crate::anvil_api::model_manifest::Component {
+            path: String::new(),
+            size: object.size.max(0) as u64,
+            hash: object.etag.clone(),
+        }],
+        base_artifact_id: String::new(),
+        delta_artifact_ids: Vec::new(),
+        signatures: Vec::new(),
+        merkle_root: String::new(),
+        meta: HashMap::from([
+            ("source_repo".to_string(), source_repo.to_string()),
+            ("revision".to_string(), revision.to_string()),
+        ]),
+    };
+    persistence
+        .create_model_artifact(&artifact_id, bucket.id, key, &manifest)
+        .await?;
+    persistence
+        .create_model_tensors(&artifact_id, &tensors)
+        .await?;
+    info!(artifact_id = %artifact_id, tensors = tensors.len(), "Indexed safetensors header");
+    Ok(tensors.len())
+}
+
+async fn read_object_range(
+    object_manager: &ObjectManager,
+    claims: &crate::auth::Claims,
+    bucket_name: &str,
+    key: &str,
+    start: u64,
+    end_exclusive: u64,
+) -> Result<Vec<u8>> {
+    let (_object, mut stream, _range_start) = object_manager
+        .get_object(
+            Some(claims.clone()),
+            bucket_name.to_string(),
+            key.to_string(),
+            None,
+            Some(crate::core_store::CoreByteRange {
+                start,
+                end_exclusive,
+            }),
+        )
+        .await
+        .map_err(|status| anyhow!(status.message().to_string()))?;
+    let mut bytes = Vec::with_capacity(end_exclusive.saturating_sub(start) as usize);
+    while let Some(chunk) = stream.next().await {
+        bytes.extend_from_slice(&chunk.map_err(|status| anyhow!(status.message().to_string()))?);
+    }
+    Ok(bytes)
+}
+
 async fn handle_delete_object(persistence: &Persistence, task: &Task) -> Result<()> {
     let payload: DeleteObjectPayload = serde_json::from_value(task.payload.clone())?;
 
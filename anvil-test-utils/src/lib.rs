@@ -936,6 +936,7 @@ pub async fn get_access_token_for_test(
                     .get_access_token(GetAccessTokenRequest {
                         client_id: client_id.to_string(),
                         client_secret: client_secret.to_string(),
+                        requested_ttl_secs: 0,
                     })
                     .await
                 {
@@ -1157,6 +1158,7 @@ pub async fn get_auth_token(_admin_state_path: &str, grpc_addr: &str) -> String
         .get_access_token(GetAccessTokenRequest {
             client_id: "test-app".to_string(),
             client_secret: "test-secret".to_string(),
+            requested_ttl_secs: 0,
         })
         .await
         .unwrap()
@@ -1761,6 +1763,16 @@ impl TestCluster {
         &self,
         tenant_id: &str,
         app_name: &str,
+    ) -> (String, String) {
+        self.rotate_application_secret_with_grace(tenant_id, app_name, 0)
+            .await
+    }
+
+    pub async fn rotate_application_secret_with_grace(
+        &self,
+        tenant_id: &str,
+        app_name: &str,
+        grace_secs: u64,
     ) -> (String, String) {
         let mut client = AdminServiceClient::connect(self.admin_addrs[0].clone())
             .await
@@ -1769,6 +1781,7 @@ impl TestCluster {
             context: Some(test_admin_context(&format!("rotate-app-{app_name}"), 1)),
             tenant_id: tenant_id.to_string(),
             app_name: app_name.to_string(),
+            grace_secs,
         });
         request.metadata_mut().insert(
             "authorization",
@@ -70,6 +70,41 @@ pub async fn start_node_with_admin_listener(
                 error!("Worker process failed: {}", e);
             }
         });
+        if state.config.shard_gc_interval_secs > 0 {
+            tokio::spawn(anvil_core::worker::schedule_garbage_collection(
+                state.persistence.clone(),
+                std::time::Duration::from_secs(state.config.shard_gc_interval_secs),
+                state.config.shard_gc_grace_period_secs,
+            ));
+        }
+        if state.config.lifecycle_sweep_interval_secs > 0 {
+            tokio::spawn(anvil_core::worker::schedule_lifecycle_sweep(
+                state.persistence.clone(),
+                std::time::Duration::from_secs(state.config.lifecycle_sweep_interval_secs),
+            ));
+        }
+        if state.config.shard_scrub_interval_secs > 0 {
+            tokio::spawn(anvil_core::worker::schedule_shard_scrub(
+                state.persistence.clone(),
+                std::time::Duration::from_secs(state.config.shard_scrub_interval_secs),
+            ));
+        }
+    }
+
+    // Any node serving reads can find a block with shards missing, whether or
+    // not it runs the background worker that repairs it.
+    tokio::spawn(anvil_core::worker::schedule_shard_repairs(
+        state.persistence.clone(),
+        state.core_store.clone(),
+        anvil_core::worker::SHARD_REPAIR_QUEUE_INTERVAL,
+    ));
+
+    if state.config.tenant_rate_limit_refresh_secs > 0 {
+        tokio::spawn(anvil_core::rate_limit::refresh_tenant_rate_limits(
+            state.persistence.clone(),
+            state.tenant_rate_limiter.clone(),
+            std::time::Duration::from_secs(state.config.tenant_rate_limit_refresh_secs),
+        ));
     }
 
     // --- Services ---
@@ -173,8 +208,11 @@ pub async fn start_node_with_admin_listener(
         swarm,
         state.cluster.clone(),
         state.config.public_api_addr.clone(),
+        state.config.zone.clone(),
+        state.config.storage_path.clone(),
         state.config.cluster_secret.clone(),
         state.persistence.cache().clone(),
+        std::time::Duration::from_secs(state.config.cluster_peer_timeout_secs),
         outbound_events_rx,
     ));
     let server_task = tokio::spawn(async move {
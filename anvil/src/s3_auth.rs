@@ -3,7 +3,7 @@ use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::{AppState, auth::Claims};
+use crate::{AppState, auth::Claims, s3_gateway::s3_error};
 use aws_credential_types::Credentials;
 use aws_sigv4::http_request::{
     PercentEncodingMode, SignableBody, SignableRequest, SignatureLocation, SigningParams,
@@ -14,7 +14,7 @@ use aws_smithy_runtime_api::client::identity::Identity;
 use axum::{
     body::Body,
     extract::{ConnectInfo, Request, State},
-    http::{self, HeaderMap},
+    http::{self, HeaderMap, StatusCode},
     middleware::Next,
     response::Response,
 };
@@ -29,6 +29,18 @@ use tracing::{debug, info, warn};
 
 type HmacSha256 = Hmac<Sha256>;
 const SIGV4_MAX_CLOCK_SKEW: Duration = Duration::from_secs(15 * 60);
+/// SigV4 presigned URLs may be valid for at most seven days.
+const SIGV4_PRESIGNED_MAX_EXPIRES_SECS: u64 = 7 * 24 * 60 * 60;
+/// Query parameters that carry a presigned signature and are therefore not
+/// part of the canonical query string the client signed.
+const SIGV4_PRESIGNED_AUTH_PARAMS: &[&str] = &[
+    "X-Amz-Algorithm",
+    "X-Amz-Credential",
+    "X-Amz-Date",
+    "X-Amz-Expires",
+    "X-Amz-SignedHeaders",
+    "X-Amz-Signature",
+];
 
 #[derive(Clone, Debug)]
 struct AwsChunkedVerification {
@@ -38,6 +50,31 @@ struct AwsChunkedVerification {
     previous_signature: String,
 }
 
+/// Raised when an `aws-chunked` chunk signature does not continue the
+/// SigV4 chain, so the decoder can answer 403 rather than 400.
+#[derive(Debug)]
+struct AwsChunkSignatureMismatch;
+
+impl std::fmt::Display for AwsChunkSignatureMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Malformed chunk: chunk signature mismatch")
+    }
+}
+
+impl std::error::Error for AwsChunkSignatureMismatch {}
+
+/// Maps an `aws-chunked` decode failure to its S3 error document. A chunk
+/// signature mismatch is an authentication failure; anything else is a
+/// malformed request.
+fn aws_chunked_error_response(error: &anyhow::Error) -> Response {
+    let message = format!("Failed to decode aws-chunked body: {error}");
+    if error.is::<AwsChunkSignatureMismatch>() {
+        s3_error("SignatureDoesNotMatch", &message, StatusCode::FORBIDDEN)
+    } else {
+        s3_error("InvalidRequest", &message, StatusCode::BAD_REQUEST)
+    }
+}
+
 /// Middleware (Stage 2) to decode an `aws-chunked` request body.
 /// This runs AFTER `sigv4_auth`.
 pub async fn aws_chunked_decoder(req: Request, next: Next) -> Response {
@@ -52,21 +89,28 @@ pub async fn aws_chunked_decoder(req: Request, next: Next) -> Response {
     if is_streaming {
         let verification = parts.extensions.get::<AwsChunkedVerification>().cloned();
         match decode_aws_chunked_body(body, verification.as_ref()).await {
-            Ok(decoded_bytes) => {
+            Ok((decoded_bytes, trailers)) => {
                 // Remove the chunked encoding header as it's no longer accurate
                 parts.headers.remove("content-encoding");
+                // Trailing checksums are handled exactly like their header form.
+                for (name, value) in trailers {
+                    if !name.starts_with("x-amz-checksum-") {
+                        continue;
+                    }
+                    if let (Ok(name), Ok(value)) = (
+                        http::HeaderName::from_bytes(name.as_bytes()),
+                        http::HeaderValue::from_str(&value),
+                    ) {
+                        parts.headers.insert(name, value);
+                    }
+                }
                 // Create a new request with the clean body
                 let new_req = Request::from_parts(parts, Body::from(decoded_bytes));
                 next.run(new_req).await
             }
             Err(e) => {
                 warn!(error = %e, "Failed to decode aws-chunked body");
-                Response::builder()
-                    .status(400)
-                    .body(Body::from(format!(
-                        "Failed to decode aws-chunked body: {e}"
-                    )))
-                    .unwrap()
+                aws_chunked_error_response(&e)
             }
         }
     } else {
@@ -107,10 +151,11 @@ pub async fn sigv4_auth(State(state): State<AppState>, req: Request, next: Next)
             Ok(b) => b.to_bytes(),
             Err(e) => {
                 warn!(error = %e, "Failed to read body in SigV4 middleware");
-                return Response::builder()
-                    .status(400)
-                    .body(Body::from(format!("Failed to read body: {e}")))
-                    .unwrap();
+                return s3_error(
+                    "IncompleteBody",
+                    &format!("Failed to read body: {e}"),
+                    StatusCode::BAD_REQUEST,
+                );
             }
         };
         (Some(bytes.clone()), Body::from(bytes))
@@ -120,6 +165,21 @@ pub async fn sigv4_auth(State(state): State<AppState>, req: Request, next: Next)
 
     let mut req = Request::from_parts(parts.clone(), reconstituted_body);
 
+    match parts.uri.query().map(parse_presigned_query).transpose() {
+        Ok(Some(Some(presigned))) => {
+            return presigned_sigv4_auth(&state, &parts, req, presigned, next).await;
+        }
+        Ok(_) => {}
+        Err(e) => {
+            warn!(error = %e, "Failed to parse SigV4 presigned query");
+            return s3_error(
+                "AuthorizationQueryParametersError",
+                &format!("Invalid presigned query: {e}"),
+                StatusCode::BAD_REQUEST,
+            );
+        }
+    }
+
     let auth_header = match parts
         .headers
         .get(http::header::AUTHORIZATION)
@@ -132,10 +192,11 @@ pub async fn sigv4_auth(State(state): State<AppState>, req: Request, next: Next)
                 debug!("No SigV4 for GET/HEAD, deferring auth to handler");
                 return next.run(req).await;
             }
-            return Response::builder()
-                .status(401)
-                .body(Body::from("Missing Authorization"))
-                .unwrap();
+            return s3_error(
+                "AccessDenied",
+                "Missing Authorization",
+                StatusCode::FORBIDDEN,
+            );
         }
     };
 
@@ -143,54 +204,18 @@ pub async fn sigv4_auth(State(state): State<AppState>, req: Request, next: Next)
         Ok(p) => p,
         Err(e) => {
             warn!(error = %e, "Failed to parse SigV4 Authorization header");
-            return Response::builder()
-                .status(400)
-                .body(Body::from(format!("Invalid Authorization header: {e}")))
-                .unwrap();
-        }
-    };
-
-    let app_details = match state
-        .persistence
-        .get_app_by_client_id(&parsed.access_key_id)
-        .await
-    {
-        Ok(Some(d)) => d,
-        _ => {
-            warn!(access_key_id = %parsed.access_key_id, "SigV4 auth failed: Invalid access key");
-            return Response::builder()
-                .status(403)
-                .body(Body::from("Invalid access key"))
-                .unwrap();
+            return s3_error(
+                "AuthorizationHeaderMalformed",
+                &format!("Invalid Authorization header: {e}"),
+                StatusCode::BAD_REQUEST,
+            );
         }
     };
 
-    let secret_bytes = match state
-        .secret_keyring
-        .decrypt(&app_details.client_secret_encrypted)
-    {
-        Ok(s) => s,
-        Err(_) => {
-            warn!(access_key_id = %parsed.access_key_id, "Failed to decrypt secret for SigV4 auth");
-            return Response::builder()
-                .status(500)
-                .body(Body::from("Failed to decrypt secret"))
-                .unwrap();
-        }
+    let (claims, secrets) = match sigv4_credentials(&state, &parsed.access_key_id).await {
+        Ok(credentials) => credentials,
+        Err(response) => return response,
     };
-    let secret = match String::from_utf8(secret_bytes) {
-        Ok(s) => s,
-        Err(_) => {
-            warn!(access_key_id = %parsed.access_key_id, "Decrypted secret is not valid UTF-8");
-            return Response::builder()
-                .status(500)
-                .body(Body::from("Decrypted secret is not valid UTF-8"))
-                .unwrap();
-        }
-    };
-
-    let identity: Identity =
-        Credentials::new(&parsed.access_key_id, &secret, None, None, "sigv4-verify").into();
 
     let signing_time = match parts
         .headers
@@ -203,29 +228,28 @@ pub async fn sigv4_auth(State(state): State<AppState>, req: Request, next: Next)
             Some(t) => t,
             None => {
                 warn!(access_key_id = %parsed.access_key_id, "Missing or invalid X-Amz-Date for SigV4");
-                return Response::builder()
-                    .status(400)
-                    .body(Body::from("Missing or invalid X-Amz-Date"))
-                    .unwrap();
+                return s3_error(
+                    "AuthorizationHeaderMalformed",
+                    "Missing or invalid X-Amz-Date",
+                    StatusCode::BAD_REQUEST,
+                );
             }
         },
     };
     if !sigv4_timestamp_is_fresh(signing_time, SystemTime::now(), SIGV4_MAX_CLOCK_SKEW) {
         warn!(access_key_id = %parsed.access_key_id, "SigV4 request timestamp outside allowed freshness window");
-        return Response::builder()
-            .status(403)
-            .body(Body::from("Request timestamp outside allowed SigV4 window"))
-            .unwrap();
+        return s3_error(
+            "RequestTimeTooSkewed",
+            "Request timestamp outside allowed SigV4 window",
+            StatusCode::FORBIDDEN,
+        );
     }
 
     let host = match sigv4_effective_host(state.config.as_ref(), &parts) {
         Ok(host) => host,
         Err(err) => {
             warn!(error = %err, "Rejected SigV4 request with invalid forwarded host metadata");
-            return Response::builder()
-                .status(400)
-                .body(Body::from(err.to_string()))
-                .unwrap();
+            return s3_error("InvalidRequest", &err.to_string(), StatusCode::BAD_REQUEST);
         }
     };
     let scheme = detect_scheme(state.config.as_ref(), &parts.headers, &parts);
@@ -236,24 +260,6 @@ pub async fn sigv4_auth(State(state): State<AppState>, req: Request, next: Next)
         .unwrap_or("/");
     let absolute_url = format!("{scheme}://{host}{path_q}");
 
-    let mut settings = SigningSettings::default();
-    settings.signature_location = SignatureLocation::Headers;
-    settings.percent_encoding_mode = PercentEncodingMode::Single;
-    settings.uri_path_normalization_mode = UriPathNormalizationMode::Disabled;
-    settings.payload_checksum_kind = aws_sigv4::http_request::PayloadChecksumKind::XAmzSha256;
-    settings.expires_in = None;
-    settings.excluded_headers = Some(vec![Cow::Borrowed("authorization")]);
-
-    let signing_params: SigningParams = v4::SigningParams::builder()
-        .identity(&identity)
-        .region(&parsed.region)
-        .name(&parsed.service)
-        .time(signing_time)
-        .settings(settings)
-        .build()
-        .expect("valid signing params")
-        .into();
-
     // IMPORTANT: use exactly what the client signed, if provided.
     let payload_hash = parts
         .headers
@@ -286,47 +292,48 @@ pub async fn sigv4_auth(State(state): State<AppState>, req: Request, next: Next)
         hdrs.insert("host".to_string(), host.clone());
     }
 
-    let headers_iter = hdrs
+    let signed_headers = hdrs
         .iter()
         .filter(|(name, _)| signed_set.contains(name.as_str()))
-        .map(|(name, val)| (name.as_str(), val.as_str()));
-
-    let signable_req = match SignableRequest::new(
-        parts.method.as_str(),
-        &absolute_url,
-        headers_iter,
-        SignableBody::Precomputed(payload_hash.clone()),
-    ) {
-        Ok(s) => s,
-        Err(e) => {
-            warn!(error = %e, access_key_id = %parsed.access_key_id, "Bad request for signing");
-            return Response::builder()
-                .status(400)
-                .body(Body::from(format!("Bad request for signing: {e}")))
-                .unwrap();
-        }
-    };
+        .map(|(name, val)| (name.as_str(), val.as_str()))
+        .collect::<Vec<_>>();
 
-    // Compute signature for THIS request exactly as the client would have
-    let out = match sign(signable_req, &signing_params) {
-        Ok(o) => o,
-        Err(_) => {
-            warn!(access_key_id = %parsed.access_key_id, "SigV4 signature computation failed");
-            return Response::builder()
-                .status(403)
-                .body(Body::from("Signature verification failed"))
-                .unwrap();
+    // Compute signature for THIS request exactly as the client would have,
+    // once per accepted secret so a graced previous secret still verifies.
+    let mut matched_secret = None;
+    for secret in &secrets {
+        let computed_sig = match header_signature(
+            &parsed,
+            secret,
+            signing_time,
+            parts.method.as_str(),
+            &absolute_url,
+            &signed_headers,
+            &payload_hash,
+        ) {
+            Ok(signature) => signature,
+            Err(e) => {
+                warn!(error = %e, access_key_id = %parsed.access_key_id, "Bad request for signing");
+                return s3_error(
+                    "InvalidRequest",
+                    &format!("Bad request for signing: {e}"),
+                    StatusCode::BAD_REQUEST,
+                );
+            }
+        };
+        if constant_time_eq_str(&computed_sig, &parsed.signature) {
+            matched_secret = Some(secret);
+            break;
         }
-    };
-    let (_instr, computed_sig) = out.into_parts();
-
-    if !constant_time_eq_str(computed_sig.as_str(), &parsed.signature) {
-        warn!(access_key_id = %parsed.access_key_id, "SigV4 signature mismatch");
-        return Response::builder()
-            .status(403)
-            .body(Body::from("Signature verification failed"))
-            .unwrap();
     }
+    let Some(secret) = matched_secret else {
+        warn!(access_key_id = %parsed.access_key_id, "SigV4 signature mismatch");
+        return s3_error(
+            "SignatureDoesNotMatch",
+            "Signature verification failed",
+            StatusCode::FORBIDDEN,
+        );
+    };
 
     info!(access_key_id = %parsed.access_key_id, "SigV4 authentication successful");
 
@@ -340,7 +347,7 @@ pub async fn sigv4_auth(State(state): State<AppState>, req: Request, next: Next)
             .unwrap_or_else(|| format!("{}T000000Z", parsed.date));
         req.extensions_mut().insert(AwsChunkedVerification {
             signing_key: derive_sigv4_signing_key(
-                &secret,
+                secret,
                 &parsed.date,
                 &parsed.region,
                 &parsed.service,
@@ -354,25 +361,202 @@ pub async fn sigv4_auth(State(state): State<AppState>, req: Request, next: Next)
         });
     }
 
+    req.extensions_mut().insert(claims);
+
+    next.run(req).await
+}
+
+/// Answers `SlowDown` with a `Retry-After` header once the authenticated
+/// tenant has spent its request rate limit. Runs after `sigv4_auth`, so
+/// anonymous requests carry no claims and pass through.
+pub async fn tenant_rate_limit(
+    State(state): State<AppState>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if let Some(claims) = req.extensions().get::<Claims>()
+        && let Err(retry_after) = state.tenant_rate_limiter.check(claims.tenant_id)
+    {
+        let mut response = s3_error(
+            "SlowDown",
+            "Please reduce your request rate.",
+            StatusCode::TOO_MANY_REQUESTS,
+        );
+        response.headers_mut().insert(
+            http::header::RETRY_AFTER,
+            http::HeaderValue::from(anvil_core::rate_limit::retry_after_secs(retry_after)),
+        );
+        return response;
+    }
+    next.run(req).await
+}
+
+/// Verifies a query-string (presigned URL) SigV4 signature. The canonical
+/// request is rebuilt without the signature parameters and signed with
+/// `SignatureLocation::QueryParams`, which re-adds them exactly as the client did.
+async fn presigned_sigv4_auth(
+    state: &AppState,
+    parts: &http::request::Parts,
+    mut req: Request,
+    presigned: PresignedQuery,
+    next: Next,
+) -> Response {
+    let parsed = &presigned.auth;
+    let Some(signing_time) = parse_x_amz_date(&presigned.amz_date) else {
+        warn!(access_key_id = %parsed.access_key_id, "Invalid X-Amz-Date in presigned URL");
+        return s3_error(
+            "AuthorizationQueryParametersError",
+            "Missing or invalid X-Amz-Date",
+            StatusCode::BAD_REQUEST,
+        );
+    };
+    if !presigned_url_is_live(
+        signing_time,
+        presigned.expires_secs,
+        SystemTime::now(),
+        SIGV4_MAX_CLOCK_SKEW,
+    ) {
+        warn!(access_key_id = %parsed.access_key_id, "SigV4 presigned URL expired or not yet valid");
+        return s3_error("AccessDenied", "Request has expired", StatusCode::FORBIDDEN);
+    }
+
+    let (claims, secrets) = match sigv4_credentials(state, &parsed.access_key_id).await {
+        Ok(credentials) => credentials,
+        Err(response) => return response,
+    };
+
+    let host = match sigv4_effective_host(state.config.as_ref(), parts) {
+        Ok(host) => host,
+        Err(err) => {
+            warn!(error = %err, "Rejected SigV4 request with invalid forwarded host metadata");
+            return s3_error("InvalidRequest", &err.to_string(), StatusCode::BAD_REQUEST);
+        }
+    };
+    let scheme = detect_scheme(state.config.as_ref(), &parts.headers, parts);
+    let unsigned_query = presigned_unsigned_query(parts.uri.query().unwrap_or_default());
+    let absolute_url = if unsigned_query.is_empty() {
+        format!("{scheme}://{host}{}", parts.uri.path())
+    } else {
+        format!("{scheme}://{host}{}?{unsigned_query}", parts.uri.path())
+    };
+
+    let mut hdrs: HashMap<String, String> = HashMap::new();
+    for (k, v) in parts.headers.iter() {
+        if let Ok(val) = v.to_str() {
+            hdrs.insert(k.as_str().to_ascii_lowercase(), val.to_string());
+        }
+    }
+    if parsed.signed_headers.iter().any(|name| name == "host") {
+        hdrs.insert("host".to_string(), host);
+    }
+    let signed_headers = parsed
+        .signed_headers
+        .iter()
+        .filter_map(|name| hdrs.get(name).map(|value| (name.as_str(), value.as_str())))
+        .collect::<Vec<_>>();
+
+    let mut signature_matches = false;
+    for secret in &secrets {
+        let computed_sig = match presigned_signature(
+            secret,
+            &presigned,
+            signing_time,
+            parts.method.as_str(),
+            &absolute_url,
+            &signed_headers,
+        ) {
+            Ok(signature) => signature,
+            Err(e) => {
+                warn!(error = %e, access_key_id = %parsed.access_key_id, "Bad presigned request for signing");
+                return s3_error(
+                    "InvalidRequest",
+                    &format!("Bad request for signing: {e}"),
+                    StatusCode::BAD_REQUEST,
+                );
+            }
+        };
+        if constant_time_eq_str(&computed_sig, &parsed.signature) {
+            signature_matches = true;
+            break;
+        }
+    }
+    if !signature_matches {
+        warn!(access_key_id = %parsed.access_key_id, "SigV4 presigned signature mismatch");
+        return s3_error(
+            "SignatureDoesNotMatch",
+            "Signature verification failed",
+            StatusCode::FORBIDDEN,
+        );
+    }
+
+    info!(access_key_id = %parsed.access_key_id, "SigV4 presigned authentication successful");
+    req.extensions_mut().insert(claims);
+    next.run(req).await
+}
+
+/// Resolves the application behind a SigV4 access key and decrypts every
+/// secret it currently accepts: the active one first, then a previous secret
+/// still inside its rotation grace window.
+async fn sigv4_credentials(
+    state: &AppState,
+    access_key_id: &str,
+) -> Result<(Claims, Vec<String>), Response> {
+    let app_details = match state.persistence.get_app_by_client_id(access_key_id).await {
+        Ok(Some(d)) => d,
+        _ => {
+            warn!(access_key_id = %access_key_id, "SigV4 auth failed: Invalid access key");
+            return Err(s3_error(
+                "InvalidAccessKeyId",
+                "Invalid access key",
+                StatusCode::FORBIDDEN,
+            ));
+        }
+    };
+
+    let mut secrets = Vec::new();
+    for encrypted in app_details.accepted_client_secrets(chrono::Utc::now()) {
+        let secret_bytes = match state.secret_keyring.decrypt(encrypted) {
+            Ok(s) => s,
+            Err(_) => {
+                warn!(access_key_id = %access_key_id, "Failed to decrypt secret for SigV4 auth");
+                return Err(s3_error(
+                    "InternalError",
+                    "Failed to decrypt secret",
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ));
+            }
+        };
+        match String::from_utf8(secret_bytes) {
+            Ok(s) => secrets.push(s),
+            Err(_) => {
+                warn!(access_key_id = %access_key_id, "Decrypted secret is not valid UTF-8");
+                return Err(s3_error(
+                    "InternalError",
+                    "Decrypted secret is not valid UTF-8",
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ));
+            }
+        }
+    }
+
     let claims = Claims {
         sub: app_details.id.to_string(),
         tenant_id: app_details.tenant_id,
         jti: None,
         exp: 0, // SigV4 has its own expiry mechanism
     };
-    req.extensions_mut().insert(claims);
-
-    next.run(req).await
+    Ok((claims, secrets))
 }
 
 // ----------------- helpers -----------------
 
 /// Decode an `aws-chunked` content-encoded body and, when SigV4 streaming
 /// verification metadata is present, verify every chunk signature in the chain.
+/// Returns the payload and the trailing headers, with lowercased names.
 async fn decode_aws_chunked_body(
     body: Body,
     verification: Option<&AwsChunkedVerification>,
-) -> anyhow::Result<bytes::Bytes> {
+) -> anyhow::Result<(bytes::Bytes, Vec<(String, String)>)> {
     use bytes::{Buf, BytesMut};
 
     let mut buffer = BytesMut::from(body.collect().await?.to_bytes());
@@ -396,8 +580,8 @@ async fn decode_aws_chunked_body(
                 chunk_signature,
                 b"",
             )?;
-            consume_aws_chunked_trailers(&mut buffer)?;
-            break;
+            let trailers = consume_aws_chunked_trailers(&mut buffer)?;
+            return Ok((decoded.freeze(), trailers));
         }
 
         if buffer.len() < chunk_size + 2 {
@@ -422,8 +606,6 @@ async fn decode_aws_chunked_body(
         )?;
         decoded.extend_from_slice(&chunk);
     }
-
-    Ok(decoded.freeze())
 }
 
 fn verify_aws_chunk_signature(
@@ -443,15 +625,18 @@ fn verify_aws_chunk_signature(
         .ok_or_else(|| anyhow::anyhow!("Missing previous streaming signature"))?;
     let expected = aws_chunk_signature(v, previous, chunk);
     if !constant_time_eq_str(&expected, supplied) {
-        return Err(anyhow::anyhow!("Malformed chunk: chunk signature mismatch"));
+        return Err(AwsChunkSignatureMismatch.into());
     }
     *previous_signature = Some(supplied.to_string());
     Ok(())
 }
 
-fn consume_aws_chunked_trailers(buffer: &mut bytes::BytesMut) -> anyhow::Result<()> {
+fn consume_aws_chunked_trailers(
+    buffer: &mut bytes::BytesMut,
+) -> anyhow::Result<Vec<(String, String)>> {
     use bytes::Buf;
 
+    let mut trailers = Vec::new();
     loop {
         let line_end = buffer
             .windows(2)
@@ -464,7 +649,11 @@ fn consume_aws_chunked_trailers(buffer: &mut bytes::BytesMut) -> anyhow::Result<
                     "Malformed chunk: trailing bytes after final chunk"
                 ));
             }
-            return Ok(());
+            return Ok(trailers);
+        }
+        let line = std::str::from_utf8(&buffer[..line_end])?;
+        if let Some((name, value)) = line.split_once(':') {
+            trailers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
         }
         buffer.advance(line_end + 2);
     }
@@ -523,6 +712,12 @@ fn hmac_sha256_hex(key: &[u8], data: &[u8]) -> String {
     hex::encode(hmac_sha256(key, data))
 }
 
+struct PresignedQuery {
+    auth: ParsedAuth,
+    amz_date: String,
+    expires_secs: u64,
+}
+
 struct ParsedAuth {
     access_key_id: String,
     date: String, // YYYYMMDD
@@ -655,14 +850,22 @@ fn parse_auth_header(h: &str) -> Result<ParsedAuth, &'static str> {
     let sig = signature.ok_or("missing Signature")?.to_string();
     let sh = signed_headers.ok_or("missing SignedHeaders")?;
 
-    let mut pieces = cred.split('/');
+    parse_credential_scope(cred, sh, sig)
+}
+
+fn parse_credential_scope(
+    credential: &str,
+    signed_headers: &str,
+    signature: String,
+) -> Result<ParsedAuth, &'static str> {
+    let mut pieces = credential.split('/');
     let access_key_id = pieces.next().ok_or("bad Credential")?.to_string();
     let date = pieces.next().ok_or("bad date")?.to_string();
     let region = pieces.next().ok_or("bad region")?.to_string();
     let service = pieces.next().ok_or("bad service")?.to_string();
     // trailing aws4_request ignored
 
-    let signed_headers = sh
+    let signed_headers = signed_headers
         .split(';')
         .map(|s| s.trim().to_ascii_lowercase())
         .collect::<Vec<_>>();
@@ -673,10 +876,231 @@ fn parse_auth_header(h: &str) -> Result<ParsedAuth, &'static str> {
         region,
         service,
         signed_headers,
-        signature: sig,
+        signature,
     })
 }
 
+// Parse presigned URL parameters. Returns None when the query carries no
+// X-Amz-Algorithm, i.e. the request is not presigned.
+fn parse_presigned_query(query: &str) -> Result<Option<PresignedQuery>, &'static str> {
+    let params = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (
+                crate::s3_gateway::percent_decode_query_component(name),
+                crate::s3_gateway::percent_decode_query_component(value),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+    let Some(algorithm) = params.get("X-Amz-Algorithm") else {
+        return Ok(None);
+    };
+    if algorithm != "AWS4-HMAC-SHA256" {
+        return Err("unsupported X-Amz-Algorithm");
+    }
+    let credential = params
+        .get("X-Amz-Credential")
+        .ok_or("missing X-Amz-Credential")?;
+    let signed_headers = params
+        .get("X-Amz-SignedHeaders")
+        .ok_or("missing X-Amz-SignedHeaders")?;
+    let signature = params
+        .get("X-Amz-Signature")
+        .ok_or("missing X-Amz-Signature")?;
+    let amz_date = params.get("X-Amz-Date").ok_or("missing X-Amz-Date")?;
+    let expires_secs = params
+        .get("X-Amz-Expires")
+        .ok_or("missing X-Amz-Expires")?
+        .parse::<u64>()
+        .map_err(|_| "invalid X-Amz-Expires")?;
+    if expires_secs == 0 || expires_secs > SIGV4_PRESIGNED_MAX_EXPIRES_SECS {
+        return Err("X-Amz-Expires must be between 1 and 604800 seconds");
+    }
+
+    Ok(Some(PresignedQuery {
+        auth: parse_credential_scope(credential, signed_headers, signature.clone())?,
+        amz_date: amz_date.clone(),
+        expires_secs,
+    }))
+}
+
+// Drop the presigned signature parameters, keeping every other pair exactly
+// as the client encoded it.
+fn presigned_unsigned_query(query: &str) -> String {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter(|pair| {
+            let name = pair.split_once('=').map_or(*pair, |(name, _)| name);
+            !SIGV4_PRESIGNED_AUTH_PARAMS.contains(&name)
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn header_signature(
+    parsed: &ParsedAuth,
+    secret: &str,
+    signing_time: SystemTime,
+    method: &str,
+    absolute_url: &str,
+    signed_headers: &[(&str, &str)],
+    payload_hash: &str,
+) -> Result<String, String> {
+    let identity: Identity =
+        Credentials::new(&parsed.access_key_id, secret, None, None, "sigv4-verify").into();
+
+    let mut settings = SigningSettings::default();
+    settings.signature_location = SignatureLocation::Headers;
+    settings.percent_encoding_mode = PercentEncodingMode::Single;
+    settings.uri_path_normalization_mode = UriPathNormalizationMode::Disabled;
+    settings.payload_checksum_kind = aws_sigv4::http_request::PayloadChecksumKind::XAmzSha256;
+    settings.expires_in = None;
+    settings.excluded_headers = Some(vec![Cow::Borrowed("authorization")]);
+
+    let signing_params: SigningParams = v4::SigningParams::builder()
+        .identity(&identity)
+        .region(&parsed.region)
+        .name(&parsed.service)
+        .time(signing_time)
+        .settings(settings)
+        .build()
+        .map_err(|e| e.to_string())?
+        .into();
+
+    let signable_req = SignableRequest::new(
+        method,
+        absolute_url,
+        signed_headers.iter().copied(),
+        SignableBody::Precomputed(payload_hash.to_string()),
+    )
+    .map_err(|e| e.to_string())?;
+    let (_instr, signature) = sign(signable_req, &signing_params)
+        .map_err(|e| e.to_string())?
+        .into_parts();
+    Ok(signature)
+}
+
+fn presigned_signature(
+    secret: &str,
+    presigned: &PresignedQuery,
+    signing_time: SystemTime,
+    method: &str,
+    absolute_url: &str,
+    signed_headers: &[(&str, &str)],
+) -> Result<String, String> {
+    let parsed = &presigned.auth;
+    let identity: Identity =
+        Credentials::new(&parsed.access_key_id, secret, None, None, "sigv4-verify").into();
+
+    let signing_params: SigningParams = v4::SigningParams::builder()
+        .identity(&identity)
+        .region(&parsed.region)
+        .name(&parsed.service)
+        .time(signing_time)
+        .settings(presigned_signing_settings(presigned.expires_secs))
+        .build()
+        .map_err(|e| e.to_string())?
+        .into();
+
+    // Presigned URLs cannot carry a payload hash, so the client signs UNSIGNED-PAYLOAD.
+    let signable_req = SignableRequest::new(
+        method,
+        absolute_url,
+        signed_headers.iter().copied(),
+        SignableBody::UnsignedPayload,
+    )
+    .map_err(|e| e.to_string())?;
+    let (_instr, signature) = sign(signable_req, &signing_params)
+        .map_err(|e| e.to_string())?
+        .into_parts();
+    Ok(signature)
+}
+
+fn presigned_signing_settings(expires_secs: u64) -> SigningSettings {
+    let mut settings = SigningSettings::default();
+    settings.signature_location = SignatureLocation::QueryParams;
+    settings.percent_encoding_mode = PercentEncodingMode::Single;
+    settings.uri_path_normalization_mode = UriPathNormalizationMode::Disabled;
+    settings.expires_in = Some(Duration::from_secs(expires_secs));
+    settings
+}
+
+/// Presigns `method` on the path-style `url` so that it passes
+/// [`sigv4_auth`] until `expires_in` after `signing_time`. Only `host` is
+/// signed and the payload is `UNSIGNED-PAYLOAD`, so any HTTP client can use
+/// the returned URL as is.
+pub fn presign_url(
+    access_key_id: &str,
+    secret: &str,
+    region: &str,
+    method: &str,
+    url: &str,
+    expires_in: Duration,
+    signing_time: SystemTime,
+) -> Result<String, String> {
+    let expires_secs = expires_in.as_secs();
+    if expires_secs == 0 || expires_secs > SIGV4_PRESIGNED_MAX_EXPIRES_SECS {
+        return Err(format!(
+            "presigned URLs must expire within 1 to {SIGV4_PRESIGNED_MAX_EXPIRES_SECS} seconds"
+        ));
+    }
+    let mut request = http::Request::builder()
+        .method(method)
+        .uri(url)
+        .body(())
+        .map_err(|e| e.to_string())?;
+    let host = request
+        .uri()
+        .authority()
+        .ok_or("presigned URL needs a host")?
+        .to_string();
+
+    let identity: Identity =
+        Credentials::new(access_key_id, secret, None, None, "sigv4-presign").into();
+    let signing_params: SigningParams = v4::SigningParams::builder()
+        .identity(&identity)
+        .region(region)
+        .name("s3")
+        .time(signing_time)
+        .settings(presigned_signing_settings(expires_secs))
+        .build()
+        .map_err(|e| e.to_string())?
+        .into();
+    let signable_req = SignableRequest::new(
+        method,
+        url,
+        [("host", host.as_str())].into_iter(),
+        SignableBody::UnsignedPayload,
+    )
+    .map_err(|e| e.to_string())?;
+    let (instructions, _signature) = sign(signable_req, &signing_params)
+        .map_err(|e| e.to_string())?
+        .into_parts();
+    instructions.apply_to_request_http1x(&mut request);
+    Ok(request.uri().to_string())
+}
+
+fn presigned_url_is_live(
+    signing_time: SystemTime,
+    expires_secs: u64,
+    now: SystemTime,
+    allowed_skew: Duration,
+) -> bool {
+    if signing_time
+        .duration_since(now)
+        .is_ok_and(|ahead| ahead > allowed_skew)
+    {
+        return false;
+    }
+    match now.duration_since(signing_time) {
+        Ok(elapsed) => elapsed <= Duration::from_secs(expires_secs),
+        Err(_) => true,
+    }
+}
+
 // Parse "YYYYMMDDTHHMMSSZ" into SystemTime
 fn parse_x_amz_date(s: &str) -> Option<SystemTime> {
     if s.len() != 16 || !s.ends_with('Z') || !s.contains('T') {
@@ -893,7 +1317,7 @@ mod tests {
         let verification = test_verification();
         let body = signed_chunked_body(&[b"hello", b" world"], &verification);
 
-        let decoded = decode_aws_chunked_body(Body::from(body), Some(&verification))
+        let (decoded, _) = decode_aws_chunked_body(Body::from(body), Some(&verification))
             .await
             .expect("signed chunk chain should verify");
 
@@ -919,6 +1343,36 @@ mod tests {
             error.to_string().contains("chunk signature mismatch"),
             "unexpected error: {error}"
         );
+        assert!(error.is::<AwsChunkSignatureMismatch>());
+    }
+
+    #[tokio::test]
+    async fn aws_chunked_errors_render_s3_error_documents() {
+        let verification = test_verification();
+        let mut body = signed_chunked_body(&[b"hello"], &verification);
+        let last = body.len() - 5;
+        body[last] ^= 1;
+        let mismatch = decode_aws_chunked_body(Body::from(body), Some(&verification))
+            .await
+            .expect_err("tampered signature must not verify");
+        let response = aws_chunked_error_response(&mismatch);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let xml = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let xml = String::from_utf8(xml.to_vec()).unwrap();
+        assert!(xml.contains("<Code>SignatureDoesNotMatch</Code>"), "{xml}");
+        assert!(xml.contains("<RequestId>"), "{xml}");
+
+        let malformed = decode_aws_chunked_body(Body::from(b"zz\r\n".to_vec()), None)
+            .await
+            .expect_err("malformed chunk size must fail");
+        let response = aws_chunked_error_response(&malformed);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let xml = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(String::from_utf8_lossy(&xml).contains("<Code>InvalidRequest</Code>"));
     }
 
     #[tokio::test]
@@ -940,13 +1394,28 @@ mod tests {
     async fn aws_chunked_decoder_decodes_unsigned_streams_when_verification_is_absent() {
         let body = b"5\r\nhello\r\n6\r\n world\r\n0\r\n\r\n".to_vec();
 
-        let decoded = decode_aws_chunked_body(Body::from(body), None)
+        let (decoded, _) = decode_aws_chunked_body(Body::from(body), None)
             .await
             .expect("unsigned stream should decode when verification is absent");
 
         assert_eq!(decoded.as_ref(), b"hello world");
     }
 
+    #[tokio::test]
+    async fn aws_chunked_decoder_returns_trailing_checksums() {
+        let body = b"5\r\nhello\r\n0\r\nX-Amz-Checksum-CRC32:NhCmhg==\r\n\r\n".to_vec();
+
+        let (decoded, trailers) = decode_aws_chunked_body(Body::from(body), None)
+            .await
+            .expect("trailers should decode");
+
+        assert_eq!(decoded.as_ref(), b"hello");
+        assert_eq!(
+            trailers,
+            [("x-amz-checksum-crc32".to_string(), "NhCmhg==".to_string())]
+        );
+    }
+
     #[test]
     fn sigv4_timestamp_freshness_accepts_only_allowed_clock_skew() {
         let now = UNIX_EPOCH + Duration::from_secs(1_000_000);
@@ -971,4 +1440,136 @@ mod tests {
             SIGV4_MAX_CLOCK_SKEW
         ));
     }
+
+    #[test]
+    fn presigned_query_signature_round_trips_and_rejects_tampering() {
+        let signing_time = parse_x_amz_date("20260629T120000Z").unwrap();
+        let identity: Identity =
+            Credentials::new("AKIDPRESIGN", "test-secret", None, None, "test").into();
+        let mut settings = SigningSettings::default();
+        settings.signature_location = SignatureLocation::QueryParams;
+        settings.percent_encoding_mode = PercentEncodingMode::Single;
+        settings.uri_path_normalization_mode = UriPathNormalizationMode::Disabled;
+        settings.expires_in = Some(Duration::from_secs(900));
+        let signing_params: SigningParams = v4::SigningParams::builder()
+            .identity(&identity)
+            .region("test-region-1")
+            .name("s3")
+            .time(signing_time)
+            .settings(settings)
+            .build()
+            .unwrap()
+            .into();
+        let url = "https://s3.example.test/bucket/models/weights.bin?versionId=abc";
+        let host = [("host", "s3.example.test")];
+        let signable = SignableRequest::new(
+            "GET",
+            url,
+            host.iter().copied(),
+            SignableBody::UnsignedPayload,
+        )
+        .unwrap();
+        let (instructions, _) = sign(signable, &signing_params).unwrap().into_parts();
+        let mut request = http::Request::builder().uri(url).body(()).unwrap();
+        instructions.apply_to_request_http1x(&mut request);
+        let query = request.uri().query().unwrap().to_string();
+
+        let presigned = parse_presigned_query(&query).unwrap().unwrap();
+        assert_eq!(presigned.auth.access_key_id, "AKIDPRESIGN");
+        assert_eq!(presigned.expires_secs, 900);
+        assert_eq!(presigned_unsigned_query(&query), "versionId=abc");
+
+        let verify = |secret: &str, url: &str| {
+            presigned_signature(secret, &presigned, signing_time, "GET", url, &host).unwrap()
+        };
+        assert_eq!(verify("test-secret", url), presigned.auth.signature);
+        assert_ne!(
+            verify(
+                "test-secret",
+                "https://s3.example.test/bucket/models/other.bin?versionId=abc"
+            ),
+            presigned.auth.signature
+        );
+        assert_ne!(verify("wrong-secret", url), presigned.auth.signature);
+    }
+
+    #[test]
+    fn presign_url_produces_urls_the_verifier_accepts() {
+        let signing_time = parse_x_amz_date("20260629T120000Z").unwrap();
+        let url = "http://127.0.0.1:50051/bucket/models/weights%20v2.bin";
+        let presigned_url = presign_url(
+            "AKIDPRESIGN",
+            "test-secret",
+            "us-east-1",
+            "PUT",
+            url,
+            Duration::from_secs(3600),
+            signing_time,
+        )
+        .unwrap();
+
+        let (unsigned_url, query) = presigned_url.split_once('?').unwrap();
+        assert_eq!(unsigned_url, url);
+        let presigned = parse_presigned_query(query).unwrap().unwrap();
+        assert_eq!(presigned.auth.access_key_id, "AKIDPRESIGN");
+        assert_eq!(presigned.auth.signed_headers, ["host"]);
+        assert_eq!(presigned.expires_secs, 3600);
+        assert_eq!(presigned_unsigned_query(query), "");
+
+        let host = [("host", "127.0.0.1:50051")];
+        let verify = |method: &str| {
+            presigned_signature("test-secret", &presigned, signing_time, method, url, &host)
+                .unwrap()
+        };
+        assert_eq!(verify("PUT"), presigned.auth.signature);
+        assert_ne!(verify("GET"), presigned.auth.signature);
+
+        for expires_in in [Duration::ZERO, Duration::from_secs(7 * 24 * 60 * 60 + 1)] {
+            assert!(
+                presign_url(
+                    "AKID",
+                    "secret",
+                    "us-east-1",
+                    "GET",
+                    url,
+                    expires_in,
+                    signing_time
+                )
+                .is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn presigned_urls_are_only_live_within_their_expiry_window() {
+        let signed = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        assert!(presigned_url_is_live(
+            signed,
+            900,
+            signed + Duration::from_secs(899),
+            SIGV4_MAX_CLOCK_SKEW
+        ));
+        assert!(!presigned_url_is_live(
+            signed,
+            900,
+            signed + Duration::from_secs(901),
+            SIGV4_MAX_CLOCK_SKEW
+        ));
+        assert!(!presigned_url_is_live(
+            signed + Duration::from_secs(16 * 60),
+            900,
+            signed,
+            SIGV4_MAX_CLOCK_SKEW
+        ));
+
+        assert!(parse_presigned_query("list-type=2").unwrap().is_none());
+        assert!(
+            parse_presigned_query(
+                "X-Amz-Algorithm=AWS4-HMAC-SHA256&X-Amz-Credential=AKID%2F20260629%2Fr%2Fs3%2Faws4_request\
+                 &X-Amz-Date=20260629T120000Z&X-Amz-Expires=604801&X-Amz-SignedHeaders=host\
+                 &X-Amz-Signature=abc"
+            )
+            .is_err()
+        );
+    }
 }
@@ -1,21 +1,22 @@
 use crate::AppState;
 use crate::auth::Claims;
-use crate::s3_auth::{aws_chunked_decoder, sigv4_auth};
+use crate::s3_auth::{aws_chunked_decoder, sigv4_auth, tenant_rate_limit};
 use anvil_core::anvil_api::internal_proxy_service_client::InternalProxyServiceClient;
 use anvil_core::anvil_api::{
     ProxyHeader, ProxyRequestChunk, ProxyRequestHeader, ProxyResponseHeader, proxy_request_chunk,
     proxy_response_chunk,
 };
 use anvil_core::bucket_journal;
+use anvil_core::core_store::StorageClass;
 use anvil_core::mesh_directory::{BucketLocatorStatus, TenantNameStatus};
-use anvil_core::mesh_lifecycle::{LifecycleState, NodeCapability};
 use anvil_core::object_links;
 use anvil_core::object_manager::{
-    ObjectLinkReadMode, ObjectReadConsistency, ObjectWriteOptions, ObjectWriteVisibility,
+    CopyObjectMetadata, ObjectConditions, ObjectLinkReadMode, ObjectReadConsistency,
+    ObjectWriteOptions, ObjectWriteVisibility, UploadPartOptions, etag_condition_matches,
 };
 use anvil_core::observability::RESERVED_NAMESPACE_REJECTION_COUNT;
 use anvil_core::permissions::AnvilAction;
-use anvil_core::persistence::Object;
+use anvil_core::persistence::{Object, ObjectRetention, Tenant};
 use anvil_core::routing::{
     self as core_routing, CrossRegionRoutingPolicy, HostAliasDescriptor, ObjectRoute, RouteRequest,
     RouteSource, RoutingConfig, RoutingError,
@@ -62,9 +63,13 @@ use proxy::*;
 use routing::*;
 #[allow(unused_imports)]
 use util::*;
+pub(crate) use util::{percent_decode_query_component, s3_error};
 
 pub fn app(state: AppState) -> Router {
+    // `_anvil` is not a valid bucket name, so the probes never shadow a bucket.
     let public = Router::new()
+        .route("/_anvil/healthz", get(liveness_check))
+        .route("/_anvil/readyz", get(readiness_check))
         .route("/ready", get(readiness_check))
         .with_state(state.clone());
 
@@ -103,6 +108,10 @@ pub fn app(state: AppState) -> Router {
             state.clone(),
             s3_host_routing,
         ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            tenant_rate_limit,
+        ))
         .layer(middleware::from_fn(aws_chunked_decoder))
         .layer(middleware::from_fn_with_state(state.clone(), sigv4_auth))
         .layer(middleware::from_fn_with_state(
@@ -110,7 +119,9 @@ pub fn app(state: AppState) -> Router {
             reserved_namespace_guard,
         ));
 
-    public.merge(s3_routes)
+    public
+        .merge(s3_routes)
+        .layer(middleware::from_fn(anvil_core::middleware::request_id_mw))
 }
 
 #[cfg(test)]
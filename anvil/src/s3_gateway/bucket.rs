@@ -1,4 +1,5 @@
 use super::*;
+use base64::{Engine as _, engine::general_purpose::STANDARD};
 
 #[derive(Deserialize)]
 pub(super) struct CreateBucketConfiguration {
@@ -12,6 +13,9 @@ pub(super) struct BucketVersioningConfigurationXml {
     status: Option<String>,
 }
 
+/// S3 rejects DeleteObjects requests that name more than 1000 keys.
+pub(super) const S3_DELETE_OBJECTS_MAX_KEYS: usize = 1000;
+
 #[derive(Debug, Deserialize)]
 pub(super) struct DeleteObjectsXml {
     #[serde(rename = "Object", default)]
@@ -54,17 +58,14 @@ pub(super) async fn list_buckets(State(state): State<AppState>, req: Request) ->
 
     match state.bucket_manager.list_buckets(&claims).await {
         Ok(buckets) => {
+            let owner = match s3_owner_tenant(&state, claims.tenant_id).await {
+                Ok(owner) => owner,
+                Err(response) => return response,
+            };
             let mut xml = String::from(
                 "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<ListAllMyBucketsResult xmlns=\"http://s3.amazonaws.com/doc/2006-03-01/\">\n",
             );
-            xml.push_str("  <Owner>\n");
-            xml.push_str(&format!("    <ID>{}</ID>\n", claims.tenant_id));
-            // S3 requires DisplayName; Anvil's tenant identifier is the stable owner label.
-            xml.push_str(&format!(
-                "    <DisplayName>{}</DisplayName>\n",
-                claims.tenant_id
-            ));
-            xml.push_str("  </Owner>\n");
+            append_owner_xml(&mut xml, "  ", &owner);
             xml.push_str("  <Buckets>\n");
             for b in buckets {
                 xml.push_str("    <Bucket>\n");
@@ -165,6 +166,11 @@ pub(super) async fn create_bucket(
                 status.message(),
                 axum::http::StatusCode::BAD_REQUEST,
             ),
+            tonic::Code::ResourceExhausted => s3_error(
+                "TooManyBuckets",
+                status.message(),
+                axum::http::StatusCode::BAD_REQUEST,
+            ),
             _ => s3_error(
                 "InternalError",
                 status.message(),
@@ -375,9 +381,36 @@ pub(super) async fn head_bucket(
     }
     bucket_name = s3_routed_bucket(&req, bucket_name);
 
-    let claims = match req.extensions().get::<Claims>().cloned() {
-        Some(c) => c,
-        None => {
+    let checked_route = match s3_checked_route(
+        &state,
+        s3_host_route(&req),
+        req.extensions().get::<Claims>().cloned(),
+    )
+    .await
+    {
+        Ok(checked_route) => checked_route,
+        Err(response) => return response,
+    };
+
+    // Anonymous HEAD is only answered for tenant-routed public buckets.
+    let (tenant_id, authenticated) = match (checked_route.claims, checked_route.tenant_id) {
+        (Some(claims), _) => {
+            match s3_remote_bucket_response_for_authorized_claims(
+                &state,
+                &claims,
+                &bucket_name,
+                AnvilAction::BucketRead,
+            )
+            .await
+            {
+                Ok(Some(response)) => return response,
+                Ok(None) => {}
+                Err(response) => return response,
+            }
+            (claims.tenant_id, true)
+        }
+        (None, Some(route_tenant_id)) => (route_tenant_id, false),
+        (None, None) => {
             return s3_error(
                 "AccessDenied",
                 "Missing credentials for HEAD request",
@@ -385,30 +418,16 @@ pub(super) async fn head_bucket(
             );
         }
     };
-    let checked_route = match s3_checked_route(&state, s3_host_route(&req), Some(claims)).await {
-        Ok(checked_route) => checked_route,
-        Err(response) => return response,
-    };
-    let claims = checked_route
-        .claims
-        .expect("authenticated head bucket path supplied claims");
 
-    match s3_remote_bucket_response_for_authorized_claims(
-        &state,
-        &claims,
-        &bucket_name,
-        AnvilAction::BucketRead,
-    )
-    .await
-    {
-        Ok(Some(response)) => return response,
-        Ok(None) => {}
-        Err(response) => return response,
-    }
-
-    match bucket_journal::read_current_bucket(&state.storage, claims.tenant_id, &bucket_name).await
-    {
+    match bucket_journal::read_current_bucket(&state.storage, tenant_id, &bucket_name).await {
         Ok(Some(bucket)) => {
+            if !authenticated && !bucket.is_public_read {
+                return s3_error(
+                    "AccessDenied",
+                    "Access Denied",
+                    axum::http::StatusCode::FORBIDDEN,
+                );
+            }
             if bucket.region != state.region {
                 return s3_remote_bucket_response(
                     state.config.cross_region_routing_policy,
@@ -509,32 +528,13 @@ pub(super) async fn list_objects(
         return get_bucket_location_response(state, claims, &bucket).await;
     }
 
-    let is_list_v2 = q
-        .get("list-type")
-        .or_else(|| q.get("listType"))
-        .is_some_and(|value| value == "2");
-    let prefix = q.get("prefix").cloned().unwrap_or_default();
-    let continuation_token = q
-        .get("continuation-token")
-        .or_else(|| q.get("continuationToken"))
-        .cloned();
-    let marker = q.get("marker").cloned().unwrap_or_default();
-    let start_after = if is_list_v2 {
-        continuation_token.clone().unwrap_or_else(|| {
-            q.get("start-after")
-                .or_else(|| q.get("startAfter"))
-                .cloned()
-                .unwrap_or_default()
-        })
-    } else {
-        marker.clone()
+    let list_query = match ListObjectsQuery::parse(&q) {
+        Ok(list_query) => list_query,
+        Err(response) => return response,
     };
-    let delimiter = q.get("delimiter").cloned().unwrap_or_default();
-    let max_keys: i32 = q
-        .get("max-keys")
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(1000);
-    let fetch_limit = max_keys.saturating_add(1);
+    let fetch_limit = i32::try_from(list_query.max_keys)
+        .unwrap_or(i32::MAX)
+        .saturating_add(1);
 
     match state
         .object_manager
@@ -542,69 +542,35 @@ pub(super) async fn list_objects(
             claims,
             checked_route.tenant_id,
             &bucket,
-            &prefix,
-            &start_after,
+            &list_query.prefix,
+            &list_query.resume_after,
             fetch_limit,
-            &delimiter,
+            &list_query.delimiter,
             ObjectReadConsistency::Latest,
         )
         .await
     {
         Ok((objects, common_prefixes)) => {
-            let requested_max_keys = if max_keys <= 0 {
-                1000
-            } else {
-                max_keys as usize
+            // Every listed object belongs to the bucket's owning tenant.
+            let owner = match objects.first() {
+                Some(object) if list_query.fetch_owner() => {
+                    match s3_owner_tenant(&state, object.tenant_id).await {
+                        Ok(owner) => Some(owner),
+                        Err(response) => return response,
+                    }
+                }
+                _ => None,
             };
             let (entries, is_truncated, next_marker) =
-                paginate_list_bucket_entries(objects, common_prefixes, requested_max_keys);
-            let key_count = entries.len() as i32;
-            let mut xml = String::from(
-                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<ListBucketResult xmlns=\"http://s3.amazonaws.com/doc/2006-03-01/\">
-",
+                paginate_list_bucket_entries(objects, common_prefixes, list_query.max_keys);
+            let xml = render_list_bucket_result(
+                &bucket,
+                &list_query,
+                entries,
+                is_truncated,
+                next_marker,
+                owner.as_ref(),
             );
-            xml.push_str(&format!("  <Name>{}</Name>\n", &*bucket));
-            xml.push_str(&format!("  <Prefix>{}</Prefix>\n", xml_escape(&prefix)));
-            if is_list_v2 {
-                if let Some(token) = continuation_token {
-                    xml.push_str(&format!(
-                        "  <ContinuationToken>{}</ContinuationToken>\n",
-                        xml_escape(&token)
-                    ));
-                }
-                xml.push_str(&format!("  <KeyCount>{}</KeyCount>\n", key_count));
-            } else {
-                xml.push_str(&format!("  <Marker>{}</Marker>\n", xml_escape(&marker)));
-            }
-            if !delimiter.is_empty() {
-                xml.push_str(&format!(
-                    "  <Delimiter>{}</Delimiter>\n",
-                    xml_escape(&delimiter)
-                ));
-            }
-            xml.push_str(&format!("  <MaxKeys>{}</MaxKeys>\n", max_keys));
-            xml.push_str(&format!(
-                "  <IsTruncated>{}</IsTruncated>\n",
-                if is_truncated { "true" } else { "false" }
-            ));
-            if let Some(token) = next_marker {
-                if is_list_v2 {
-                    xml.push_str(&format!(
-                        "  <NextContinuationToken>{}</NextContinuationToken>\n",
-                        xml_escape(&token)
-                    ));
-                } else {
-                    xml.push_str(&format!(
-                        "  <NextMarker>{}</NextMarker>\n",
-                        xml_escape(&token)
-                    ));
-                }
-            }
-            for entry in entries {
-                append_list_bucket_entry_xml(&mut xml, entry);
-            }
-            xml.push_str("</ListBucketResult>\n");
-
             Response::builder()
                 .status(200)
                 .header("Content-Type", "application/xml")
@@ -730,6 +696,14 @@ pub(super) async fn delete_objects(
         }
     };
 
+    if request.objects.len() > S3_DELETE_OBJECTS_MAX_KEYS {
+        return s3_error(
+            "MalformedXML",
+            &format!("DeleteObjects accepts at most {S3_DELETE_OBJECTS_MAX_KEYS} keys"),
+            axum::http::StatusCode::BAD_REQUEST,
+        );
+    }
+
     let quiet = request.quiet.unwrap_or(false);
     let mut deleted = Vec::new();
     let mut errors = Vec::new();
@@ -1176,7 +1150,10 @@ pub(super) async fn list_object_versions_response(
                 if !version.is_delete_marker {
                     xml.push_str(&format!("    <ETag>\"{}\"</ETag>\n", object.etag));
                     xml.push_str(&format!("    <Size>{}</Size>\n", object.size));
-                    xml.push_str("    <StorageClass>STANDARD</StorageClass>\n");
+                    xml.push_str(&format!(
+                        "    <StorageClass>{}</StorageClass>\n",
+                        s3_storage_class_name(&object)
+                    ));
                 }
                 xml.push_str(&format!("  </{}>\n", tag));
             }
@@ -1197,6 +1174,164 @@ pub(super) async fn list_object_versions_response(
     }
 }
 
+/// Largest page a ListObjects request may ask for, as in S3.
+const MAX_LIST_KEYS: usize = 1000;
+
+/// Parameters of a bucket GET that lists objects. `list-type=2` selects
+/// ListObjectsV2; anything else is answered as the original ListObjects.
+pub(super) struct ListObjectsQuery {
+    pub(super) prefix: String,
+    pub(super) delimiter: String,
+    pub(super) max_keys: usize,
+    /// Key the listing resumes after, taken from whichever of the marker,
+    /// continuation token, or `start-after` the version honours.
+    pub(super) resume_after: String,
+    pub(super) version: ListObjectsVersion,
+}
+
+pub(super) enum ListObjectsVersion {
+    V1 {
+        marker: String,
+    },
+    V2 {
+        continuation_token: Option<String>,
+        start_after: Option<String>,
+        fetch_owner: bool,
+    },
+}
+
+impl ListObjectsQuery {
+    pub(super) fn parse(q: &HashMap<String, String>) -> Result<Self, Response> {
+        let param = |name: &str, alias: &str| q.get(name).or_else(|| q.get(alias)).cloned();
+        let max_keys = match q.get("max-keys").or_else(|| q.get("maxKeys")) {
+            None => MAX_LIST_KEYS,
+            Some(value) => match value.parse::<usize>() {
+                Ok(max_keys) => max_keys.min(MAX_LIST_KEYS),
+                Err(_) => {
+                    return Err(s3_error(
+                        "InvalidArgument",
+                        "max-keys must be a non-negative integer",
+                        axum::http::StatusCode::BAD_REQUEST,
+                    ));
+                }
+            },
+        };
+        let is_v2 = param("list-type", "listType").is_some_and(|value| value == "2");
+        let (resume_after, version) = if is_v2 {
+            let continuation_token = param("continuation-token", "continuationToken");
+            let start_after = param("start-after", "startAfter");
+            // A continuation token takes precedence: it already lies past
+            // `start-after` when both are sent.
+            let resume_after = match continuation_token.as_deref() {
+                Some(token) => decode_list_continuation_token(token).ok_or_else(|| {
+                    s3_error(
+                        "InvalidArgument",
+                        "The continuation token provided is incorrect",
+                        axum::http::StatusCode::BAD_REQUEST,
+                    )
+                })?,
+                None => start_after.clone().unwrap_or_default(),
+            };
+            let fetch_owner = q.get("fetch-owner").is_some_and(|value| value == "true");
+            (
+                resume_after,
+                ListObjectsVersion::V2 {
+                    continuation_token,
+                    start_after,
+                    fetch_owner,
+                },
+            )
+        } else {
+            let marker = q.get("marker").cloned().unwrap_or_default();
+            (marker.clone(), ListObjectsVersion::V1 { marker })
+        };
+        Ok(Self {
+            prefix: q.get("prefix").cloned().unwrap_or_default(),
+            delimiter: q.get("delimiter").cloned().unwrap_or_default(),
+            max_keys,
+            resume_after,
+            version,
+        })
+    }
+
+    /// ListObjects always reports owners; V2 only when asked with fetch-owner.
+    pub(super) fn fetch_owner(&self) -> bool {
+        match self.version {
+            ListObjectsVersion::V1 { .. } => true,
+            ListObjectsVersion::V2 { fetch_owner, .. } => fetch_owner,
+        }
+    }
+}
+
+/// Renders the `ListBucketResult` document in the shape of the version the
+/// request asked for.
+pub(super) fn render_list_bucket_result(
+    bucket: &str,
+    query: &ListObjectsQuery,
+    entries: Vec<ListBucketEntry>,
+    is_truncated: bool,
+    next_marker: Option<String>,
+    owner: Option<&Tenant>,
+) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<ListBucketResult xmlns=\"http://s3.amazonaws.com/doc/2006-03-01/\">\n",
+    );
+    xml.push_str(&format!("  <Name>{}</Name>\n", xml_escape(bucket)));
+    xml.push_str(&format!(
+        "  <Prefix>{}</Prefix>\n",
+        xml_escape(&query.prefix)
+    ));
+    match &query.version {
+        ListObjectsVersion::V1 { marker } => {
+            xml.push_str(&format!("  <Marker>{}</Marker>\n", xml_escape(marker)));
+        }
+        ListObjectsVersion::V2 {
+            continuation_token,
+            start_after,
+            ..
+        } => {
+            if let Some(token) = continuation_token {
+                xml.push_str(&format!(
+                    "  <ContinuationToken>{}</ContinuationToken>\n",
+                    xml_escape(token)
+                ));
+            }
+            if let Some(start_after) = start_after {
+                xml.push_str(&format!(
+                    "  <StartAfter>{}</StartAfter>\n",
+                    xml_escape(start_after)
+                ));
+            }
+            xml.push_str(&format!("  <KeyCount>{}</KeyCount>\n", entries.len()));
+        }
+    }
+    if !query.delimiter.is_empty() {
+        xml.push_str(&format!(
+            "  <Delimiter>{}</Delimiter>\n",
+            xml_escape(&query.delimiter)
+        ));
+    }
+    xml.push_str(&format!("  <MaxKeys>{}</MaxKeys>\n", query.max_keys));
+    xml.push_str(&format!("  <IsTruncated>{is_truncated}</IsTruncated>\n"));
+    if let Some(marker) = next_marker {
+        match query.version {
+            ListObjectsVersion::V1 { .. } => xml.push_str(&format!(
+                "  <NextMarker>{}</NextMarker>\n",
+                xml_escape(&marker)
+            )),
+            ListObjectsVersion::V2 { .. } => xml.push_str(&format!(
+                "  <NextContinuationToken>{}</NextContinuationToken>\n",
+                xml_escape(&encode_list_continuation_token(&marker))
+            )),
+        }
+    }
+    for entry in entries {
+        append_list_bucket_entry_xml(&mut xml, entry, owner);
+    }
+    xml.push_str("</ListBucketResult>\n");
+    xml
+}
+
 pub(super) enum ListBucketEntry {
     Object(Object),
     Prefix(String),
@@ -1207,6 +1342,10 @@ pub(super) fn paginate_list_bucket_entries(
     common_prefixes: Vec<String>,
     requested_max_keys: usize,
 ) -> (Vec<ListBucketEntry>, bool, Option<String>) {
+    // S3 answers `max-keys=0` with an empty page that is not truncated.
+    if requested_max_keys == 0 {
+        return (Vec::new(), false, None);
+    }
     let mut entries = objects
         .into_iter()
         .map(ListBucketEntry::Object)
@@ -1229,6 +1368,17 @@ pub(super) fn paginate_list_bucket_entries(
     (entries, is_truncated, next_continuation_token)
 }
 
+/// ListObjectsV2 continuation tokens are opaque to clients; Anvil encodes the
+/// last key of the previous page so the next request can resume after it.
+pub(super) fn encode_list_continuation_token(last_key: &str) -> String {
+    STANDARD.encode(last_key.as_bytes())
+}
+
+pub(super) fn decode_list_continuation_token(token: &str) -> Option<String> {
+    let bytes = STANDARD.decode(token).ok()?;
+    String::from_utf8(bytes).ok()
+}
+
 impl ListBucketEntry {
     fn marker(&self) -> &str {
         match self {
@@ -1245,7 +1395,39 @@ impl ListBucketEntry {
     }
 }
 
-pub(super) fn append_list_bucket_entry_xml(xml: &mut String, entry: ListBucketEntry) {
+/// Looks up the tenant reported as an S3 `<Owner>`: its identifier is the
+/// owner ID and its name the DisplayName.
+pub(super) async fn s3_owner_tenant(state: &AppState, tenant_id: i64) -> Result<Tenant, Response> {
+    match state.persistence.get_tenant_by_id(tenant_id).await {
+        Ok(Some(tenant)) => Ok(tenant),
+        Ok(None) => Err(s3_error(
+            "InternalError",
+            &format!("owner tenant {tenant_id} not found"),
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+        )),
+        Err(err) => Err(s3_error(
+            "InternalError",
+            &err.to_string(),
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+        )),
+    }
+}
+
+pub(super) fn append_owner_xml(xml: &mut String, indent: &str, owner: &Tenant) {
+    xml.push_str(&format!("{indent}<Owner>\n"));
+    xml.push_str(&format!("{indent}  <ID>{}</ID>\n", owner.id));
+    xml.push_str(&format!(
+        "{indent}  <DisplayName>{}</DisplayName>\n",
+        xml_escape(&owner.name)
+    ));
+    xml.push_str(&format!("{indent}</Owner>\n"));
+}
+
+pub(super) fn append_list_bucket_entry_xml(
+    xml: &mut String,
+    entry: ListBucketEntry,
+    owner: Option<&Tenant>,
+) {
     match entry {
         ListBucketEntry::Object(object) => {
             xml.push_str("  <Contents>\n");
@@ -1256,7 +1438,13 @@ pub(super) fn append_list_bucket_entry_xml(xml: &mut String, entry: ListBucketEn
             ));
             xml.push_str(&format!("    <ETag>\"{}\"</ETag>\n", object.etag));
             xml.push_str(&format!("    <Size>{}</Size>\n", object.size));
-            xml.push_str("    <StorageClass>STANDARD</StorageClass>\n");
+            if let Some(owner) = owner {
+                append_owner_xml(xml, "    ", owner);
+            }
+            xml.push_str(&format!(
+                "    <StorageClass>{}</StorageClass>\n",
+                s3_storage_class_name(&object)
+            ));
             xml.push_str("  </Contents>\n");
         }
         ListBucketEntry::Prefix(prefix) => {
@@ -1310,6 +1498,185 @@ mod list_bucket_pagination_tests {
         );
     }
 
+    #[test]
+    fn list_bucket_pagination_with_zero_max_keys_returns_an_untruncated_empty_page() {
+        let (entries, is_truncated, token) = paginate_list_bucket_entries(
+            vec![object("page/a.txt")],
+            vec!["page/b/".to_string()],
+            0,
+        );
+
+        assert!(entries.is_empty());
+        assert!(!is_truncated);
+        assert_eq!(token, None);
+    }
+
+    #[test]
+    fn list_continuation_tokens_round_trip_and_reject_garbage() {
+        let token = encode_list_continuation_token("page/a b+c.txt");
+        assert_ne!(token, "page/a b+c.txt");
+        assert_eq!(
+            decode_list_continuation_token(&token).as_deref(),
+            Some("page/a b+c.txt")
+        );
+        assert_eq!(decode_list_continuation_token("not a token!"), None);
+    }
+
+    fn list_query(params: &[(&str, &str)]) -> ListObjectsQuery {
+        let q = params
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        ListObjectsQuery::parse(&q).unwrap_or_else(|_| panic!("query should parse: {params:?}"))
+    }
+
+    fn golden_page() -> (Vec<ListBucketEntry>, bool, Option<String>) {
+        let mut objects = vec![object("logs/b.txt"), object("logs/c.txt")];
+        for object in &mut objects {
+            object.etag = "9a0364b9e99bb480dd25e1f0284c8555".to_string();
+            object.size = 7;
+            object.created_at = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        }
+        paginate_list_bucket_entries(objects, vec!["logs/old/".to_string()], 2)
+    }
+
+    #[test]
+    fn list_objects_v2_renders_the_v2_document() {
+        let query = list_query(&[
+            ("list-type", "2"),
+            ("prefix", "logs/"),
+            ("delimiter", "/"),
+            ("max-keys", "2"),
+            ("start-after", "logs/a.txt"),
+        ]);
+        assert_eq!(query.resume_after, "logs/a.txt");
+        let (entries, is_truncated, next_marker) = golden_page();
+
+        let xml =
+            render_list_bucket_result("photos", &query, entries, is_truncated, next_marker, None);
+
+        assert_eq!(
+            xml,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>photos</Name>
+  <Prefix>logs/</Prefix>
+  <StartAfter>logs/a.txt</StartAfter>
+  <KeyCount>2</KeyCount>
+  <Delimiter>/</Delimiter>
+  <MaxKeys>2</MaxKeys>
+  <IsTruncated>true</IsTruncated>
+  <NextContinuationToken>bG9ncy9jLnR4dA==</NextContinuationToken>
+  <Contents>
+    <Key>logs/b.txt</Key>
+    <LastModified>2023-11-14T22:13:20+00:00</LastModified>
+    <ETag>"9a0364b9e99bb480dd25e1f0284c8555"</ETag>
+    <Size>7</Size>
+    <StorageClass>STANDARD</StorageClass>
+  </Contents>
+  <Contents>
+    <Key>logs/c.txt</Key>
+    <LastModified>2023-11-14T22:13:20+00:00</LastModified>
+    <ETag>"9a0364b9e99bb480dd25e1f0284c8555"</ETag>
+    <Size>7</Size>
+    <StorageClass>STANDARD</StorageClass>
+  </Contents>
+</ListBucketResult>
+"#
+        );
+
+        let next = list_query(&[
+            ("list-type", "2"),
+            ("continuation-token", "bG9ncy9jLnR4dA=="),
+            ("start-after", "logs/a.txt"),
+        ]);
+        assert_eq!(next.resume_after, "logs/c.txt");
+    }
+
+    #[test]
+    fn list_objects_v1_renders_markers_and_owners() {
+        let query = list_query(&[
+            ("prefix", "logs/"),
+            ("delimiter", "/"),
+            ("max-keys", "2"),
+            ("marker", "logs/a.txt"),
+            ("start-after", "logs/z.txt"),
+        ]);
+        assert_eq!(query.resume_after, "logs/a.txt");
+        assert!(query.fetch_owner());
+        let (entries, is_truncated, next_marker) = golden_page();
+
+        let xml = render_list_bucket_result(
+            "photos",
+            &query,
+            entries,
+            is_truncated,
+            next_marker,
+            Some(&Tenant {
+                id: 42,
+                name: "acme".to_string(),
+            }),
+        );
+
+        assert_eq!(
+            xml,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>photos</Name>
+  <Prefix>logs/</Prefix>
+  <Marker>logs/a.txt</Marker>
+  <Delimiter>/</Delimiter>
+  <MaxKeys>2</MaxKeys>
+  <IsTruncated>true</IsTruncated>
+  <NextMarker>logs/c.txt</NextMarker>
+  <Contents>
+    <Key>logs/b.txt</Key>
+    <LastModified>2023-11-14T22:13:20+00:00</LastModified>
+    <ETag>"9a0364b9e99bb480dd25e1f0284c8555"</ETag>
+    <Size>7</Size>
+    <Owner>
+      <ID>42</ID>
+      <DisplayName>acme</DisplayName>
+    </Owner>
+    <StorageClass>STANDARD</StorageClass>
+  </Contents>
+  <Contents>
+    <Key>logs/c.txt</Key>
+    <LastModified>2023-11-14T22:13:20+00:00</LastModified>
+    <ETag>"9a0364b9e99bb480dd25e1f0284c8555"</ETag>
+    <Size>7</Size>
+    <Owner>
+      <ID>42</ID>
+      <DisplayName>acme</DisplayName>
+    </Owner>
+    <StorageClass>STANDARD</StorageClass>
+  </Contents>
+</ListBucketResult>
+"#
+        );
+    }
+
+    #[test]
+    fn list_query_caps_max_keys_and_rejects_bad_values() {
+        assert_eq!(list_query(&[]).max_keys, MAX_LIST_KEYS);
+        assert_eq!(list_query(&[("max-keys", "5000")]).max_keys, MAX_LIST_KEYS);
+        assert_eq!(list_query(&[("max-keys", "0")]).max_keys, 0);
+        for (name, value) in [
+            ("max-keys", "-1"),
+            ("max-keys", "lots"),
+            ("continuation-token", "not a token!"),
+        ] {
+            let q = HashMap::from([
+                ("list-type".to_string(), "2".to_string()),
+                (name.to_string(), value.to_string()),
+            ]);
+            assert!(
+                ListObjectsQuery::parse(&q).is_err(),
+                "{name}={value} should be rejected"
+            );
+        }
+    }
+
     fn object(key: &str) -> Object {
         Object {
             id: 0,
@@ -1330,25 +1697,45 @@ mod list_bucket_pagination_tests {
             created_at: Utc::now(),
             deleted_at: None,
             storage_class: None,
+            requested_storage_class: None,
             user_meta: None,
             shard_map: None,
             checksum: None,
             link: None,
+            retention: None,
         }
     }
 }
 
+/// Liveness only: the process is up and its HTTP listener answers.
+pub(super) async fn liveness_check() -> Response {
+    (axum::http::StatusCode::OK, "OK").into_response()
+}
+
 pub(super) async fn readiness_check(State(state): State<AppState>) -> Response {
-    // Cluster readiness: at least 1 peer known (self included).
+    // Metadata store: listing region descriptors is a cheap CoreStore read.
+    if let Err(err) = state.persistence.list_region_descriptors().await {
+        return not_ready_response("metadata", serde_json::json!({ "error": err.to_string() }));
+    }
+    // Cluster readiness: enough peers known (self included).
     let peers = state.cluster.read().await.len();
-    if peers >= 1 {
-        (axum::http::StatusCode::OK, "READY").into_response()
-    } else {
-        let body = serde_json::json!({"status":"not_ready","peers":peers});
-        (
-            axum::http::StatusCode::SERVICE_UNAVAILABLE,
-            axum::response::Json(body),
-        )
-            .into_response()
+    let min_peers = state.config.readiness_min_peers.max(1);
+    if peers < min_peers {
+        return not_ready_response(
+            "cluster",
+            serde_json::json!({ "peers": peers, "min_peers": min_peers }),
+        );
     }
+    (axum::http::StatusCode::OK, "READY").into_response()
+}
+
+/// The probe is unauthenticated, so the failing subsystem and its details go
+/// to the node's log rather than into the response.
+fn not_ready_response(subsystem: &str, details: serde_json::Value) -> Response {
+    tracing::warn!(subsystem, %details, "node is not ready");
+    (
+        axum::http::StatusCode::SERVICE_UNAVAILABLE,
+        axum::response::Json(serde_json::json!({ "status": "not_ready" })),
+    )
+        .into_response()
 }
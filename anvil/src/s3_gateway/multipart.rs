@@ -133,6 +133,7 @@ pub(super) async fn upload_part(
     upload_id: uuid::Uuid,
     part_number: i32,
     body_stream: impl Stream<Item = Result<Vec<u8>, tonic::Status>> + Unpin,
+    options: UploadPartOptions,
 ) -> Response {
     match state
         .object_manager
@@ -143,16 +144,19 @@ pub(super) async fn upload_part(
             upload_id,
             part_number,
             body_stream,
-            None,
-            None,
+            options,
         )
         .await
     {
-        Ok(result) => Response::builder()
-            .status(200)
-            .header("ETag", format!("\"{}\"", result.etag))
-            .body(Body::empty())
-            .unwrap(),
+        Ok(result) => {
+            let mut builder = Response::builder()
+                .status(200)
+                .header("ETag", format!("\"{}\"", result.etag));
+            if let Some(checksum) = result.checksum {
+                builder = builder.header(checksum.algorithm().header_name(), checksum.to_base64());
+            }
+            builder.body(Body::empty()).unwrap()
+        }
         Err(status) => s3_status_to_response_for_auth(
             status,
             true,
@@ -1,4 +1,8 @@
 use super::*;
+use anvil_core::object_checksum::{ChecksumAlgorithm, ObjectChecksum};
+use base64::{Engine as _, engine::general_purpose::STANDARD};
+
+const DEFAULT_OBJECT_CONTENT_TYPE: &str = "application/octet-stream";
 
 pub(super) fn s3_user_metadata(headers: &axum::http::HeaderMap) -> Option<serde_json::Value> {
     let mut values = serde_json::Map::new();
@@ -21,6 +25,258 @@ pub(super) fn s3_user_metadata(headers: &axum::http::HeaderMap) -> Option<serde_
     }
 }
 
+/// Returns the payload size the client declared for a PUT body. Streaming
+/// SigV4 uploads frame the payload, so only `x-amz-decoded-content-length`
+/// describes the object bytes for those requests.
+pub(super) fn s3_declared_content_length(headers: &axum::http::HeaderMap) -> Option<u64> {
+    let header_u64 = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+    };
+    if let Some(decoded) = header_u64("x-amz-decoded-content-length") {
+        return Some(decoded);
+    }
+    let streaming = headers
+        .get("x-amz-content-sha256")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("STREAMING-"));
+    if streaming {
+        return None;
+    }
+    header_u64("content-length")
+}
+
+/// Decodes the `Content-MD5` request header. Anything other than base64 of a
+/// 16-byte digest is rejected with `InvalidDigest`, as S3 does.
+pub(super) fn s3_content_md5(
+    headers: &axum::http::HeaderMap,
+) -> Result<Option<[u8; 16]>, Response> {
+    let Some(value) = headers.get("content-md5") else {
+        return Ok(None);
+    };
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| STANDARD.decode(value.trim()).ok())
+        .and_then(|digest| <[u8; 16]>::try_from(digest).ok())
+        .map(Some)
+        .ok_or_else(|| {
+            s3_error(
+                "InvalidDigest",
+                "The Content-MD5 you specified was invalid",
+                axum::http::StatusCode::BAD_REQUEST,
+            )
+        })
+}
+
+/// Returns the hex SHA-256 the client declared for the PUT body. Unsigned and
+/// streaming uploads send sentinel values instead of a digest, so only a
+/// 64-digit hex value is returned.
+pub(super) fn s3_declared_payload_sha256(headers: &axum::http::HeaderMap) -> Option<String> {
+    headers
+        .get("x-amz-content-sha256")
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| value.len() == 64 && value.bytes().all(|byte| byte.is_ascii_hexdigit()))
+        .map(str::to_ascii_lowercase)
+}
+
+/// Additional checksum requested for a PUT. A value in one of the
+/// `x-amz-checksum-<algorithm>` headers (or aws-chunked trailers, which the
+/// chunk decoder copies into the headers) is checked against the payload;
+/// `x-amz-checksum-algorithm` alone only asks for the checksum to be
+/// computed and stored. Malformed or conflicting values are rejected with
+/// `InvalidRequest`.
+pub(super) fn s3_requested_checksum(
+    headers: &axum::http::HeaderMap,
+) -> Result<(Option<ChecksumAlgorithm>, Option<ObjectChecksum>), Response> {
+    let invalid = |message: &str| {
+        s3_error(
+            "InvalidRequest",
+            message,
+            axum::http::StatusCode::BAD_REQUEST,
+        )
+    };
+    let mut algorithm = None;
+    for name in ["x-amz-checksum-algorithm", "x-amz-sdk-checksum-algorithm"] {
+        let Some(value) = headers.get(name) else {
+            continue;
+        };
+        let requested = value
+            .to_str()
+            .ok()
+            .and_then(ChecksumAlgorithm::from_s3)
+            .ok_or_else(|| invalid(&format!("Value for {name} header is invalid.")))?;
+        if algorithm.is_some_and(|algorithm| algorithm != requested) {
+            return Err(invalid("Conflicting checksum algorithms were requested."));
+        }
+        algorithm = Some(requested);
+    }
+    let mut declared = None;
+    for candidate in ChecksumAlgorithm::ALL {
+        let Some(value) = headers.get(candidate.header_name()) else {
+            continue;
+        };
+        if declared.is_some() {
+            return Err(invalid(
+                "Expecting a single x-amz-checksum- header. Multiple checksum Types are not allowed.",
+            ));
+        }
+        let checksum = value
+            .to_str()
+            .ok()
+            .and_then(|value| ObjectChecksum::from_base64(candidate, value))
+            .ok_or_else(|| {
+                invalid(&format!(
+                    "Value for {} header is invalid.",
+                    candidate.header_name()
+                ))
+            })?;
+        declared = Some(checksum);
+    }
+    if let (Some(algorithm), Some(declared)) = (algorithm, declared)
+        && algorithm != declared.algorithm()
+    {
+        return Err(invalid(&format!(
+            "Value for {} header does not match the requested {} checksum.",
+            declared.algorithm().header_name(),
+            algorithm.s3_name()
+        )));
+    }
+    Ok((algorithm, declared))
+}
+
+/// Adds the additional checksum recorded for `object`. Reads only return it
+/// when the client opted in with `x-amz-checksum-mode: ENABLED`, as S3 does.
+fn add_checksum_header(
+    builder: axum::http::response::Builder,
+    object: &Object,
+) -> axum::http::response::Builder {
+    match object.checksum.as_deref().and_then(ObjectChecksum::decode) {
+        Some(checksum) => builder.header(checksum.algorithm().header_name(), checksum.to_base64()),
+        None => builder,
+    }
+}
+
+fn s3_checksum_mode_enabled(headers: &axum::http::HeaderMap) -> bool {
+    headers
+        .get("x-amz-checksum-mode")
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("ENABLED"))
+}
+
+/// S3 class named by `x-amz-storage-class`, or `Err` with an
+/// `InvalidStorageClass` response for values the gateway does not map. The
+/// object manager resolves the name to a tier and records it on the object.
+pub(super) fn s3_requested_storage_class(
+    headers: &axum::http::HeaderMap,
+) -> Result<Option<&'static str>, Response> {
+    let Some(value) = headers.get("x-amz-storage-class") else {
+        return Ok(None);
+    };
+    value
+        .to_str()
+        .ok()
+        .and_then(|value| StorageClass::s3_class_name(value.trim()))
+        .map(Some)
+        .ok_or_else(|| {
+            s3_error(
+                "InvalidStorageClass",
+                "The storage class you specified is not valid",
+                axum::http::StatusCode::BAD_REQUEST,
+            )
+        })
+}
+
+/// Object-lock settings from `x-amz-object-lock-retain-until-date` and
+/// `x-amz-object-lock-legal-hold` on a PUT. The lock mode header is accepted
+/// but not distinguished: every retention behaves like compliance mode.
+pub(super) fn s3_requested_retention(
+    headers: &axum::http::HeaderMap,
+) -> Result<Option<ObjectRetention>, Response> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+    };
+    let invalid = |message: &str| {
+        s3_error(
+            "InvalidArgument",
+            message,
+            axum::http::StatusCode::BAD_REQUEST,
+        )
+    };
+    let retain_until = header("x-amz-object-lock-retain-until-date")
+        .map(|value| {
+            chrono::DateTime::parse_from_rfc3339(value)
+                .map(|until| until.with_timezone(&chrono::Utc))
+                .map_err(|_| invalid("The retain until date must be an ISO 8601 timestamp"))
+        })
+        .transpose()?;
+    let legal_hold = match header("x-amz-object-lock-legal-hold") {
+        None => false,
+        Some(value) if value.eq_ignore_ascii_case("ON") => true,
+        Some(value) if value.eq_ignore_ascii_case("OFF") => false,
+        Some(_) => return Err(invalid("The legal hold status must be ON or OFF")),
+    };
+    if retain_until.is_none() && !legal_hold {
+        return Ok(None);
+    }
+    Ok(Some(ObjectRetention {
+        retain_until,
+        legal_hold,
+    }))
+}
+
+fn add_object_lock_headers(
+    mut builder: axum::http::response::Builder,
+    retention: Option<ObjectRetention>,
+) -> axum::http::response::Builder {
+    let Some(retention) = retention else {
+        return builder;
+    };
+    if let Some(retain_until) = retention.retain_until {
+        builder = builder
+            .header("x-amz-object-lock-mode", "COMPLIANCE")
+            .header(
+                "x-amz-object-lock-retain-until-date",
+                retain_until.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            );
+    }
+    builder.header(
+        "x-amz-object-lock-legal-hold",
+        if retention.legal_hold { "ON" } else { "OFF" },
+    )
+}
+
+/// Storage class reported for `object`: the S3 class it was written with,
+/// or the name of the tier its concrete class belongs to.
+pub(super) fn s3_storage_class_name(object: &Object) -> &'static str {
+    object
+        .requested_storage_class
+        .as_deref()
+        .and_then(StorageClass::s3_class_name)
+        .unwrap_or_else(|| StorageClass::of_class_id(object.storage_class.as_deref()).s3_name())
+}
+
+/// `If-Match` / `If-None-Match` on a write, enforced by the object manager at
+/// commit time in addition to the early check against the current version.
+pub(super) fn s3_write_conditions(headers: &axum::http::HeaderMap) -> ObjectConditions {
+    let header = |name: axum::http::HeaderName| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string)
+    };
+    ObjectConditions {
+        if_match: header(axum::http::header::IF_MATCH),
+        if_none_match: header(axum::http::header::IF_NONE_MATCH),
+    }
+}
+
 pub(super) fn add_s3_user_metadata_headers(
     mut builder: axum::http::response::Builder,
     user_meta: Option<&serde_json::Value>,
@@ -113,9 +369,14 @@ pub(super) async fn get_object(
     {
         return response;
     }
-    let requested_range = match parse_http_range(req.headers(), None) {
-        Ok(range) => range,
-        Err(response) => return response,
+    let range_requests_enabled = !state.config.s3_disable_range_requests;
+    let requested_range = if range_requests_enabled {
+        match parse_http_range(req.headers(), None) {
+            Ok(range) => range,
+            Err(response) => return response,
+        }
+    } else {
+        None
     };
 
     let response_bucket = bucket.clone();
@@ -161,13 +422,27 @@ pub(super) async fn get_object(
                 ),
                 None => (axum::http::StatusCode::OK, object.size, stream),
             };
-            let mut builder = Response::builder()
+            let storage_class = s3_storage_class_name(&object);
+            let mut builder = Response::builder();
+            // A checksum covers the whole object, so ranged reads omit it.
+            if range.is_none() && s3_checksum_mode_enabled(req.headers()) {
+                builder = add_checksum_header(builder, &object);
+            }
+            builder = builder
                 .status(status)
-                .header("Content-Type", object.content_type.unwrap_or_default())
+                .header(
+                    "Content-Type",
+                    object
+                        .content_type
+                        .unwrap_or_else(|| DEFAULT_OBJECT_CONTENT_TYPE.to_string()),
+                )
                 .header("Content-Length", content_length)
+                .header("x-amz-storage-class", storage_class)
                 .header("ETag", object.etag)
-                .header("Accept-Ranges", "bytes")
                 .header("x-amz-version-id", object.version_id.to_string());
+            if range_requests_enabled {
+                builder = builder.header("Accept-Ranges", "bytes");
+            }
             builder = add_followed_link_headers(builder, followed_link.as_ref());
             builder = add_s3_user_metadata_headers(builder, object.user_meta.as_ref());
             if let Some(range) = range {
@@ -476,6 +751,20 @@ pub(super) async fn put_object(
                 );
             }
         };
+        let (checksum_algorithm, expected_checksum) = match s3_requested_checksum(req.headers()) {
+            Ok(checksum) => checksum,
+            Err(response) => return response,
+        };
+        let options = UploadPartOptions {
+            expected_md5: match s3_content_md5(req.headers()) {
+                Ok(expected_md5) => expected_md5,
+                Err(response) => return response,
+            },
+            expected_sha256: s3_declared_payload_sha256(req.headers()),
+            checksum_algorithm,
+            expected_checksum,
+            ..Default::default()
+        };
         let body_stream = req.into_body().into_data_stream().map(|r| {
             r.map(|chunk| chunk.to_vec())
                 .map_err(|e| tonic::Status::internal(e.to_string()))
@@ -488,6 +777,7 @@ pub(super) async fn put_object(
             upload_id,
             part_number,
             body_stream,
+            options,
         )
         .await;
     }
@@ -542,6 +832,22 @@ pub(super) async fn put_object(
         }
     }
 
+    let expected_md5 = match s3_content_md5(req.headers()) {
+        Ok(expected_md5) => expected_md5,
+        Err(response) => return response,
+    };
+    let storage_class = match s3_requested_storage_class(req.headers()) {
+        Ok(storage_class) => storage_class,
+        Err(response) => return response,
+    };
+    let retention = match s3_requested_retention(req.headers()) {
+        Ok(retention) => retention,
+        Err(response) => return response,
+    };
+    let (checksum_algorithm, expected_checksum) = match s3_requested_checksum(req.headers()) {
+        Ok(checksum) => checksum,
+        Err(response) => return response,
+    };
     let options = ObjectWriteOptions {
         content_type: req
             .headers()
@@ -551,7 +857,15 @@ pub(super) async fn put_object(
         user_metadata: s3_user_metadata(req.headers()),
         transaction_id: None,
         transaction_principal: None,
-        storage_class_id: None,
+        storage_class_id: storage_class.map(ToString::to_string),
+        expected_size: s3_declared_content_length(req.headers()),
+        expected_md5,
+        expected_sha256: s3_declared_payload_sha256(req.headers()),
+        checksum_algorithm,
+        expected_checksum,
+        md5_etag: true,
+        conditions: s3_write_conditions(req.headers()),
+        retention,
         ..Default::default()
     };
     let body_stream = req.into_body().into_data_stream().map(|r| {
@@ -564,13 +878,18 @@ pub(super) async fn put_object(
         .put_object(&claims, &bucket, &key, body_stream, options)
         .await
     {
-        Ok(object) => Response::builder()
+        Ok(object) => add_checksum_header(Response::builder(), &object)
             .status(200)
             .header("ETag", object.etag)
             .header("x-amz-version-id", object.version_id.to_string())
             .body(Body::empty())
             .unwrap(),
         Err(status) => match status.code() {
+            tonic::Code::InvalidArgument => s3_error(
+                s3_invalid_argument_code(status.message()),
+                status.message(),
+                axum::http::StatusCode::BAD_REQUEST,
+            ),
             tonic::Code::FailedPrecondition => {
                 if let Some(response) = s3_remote_bucket_response_from_status(
                     &status,
@@ -594,6 +913,11 @@ pub(super) async fn put_object(
                 status.message(),
                 axum::http::StatusCode::FORBIDDEN,
             ),
+            tonic::Code::ResourceExhausted => s3_error(
+                "QuotaExceeded",
+                status.message(),
+                axum::http::StatusCode::FORBIDDEN,
+            ),
             tonic::Code::Unavailable => {
                 s3_unavailable_status_to_response(&status, state.config.cross_region_routing_policy)
             }
@@ -702,6 +1026,33 @@ pub(super) async fn copy_object(
         return response;
     }
 
+    let metadata = match s3_copy_metadata(headers) {
+        Ok(metadata) => metadata,
+        Err(response) => return response,
+    };
+
+    // Copying the current version onto itself while keeping its metadata
+    // changes nothing, since the content is addressed by hash. A REPLACE
+    // directive or a named source version is a real copy.
+    if source_version_id.is_none()
+        && source_bucket == destination_bucket
+        && source_key == destination_key
+        && metadata.is_none()
+    {
+        return match state
+            .object_manager
+            .validate_write_request(&claims, &destination_bucket, &destination_key)
+            .await
+        {
+            Ok(()) => copy_object_result_response(&source_object),
+            Err(status) => copy_status_to_response(
+                status,
+                "NoSuchBucket",
+                state.config.cross_region_routing_policy,
+            ),
+        };
+    }
+
     match state
         .object_manager
         .copy_object(
@@ -711,24 +1062,12 @@ pub(super) async fn copy_object(
             source_version_id,
             &destination_bucket,
             &destination_key,
+            metadata,
             None,
         )
         .await
     {
-        Ok(object) => {
-            let xml = format!(
-                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<CopyObjectResult>\n  <LastModified>{}</LastModified>\n  <ETag>\"{}\"</ETag>\n</CopyObjectResult>\n",
-                object.created_at.to_rfc3339(),
-                object.etag
-            );
-            Response::builder()
-                .status(200)
-                .header("Content-Type", "application/xml")
-                .header("ETag", object.etag)
-                .header("x-amz-version-id", object.version_id.to_string())
-                .body(Body::from(xml))
-                .unwrap()
-        }
+        Ok(object) => copy_object_result_response(&object),
         Err(status) => copy_status_to_response(
             status,
             "NoSuchBucket",
@@ -737,6 +1076,49 @@ pub(super) async fn copy_object(
     }
 }
 
+/// Metadata a copy records in place of the source's: `Some` for
+/// `x-amz-metadata-directive: REPLACE`, `None` for `COPY`, which is also the
+/// default when the header is absent.
+fn s3_copy_metadata(
+    headers: &axum::http::HeaderMap,
+) -> Result<Option<CopyObjectMetadata>, Response> {
+    let Some(directive) = headers.get("x-amz-metadata-directive") else {
+        return Ok(None);
+    };
+    match directive.to_str().map(str::trim) {
+        Ok(directive) if directive.eq_ignore_ascii_case("COPY") => Ok(None),
+        Ok(directive) if directive.eq_ignore_ascii_case("REPLACE") => {
+            Ok(Some(CopyObjectMetadata {
+                content_type: headers
+                    .get("content-type")
+                    .and_then(|value| value.to_str().ok())
+                    .map(ToString::to_string),
+                user_metadata: s3_user_metadata(headers),
+            }))
+        }
+        _ => Err(s3_error(
+            "InvalidArgument",
+            "Unknown metadata directive.",
+            axum::http::StatusCode::BAD_REQUEST,
+        )),
+    }
+}
+
+fn copy_object_result_response(object: &Object) -> Response {
+    let xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<CopyObjectResult>\n  <LastModified>{}</LastModified>\n  <ETag>\"{}\"</ETag>\n</CopyObjectResult>\n",
+        object.created_at.to_rfc3339(),
+        object.etag
+    );
+    Response::builder()
+        .status(200)
+        .header("Content-Type", "application/xml")
+        .header("ETag", object.etag.clone())
+        .header("x-amz-version-id", object.version_id.to_string())
+        .body(Body::from(xml))
+        .unwrap()
+}
+
 pub(super) fn parse_copy_source(
     value: &str,
 ) -> Result<(String, String, Option<uuid::Uuid>), Response> {
@@ -1065,17 +1447,28 @@ pub(super) async fn head_object(
             {
                 return response;
             }
-            let builder = Response::builder()
+            let mut builder = Response::builder();
+            if s3_checksum_mode_enabled(req.headers()) {
+                builder = add_checksum_header(builder, &object);
+            }
+            builder = builder
                 .status(200)
                 .header(
                     "Content-Type",
-                    object.content_type.clone().unwrap_or_default(),
+                    object
+                        .content_type
+                        .clone()
+                        .unwrap_or_else(|| DEFAULT_OBJECT_CONTENT_TYPE.to_string()),
                 )
                 .header("Content-Length", object.size)
+                .header("x-amz-storage-class", s3_storage_class_name(&object))
                 .header("ETag", object.etag)
-                .header("Accept-Ranges", "bytes")
                 .header("x-amz-version-id", object.version_id.to_string());
+            if !state.config.s3_disable_range_requests {
+                builder = builder.header("Accept-Ranges", "bytes");
+            }
             let builder = add_followed_link_headers(builder, followed_link.as_ref());
+            let builder = add_object_lock_headers(builder, object.retention);
             add_s3_user_metadata_headers(builder, object.user_meta.as_ref())
                 .body(Body::empty())
                 .unwrap()
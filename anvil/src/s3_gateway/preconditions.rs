@@ -120,21 +120,6 @@ pub(super) fn evaluate_copy_source_preconditions(
     None
 }
 
-pub(super) fn etag_condition_matches(header_value: &str, current_etag: &str) -> bool {
-    header_value
-        .split(',')
-        .map(str::trim)
-        .any(|candidate| candidate == "*" || normalize_etag(candidate) == current_etag)
-}
-
-pub(super) fn normalize_etag(value: &str) -> &str {
-    value
-        .strip_prefix("W/")
-        .unwrap_or(value)
-        .trim()
-        .trim_matches('"')
-}
-
 pub(super) fn precondition_failed_response() -> Response {
     s3_error(
         "PreconditionFailed",
@@ -1,7 +1,7 @@
 use super::*;
 
 pub(super) fn s3_redirect(region: &str) -> Response {
-    let request_id = new_s3_request_id();
+    let request_id = s3_request_id();
     let escaped_region = xml_escape(region);
     let body = format!(
         "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Error>\n  <Code>PermanentRedirect</Code>\n  <Message>The bucket is in this region: {escaped_region}. Please use this region to retry the request.</Message>\n  <BucketRegion>{escaped_region}</BucketRegion>\n  <RequestId>{request_id}</RequestId>\n</Error>\n"
@@ -19,29 +19,11 @@ pub(super) async fn select_remote_bucket_proxy_target(
     state: &AppState,
     region: &str,
 ) -> anyhow::Result<Option<String>> {
-    let mut nodes = state
+    state
         .persistence
-        .list_node_descriptors(Some(region), None)
+        .region_object_endpoint(region)
         .await
-        .map_err(|error| anyhow::anyhow!(error.to_string()))?;
-    nodes.sort_by(|left, right| left.node_id.cmp(&right.node_id));
-    Ok(nodes.into_iter().find_map(|node| {
-        let can_proxy = node.state == LifecycleState::Active
-            && node
-                .capabilities
-                .iter()
-                .any(|capability| *capability == NodeCapability::Object)
-            && !node.public_api_addr.trim().is_empty();
-        can_proxy.then(|| normalize_proxy_endpoint(&node.public_api_addr))
-    }))
-}
-
-pub(super) fn normalize_proxy_endpoint(endpoint: &str) -> String {
-    if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
-        endpoint.to_string()
-    } else {
-        format!("http://{endpoint}")
-    }
+        .map_err(|error| anyhow::anyhow!(error.to_string()))
 }
 
 pub(super) fn s3_remote_bucket_response(
@@ -205,7 +187,7 @@ pub(super) async fn proxy_s3_object_request(
     version_id: Option<uuid::Uuid>,
     body: Option<Body>,
 ) -> Response {
-    let request_id = new_s3_request_id();
+    let request_id = s3_request_id();
     let mut proxy_headers = http_headers_to_proxy_headers(headers);
     if let Some(version_id) = version_id {
         proxy_headers.push(proxy_header("x-anvil-version-id", version_id.to_string()));
@@ -262,8 +244,8 @@ pub(super) async fn proxy_s3_object_request(
         );
     }
 
-    let mut client = match InternalProxyServiceClient::connect(target.endpoint.clone()).await {
-        Ok(client) => client,
+    let mut client = match state.peer_channels.channel(&target.endpoint).await {
+        Ok(channel) => InternalProxyServiceClient::new(channel),
         Err(error) => {
             return add_bucket_region_header(
                 s3_error(
@@ -328,10 +310,19 @@ pub(super) async fn proxy_s3_object_request(
             }
         },
     );
+    anvil_core::middleware::propagate_request_id(&mut request);
 
     let response = match client.proxy_object(request).await {
         Ok(response) => response,
-        Err(status) => return s3_proxy_status_to_response(status, &target.region),
+        Err(status) => {
+            if matches!(
+                status.code(),
+                tonic::Code::Unavailable | tonic::Code::Unknown
+            ) {
+                state.peer_channels.invalidate(&target.endpoint).await;
+            }
+            return s3_proxy_status_to_response(status, &target.region);
+        }
     };
     let mut stream = response.into_inner();
     let first = match stream.next().await {
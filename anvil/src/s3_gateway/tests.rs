@@ -718,6 +718,143 @@ fn head_bucket_uses_remote_locator_before_local_bucket_metadata() {
     });
 }
 
+#[test]
+fn anonymous_head_bucket_is_answered_only_for_public_buckets() {
+    run_s3_gateway_async_test(async move {
+        let (_temp, state, claims, bucket, _link_key) = seeded_local_object_link().await;
+        let anonymous_head = |bucket: String| {
+            let mut req = Request::builder()
+                .method("HEAD")
+                .uri(format!("/acme/{bucket}"))
+                .body(Body::empty())
+                .unwrap();
+            req.extensions_mut().insert(S3HostRoute(ObjectRoute {
+                tenant: "acme".to_string(),
+                bucket,
+                region: "us-east-1".to_string(),
+                key: String::new(),
+                source: RouteSource::PathStyle,
+            }));
+            req
+        };
+
+        let response = head_bucket(
+            State(state.clone()),
+            Path(bucket.clone()),
+            anonymous_head(bucket.clone()),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::FORBIDDEN);
+
+        state
+            .persistence
+            .set_bucket_public_access(claims.tenant_id, &bucket, true)
+            .await
+            .unwrap();
+        let response = head_bucket(
+            State(state.clone()),
+            Path(bucket.clone()),
+            anonymous_head(bucket.clone()),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let response = head_bucket(
+            State(state.clone()),
+            Path("missing".to_string()),
+            anonymous_head("missing".to_string()),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+
+        let unrouted = Request::builder()
+            .method("HEAD")
+            .uri(format!("/{bucket}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = head_bucket(State(state), Path(bucket), unrouted).await;
+        assert_eq!(response.status(), axum::http::StatusCode::FORBIDDEN);
+    });
+}
+
+#[test]
+fn anonymous_list_objects_is_allowed_only_for_tenant_routed_public_buckets() {
+    run_s3_gateway_async_test(async move {
+        let (_temp, state, claims, bucket, _link_key) = seeded_local_object_link().await;
+        let anonymous_list = |routed: bool| {
+            let mut req = Request::builder()
+                .uri(format!("/acme/{bucket}?list-type=2"))
+                .body(Body::empty())
+                .unwrap();
+            if routed {
+                req.extensions_mut().insert(S3HostRoute(ObjectRoute {
+                    tenant: "acme".to_string(),
+                    bucket: bucket.clone(),
+                    region: "us-east-1".to_string(),
+                    key: String::new(),
+                    source: RouteSource::PathStyle,
+                }));
+            }
+            let q = HashMap::from([("list-type".to_string(), "2".to_string())]);
+            list_objects(State(state.clone()), Path(bucket.clone()), Query(q), req)
+        };
+
+        let response = anonymous_list(true).await;
+        assert_eq!(response.status(), axum::http::StatusCode::FORBIDDEN);
+
+        state
+            .bucket_manager
+            .set_bucket_public_access(&claims, &bucket, true)
+            .await
+            .unwrap();
+        let response = anonymous_list(true).await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let xml = response_xml(response).await;
+        assert!(xml.contains("<Key>versions/app-v1.bin</Key>"), "{xml}");
+
+        // Bucket names are only unique within a tenant, so an anonymous
+        // request must name the tenant through its route.
+        let response = anonymous_list(false).await;
+        assert_eq!(response.status(), axum::http::StatusCode::FORBIDDEN);
+    });
+}
+
+#[test]
+fn list_objects_v2_reports_tenant_owner_when_fetch_owner_is_set() {
+    run_s3_gateway_async_test(async move {
+        let (_temp, state, claims, bucket, _link_key) = seeded_local_object_link().await;
+        let list = |fetch_owner: bool| {
+            let mut q = HashMap::from([
+                ("list-type".to_string(), "2".to_string()),
+                ("prefix".to_string(), "versions/".to_string()),
+            ]);
+            if fetch_owner {
+                q.insert("fetch-owner".to_string(), "true".to_string());
+            }
+            let mut req = Request::builder()
+                .uri(format!("/{bucket}?list-type=2"))
+                .body(Body::empty())
+                .unwrap();
+            req.extensions_mut().insert(claims.clone());
+            list_objects(State(state.clone()), Path(bucket.clone()), Query(q), req)
+        };
+
+        let response = list(true).await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let xml = response_xml(response).await;
+        let owner = format!(
+            "<Owner>\n      <ID>{}</ID>\n      <DisplayName>acme</DisplayName>\n    </Owner>",
+            claims.tenant_id
+        );
+        assert!(xml.contains("<Key>versions/app-v1.bin</Key>"), "{xml}");
+        assert!(xml.contains(&owner), "{xml}");
+
+        let xml = response_xml(list(false).await).await;
+        assert!(xml.contains("<Key>versions/app-v1.bin</Key>"), "{xml}");
+        assert!(!xml.contains("<Owner>"), "{xml}");
+    });
+}
+
 #[test]
 fn object_link_get_and_head_follow_by_default_with_link_headers() {
     run_s3_gateway_async_test(async move {
@@ -788,6 +925,195 @@ fn object_link_get_and_head_follow_by_default_with_link_headers() {
     });
 }
 
+#[test]
+fn head_object_advertises_byte_ranges_unless_disabled() {
+    run_s3_gateway_async_test(async move {
+        let (_temp, state, claims, bucket, link_key) = seeded_local_object_link().await;
+        let head = |state: AppState| {
+            let mut req = Request::builder()
+                .method(axum::http::Method::HEAD)
+                .uri(format!("/{bucket}/{link_key}"))
+                .body(Body::empty())
+                .unwrap();
+            req.extensions_mut().insert(claims.clone());
+            head_object(
+                State(state),
+                Path((bucket.clone(), link_key.clone())),
+                Query(HashMap::new()),
+                req,
+            )
+        };
+
+        let enabled = head(state.clone()).await;
+        assert_eq!(enabled.status(), axum::http::StatusCode::OK);
+        assert_eq!(enabled.headers().get("Accept-Ranges").unwrap(), "bytes");
+
+        let mut disabled_state = state;
+        disabled_state.config = std::sync::Arc::new(anvil_core::config::Config {
+            s3_disable_range_requests: true,
+            ..(*disabled_state.config).clone()
+        });
+        let disabled = head(disabled_state).await;
+        assert_eq!(disabled.status(), axum::http::StatusCode::OK);
+        assert!(disabled.headers().get("Accept-Ranges").is_none());
+    });
+}
+
+#[test]
+fn copy_object_onto_itself_is_a_no_op_only_when_keeping_metadata() {
+    run_s3_gateway_async_test(async move {
+        let (_temp, state, claims, bucket, _link_key) = seeded_local_object_link().await;
+        let key = "versions/app-v1.bin";
+        let before = state
+            .object_manager
+            .head_object(Some(claims.clone()), &bucket, key, None)
+            .await
+            .unwrap();
+        let current = || {
+            let state = state.clone();
+            let claims = claims.clone();
+            let bucket = bucket.clone();
+            async move {
+                state
+                    .object_manager
+                    .head_object(Some(claims), &bucket, key, None)
+                    .await
+                    .unwrap()
+            }
+        };
+        let copy_onto_itself = |headers: &[(&'static str, &'static str)], copy_source: String| {
+            let state = state.clone();
+            let claims = claims.clone();
+            let bucket = bucket.clone();
+            let mut header_map = axum::http::HeaderMap::new();
+            for (name, value) in headers {
+                header_map.insert(*name, value.parse().unwrap());
+            }
+            async move {
+                copy_object(
+                    state,
+                    claims,
+                    bucket,
+                    key.to_string(),
+                    copy_source,
+                    &header_map,
+                )
+                .await
+            }
+        };
+
+        // Without a directive S3 copies the metadata, as with COPY.
+        for headers in [&[][..], &[("x-amz-metadata-directive", "COPY")][..]] {
+            let response = copy_onto_itself(headers, format!("{bucket}/{key}")).await;
+            assert_eq!(response.status(), axum::http::StatusCode::OK);
+            assert_eq!(
+                response.headers().get("x-amz-version-id").unwrap(),
+                before.version_id.to_string().as_str()
+            );
+            let body = String::from_utf8(response_body(response).await).unwrap();
+            assert!(body.contains("<CopyObjectResult>"));
+            assert!(body.contains(&before.etag));
+            assert_eq!(current().await.version_id, before.version_id);
+        }
+
+        // Replacing the metadata writes a new version that carries the
+        // request's metadata.
+        let response = copy_onto_itself(
+            &[
+                ("x-amz-metadata-directive", "REPLACE"),
+                ("content-type", "text/plain"),
+                ("x-amz-meta-owner", "ops"),
+            ],
+            format!("{bucket}/{key}"),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let replaced = current().await;
+        assert_ne!(replaced.version_id, before.version_id);
+        assert_eq!(
+            response.headers().get("x-amz-version-id").unwrap(),
+            replaced.version_id.to_string().as_str()
+        );
+        assert_eq!(replaced.content_hash, before.content_hash);
+        assert_eq!(replaced.content_type.as_deref(), Some("text/plain"));
+        assert_eq!(
+            replaced.user_meta,
+            Some(serde_json::json!({ "owner": "ops" }))
+        );
+
+        // Copying a named version is a real copy too, and keeps that
+        // version's metadata.
+        let response = copy_onto_itself(
+            &[("x-amz-metadata-directive", "COPY")],
+            format!("{bucket}/{key}?versionId={}", before.version_id),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let restored = current().await;
+        assert_ne!(restored.version_id, replaced.version_id);
+        assert_eq!(restored.content_hash, before.content_hash);
+        assert_eq!(restored.content_type, before.content_type);
+        assert_eq!(restored.user_meta, before.user_meta);
+
+        let response = copy_onto_itself(
+            &[("x-amz-metadata-directive", "MERGE")],
+            format!("{bucket}/{key}"),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+    });
+}
+
+#[test]
+fn delete_objects_honours_quiet_and_rejects_oversized_batches() {
+    run_s3_gateway_async_test(async move {
+        let (_temp, state, claims, bucket, _link_key) = seeded_local_object_link().await;
+        let delete_body = |keys: &[String], quiet: bool| {
+            let objects = keys
+                .iter()
+                .map(|key| format!("<Object><Key>{key}</Key></Object>"))
+                .collect::<String>();
+            axum::body::Bytes::from(format!("<Delete><Quiet>{quiet}</Quiet>{objects}</Delete>"))
+        };
+
+        let oversized = (0..=S3_DELETE_OBJECTS_MAX_KEYS)
+            .map(|index| format!("bulk/{index}.bin"))
+            .collect::<Vec<_>>();
+        let response = delete_objects(
+            state.clone(),
+            claims.clone(),
+            bucket.clone(),
+            delete_body(&oversized, false),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::BAD_REQUEST);
+        assert!(response_xml(response).await.contains("MalformedXML"));
+
+        let keys = vec![
+            "versions/app-v1.bin".to_string(),
+            "missing/object.bin".to_string(),
+        ];
+        let response = delete_objects(
+            state.clone(),
+            claims.clone(),
+            bucket.clone(),
+            delete_body(&keys, true),
+        )
+        .await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let body = response_xml(response).await;
+        assert!(body.contains("<DeleteResult"));
+        assert!(!body.contains("<Deleted>"));
+        assert!(!body.contains("<Error>"));
+        let missing = state
+            .object_manager
+            .head_object(Some(claims), &bucket, "versions/app-v1.bin", None)
+            .await
+            .expect_err("quiet deletes must still delete");
+        assert_eq!(missing.code(), tonic::Code::NotFound);
+    });
+}
+
 #[test]
 fn object_link_metadata_mode_returns_descriptor_json() {
     run_s3_gateway_async_test(async move {
@@ -825,6 +1151,122 @@ fn object_link_metadata_mode_returns_descriptor_json() {
     });
 }
 
+#[test]
+fn gateway_responses_echo_the_request_id_as_x_amz_request_id() {
+    run_s3_gateway_async_test(async move {
+        use tower::ServiceExt;
+
+        let temp = tempdir().unwrap();
+        let config = routing_config_with_policy(
+            &temp.path().join("storage"),
+            CrossRegionRoutingPolicy::RedirectPreferred,
+        );
+        let state = AppState::new(config, None, personaldb_test_protocol_keyring())
+            .await
+            .unwrap();
+
+        let request = axum::http::Request::builder()
+            .uri("/_anvil/healthz")
+            .header(
+                anvil_core::middleware::ANVIL_REQUEST_ID_HEADER,
+                "0123456789abcdef0123456789abcdef",
+            )
+            .body(Body::empty())
+            .unwrap();
+        let response = app(state).oneshot(request).await.unwrap();
+        for header in [
+            "x-amz-request-id",
+            anvil_core::middleware::ANVIL_REQUEST_ID_HEADER,
+        ] {
+            assert_eq!(
+                response.headers().get(header).unwrap(),
+                "0123456789abcdef0123456789abcdef",
+                "{header} should carry the forwarded request id"
+            );
+        }
+    });
+}
+
+#[test]
+fn health_probes_do_not_shadow_buckets_named_after_them() {
+    run_s3_gateway_async_test(async move {
+        use tower::ServiceExt;
+
+        let temp = tempdir().unwrap();
+        let config = routing_config_with_policy(
+            &temp.path().join("storage"),
+            CrossRegionRoutingPolicy::RedirectPreferred,
+        );
+        let state = AppState::new(config, None, personaldb_test_protocol_keyring())
+            .await
+            .unwrap();
+        let get = |uri: &'static str| {
+            app(state.clone()).oneshot(
+                axum::http::Request::builder()
+                    .uri(uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+        };
+
+        let live = get("/_anvil/healthz").await.unwrap();
+        assert_eq!(live.status(), axum::http::StatusCode::OK);
+        for bucket in ["/healthz", "/readyz"] {
+            let response = get(bucket).await.unwrap();
+            assert_ne!(response.status(), axum::http::StatusCode::OK, "{bucket}");
+            let body = String::from_utf8(response_body(response).await).unwrap();
+            assert!(body.contains("<Error>"), "{bucket}: {body}");
+        }
+    });
+}
+
+#[test]
+fn readiness_stays_unavailable_until_enough_peers_join() {
+    run_s3_gateway_async_test(async move {
+        let temp = tempdir().unwrap();
+        let mut config = routing_config_with_policy(
+            &temp.path().join("storage"),
+            CrossRegionRoutingPolicy::RedirectPreferred,
+        );
+        config.readiness_min_peers = 2;
+        let state = AppState::new(config, None, personaldb_test_protocol_keyring())
+            .await
+            .unwrap();
+
+        let live = liveness_check().await;
+        assert_eq!(live.status(), axum::http::StatusCode::OK);
+
+        let not_ready = readiness_check(State(state.clone())).await;
+        assert_eq!(
+            not_ready.status(),
+            axum::http::StatusCode::SERVICE_UNAVAILABLE
+        );
+        let body: serde_json::Value =
+            serde_json::from_slice(&response_body(not_ready).await).unwrap();
+        assert_eq!(body, serde_json::json!({ "status": "not_ready" }));
+
+        {
+            let mut cluster = state.cluster.write().await;
+            for _ in 0..2 {
+                cluster.insert(
+                    libp2p::PeerId::random(),
+                    anvil_core::cluster::PeerInfo {
+                        p2p_addrs: Vec::new(),
+                        grpc_addr: String::new(),
+                        zone: None,
+                        free_bytes: None,
+                        last_seen: chrono::Utc::now(),
+                        stale: false,
+                    },
+                );
+            }
+        }
+        let ready = readiness_check(State(state)).await;
+        assert_eq!(ready.status(), axum::http::StatusCode::OK);
+        assert_eq!(response_body(ready).await, b"READY");
+    });
+}
+
 #[test]
 fn reserved_namespace_guard_detects_object_keys() {
     assert!(request_targets_reserved_namespace(&request(
@@ -1147,6 +1589,41 @@ fn copy_source_preconditions_return_precondition_failed() {
     );
 }
 
+#[test]
+fn put_digest_headers_decode_content_md5_and_hex_payload_sha256() {
+    let md5 = s3_content_md5(&etag_headers(
+        axum::http::header::CONTENT_MD5,
+        "1B2M2Y8AsgTpgAmY7PhCfg==",
+    ))
+    .unwrap()
+    .expect("Content-MD5 should decode");
+    assert_eq!(hex::encode(md5), "d41d8cd98f00b204e9800998ecf8427e");
+    assert!(
+        s3_content_md5(&axum::http::HeaderMap::new())
+            .unwrap()
+            .is_none()
+    );
+    let invalid = s3_content_md5(&etag_headers(
+        axum::http::header::CONTENT_MD5,
+        "bm90LWEtZGlnZXN0",
+    ))
+    .expect_err("a short digest must be rejected");
+    assert_eq!(invalid.status(), axum::http::StatusCode::BAD_REQUEST);
+
+    let digest = "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855";
+    assert_eq!(
+        s3_declared_payload_sha256(&x_amz_headers("x-amz-content-sha256", digest)),
+        Some(digest.to_ascii_lowercase())
+    );
+    for sentinel in ["UNSIGNED-PAYLOAD", "STREAMING-AWS4-HMAC-SHA256-PAYLOAD"] {
+        assert_eq!(
+            s3_declared_payload_sha256(&x_amz_headers("x-amz-content-sha256", sentinel)),
+            None
+        );
+    }
+    assert_eq!(s3_invalid_argument_code("BadDigest: mismatch"), "BadDigest");
+}
+
 #[test]
 fn date_preconditions_compare_against_second_precision_last_modified() {
     let last_modified = chrono::DateTime::from_timestamp(1_700_000_000, 999_000_000).unwrap();
@@ -1,7 +1,10 @@
 use super::*;
 
-pub(super) fn s3_error(code: &str, message: &str, status: axum::http::StatusCode) -> Response {
-    let request_id = new_s3_request_id();
+/// Renders an S3 error document. SDKs branch on `Code` for retries and error
+/// classification, so every S3-facing failure should go through here rather
+/// than returning a plain-text body.
+pub(crate) fn s3_error(code: &str, message: &str, status: axum::http::StatusCode) -> Response {
+    let request_id = s3_request_id();
     let body = format!(
         "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Error>\n  <Code>{}</Code>\n  <Message>{}</Message>\n  <RequestId>{}</RequestId>\n</Error>\n",
         code,
@@ -16,8 +19,11 @@ pub(super) fn s3_error(code: &str, message: &str, status: axum::http::StatusCode
         .unwrap()
 }
 
-pub(super) fn new_s3_request_id() -> String {
-    uuid::Uuid::new_v4().simple().to_string()
+/// The id of the request being served, so S3 error documents and headers
+/// match the id in the node's logs.
+pub(super) fn s3_request_id() -> String {
+    anvil_core::middleware::current_request_id()
+        .unwrap_or_else(|| uuid::Uuid::new_v4().simple().to_string())
 }
 
 pub(super) fn s3_query_map(uri: &Uri) -> HashMap<String, String> {
@@ -38,7 +44,7 @@ pub(super) fn s3_query_map(uri: &Uri) -> HashMap<String, String> {
         .unwrap_or_default()
 }
 
-pub(super) fn percent_decode_query_component(value: &str) -> String {
+pub(crate) fn percent_decode_query_component(value: &str) -> String {
     let value = value.replace('+', " ");
     percent_decode(value.as_bytes())
 }
@@ -112,8 +118,13 @@ pub(super) fn s3_status_to_response_for_auth(
             status.message(),
             axum::http::StatusCode::FORBIDDEN,
         ),
+        tonic::Code::ResourceExhausted => s3_error(
+            "QuotaExceeded",
+            status.message(),
+            axum::http::StatusCode::FORBIDDEN,
+        ),
         tonic::Code::InvalidArgument => s3_error(
-            "InvalidArgument",
+            s3_invalid_argument_code(status.message()),
             status.message(),
             axum::http::StatusCode::BAD_REQUEST,
         ),
@@ -125,6 +136,21 @@ pub(super) fn s3_status_to_response_for_auth(
     }
 }
 
+/// Maps invalid-argument statuses whose message carries an S3 error code
+/// prefix onto that code, falling back to `InvalidArgument`.
+pub(super) fn s3_invalid_argument_code(message: &str) -> &'static str {
+    [
+        "BadDigest",
+        "EntityTooLarge",
+        "EntityTooSmall",
+        "IncompleteBody",
+        "MetadataTooLarge",
+    ]
+    .into_iter()
+    .find(|code| message.starts_with(code))
+    .unwrap_or("InvalidArgument")
+}
+
 pub(super) fn xml_escape(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -44,6 +44,10 @@ fn admin_rpc_relation_mapping_is_complete() {
 
     let expected = [
         "CreateTenant",
+        "SetTenantBucketLimit",
+        "SetTenantRateLimit",
+        "ListTenants",
+        "DeleteTenant",
         "CreateApplication",
         "RotateApplicationSecret",
         "GrantApplicationPolicy",
@@ -51,6 +55,10 @@ fn admin_rpc_relation_mapping_is_complete() {
         "RotateSecretEncryptionKey",
         "CreateBucketAdmin",
         "SetBucketPublicAccessAdmin",
+        "SetBucketQuotaAdmin",
+        "SetBucketReplicationAdmin",
+        "SetBucketLifecycleAdmin",
+        "AdminCopyObject",
         "CreateHostAlias",
         "ActivateHostAlias",
         "SuspendHostAlias",
@@ -71,12 +79,18 @@ fn admin_rpc_relation_mapping_is_complete() {
         "RegisterNode",
         "ActivateNode",
         "DrainNode",
+        "GetNodeDrainProgress",
         "ForceOfflineNode",
+        "SetNodePlacementEligible",
         "RemoveNode",
         "ListNodes",
+        "GetClusterState",
         "ListRoutingRecords",
         "RepairRoutingRecord",
         "RunRepair",
+        "RunGarbageCollection",
+        "ListTasks",
+        "RetryTask",
         "ListDiagnostics",
         "ListAuditEvents",
     ];
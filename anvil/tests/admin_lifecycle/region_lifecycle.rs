@@ -324,6 +324,29 @@ async fn admin_lifecycle_rejects_invalid_region_cell_and_node_transitions() {
         .unwrap()
         .into_inner();
     assert_eq!(drained.state, 4);
+    // node-a joined after every write, so it holds no shards to move.
+    assert_eq!(drained.shard_migrations_queued, 0);
+
+    let progress = client
+        .get_node_drain_progress(with_auth(
+            tonic::Request::new(GetNodeDrainProgressRequest {
+                node_id: "node-a".to_string(),
+            }),
+            &token,
+        ))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(progress.state, 4);
+    assert_eq!(progress.generation, drained.generation);
+    assert_eq!(
+        (
+            progress.pending_shard_migrations,
+            progress.completed_shard_migrations,
+            progress.failed_shard_migrations
+        ),
+        (0, 0, 0)
+    );
 
     let listed = client
         .list_nodes(with_auth(
@@ -568,3 +591,58 @@ async fn admin_region_drain_applies_bucket_dispositions_and_exceptions() {
             .contains("remain_proxy_only")
     );
 }
+
+#[tokio::test]
+async fn admin_cluster_state_lists_gossip_peers() {
+    let node = spawn_admin_node().await;
+    let token = admin_token(&node);
+    let mut client = AdminServiceClient::connect(node.admin_url.clone())
+        .await
+        .unwrap();
+
+    let remote_peer = libp2p::PeerId::random();
+    node.state.cluster.write().await.insert(
+        remote_peer,
+        anvil::cluster::PeerInfo {
+            p2p_addrs: Vec::new(),
+            grpc_addr: "http://10.0.0.2:50051".to_string(),
+            zone: Some("zone-b".to_string()),
+            free_bytes: Some(1 << 40),
+            last_seen: chrono::Utc::now() - chrono::Duration::seconds(20),
+            stale: true,
+        },
+    );
+
+    let peers = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let peers = client
+                .get_cluster_state(with_auth(
+                    tonic::Request::new(GetClusterStateRequest {}),
+                    &token,
+                ))
+                .await
+                .unwrap()
+                .into_inner()
+                .peers;
+            if peers.iter().any(|peer| peer.local) {
+                return peers;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("local peer never joined the cluster state");
+
+    assert_eq!(peers.len(), 2);
+    let local = peers.iter().find(|peer| peer.local).unwrap();
+    assert_eq!(local.grpc_addr, node.public_url);
+    assert!(!local.stale);
+    let remote = peers
+        .iter()
+        .find(|peer| peer.peer_id == remote_peer.to_base58())
+        .unwrap();
+    assert_eq!(remote.zone.as_deref(), Some("zone-b"));
+    assert_eq!(remote.free_bytes, Some(1 << 40));
+    assert!(remote.stale);
+    assert!(remote.node_id.is_empty());
+}
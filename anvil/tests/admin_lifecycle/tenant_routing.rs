@@ -245,6 +245,7 @@ async fn admin_tenant_app_and_bucket_workflow_issues_usable_credentials() {
         .get_access_token(tonic::Request::new(GetAccessTokenRequest {
             client_id: app_secret.client_id.clone(),
             client_secret: app_secret.client_secret.clone(),
+            requested_ttl_secs: 0,
         }))
         .await
         .unwrap()
@@ -528,3 +529,111 @@ async fn admin_routing_records_list_and_repair_mesh_locators() {
         "routing projection diagnostics should clear after stream-backed repair"
     );
 }
+
+#[tokio::test]
+async fn tenants_are_listed_and_deleted_once_they_own_no_buckets() {
+    let node = spawn_admin_node().await;
+    let admin_token = admin_token(&node);
+    let mut client = AdminServiceClient::connect(node.admin_url.clone())
+        .await
+        .unwrap();
+
+    let tenant = client
+        .create_tenant(with_auth(
+            tonic::Request::new(CreateTenantRequest {
+                context: Some(context("offboard-tenant", 0)),
+                name: "offboard-tenant".to_string(),
+                home_region: "eu-west-1".to_string(),
+            }),
+            &admin_token,
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .tenant
+        .unwrap();
+    client
+        .create_bucket_admin(with_auth(
+            tonic::Request::new(CreateBucketAdminRequest {
+                context: Some(context("offboard-bucket", 0)),
+                tenant_id: tenant.tenant_id.clone(),
+                bucket_name: "offboard-assets".to_string(),
+                region: "eu-west-1".to_string(),
+            }),
+            &admin_token,
+        ))
+        .await
+        .unwrap();
+
+    let listed = client
+        .list_tenants(with_auth(
+            tonic::Request::new(ListTenantsRequest { page: None }),
+            &admin_token,
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .tenants;
+    assert!(
+        listed
+            .iter()
+            .any(|listed| listed.tenant_id == tenant.tenant_id)
+    );
+
+    let refused = client
+        .delete_tenant(with_auth(
+            tonic::Request::new(DeleteTenantRequest {
+                context: Some(context("offboard-delete-refused", 0)),
+                tenant_id: tenant.tenant_id.clone(),
+            }),
+            &admin_token,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(refused.code(), Code::FailedPrecondition);
+
+    let tenant_id = tenant.tenant_id.parse::<i64>().unwrap();
+    node.state
+        .persistence
+        .soft_delete_bucket(tenant_id, "offboard-assets")
+        .await
+        .unwrap()
+        .unwrap();
+    client
+        .delete_tenant(with_auth(
+            tonic::Request::new(DeleteTenantRequest {
+                context: Some(context("offboard-delete", 0)),
+                tenant_id: tenant.tenant_id.clone(),
+            }),
+            &admin_token,
+        ))
+        .await
+        .unwrap();
+
+    let listed = client
+        .list_tenants(with_auth(
+            tonic::Request::new(ListTenantsRequest { page: None }),
+            &admin_token,
+        ))
+        .await
+        .unwrap()
+        .into_inner()
+        .tenants;
+    assert!(
+        listed
+            .iter()
+            .all(|listed| listed.tenant_id != tenant.tenant_id)
+    );
+    let recreate = client
+        .create_tenant(with_auth(
+            tonic::Request::new(CreateTenantRequest {
+                context: Some(context("offboard-recreate", 0)),
+                name: "offboard-tenant".to_string(),
+                home_region: "eu-west-1".to_string(),
+            }),
+            &admin_token,
+        ))
+        .await
+        .unwrap_err();
+    assert!(recreate.message().contains("cannot be reused"));
+}
@@ -29,6 +29,7 @@ async fn token_identifies_principal_and_zanzibar_grants_authorise_runtime_action
         .get_access_token(GetAccessTokenRequest {
             client_id,
             client_secret,
+            requested_ttl_secs: 0,
         })
         .await
         .unwrap()
@@ -66,6 +67,7 @@ async fn token_identifies_principal_and_zanzibar_grants_authorise_runtime_action
         .get_access_token(GetAccessTokenRequest {
             client_id: unauthorised_client_id,
             client_secret: unauthorised_client_secret,
+            requested_ttl_secs: 0,
         })
         .await
         .unwrap()
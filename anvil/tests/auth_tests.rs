@@ -167,6 +167,7 @@ async fn try_get_token(
         .get_access_token(GetAccessTokenRequest {
             client_id: client_id.to_string(),
             client_secret: client_secret.to_string(),
+            requested_ttl_secs: 0,
         })
         .await
         .map(|r| r.into_inner().access_token)
@@ -278,6 +279,9 @@ async fn put_test_object(
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -695,6 +695,9 @@ async fn test_object_read_uses_relationship_authorization_before_streaming_bytes
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -714,6 +714,53 @@ async fn tenant_cannot_bind_host_alias_to_other_tenant_bucket() {
     assert_eq!(denied.code(), tonic::Code::NotFound);
 }
 
+#[tokio::test]
+async fn tenants_can_create_same_named_buckets_in_isolation() {
+    let cluster = shared_docker_test_cluster().await;
+    let tenant_a = create_docker_storage_test_actor(&cluster, "bucket-iso-a").await;
+    let tenant_b = create_docker_storage_test_actor(&cluster, "bucket-iso-b").await;
+    assert_ne!(tenant_a.tenant_id, tenant_b.tenant_id);
+
+    let bucket_name = unique_test_name("shared-name");
+    let mut bucket_client = BucketServiceClient::connect(tenant_a.grpc_addr.clone())
+        .await
+        .unwrap();
+    let mut bucket_ids = Vec::new();
+    for actor in [&tenant_a, &tenant_b] {
+        let mut create_bucket = Request::new(CreateBucketRequest {
+            bucket_name: bucket_name.clone(),
+            region: actor.region.clone(),
+            options: None,
+        });
+        add_bearer(&mut create_bucket, &actor.token);
+        bucket_ids.push(
+            bucket_client
+                .create_bucket(create_bucket)
+                .await
+                .expect("each tenant owns its own bucket namespace")
+                .into_inner()
+                .bucket_id,
+        );
+    }
+    assert_ne!(bucket_ids[0], bucket_ids[1]);
+
+    for (actor, bucket_id) in [(&tenant_a, bucket_ids[0]), (&tenant_b, bucket_ids[1])] {
+        let mut list_buckets = Request::new(ListBucketsRequest {});
+        add_bearer(&mut list_buckets, &actor.token);
+        let listed = bucket_client
+            .list_buckets(list_buckets)
+            .await
+            .unwrap()
+            .into_inner()
+            .buckets
+            .into_iter()
+            .filter(|bucket| bucket.name == bucket_name)
+            .map(|bucket| bucket.bucket_id)
+            .collect::<Vec<_>>();
+        assert_eq!(listed, vec![bucket_id]);
+    }
+}
+
 #[tokio::test]
 async fn tenant_diagnostics_are_tenant_scoped() {
     let cluster = shared_docker_test_cluster().await;
@@ -60,6 +60,9 @@ async fn test_set_public_access_and_get() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let chunks = vec![
         PutObjectRequest {
@@ -243,6 +246,79 @@ async fn test_reset_app_secret() {
     );
 }
 
+#[tokio::test]
+async fn rotated_secret_stays_valid_until_grace_window_closes() {
+    let cluster = shared_default_test_cluster().await;
+    let app_name = unique_test_name("app-grace");
+    let (client_id, original_secret) = create_app(&cluster, &app_name).await;
+    grant_policy(&cluster, &app_name, "bucket:list", "buckets").await;
+
+    let grace = Duration::from_secs(3);
+    let (_client_id, new_secret) = cluster
+        .rotate_application_secret_with_grace("default", &app_name, grace.as_secs())
+        .await;
+    let grace_closes_at = std::time::Instant::now() + grace;
+    assert_ne!(original_secret, new_secret);
+
+    let mut auth_client = AuthServiceClient::connect(cluster.grpc_addrs[0].clone())
+        .await
+        .unwrap();
+    for secret in [&original_secret, &new_secret] {
+        auth_client
+            .get_access_token(GetAccessTokenRequest {
+                client_id: client_id.clone(),
+                client_secret: secret.clone(),
+                requested_ttl_secs: 0,
+            })
+            .await
+            .expect("both secrets must mint tokens inside the grace window");
+        cluster
+            .get_s3_client("test-region-1", &client_id, secret)
+            .await
+            .list_buckets()
+            .send()
+            .await
+            .expect("both secrets must sign S3 requests inside the grace window");
+    }
+
+    tokio::time::sleep_until((grace_closes_at + Duration::from_secs(1)).into()).await;
+
+    let err = auth_client
+        .get_access_token(GetAccessTokenRequest {
+            client_id: client_id.clone(),
+            client_secret: original_secret.clone(),
+            requested_ttl_secs: 0,
+        })
+        .await
+        .expect_err("the previous secret must stop minting tokens after the grace window");
+    assert_eq!(err.code(), tonic::Code::Unauthenticated);
+    assert!(
+        cluster
+            .get_s3_client("test-region-1", &client_id, &original_secret)
+            .await
+            .list_buckets()
+            .send()
+            .await
+            .is_err(),
+        "the previous secret must stop signing S3 requests after the grace window"
+    );
+    auth_client
+        .get_access_token(GetAccessTokenRequest {
+            client_id: client_id.clone(),
+            client_secret: new_secret.clone(),
+            requested_ttl_secs: 0,
+        })
+        .await
+        .unwrap();
+    cluster
+        .get_s3_client("test-region-1", &client_id, &new_secret)
+        .await
+        .list_buckets()
+        .send()
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 async fn test_service_set_public_access() {
     let cluster = shared_docker_test_cluster().await;
@@ -293,6 +369,9 @@ async fn test_service_set_public_access() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let chunks = vec![
         PutObjectRequest {
@@ -3,9 +3,9 @@
 use anvil::anvil_api::bucket_service_client::BucketServiceClient;
 use anvil::anvil_api::object_service_client::ObjectServiceClient;
 use anvil::anvil_api::{
-    AbortMultipartRequest, CreateBucketRequest, DeleteBucketRequest, GetBucketPolicyRequest,
-    InitiateMultipartRequest, ListBucketsRequest, NativeMutationContext, ObjectMetadata,
-    PutBucketPolicyRequest, PutObjectRequest, WatchBucketMetadataRequest,
+    AbortMultipartRequest, CreateBucketRequest, DeleteBucketPolicyRequest, DeleteBucketRequest,
+    GetBucketPolicyRequest, InitiateMultipartRequest, ListBucketsRequest, NativeMutationContext,
+    ObjectMetadata, PutBucketPolicyRequest, PutObjectRequest, WatchBucketMetadataRequest,
 };
 use anvil::tasks::TaskStatus;
 use futures_util::StreamExt;
@@ -153,6 +153,66 @@ async fn concurrent_bucket_creates_allocate_unique_ids() {
     }
 }
 
+#[tokio::test]
+async fn concurrent_bucket_creates_stop_at_the_tenant_bucket_limit() {
+    let mut cluster = isolated_test_cluster_with_config(
+        "uses a custom default bucket limit per tenant",
+        &["test-region-1"],
+        |config| {
+            config.max_buckets_per_tenant = 2;
+        },
+    )
+    .await;
+    cluster.start_and_converge(Duration::from_secs(5)).await;
+    let actor = create_storage_test_actor(&cluster, "bucket-limit").await;
+    let create = |name: String| {
+        let actor = actor.clone();
+        async move {
+            let mut client = BucketServiceClient::connect(actor.grpc_addr.clone())
+                .await
+                .unwrap();
+            client
+                .create_bucket(authenticated(
+                    Request::new(CreateBucketRequest {
+                        bucket_name: name,
+                        region: actor.region.clone(),
+                        options: None,
+                    }),
+                    &actor.token,
+                ))
+                .await
+        }
+    };
+
+    let results = futures_util::future::join_all(
+        (0..4).map(|ordinal| create(unique_test_name(&format!("limited-{ordinal}")))),
+    )
+    .await;
+    let created = results.iter().filter(|result| result.is_ok()).count();
+    assert_eq!(
+        created, 2,
+        "creates must stop at the default limit: {results:?}"
+    );
+    for result in &results {
+        if let Err(status) = result {
+            assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        }
+    }
+
+    cluster.states[0]
+        .persistence
+        .set_tenant_max_buckets(actor.tenant_id, Some(3))
+        .await
+        .unwrap();
+    create(unique_test_name("limited-override"))
+        .await
+        .expect("the tenant override raises the limit");
+    let status = create(unique_test_name("limited-over"))
+        .await
+        .expect_err("the override is a limit too");
+    assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+}
+
 #[tokio::test]
 async fn test_task_claim_marks_tasks_running_before_execution() {
     let cluster = isolated_test_cluster(
@@ -260,6 +320,9 @@ async fn test_delete_bucket_rejects_retained_objects() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -404,7 +467,7 @@ async fn test_list_buckets() {
 }
 
 #[tokio::test]
-async fn test_get_bucket_policy_reflects_public_read_flag() {
+async fn test_bucket_policy_reports_public_read_flag_until_deleted() {
     let cluster = shared_docker_test_cluster().await;
     let actor = create_bucket_test_actor(&cluster, "bucket-policy").await;
 
@@ -418,7 +481,7 @@ async fn test_get_bucket_policy_reflects_public_read_flag() {
         .await
         .unwrap();
 
-    let policy = bucket_client
+    let missing = bucket_client
         .get_bucket_policy(authenticated(
             Request::new(GetBucketPolicyRequest {
                 bucket_name: bucket_name.clone(),
@@ -426,11 +489,9 @@ async fn test_get_bucket_policy_reflects_public_read_flag() {
             &actor.token,
         ))
         .await
-        .unwrap()
-        .into_inner()
-        .policy_json;
-    let policy: serde_json::Value = serde_json::from_str(&policy).unwrap();
-    assert_eq!(policy["is_public_read"], false);
+        .unwrap_err();
+    assert_eq!(missing.code(), tonic::Code::NotFound);
+    assert!(missing.message().starts_with("NoSuchBucketPolicy"));
 
     bucket_client
         .put_bucket_policy(authenticated(
@@ -446,7 +507,9 @@ async fn test_get_bucket_policy_reflects_public_read_flag() {
 
     let policy = bucket_client
         .get_bucket_policy(authenticated(
-            Request::new(GetBucketPolicyRequest { bucket_name }),
+            Request::new(GetBucketPolicyRequest {
+                bucket_name: bucket_name.clone(),
+            }),
             &actor.token,
         ))
         .await
@@ -455,6 +518,25 @@ async fn test_get_bucket_policy_reflects_public_read_flag() {
         .policy_json;
     let policy: serde_json::Value = serde_json::from_str(&policy).unwrap();
     assert_eq!(policy["is_public_read"], true);
+
+    bucket_client
+        .delete_bucket_policy(authenticated(
+            Request::new(DeleteBucketPolicyRequest {
+                bucket_name: bucket_name.clone(),
+                options: None,
+            }),
+            &actor.token,
+        ))
+        .await
+        .unwrap();
+    let deleted = bucket_client
+        .get_bucket_policy(authenticated(
+            Request::new(GetBucketPolicyRequest { bucket_name }),
+            &actor.token,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(deleted.code(), tonic::Code::NotFound);
 }
 
 #[tokio::test]
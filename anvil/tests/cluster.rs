@@ -1,12 +1,17 @@
 #![recursion_limit = "256"]
 
-use std::{sync::Arc, time::Duration};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
-use anvil::cluster::{ClusterBehaviour, ClusterEvent, ClusterMessage, create_swarm};
+use anvil::cache::MetadataCache;
+use anvil::cluster::{
+    ClusterBehaviour, ClusterEvent, ClusterMessage, ClusterState, MetadataEvent, create_swarm,
+    run_gossip,
+};
 use chrono::Utc;
 use futures_util::StreamExt;
 use libp2p::{Swarm, gossipsub, swarm::SwarmEvent};
 use tempfile::TempDir;
+use tokio::sync::RwLock;
 
 fn cluster_test_config(secret: &str) -> (TempDir, Arc<anvil::config::Config>) {
     let storage = tempfile::tempdir().unwrap();
@@ -183,6 +188,8 @@ async fn test_cluster_gossip() {
         peer_id: *swarm1.local_peer_id(),
         p2p_addrs: vec!["/ip4/127.0.0.1/udp/1234/quic-v1".to_string()],
         grpc_addr: "127.0.0.1:50051".to_string(),
+        zone: Some("zone-a".to_string()),
+        free_bytes: Some(512 << 30),
         timestamp: Utc::now().timestamp(),
         signature: vec![],
     };
@@ -195,6 +202,8 @@ async fn test_cluster_gossip() {
     let received_message = receive_gossip_message(&mut swarm1, &mut swarm2).await;
     assert!(received_message.verify("test-secret").is_ok());
     assert_eq!(received_message.peer_id, *swarm1.local_peer_id());
+    assert_eq!(received_message.zone.as_deref(), Some("zone-a"));
+    assert_eq!(received_message.free_bytes, Some(512 << 30));
 }
 
 #[tokio::test]
@@ -218,6 +227,8 @@ async fn test_cluster_gossip_invalid_secret() {
         peer_id: *swarm1.local_peer_id(),
         p2p_addrs: vec!["/ip4/127.0.0.1/udp/1234/quic-v1".to_string()],
         grpc_addr: "127.0.0.1:50051".to_string(),
+        zone: None,
+        free_bytes: None,
         timestamp: Utc::now().timestamp(),
         signature: vec![],
     };
@@ -230,3 +241,74 @@ async fn test_cluster_gossip_invalid_secret() {
     let received_message = receive_gossip_message(&mut swarm1, &mut swarm2).await;
     assert!(received_message.verify("secret-2").is_err());
 }
+
+fn spawn_gossip(
+    swarm: Swarm<ClusterBehaviour>,
+    config: &anvil::config::Config,
+    cluster_state: ClusterState,
+    peer_timeout: Duration,
+) -> (
+    tokio::task::JoinHandle<anyhow::Result<()>>,
+    tokio::sync::mpsc::Sender<MetadataEvent>,
+) {
+    let (events_tx, events_rx) = tokio::sync::mpsc::channel(1);
+    let task = tokio::spawn(run_gossip(
+        swarm,
+        cluster_state,
+        config.public_api_addr.clone(),
+        None,
+        config.storage_path.clone(),
+        config.cluster_secret.clone(),
+        MetadataCache::new(config),
+        peer_timeout,
+        events_rx,
+    ));
+    (task, events_tx)
+}
+
+#[tokio::test]
+async fn stopped_peer_is_evicted_from_cluster_state() {
+    let peer_timeout = Duration::from_secs(3);
+    let (_storage1, config1) = cluster_test_config("test-secret");
+    let (_storage2, config2) = cluster_test_config("test-secret");
+    let mut swarm1 = create_swarm(config1.clone()).await.unwrap();
+    let mut swarm2 = create_swarm(config2.clone()).await.unwrap();
+    let peer2 = *swarm2.local_peer_id();
+
+    let listen_addr = first_listen_addr(&mut swarm1).await;
+    swarm2.dial(listen_addr).unwrap();
+
+    let state1: ClusterState = Arc::new(RwLock::new(HashMap::new()));
+    let state2: ClusterState = Arc::new(RwLock::new(HashMap::new()));
+    let (node1, _events1) = spawn_gossip(swarm1, &config1, state1.clone(), peer_timeout);
+    let (node2, _events2) = spawn_gossip(swarm2, &config2, state2, peer_timeout);
+
+    tokio::time::timeout(Duration::from_secs(20), async {
+        while !state1.read().await.contains_key(&peer2) {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("node 1 never learned about node 2");
+
+    // Stop node 2; node 1 must drop it once the peer timeout passes.
+    node2.abort();
+    tokio::time::timeout(peer_timeout * 2, async {
+        while state1.read().await.contains_key(&peer2) {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await
+    .expect("stopped node was not evicted within the peer timeout");
+    node1.abort();
+}
+
+#[test]
+fn metadata_event_debug_output_redacts_api_keys() {
+    let event = MetadataEvent::TenantUpdated {
+        api_key: "tenant-secret-key".to_string(),
+    };
+    let logged = format!("{event:?}");
+    assert!(logged.contains("TenantUpdated"));
+    assert!(!logged.contains("tenant-secret-key"));
+}
@@ -97,6 +97,9 @@ async fn test_distributed_reconstruction_on_node_failure() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let mut chunks = vec![PutObjectRequest {
         data: Some(anvil::anvil_api::put_object_request::Data::Metadata(
@@ -127,6 +127,9 @@ async fn test_distributed_put_and_get() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let mut chunks = vec![PutObjectRequest {
         data: Some(anvil_api::put_object_request::Data::Metadata(metadata)),
@@ -260,6 +263,9 @@ async fn test_single_node_put() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let chunks = vec![
         PutObjectRequest {
@@ -344,6 +350,9 @@ async fn test_multi_region_list_and_isolation() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let chunks = vec![
         PutObjectRequest {
@@ -590,6 +590,7 @@ fn raw_blob_and_shard_internal_paths_are_not_public_service_apis() {
     for method in [
         "pub(crate) async fn put_internal_shard",
         "pub(crate) async fn read_internal_shard_range",
+        "pub(crate) async fn open_internal_shard_reader",
         "pub(crate) async fn get_internal_shard_receipt",
     ] {
         assert!(
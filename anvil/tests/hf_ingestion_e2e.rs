@@ -169,6 +169,7 @@ async fn hf_ingestion_config_json() {
                 target_prefix: "gpt-oss-20b".into(),
                 include_globs: vec!["config.json".into()],
                 exclude_globs: vec![],
+                repo_type: String::new(),
             }),
             &token,
         ))
@@ -233,6 +234,7 @@ async fn hf_ingestion_config_json() {
                 target_prefix: "gpt-oss-20b".into(),
                 include_globs: vec!["README.md".into()],
                 exclude_globs: vec![],
+                repo_type: String::new(),
             }),
             &token,
         ))
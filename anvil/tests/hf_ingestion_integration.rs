@@ -93,6 +93,58 @@ async fn hf_keys_are_tenant_scoped() {
     assert!(second_keys.iter().any(|key| key.name == key_name));
 }
 
+#[tokio::test]
+async fn hf_ingestion_rejects_malformed_repo_before_enqueueing() {
+    let cluster = shared_docker_test_cluster().await;
+    let actor = create_docker_storage_test_actor(&cluster, "hf-bad-repo").await;
+    let mut client =
+        anvil::anvil_api::hf_ingestion_service_client::HfIngestionServiceClient::connect(
+            actor.grpc_addr.clone(),
+        )
+        .await
+        .unwrap();
+
+    for repo in ["openai/", "a/b/c", "not a repo", "../escape"] {
+        let status = client
+            .start_ingestion(authorized(
+                tonic::Request::new(anvil::anvil_api::StartHfIngestionRequest {
+                    key_name: "unused-key".into(),
+                    repo: repo.into(),
+                    revision: String::new(),
+                    target_bucket: "unused-bucket".into(),
+                    target_region: actor.region.clone(),
+                    target_prefix: String::new(),
+                    include_globs: vec![],
+                    exclude_globs: vec![],
+                    repo_type: String::new(),
+                }),
+                &actor.token,
+            ))
+            .await
+            .expect_err("malformed repo ids must be rejected before lookup or enqueue");
+        assert_eq!(status.code(), tonic::Code::InvalidArgument, "repo {repo}");
+    }
+
+    let status = client
+        .start_ingestion(authorized(
+            tonic::Request::new(anvil::anvil_api::StartHfIngestionRequest {
+                key_name: "unused-key".into(),
+                repo: "owner/repo".into(),
+                revision: String::new(),
+                target_bucket: "unused-bucket".into(),
+                target_region: actor.region.clone(),
+                target_prefix: String::new(),
+                include_globs: vec![],
+                exclude_globs: vec![],
+                repo_type: "collection".into(),
+            }),
+            &actor.token,
+        ))
+        .await
+        .expect_err("unknown repo types must be rejected before lookup or enqueue");
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+}
+
 #[tokio::test]
 async fn hf_ingestion_single_file_integration() {
     let cluster = shared_docker_test_cluster().await;
@@ -154,6 +206,7 @@ async fn hf_ingestion_single_file_integration() {
         target_prefix: "gpt-oss-20b".into(),
         include_globs: vec!["config.json".into()],
         exclude_globs: vec![],
+        repo_type: String::new(),
     });
     sreq.metadata_mut().insert(
         "authorization",
@@ -329,6 +382,7 @@ async fn hf_ingestion_permission_denied() {
         target_prefix: "gpt-oss-20b".into(),
         include_globs: vec!["config.json".into()],
         exclude_globs: vec![],
+        repo_type: String::new(),
     });
     // Create a same-tenant app with no HF ingestion grant.
     let limited_actor = cluster
@@ -58,6 +58,7 @@ async fn get_app_token(grpc_addr: &str, client_id: &str, client_secret: &str) ->
         .get_access_token(GetAccessTokenRequest {
             client_id: client_id.to_string(),
             client_secret: client_secret.to_string(),
+            requested_ttl_secs: 0,
         })
         .await
         .unwrap()
@@ -326,6 +327,9 @@ async fn put_json_object(
                 content_type: Some("application/json".to_string()),
                 user_metadata_json: String::new(),
                 storage_class: None,
+                retention: None,
+                if_match: None,
+                if_none_match: None,
             },
         )),
     };
@@ -128,6 +128,9 @@ async fn test_full_text_index_builds_from_object_write_task() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -296,6 +299,9 @@ async fn test_full_text_index_build_extracts_json_pointer_from_object_write_task
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -73,6 +73,9 @@ async fn test_vector_index_builds_from_object_write_task() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -370,6 +373,9 @@ async fn test_vector_index_build_records_dimension_mismatch_diagnostic() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -503,6 +509,9 @@ async fn test_hybrid_index_builds_text_and_vector_segments_from_object_write_tas
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
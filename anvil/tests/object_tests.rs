@@ -85,6 +85,9 @@ async fn put_native_object_bytes(
                     content_type: content_type.map(ToOwned::to_owned),
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -349,6 +352,9 @@ fn put_object_chunks(
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -129,6 +129,9 @@ async fn test_mutation_batch_rejects_stale_lease_fence_for_state_update() {
                 content_type: Some("application/json".to_string()),
                 user_metadata_json: String::new(),
                 storage_class: None,
+                retention: None,
+                if_match: None,
+                if_none_match: None,
             },
         )),
     };
@@ -690,6 +693,9 @@ async fn test_compose_object_concatenates_sources_in_order() {
             content_type: None,
             user_metadata_json: String::new(),
             storage_class: None,
+            retention: None,
+            if_match: None,
+            if_none_match: None,
         };
         let chunks = vec![
             PutObjectRequest {
@@ -49,6 +49,9 @@ async fn test_copy_object_creates_independent_destination_version() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let chunks = vec![
         PutObjectRequest {
@@ -73,6 +76,30 @@ async fn test_copy_object_creates_independent_destination_version() {
         .unwrap()
         .into_inner();
 
+    let mut mismatched_copy_req = Request::new(CopyObjectRequest {
+        source_bucket_name: bucket_name.clone(),
+        source_object_key: source_key.clone(),
+        source_version_id: Some(put_res.version_id.clone()),
+        destination_bucket_name: bucket_name.clone(),
+        destination_object_key: destination_key.clone(),
+        mutation_context: Some(native_mutation_context(
+            &actor,
+            bucket_id,
+            "copy-object-mismatched-source",
+        )),
+        source_if_match: Some("\"not-the-source-etag\"".to_string()),
+        ..Default::default()
+    });
+    mismatched_copy_req.metadata_mut().insert(
+        "authorization",
+        format!("Bearer {}", token).parse().unwrap(),
+    );
+    let mismatched = object_client
+        .copy_object(mismatched_copy_req)
+        .await
+        .expect_err("a failing copy-source precondition must reject the copy");
+    assert_eq!(mismatched.code(), tonic::Code::FailedPrecondition);
+
     let mut copy_req = Request::new(CopyObjectRequest {
         source_bucket_name: bucket_name.clone(),
         source_object_key: source_key.clone(),
@@ -80,6 +107,8 @@ async fn test_copy_object_creates_independent_destination_version() {
         destination_bucket_name: bucket_name.clone(),
         destination_object_key: destination_key.clone(),
         mutation_context: Some(native_mutation_context(&actor, bucket_id, "copy-object")),
+        source_if_match: Some(format!("\"{}\"", put_res.etag)),
+        ..Default::default()
     });
     copy_req.metadata_mut().insert(
         "authorization",
@@ -330,6 +359,9 @@ async fn test_watch_prefix_streams_snapshot_and_live_events() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let chunks = vec![
         PutObjectRequest {
@@ -670,6 +702,9 @@ async fn test_grpc_object_metadata_round_trips_through_get_head_and_list() {
                 content_type: Some("application/json".to_string()),
                 user_metadata_json: user_metadata.clone(),
                 storage_class: None,
+                retention: None,
+                if_match: None,
+                if_none_match: None,
             },
         )),
     };
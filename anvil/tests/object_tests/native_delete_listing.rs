@@ -810,6 +810,9 @@ async fn test_delete_object_creates_delete_marker() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let chunks = vec![
         PutObjectRequest {
@@ -966,6 +969,9 @@ async fn test_delete_object_specific_version_removes_only_that_version() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -1000,6 +1006,9 @@ async fn test_delete_object_specific_version_removes_only_that_version() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -1286,6 +1295,9 @@ async fn test_utf8_object_keys_with_spaces_round_trip() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -1347,6 +1359,9 @@ async fn test_utf8_object_keys_with_spaces_round_trip() {
                         content_type: None,
                         user_metadata_json: String::new(),
                         storage_class: None,
+                        retention: None,
+                        if_match: None,
+                        if_none_match: None,
                     },
                 )),
             },
@@ -1459,6 +1474,9 @@ async fn test_listing_omits_reserved_internal_object_keys() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -46,6 +46,9 @@ async fn test_patch_json_object_writes_new_merged_version() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let initial_json = br#"{"title":"old","stats":{"open":2,"closed":1},"remove_me":true}"#;
     let chunks = vec![
@@ -173,6 +176,9 @@ async fn test_list_objects_with_delimiter() {
             content_type: None,
             user_metadata_json: String::new(),
             storage_class: None,
+            retention: None,
+            if_match: None,
+            if_none_match: None,
         };
         let chunks = vec![
             PutObjectRequest {
@@ -52,6 +52,9 @@ async fn test_native_object_api_rejects_reserved_internal_namespaces() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -82,6 +85,9 @@ async fn test_native_object_api_rejects_reserved_internal_namespaces() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -194,6 +200,9 @@ async fn test_native_object_api_rejects_reserved_internal_namespaces() {
                         content_type: None,
                         user_metadata_json: String::new(),
                         storage_class: None,
+                        retention: None,
+                        if_match: None,
+                        if_none_match: None,
                     },
                 )),
             },
@@ -271,6 +280,7 @@ async fn test_native_object_api_rejects_reserved_internal_namespaces() {
         destination_bucket_name: bucket_name.clone(),
         destination_object_key: "visible/copied-from-reserved.json".to_string(),
         mutation_context: Some(native_mutation_context(&actor, bucket_id, "copy-object")),
+        ..Default::default()
     });
     copy_from_reserved.metadata_mut().insert(
         "authorization",
@@ -285,6 +295,7 @@ async fn test_native_object_api_rejects_reserved_internal_namespaces() {
         destination_bucket_name: bucket_name.clone(),
         destination_object_key: reserved_key.clone(),
         mutation_context: Some(native_mutation_context(&actor, bucket_id, "copy-object")),
+        ..Default::default()
     });
     copy_to_reserved.metadata_mut().insert(
         "authorization",
@@ -516,6 +527,9 @@ async fn test_head_object() {
         content_type: None,
         user_metadata_json: String::new(),
         storage_class: None,
+        retention: None,
+        if_match: None,
+        if_none_match: None,
     };
     let chunks = vec![
         PutObjectRequest {
@@ -615,6 +629,9 @@ async fn test_object_payloads_are_corestore_backed_and_readable() {
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
@@ -649,6 +666,9 @@ async fn test_object_payloads_are_corestore_backed_and_readable() {
                 content_type: None,
                 user_metadata_json: String::new(),
                 storage_class: None,
+                retention: None,
+                if_match: None,
+                if_none_match: None,
             },
         )),
     }];
@@ -864,6 +884,9 @@ async fn test_object_version_records_index_policy_snapshot_and_mutation_metadata
                     content_type: None,
                     user_metadata_json: String::new(),
                     storage_class: None,
+                    retention: None,
+                    if_match: None,
+                    if_none_match: None,
                 },
             )),
         },
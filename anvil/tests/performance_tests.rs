@@ -239,6 +239,7 @@ async fn performance_docker_end_user_flow() {
                 .get_access_token(GetAccessTokenRequest {
                     client_id: client_id.clone(),
                     client_secret: client_secret.clone(),
+                    requested_ttl_secs: 0,
                 })
                 .await
                 .unwrap()
@@ -298,6 +299,9 @@ async fn performance_docker_end_user_flow() {
                         content_type: Some("text/plain".to_string()),
                         user_metadata_json: String::new(),
                         storage_class: None,
+                        retention: None,
+                        if_match: None,
+                        if_none_match: None,
                     },
                 )),
             };
@@ -463,6 +467,9 @@ async fn put_json_object(
                 content_type: Some("application/json".to_string()),
                 user_metadata_json: String::new(),
                 storage_class: None,
+                retention: None,
+                if_match: None,
+                if_none_match: None,
             },
         )),
     };
@@ -84,6 +84,9 @@ async fn rust_client_calls_live_native_api() {
                 content_type: None,
                 user_metadata_json: String::new(),
                 storage_class: None,
+                retention: None,
+                if_match: None,
+                if_none_match: None,
             })),
         },
         PutObjectRequest {
@@ -112,6 +112,100 @@ async fn test_s3_put_write_etag_preconditions() {
     );
 }
 
+#[tokio::test]
+async fn test_s3_put_storage_class_round_trips_through_head_and_list() {
+    let cluster = shared_docker_test_cluster().await;
+    let actor = create_docker_app(&cluster, "s3-storage-class").await;
+
+    let client = s3_client_for_docker_app(&cluster, &actor);
+    let bucket = unique_test_name("s3-storage-class");
+
+    client
+        .create_bucket()
+        .bucket(&bucket)
+        .send()
+        .await
+        .expect("S3 CreateBucket should succeed");
+    client
+        .put_object()
+        .bucket(&bucket)
+        .key("cold.txt")
+        .storage_class(StorageClass::Glacier)
+        .body(ByteStream::from_static(b"rarely read"))
+        .send()
+        .await
+        .expect("cold PUT should succeed");
+    client
+        .put_object()
+        .bucket(&bucket)
+        .key("hot.txt")
+        .body(ByteStream::from_static(b"often read"))
+        .send()
+        .await
+        .expect("standard PUT should succeed");
+
+    let head = client
+        .head_object()
+        .bucket(&bucket)
+        .key("cold.txt")
+        .send()
+        .await
+        .expect("HEAD should succeed");
+    assert_eq!(head.storage_class(), Some(&StorageClass::Glacier));
+    let body = client
+        .get_object()
+        .bucket(&bucket)
+        .key("cold.txt")
+        .send()
+        .await
+        .expect("cold objects should stay directly readable")
+        .body
+        .collect()
+        .await
+        .unwrap()
+        .into_bytes();
+    assert_eq!(&body[..], b"rarely read");
+
+    let listed = client
+        .list_objects_v2()
+        .bucket(&bucket)
+        .send()
+        .await
+        .expect("ListObjectsV2 should succeed");
+    let classes = listed
+        .contents()
+        .iter()
+        .map(|object| {
+            (
+                object.key().unwrap_or_default().to_string(),
+                object
+                    .storage_class()
+                    .map(|class| class.as_str().to_string()),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        classes,
+        vec![
+            ("cold.txt".to_string(), Some("GLACIER".to_string())),
+            ("hot.txt".to_string(), Some("STANDARD".to_string())),
+        ]
+    );
+
+    let invalid = client
+        .put_object()
+        .bucket(&bucket)
+        .key("invalid.txt")
+        .storage_class(StorageClass::from("NOT_A_CLASS"))
+        .body(ByteStream::from_static(b"rejected"))
+        .send()
+        .await;
+    assert!(
+        invalid.is_err(),
+        "unknown storage classes should be rejected"
+    );
+}
+
 #[tokio::test]
 async fn test_s3_list_versions_and_get_filter_by_relationship_authorization() {
     let cluster = shared_docker_test_cluster().await;
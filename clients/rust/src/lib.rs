@@ -35,20 +35,22 @@ pub mod proto {
         CreateHostAliasRequest, CreateIndexRequest, CreateObjectLinkRequest,
         CreatePersonalDbGroupRequest, CreatePersonalDbProjectionRequest, DType,
         DeleteApplicationCredentialRequest, DeleteApplicationCredentialResponse,
-        DeleteBucketRequest, DeleteBucketResponse, DeleteHfKeyRequest, DeleteHfKeyResponse,
-        DeleteHostAliasRequest, DeleteObjectLinkRequest, DeleteObjectRequest, DeleteObjectResponse,
-        DisableIndexRequest, DropIndexRequest, DropIndexResponse, ForceReleaseTaskLeaseRequest,
+        DeleteBucketPolicyRequest, DeleteBucketPolicyResponse, DeleteBucketRequest,
+        DeleteBucketResponse, DeleteHfKeyRequest, DeleteHfKeyResponse, DeleteHostAliasRequest,
+        DeleteObjectLinkRequest, DeleteObjectRequest, DeleteObjectResponse, DisableIndexRequest,
+        DropIndexRequest, DropIndexResponse, ForceReleaseTaskLeaseRequest,
         ForceReleaseTaskLeaseResponse, GetAccessTokenRequest, GetAccessTokenResponse,
         GetAuthzSchemaBindingRequest, GetAuthzSchemaBindingResponse, GetAuthzSchemaRequest,
         GetAuthzSchemaResponse, GetBoundaryMigrationRequest, GetBoundarySchemaRequest,
         GetBucketPolicyRequest, GetBucketPolicyResponse, GetGitBlobByPathRequest,
         GetGitBlobByPathResponse, GetGitObjectRequest, GetGitObjectResponse,
-        GetHfIngestionStatusRequest, GetHfIngestionStatusResponse, GetObjectRequest,
-        GetObjectResponse, GetPackageVersionRequest, GetPartitionMapRequest,
-        GetPersonalDbGroupRequest, GetPersonalDbProjectionRequest, GetSagaRequest,
-        GetStorageClassRequest, GetTensorChunk, GetTensorRequest, GetTensorsRequest,
-        GetTransactionRequest, GitBlobLocation, GitPackMetadata, GitTreeEntryRecord,
-        GrantAccessRequest, GrantAccessResponse, HeadObjectRequest, HeadObjectResponse, HfKey,
+        GetHfIngestionItemsRequest, GetHfIngestionItemsResponse, GetHfIngestionStatusRequest,
+        GetHfIngestionStatusResponse, GetObjectRequest, GetObjectResponse,
+        GetPackageVersionRequest, GetPartitionMapRequest, GetPersonalDbGroupRequest,
+        GetPersonalDbProjectionRequest, GetSagaRequest, GetStorageClassRequest, GetTensorChunk,
+        GetTensorDataRequest, GetTensorRequest, GetTensorsRequest, GetTransactionRequest,
+        GitBlobLocation, GitPackMetadata, GitTreeEntryRecord, GrantAccessRequest,
+        GrantAccessResponse, HeadObjectRequest, HeadObjectResponse, HfIngestionItem, HfKey,
         IndexBuildRecord, IndexDefinitionRecord, IndexDefinitionResponse, IndexDiagnosticRecord,
         IndexKind, IndexMaintenanceMode, IndexPolicySnapshotMode, IndexQueryHit,
         InitiateMultipartRequest, InitiateMultipartResponse, LeaseFencePrecondition,
@@ -68,10 +70,10 @@ pub mod proto {
         MutationBatchDeleteObject, MutationBatchOperation, MutationBatchOperationReceipt,
         MutationBatchPatchJsonObject, MutationBatchPutObject, MutationBatchRequest,
         MutationBatchResponse, MutationResponse, NativeMutationContext, ObjectInfo,
-        ObjectLinkResponse, ObjectMetadata, ObjectRef, ObjectSummary, ObjectVersionPrecondition,
-        ObjectVersionSummary, PackageVersion, PartitionMap, PatchJsonObjectRequest,
-        PatchJsonObjectResponse, PersonalDbCatchUpEntry, PersonalDbCatchUpRequest,
-        PersonalDbCatchUpResponse, PersonalDbCommitCertificateRecord,
+        ObjectLinkResponse, ObjectMetadata, ObjectOwner, ObjectRef, ObjectSummary,
+        ObjectVersionPrecondition, ObjectVersionSummary, PackageVersion, PartitionMap,
+        PatchJsonObjectRequest, PatchJsonObjectResponse, PersonalDbCatchUpEntry,
+        PersonalDbCatchUpRequest, PersonalDbCatchUpResponse, PersonalDbCommitCertificateRecord,
         PersonalDbCommittedHeadRecord, PersonalDbGroupManifestRecord, PersonalDbGroupResponse,
         PersonalDbLogRecord, PersonalDbProjectionResponse, PersonalDbSnapshotsHeadRecord,
         PersonalDbVoterAck, PublicMutationContext, PutAuthzSchemaRequest, PutAuthzSchemaResponse,
@@ -79,17 +81,18 @@ pub mod proto {
         PutGitPackRequest, PutGitPackResponse, PutModelManifestRequest, PutModelManifestResponse,
         PutNodeRequest, PutObjectRequest, PutObjectResponse, PutPackageBlobRequest,
         PutPackageVersionRequest, PutRegionRequest, PutRegistryRefRequest, QueryIndexRequest,
-        QueryIndexResponse, QuerySpecRequest, QuerySpecResponse, ReadAppendStreamRequest,
-        ReadAppendStreamResponse, ReadAuthzTuplesRequest, ReadAuthzTuplesResponse, ReadConsistency,
-        ReadObjectLinkRequest, ReadTaskLeaseRequest, ReadTaskLeaseResponse,
-        RepairAuthzDerivedIndexRequest, RepairAuthzDerivedIndexResponse,
-        RepairDirectoryIndexRequest, RepairDirectoryIndexResponse, RepairFindingRecord,
-        RepairIndexRequest, RepairIndexResponse, RepairPersonalDbLogChainRequest,
-        RepairPersonalDbLogChainResponse, RepairSubjectRecord, ResolveBlockedSagaRequest,
-        RevokeAccessRequest, RevokeAccessResponse, RollbackTransactionRequest,
-        RollbackTransactionResponse, RotateApplicationCredentialSecretRequest, SagaBlockSemantics,
-        SagaCompensationKind, SagaCompensationOperationContext, SagaCompensationSpec, SagaEvent,
-        SagaExecutionPolicy, SagaLifecycleState, SagaOperationContext, SagaOutcome,
+        QueryIndexResponse, QuerySpecRequest, QuerySpecResponse, QueryTensorsRequest,
+        QueryTensorsResponse, ReadAppendStreamRequest, ReadAppendStreamResponse,
+        ReadAuthzTuplesRequest, ReadAuthzTuplesResponse, ReadConsistency, ReadObjectLinkRequest,
+        ReadTaskLeaseRequest, ReadTaskLeaseResponse, RepairAuthzDerivedIndexRequest,
+        RepairAuthzDerivedIndexResponse, RepairDirectoryIndexRequest, RepairDirectoryIndexResponse,
+        RepairFindingRecord, RepairIndexRequest, RepairIndexResponse,
+        RepairPersonalDbLogChainRequest, RepairPersonalDbLogChainResponse, RepairSubjectRecord,
+        ResolveBlockedSagaRequest, RevokeAccessRequest, RevokeAccessResponse,
+        RollbackTransactionRequest, RollbackTransactionResponse,
+        RotateApplicationCredentialSecretRequest, SagaBlockSemantics, SagaCompensationKind,
+        SagaCompensationOperationContext, SagaCompensationSpec, SagaEvent, SagaExecutionPolicy,
+        SagaLifecycleState, SagaOperationContext, SagaOutcome,
         SagaRecordedCompensationOperationReceipt, SagaRecordedOperationReceipt, SagaRetryPolicy,
         SagaStatus, SagaTargetRoot, SagaTransactionBlockState, SealAppendStreamSegmentRequest,
         SealAppendStreamSegmentResponse, SealSagaCompensationProgramRequest,